@@ -1,8 +1,17 @@
 use std::hint::black_box;
 use std::time::Duration;
 
-use bench_note_checker::benchmark_names::{BENCH_GROUP, BENCH_MIXED_NOTES};
-use bench_note_checker::{MixedNotesConfig, run_mixed_notes_check, setup_mixed_notes_benchmark};
+use bench_note_checker::benchmark_names::{
+    BENCH_GROUP,
+    BENCH_MIXED_NOTES,
+    BENCH_MIXED_NOTES_PARALLEL,
+};
+use bench_note_checker::{
+    MixedNotesConfig,
+    run_mixed_notes_check,
+    run_mixed_notes_check_parallel,
+    setup_mixed_notes_benchmark,
+};
 use criterion::{Criterion, SamplingMode, criterion_group, criterion_main};
 use miden_tx::MAX_NUM_CHECKER_NOTES;
 
@@ -27,6 +36,25 @@ fn note_checker_benchmarks(c: &mut Criterion) {
         });
     }
 
+    // Compare checking the notes sequentially with checking chunks of them in parallel. The
+    // number of failing notes is chosen such that all notes fit into a single sequential check.
+    let failing_count = MAX_NUM_CHECKER_NOTES - 2;
+    let setup = setup_mixed_notes_benchmark(MixedNotesConfig { failing_note_count: failing_count })
+        .expect("failed to set up mixed notes benchmark");
+    for parallelism in [1, 2, 4] {
+        group.bench_function(
+            format!("{BENCH_MIXED_NOTES_PARALLEL}_{failing_count}_failing_{parallelism}_chunks"),
+            |b| {
+                b.to_async(
+                    tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap(),
+                )
+                .iter(|| async {
+                    black_box(run_mixed_notes_check_parallel(&setup, parallelism).await)
+                });
+            },
+        );
+    }
+
     group.finish();
 }
 
@@ -9,12 +9,13 @@ use miden_objects::testing::account_id::{
 };
 use miden_testing::{Auth, MockChain, TxContextInput};
 use miden_tx::auth::UnreachableAuth;
-use miden_tx::{NoteConsumptionChecker, TransactionExecutor};
+use miden_tx::{NoteCheckerConfig, NoteConsumptionChecker, TransactionExecutor};
 use serde::{Deserialize, Serialize};
 
 pub mod benchmark_names {
     pub const BENCH_GROUP: &str = "note_checker";
     pub const BENCH_MIXED_NOTES: &str = "mixed_successful_and_failing_notes";
+    pub const BENCH_MIXED_NOTES_PARALLEL: &str = "mixed_successful_and_failing_notes_parallel";
 }
 
 /// Benchmark result measurements for note checker performance.
@@ -119,6 +120,24 @@ pub fn setup_mixed_notes_benchmark(config: MixedNotesConfig) -> anyhow::Result<M
 
 /// Runs the note consumability check and validates the results.
 pub async fn run_mixed_notes_check(setup: &MixedNotesSetup) -> anyhow::Result<()> {
+    run_mixed_notes_check_with_parallelism(setup, None).await
+}
+
+/// Runs the note consumability check on `parallelism` chunks of the notes concurrently and
+/// validates the results.
+pub async fn run_mixed_notes_check_parallel(
+    setup: &MixedNotesSetup,
+    parallelism: usize,
+) -> anyhow::Result<()> {
+    run_mixed_notes_check_with_parallelism(setup, Some(parallelism)).await
+}
+
+/// Runs the note consumability check, either sequentially or in parallel if `parallelism` is
+/// provided, and validates the results.
+async fn run_mixed_notes_check_with_parallelism(
+    setup: &MixedNotesSetup,
+    parallelism: Option<usize>,
+) -> anyhow::Result<()> {
     // Create transaction context with the setup data.
     let tx_context = setup
         .mock_chain
@@ -132,9 +151,31 @@ pub async fn run_mixed_notes_check(setup: &MixedNotesSetup) -> anyhow::Result<()
     let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context);
     let checker = NoteConsumptionChecker::new(&executor);
 
-    let result = checker
-        .check_notes_consumability(setup.target_account_id, block_ref, setup.notes.clone(), tx_args)
-        .await?;
+    let result = match parallelism {
+        None => {
+            checker
+                .check_notes_consumability(
+                    setup.target_account_id,
+                    block_ref,
+                    setup.notes.clone(),
+                    tx_args,
+                    NoteCheckerConfig::default(),
+                )
+                .await?
+        },
+        Some(parallelism) => {
+            checker
+                .check_notes_parallel(
+                    setup.target_account_id,
+                    block_ref,
+                    setup.notes.clone(),
+                    tx_args,
+                    NoteCheckerConfig::default(),
+                    parallelism,
+                )
+                .await?
+        },
+    };
 
     // Validate that we got the expected number of successful notes.
     assert_eq!(
@@ -648,6 +648,7 @@ fn is_new_error_category<'a>(last_error: &mut Option<&'a str>, current_error: &'
 fn generate_error_file_content(category: ErrorCategory, errors: Vec<NamedError>) -> Result<String> {
     let mut output = String::new();
 
+    writeln!(output, "use miden_objects::Felt;\n").unwrap();
     writeln!(output, "use crate::errors::MasmError;\n").unwrap();
 
     writeln!(
@@ -688,6 +689,28 @@ fn generate_error_file_content(category: ErrorCategory, errors: Vec<NamedError>)
         .into_diagnostic()?;
     }
 
+    let array_name = category.array_name();
+
+    writeln!(output).into_diagnostic()?;
+    writeln!(output, "/// All errors of this category.").into_diagnostic()?;
+    writeln!(output, "pub static {array_name}: [MasmError; {}] = [", errors.len())
+        .into_diagnostic()?;
+    for NamedError { name, .. } in errors.iter() {
+        writeln!(output, "    ERR_{name},").into_diagnostic()?;
+    }
+    writeln!(output, "];").into_diagnostic()?;
+
+    writeln!(
+        output,
+        r#"
+/// Returns the message of the error in this category with the provided error code, or `None` if
+/// no such error exists.
+pub fn message_for_code(code: Felt) -> Option<&'static str> {{
+    {array_name}.iter().find(|error| error.code() == code).map(MasmError::message)
+}}"#
+    )
+    .into_diagnostic()?;
+
     Ok(output)
 }
 
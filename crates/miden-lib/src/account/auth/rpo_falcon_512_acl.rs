@@ -138,6 +138,35 @@ impl AuthRpoFalcon512Acl {
 
         Ok(Self { pub_key, config })
     }
+
+    /// Adds the given procedure root to the procedures tracked by the ACL, so that calling it
+    /// requires authentication.
+    ///
+    /// This is needed when upgrading account code, as the root of a new procedure must be tracked
+    /// before it is called.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - the procedure is already tracked.
+    /// - tracking the procedure would exceed [AccountCode::MAX_NUM_PROCEDURES] procedures.
+    pub fn add_tracked_procedure(&mut self, proc_root: Word) -> Result<(), AccountError> {
+        if self.config.auth_trigger_procedures.contains(&proc_root) {
+            return Err(AccountError::other(format!(
+                "procedure {proc_root} is already tracked by the ACL"
+            )));
+        }
+
+        let max_procedures = AccountCode::MAX_NUM_PROCEDURES;
+        if self.config.auth_trigger_procedures.len() >= max_procedures {
+            return Err(AccountError::other(format!(
+                "Cannot track more than {max_procedures} procedures (account limit)"
+            )));
+        }
+
+        self.config.auth_trigger_procedures.push(proc_root);
+
+        Ok(())
+    }
 }
 
 impl From<AuthRpoFalcon512Acl> for AccountComponent {
@@ -327,4 +356,33 @@ mod tests {
             expected_slot_1: Word::from([2u32, 1, 1, 0]),
         });
     }
+
+    /// Test that procedures added after construction are tracked in the procedure roots map.
+    #[test]
+    fn test_rpo_falcon_512_acl_add_tracked_procedure() {
+        let procedures = get_basic_wallet_procedures();
+        let mut component = AuthRpoFalcon512Acl::new(
+            PublicKeyCommitment::from(Word::empty()),
+            AuthRpoFalcon512AclConfig::new().with_auth_trigger_procedures(vec![procedures[0]]),
+        )
+        .expect("component creation failed");
+
+        component.add_tracked_procedure(procedures[1]).expect("adding procedure failed");
+        assert!(component.add_tracked_procedure(procedures[1]).is_err());
+
+        let account = AccountBuilder::new([0; 32])
+            .with_auth_component(component)
+            .with_component(BasicWallet)
+            .build()
+            .expect("account building failed");
+
+        let slot_1 = account.storage().get_item(1).expect("storage slot 1 access failed");
+        assert_eq!(slot_1, Word::from([2u32, 0, 0, 0]));
+
+        let proc_root = account
+            .storage()
+            .get_map_item(2, Word::from([1u32, 0, 0, 0]))
+            .expect("storage map access failed");
+        assert_eq!(proc_root, procedures[1]);
+    }
 }
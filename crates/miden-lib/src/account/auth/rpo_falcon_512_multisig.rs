@@ -1,58 +1,126 @@
+use alloc::collections::BTreeSet;
+use alloc::format;
 use alloc::vec::Vec;
 
-use miden_objects::account::{AccountComponent, PublicKeyCommitment, StorageMap, StorageSlot};
-use miden_objects::{AccountError, Word};
+use miden_objects::account::{
+    AccountComponent,
+    AccountStorage,
+    PublicKeyCommitment,
+    StorageMap,
+    StorageSlot,
+};
+use miden_objects::{AccountError, Felt, Word};
 
 use crate::account::components::rpo_falcon_512_multisig_library;
 
 // MULTISIG AUTHENTICATION COMPONENT
 // ================================================================================================
 
+/// The index of the slot storing `[threshold, num_approvers, 0, 0]`, relative to the first slot
+/// of the component.
+const THRESHOLD_CONFIG_SLOT: u8 = 0;
+
+/// The index of the map slot storing the approver public keys, relative to the first slot of the
+/// component.
+const PUBLIC_KEYS_MAP_SLOT: u8 = 1;
+
+/// The index of the map slot storing the procedure thresholds, relative to the first slot of the
+/// component.
+const PROC_THRESHOLDS_MAP_SLOT: u8 = 3;
+
+/// The index of the map slot storing the approver weights, relative to the first slot of the
+/// component.
+const APPROVER_WEIGHTS_MAP_SLOT: u8 = 4;
+
+/// The weight of an approver for which no weight is configured.
+const DEFAULT_APPROVER_WEIGHT: u32 = 1;
+
 /// Configuration for [`AuthRpoFalcon512Multisig`] component.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AuthRpoFalcon512MultisigConfig {
     approvers: Vec<PublicKeyCommitment>,
+    weights: Vec<u32>,
     default_threshold: u32,
     proc_thresholds: Vec<(Word, u32)>,
 }
 
 impl AuthRpoFalcon512MultisigConfig {
-    /// Creates a new configuration with the given approvers and a default threshold.
+    /// Creates a new configuration with the given approvers and a default threshold, where each
+    /// approver has a weight of 1.
     ///
-    /// The `default_threshold` must be at least 1 and at most the number of approvers.
+    /// The `default_threshold` must be at least 1 and at most the number of approvers. The
+    /// approvers must be unique and their number must fit into a `u32`.
     pub fn new(
         approvers: Vec<PublicKeyCommitment>,
         default_threshold: u32,
     ) -> Result<Self, AccountError> {
+        Self::new_weighted(
+            approvers
+                .into_iter()
+                .map(|approver| (approver, DEFAULT_APPROVER_WEIGHT))
+                .collect(),
+            default_threshold,
+        )
+    }
+
+    /// Creates a new configuration with the given weighted approvers and a default threshold.
+    ///
+    /// A transaction is authorized if the sum of the weights of the approvers that signed it
+    /// reaches the threshold. The weights must be at least 1 and their sum must fit into a `u32`.
+    /// The `default_threshold` must be at least 1 and at most the total weight of the approvers.
+    /// The approvers must be unique and their number must fit into a `u32`.
+    pub fn new_weighted(
+        approvers: Vec<(PublicKeyCommitment, u32)>,
+        default_threshold: u32,
+    ) -> Result<Self, AccountError> {
+        if u32::try_from(approvers.len()).is_err() {
+            return Err(AccountError::other("number of approvers must fit into a u32"));
+        }
+        let mut unique_approvers = BTreeSet::new();
+        if !approvers
+            .iter()
+            .all(|(approver, _)| unique_approvers.insert(Word::from(*approver)))
+        {
+            return Err(AccountError::other("approvers must not contain duplicate public keys"));
+        }
+        if approvers.iter().any(|(_, weight)| *weight == 0) {
+            return Err(AccountError::other("approver weight must be at least 1"));
+        }
+        let (approvers, weights): (Vec<_>, Vec<_>) = approvers.into_iter().unzip();
+        let config = Self {
+            approvers,
+            weights,
+            default_threshold,
+            proc_thresholds: vec![],
+        };
+
+        let total_weight = config.total_weight()?;
         if default_threshold == 0 {
             return Err(AccountError::other("threshold must be at least 1"));
         }
-        if default_threshold > approvers.len() as u32 {
+        if default_threshold > total_weight {
             return Err(AccountError::other(
-                "threshold cannot be greater than number of approvers",
+                "threshold cannot be greater than the total weight of the approvers",
             ));
         }
 
-        Ok(Self {
-            approvers,
-            default_threshold,
-            proc_thresholds: vec![],
-        })
+        Ok(config)
     }
 
     /// Attaches a per-procedure threshold map. Each procedure threshold must be at least 1 and
-    /// at most the number of approvers.
+    /// at most the total weight of the approvers.
     pub fn with_proc_thresholds(
         mut self,
         proc_thresholds: Vec<(Word, u32)>,
     ) -> Result<Self, AccountError> {
+        let total_weight = self.total_weight()?;
         for (_, threshold) in &proc_thresholds {
             if *threshold == 0 {
                 return Err(AccountError::other("procedure threshold must be at least 1"));
             }
-            if *threshold > self.approvers.len() as u32 {
+            if *threshold > total_weight {
                 return Err(AccountError::other(
-                    "procedure threshold cannot be greater than number of approvers",
+                    "procedure threshold cannot be greater than the total weight of the approvers",
                 ));
             }
         }
@@ -60,10 +128,108 @@ impl AuthRpoFalcon512MultisigConfig {
         Ok(self)
     }
 
+    /// Reads the configuration of an [`AuthRpoFalcon512Multisig`] component whose storage slots
+    /// start at index `offset` of the provided account storage.
+    ///
+    /// Since the approvers and thresholds can be updated by transactions, this reflects the
+    /// configuration as of the provided storage rather than the one the account was created with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the storage does not contain the slots of the multisig component at `offset`.
+    /// - the threshold, the number of approvers or a weight do not fit into a `u32`.
+    /// - a public key of the approvers is missing from the public keys map.
+    /// - the stored configuration is invalid, e.g. the threshold exceeds the total weight of the
+    ///   approvers or an approver is stored multiple times.
+    pub fn read_from_storage(storage: &AccountStorage, offset: u8) -> Result<Self, AccountError> {
+        let slot_index = |slot: u8| {
+            offset.checked_add(slot).ok_or(AccountError::StorageIndexOutOfBounds {
+                slots_len: storage.num_slots(),
+                index: u8::MAX,
+            })
+        };
+        let to_u32 = |value: Felt, name: &str| {
+            u32::try_from(value.as_int())
+                .map_err(|_| AccountError::other(format!("multisig {name} must fit into a u32")))
+        };
+
+        let config = storage.get_item(slot_index(THRESHOLD_CONFIG_SLOT)?)?;
+        let default_threshold = to_u32(config[0], "threshold")?;
+        let num_approvers = to_u32(config[1], "number of approvers")?;
+
+        let public_keys_slot = slot_index(PUBLIC_KEYS_MAP_SLOT)?;
+        let approvers = (0..num_approvers)
+            .map(|key_index| {
+                let pub_key =
+                    storage.get_map_item(public_keys_slot, Word::from([key_index, 0, 0, 0]))?;
+                if pub_key.is_empty() {
+                    return Err(AccountError::other(format!(
+                        "public key of multisig approver {key_index} is missing"
+                    )));
+                }
+                Ok(PublicKeyCommitment::from(pub_key))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let weights_slot = slot_index(APPROVER_WEIGHTS_MAP_SLOT)?;
+        let approvers = approvers
+            .into_iter()
+            .zip(0..num_approvers)
+            .map(|(approver, key_index)| {
+                let weight =
+                    storage.get_map_item(weights_slot, Word::from([key_index, 0, 0, 0]))?;
+                let weight = match to_u32(weight[0], "approver weight")? {
+                    0 => DEFAULT_APPROVER_WEIGHT,
+                    weight => weight,
+                };
+                Ok((approver, weight))
+            })
+            .collect::<Result<Vec<_>, AccountError>>()?;
+
+        let proc_thresholds_slot = slot_index(PROC_THRESHOLDS_MAP_SLOT)?;
+        let proc_thresholds = match storage.slots().get(proc_thresholds_slot as usize) {
+            Some(StorageSlot::Map(map)) => map
+                .entries()
+                .map(|(proc_root, threshold)| {
+                    Ok((*proc_root, to_u32(threshold[0], "procedure threshold")?))
+                })
+                .collect::<Result<Vec<_>, AccountError>>()?,
+            Some(StorageSlot::Value(_)) => {
+                return Err(AccountError::StorageSlotNotMap(proc_thresholds_slot));
+            },
+            None => {
+                return Err(AccountError::StorageIndexOutOfBounds {
+                    slots_len: storage.num_slots(),
+                    index: proc_thresholds_slot,
+                });
+            },
+        };
+
+        Self::new_weighted(approvers, default_threshold)?.with_proc_thresholds(proc_thresholds)
+    }
+
     pub fn approvers(&self) -> &[PublicKeyCommitment] {
         &self.approvers
     }
 
+    /// Returns the weights of the approvers, in the same order as [`Self::approvers`].
+    pub fn approver_weights(&self) -> &[u32] {
+        &self.weights
+    }
+
+    /// Returns the sum of the weights of all approvers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sum does not fit into a `u32`.
+    pub fn total_weight(&self) -> Result<u32, AccountError> {
+        self.weights
+            .iter()
+            .try_fold(0u32, |total, weight| total.checked_add(*weight))
+            .ok_or_else(|| AccountError::other("total weight of the approvers must fit into a u32"))
+    }
+
     pub fn default_threshold(&self) -> u32 {
         self.default_threshold
     }
@@ -76,7 +242,9 @@ impl AuthRpoFalcon512MultisigConfig {
 /// An [`AccountComponent`] implementing a multisig based on RpoFalcon512 signatures.
 ///
 /// It enforces a threshold of approver signatures for every transaction, with optional
-/// per-procedure thresholds overrides. Non-uniform thresholds (especially a threshold of one)
+/// per-procedure thresholds overrides. Each approver has a weight, 1 by default, and a transaction
+/// is authorized if the sum of the weights of the approvers with a valid signature reaches the
+/// threshold. Non-uniform thresholds (especially a threshold of one)
 /// should be used with caution for private multisig accounts, as a single approver could withhold
 ///  the new state from other approvers, effectively locking them out.
 ///
@@ -85,6 +253,22 @@ impl AuthRpoFalcon512MultisigConfig {
 /// - Slot 1(map): A map with approver public keys (index -> pubkey)
 /// - Slot 2(map): A map which stores executed transactions
 /// - Slot 3(map): A map which stores procedure thresholds (PROC_ROOT -> threshold)
+/// - Slot 4(map): A map with approver weights (index -> [weight, 0, 0, 0])
+///
+/// An approver without an entry in the weights map has a weight of 1.
+///
+/// The approvers are always stored at the indices `0..num_approvers` of the public keys map. The
+/// component exposes the following procedures to update the configuration, each of which has to
+/// be authorized by the current approvers like any other transaction:
+/// - `add_approver`, which adds a public key with a weight of 1 at the end of the public keys map.
+/// - `remove_approver`, which moves the last public key and its weight to the index of the removed
+///   one.
+/// - `update_threshold`, which changes the default threshold.
+/// - `update_signers_and_threshold`, which replaces all approvers and the default threshold and
+///   resets the weights of the new approvers to 1.
+///
+/// The current configuration can be read with
+/// [`AuthRpoFalcon512MultisigConfig::read_from_storage`].
 ///
 /// This component supports all account types.
 #[derive(Debug)]
@@ -101,7 +285,7 @@ impl AuthRpoFalcon512Multisig {
 
 impl From<AuthRpoFalcon512Multisig> for AccountComponent {
     fn from(multisig: AuthRpoFalcon512Multisig) -> Self {
-        let mut storage_slots = Vec::with_capacity(3);
+        let mut storage_slots = Vec::with_capacity(5);
 
         // Slot 0: [threshold, num_approvers, 0, 0]
         let num_approvers = multisig.config.approvers().len() as u32;
@@ -138,6 +322,15 @@ impl From<AuthRpoFalcon512Multisig> for AccountComponent {
         .unwrap();
         storage_slots.push(StorageSlot::Map(proc_threshold_roots));
 
+        // Slot 4: A map with approver weights
+        let weight_entries =
+            multisig.config.approver_weights().iter().enumerate().map(|(i, weight)| {
+                (Word::from([i as u32, 0, 0, 0]), Word::from([*weight, 0, 0, 0]))
+            });
+
+        // Safe to unwrap because we know that the map keys are unique.
+        storage_slots.push(StorageSlot::Map(StorageMap::with_entries(weight_entries).unwrap()));
+
         AccountComponent::new(rpo_falcon_512_multisig_library(), storage_slots)
             .expect("Multisig auth component should satisfy the requirements of a valid account component")
             .with_supports_all_types()
@@ -149,7 +342,8 @@ mod tests {
     use alloc::string::ToString;
 
     use miden_objects::Word;
-    use miden_objects::account::AccountBuilder;
+    use miden_objects::account::{Account, AccountBuilder};
+    use miden_objects::utils::{Deserializable, Serializable};
 
     use super::*;
     use crate::account::wallets::BasicWallet;
@@ -238,7 +432,101 @@ mod tests {
             result
                 .unwrap_err()
                 .to_string()
-                .contains("threshold cannot be greater than number of approvers")
+                .contains("threshold cannot be greater than the total weight of the approvers")
+        );
+    }
+
+    /// Test that the config is read back from the storage of a multisig account
+    #[test]
+    fn test_multisig_config_read_from_storage() {
+        let approvers = vec![
+            PublicKeyCommitment::from(Word::from([1u32, 0, 0, 0])),
+            PublicKeyCommitment::from(Word::from([2u32, 0, 0, 0])),
+        ];
+        let config = AuthRpoFalcon512MultisigConfig::new(approvers, 2)
+            .unwrap()
+            .with_proc_thresholds(vec![(Word::from([5u32, 6, 7, 8]), 1)])
+            .unwrap();
+
+        let account = AccountBuilder::new([0; 32])
+            .with_auth_component(AuthRpoFalcon512Multisig::new(config.clone()).unwrap())
+            .with_component(BasicWallet)
+            .build()
+            .expect("account building failed");
+
+        let read_config =
+            AuthRpoFalcon512MultisigConfig::read_from_storage(account.storage(), 0).unwrap();
+        assert_eq!(read_config, config);
+
+        // The wallet component does not have storage, so there are no multisig slots at offset 1.
+        assert!(AuthRpoFalcon512MultisigConfig::read_from_storage(account.storage(), 1).is_err());
+    }
+
+    /// Test validation, storage and serialization of weighted multisig configs
+    #[test]
+    fn test_multisig_weighted_config() {
+        let pub_key_1 = PublicKeyCommitment::from(Word::from([1u32, 0, 0, 0]));
+        let pub_key_2 = PublicKeyCommitment::from(Word::from([2u32, 0, 0, 0]));
+
+        let result =
+            AuthRpoFalcon512MultisigConfig::new_weighted(vec![(pub_key_1, 1), (pub_key_2, 0)], 1);
+        assert!(result.unwrap_err().to_string().contains("approver weight must be at least 1"));
+
+        let result =
+            AuthRpoFalcon512MultisigConfig::new_weighted(vec![(pub_key_1, 1), (pub_key_2, 3)], 5);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("threshold cannot be greater than the total weight of the approvers")
+        );
+
+        // The threshold may exceed the number of approvers if it is reachable by their weights.
+        let config =
+            AuthRpoFalcon512MultisigConfig::new_weighted(vec![(pub_key_1, 1), (pub_key_2, 3)], 4)
+                .unwrap();
+        assert_eq!(config.approver_weights(), &[1, 3]);
+        assert_eq!(config.total_weight().unwrap(), 4);
+
+        let result = config.clone().with_proc_thresholds(vec![(Word::from([5u32, 6, 7, 8]), 5)]);
+        assert!(result.unwrap_err().to_string().contains(
+            "procedure threshold cannot be greater than the total weight of the approvers"
+        ));
+        let config = config.with_proc_thresholds(vec![(Word::from([5u32, 6, 7, 8]), 3)]).unwrap();
+
+        let account = AccountBuilder::new([0; 32])
+            .with_auth_component(AuthRpoFalcon512Multisig::new(config.clone()).unwrap())
+            .with_component(BasicWallet)
+            .build()
+            .expect("account building failed");
+
+        // Verify slot 4: Approver weights in map
+        for (i, weight) in config.approver_weights().iter().enumerate() {
+            let stored_weight = account
+                .storage()
+                .get_map_item(4, Word::from([i as u32, 0, 0, 0]))
+                .expect("storage map access failed");
+            assert_eq!(stored_weight, Word::from([*weight, 0, 0, 0]));
+        }
+
+        // The config round-trips through the serialized account.
+        let account = Account::read_from_bytes(&account.to_bytes()).unwrap();
+        let read_config =
+            AuthRpoFalcon512MultisigConfig::read_from_storage(account.storage(), 0).unwrap();
+        assert_eq!(read_config, config);
+    }
+
+    /// Test that duplicate approvers are rejected
+    #[test]
+    fn test_multisig_config_duplicate_approvers() {
+        let pub_key = PublicKeyCommitment::from(Word::from([1u32, 0, 0, 0]));
+
+        let result = AuthRpoFalcon512MultisigConfig::new(vec![pub_key, pub_key], 1);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("approvers must not contain duplicate public keys")
         );
     }
 }
@@ -33,6 +33,16 @@ static BASIC_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     Library::read_from_bytes(bytes).expect("Shipped Basic Fungible Faucet library is well-formed")
 });
 
+// Initialize the Basic Non-Fungible Faucet library only once.
+static BASIC_NON_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/assets/account_components/basic_non_fungible_faucet.masl"
+    ));
+    Library::read_from_bytes(bytes)
+        .expect("Shipped Basic Non-Fungible Faucet library is well-formed")
+});
+
 // Initialize the Network Fungible Faucet library only once.
 static NETWORK_FUNGIBLE_FAUCET_LIBRARY: LazyLock<Library> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(
@@ -76,6 +86,11 @@ pub fn basic_fungible_faucet_library() -> Library {
     BASIC_FUNGIBLE_FAUCET_LIBRARY.clone()
 }
 
+/// Returns the Basic Non-Fungible Faucet Library.
+pub fn basic_non_fungible_faucet_library() -> Library {
+    BASIC_NON_FUNGIBLE_FAUCET_LIBRARY.clone()
+}
+
 /// Returns the Network Fungible Faucet Library.
 pub fn network_fungible_faucet_library() -> Library {
     NETWORK_FUNGIBLE_FAUCET_LIBRARY.clone()
@@ -108,6 +123,7 @@ pub fn rpo_falcon_512_multisig_library() -> Library {
 pub enum WellKnownComponent {
     BasicWallet,
     BasicFungibleFaucet,
+    BasicNonFungibleFaucet,
     NetworkFungibleFaucet,
     AuthRpoFalcon512,
     AuthRpoFalcon512Acl,
@@ -121,6 +137,7 @@ impl WellKnownComponent {
         let library = match self {
             Self::BasicWallet => BASIC_WALLET_LIBRARY.as_ref(),
             Self::BasicFungibleFaucet => BASIC_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
+            Self::BasicNonFungibleFaucet => BASIC_NON_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
             Self::NetworkFungibleFaucet => NETWORK_FUNGIBLE_FAUCET_LIBRARY.as_ref(),
             Self::AuthRpoFalcon512 => RPO_FALCON_512_LIBRARY.as_ref(),
             Self::AuthRpoFalcon512Acl => RPO_FALCON_512_ACL_LIBRARY.as_ref(),
@@ -165,6 +182,8 @@ impl WellKnownComponent {
                 },
                 Self::BasicFungibleFaucet => component_interface_vec
                     .push(AccountComponentInterface::BasicFungibleFaucet(storage_offset)),
+                Self::BasicNonFungibleFaucet => component_interface_vec
+                    .push(AccountComponentInterface::BasicNonFungibleFaucet(storage_offset)),
                 Self::NetworkFungibleFaucet => component_interface_vec
                     .push(AccountComponentInterface::NetworkFungibleFaucet(storage_offset)),
                 Self::AuthRpoFalcon512 => component_interface_vec
@@ -188,6 +207,7 @@ impl WellKnownComponent {
     ) {
         Self::BasicWallet.extract_component(procedures_map, component_interface_vec);
         Self::BasicFungibleFaucet.extract_component(procedures_map, component_interface_vec);
+        Self::BasicNonFungibleFaucet.extract_component(procedures_map, component_interface_vec);
         Self::NetworkFungibleFaucet.extract_component(procedures_map, component_interface_vec);
         Self::AuthRpoFalcon512.extract_component(procedures_map, component_interface_vec);
         Self::AuthRpoFalcon512Acl.extract_component(procedures_map, component_interface_vec);
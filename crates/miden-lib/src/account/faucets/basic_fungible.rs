@@ -64,6 +64,10 @@ impl BasicFungibleFaucet {
     /// The maximum number of decimals supported by the component.
     pub const MAX_DECIMALS: u8 = 12;
 
+    /// The index of the storage slot holding the token metadata in accounts created with
+    /// [`create_basic_fungible_faucet`].
+    pub const METADATA_SLOT: u8 = 4;
+
     const DISTRIBUTE_PROC_NAME: &str = "distribute";
     const BURN_PROC_NAME: &str = "burn";
 
@@ -140,6 +144,30 @@ impl BasicFungibleFaucet {
         Err(FungibleFaucetError::NoAvailableInterface)
     }
 
+    /// Returns the token metadata `[max_supply, decimals, token_symbol, 0]` stored in the
+    /// [`Self::METADATA_SLOT`] of the provided faucet account.
+    ///
+    /// The metadata is read from the storage layout of accounts created with
+    /// [`create_basic_fungible_faucet`]. For accounts with a different layout, use
+    /// [`BasicFungibleFaucet::try_from`] instead, which locates the metadata slot through the
+    /// account interface.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - the account is not a fungible faucet account.
+    /// - the account storage does not contain the [`Self::METADATA_SLOT`].
+    pub fn metadata_from_storage(account: &Account) -> Result<[Felt; 4], FungibleFaucetError> {
+        if account.account_type() != AccountType::FungibleFaucet {
+            return Err(FungibleFaucetError::NotAFungibleFaucetAccount);
+        }
+
+        account
+            .storage()
+            .get_item(Self::METADATA_SLOT)
+            .map(Word::into)
+            .map_err(|_| FungibleFaucetError::InvalidStorageOffset(Self::METADATA_SLOT))
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -224,7 +252,7 @@ impl TryFrom<&Account> for BasicFungibleFaucet {
 /// - Slot 2: [num_tracked_procs, allow_unauthorized_output_notes, allow_unauthorized_input_notes,
 ///   0].
 /// - Slot 3: A map with tracked procedure roots.
-/// - Slot 4: Token metadata of the faucet.
+/// - Slot 4: Token metadata of the faucet, see [`BasicFungibleFaucet::METADATA_SLOT`].
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     symbol: TokenSymbol,
@@ -294,6 +322,14 @@ mod tests {
     };
     use crate::account::auth::AuthRpoFalcon512;
     use crate::account::wallets::BasicWallet;
+    use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
+
+    /// The storage slots of the falcon ACL auth component in accounts created with
+    /// [`create_basic_fungible_faucet`]. The auth component is added first, so its slots follow
+    /// the reserved faucet slot.
+    const AUTH_PUBLIC_KEY_SLOT: u8 = 1;
+    const AUTH_CONFIG_SLOT: u8 = 2;
+    const AUTH_TRACKED_PROCEDURES_SLOT: u8 = 3;
 
     #[test]
     fn faucet_contract_creation() {
@@ -323,36 +359,45 @@ mod tests {
         .unwrap();
 
         // The reserved faucet slot should be initialized to an empty word.
-        assert_eq!(faucet_account.storage().get_item(0).unwrap(), Word::empty());
+        assert_eq!(
+            faucet_account.storage().get_item(FAUCET_STORAGE_DATA_SLOT).unwrap(),
+            Word::empty()
+        );
 
-        // The falcon auth component is added first so its assigned storage slot for the public key
-        // will be 1.
-        assert_eq!(faucet_account.storage().get_item(1).unwrap(), pub_key_word);
+        assert_eq!(faucet_account.storage().get_item(AUTH_PUBLIC_KEY_SLOT).unwrap(), pub_key_word);
 
-        // Slot 2 stores [num_tracked_procs, allow_unauthorized_output_notes,
+        // The config slot stores [num_tracked_procs, allow_unauthorized_output_notes,
         // allow_unauthorized_input_notes, 0]. With 1 tracked procedure (distribute),
         // allow_unauthorized_output_notes=false, and allow_unauthorized_input_notes=true,
         // this should be [1, 0, 1, 0].
         assert_eq!(
-            faucet_account.storage().get_item(2).unwrap(),
+            faucet_account.storage().get_item(AUTH_CONFIG_SLOT).unwrap(),
             [Felt::ONE, Felt::ZERO, Felt::ONE, Felt::ZERO].into()
         );
 
-        // The procedure root map in slot 3 should contain the distribute procedure root.
+        // The tracked procedure roots map should contain the distribute procedure root.
         let distribute_root = BasicFungibleFaucet::distribute_digest();
         assert_eq!(
             faucet_account
                 .storage()
-                .get_map_item(3, [Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::ZERO].into())
+                .get_map_item(
+                    AUTH_TRACKED_PROCEDURES_SLOT,
+                    [Felt::ZERO, Felt::ZERO, Felt::ZERO, Felt::ZERO].into()
+                )
                 .unwrap(),
             distribute_root
         );
 
         // Check that faucet metadata was initialized to the given values. The faucet component is
-        // added second, so its assigned storage slot for the metadata will be 2.
+        // added after the auth component, so its metadata is stored in the metadata slot.
+        let expected_metadata = [Felt::new(123), Felt::new(2), token_symbol.into(), Felt::ZERO];
         assert_eq!(
-            faucet_account.storage().get_item(4).unwrap(),
-            [Felt::new(123), Felt::new(2), token_symbol.into(), Felt::ZERO].into()
+            faucet_account.storage().get_item(BasicFungibleFaucet::METADATA_SLOT).unwrap(),
+            expected_metadata.into()
+        );
+        assert_eq!(
+            BasicFungibleFaucet::metadata_from_storage(&faucet_account).unwrap(),
+            expected_metadata
         );
 
         assert!(faucet_account.is_faucet());
@@ -400,6 +445,11 @@ mod tests {
             .build_existing()
             .expect("failed to create wallet account");
 
+        assert_matches!(
+            BasicFungibleFaucet::metadata_from_storage(&invalid_faucet_account),
+            Err(FungibleFaucetError::InvalidStorageOffset(BasicFungibleFaucet::METADATA_SLOT))
+        );
+
         let err = BasicFungibleFaucet::try_from(invalid_faucet_account)
             .err()
             .expect("basic fungible faucet creation should fail");
@@ -0,0 +1,408 @@
+use miden_objects::account::{
+    Account,
+    AccountBuilder,
+    AccountComponent,
+    AccountId,
+    AccountStorage,
+    AccountStorageMode,
+    AccountType,
+    StorageSlot,
+};
+use miden_objects::asset::{NonFungibleAsset, TokenSymbol};
+use miden_objects::{AssetError, Felt, FieldElement, Hasher, Word};
+
+use super::NonFungibleFaucetError;
+use crate::account::AuthScheme;
+use crate::account::auth::{AuthRpoFalcon512Acl, AuthRpoFalcon512AclConfig};
+use crate::account::components::basic_non_fungible_faucet_library;
+use crate::account::interface::{AccountComponentInterface, AccountInterface};
+use crate::procedure_digest;
+
+// BASIC NON-FUNGIBLE FAUCET ACCOUNT COMPONENT
+// ================================================================================================
+
+// Initialize the digest of the `mint` procedure of the Basic Non-Fungible Faucet only once.
+procedure_digest!(
+    BASIC_NON_FUNGIBLE_FAUCET_MINT,
+    BasicNonFungibleFaucet::MINT_PROC_NAME,
+    basic_non_fungible_faucet_library
+);
+
+// Initialize the digest of the `burn` procedure of the Basic Non-Fungible Faucet only once.
+procedure_digest!(
+    BASIC_NON_FUNGIBLE_FAUCET_BURN,
+    BasicNonFungibleFaucet::BURN_PROC_NAME,
+    basic_non_fungible_faucet_library
+);
+
+/// An [`AccountComponent`] implementing a basic non-fungible faucet.
+///
+/// It reexports the procedures from `miden::contracts::faucets::basic_non_fungible`. When linking
+/// against this component, the `miden` library (i.e. [`MidenLib`](crate::MidenLib)) must be
+/// available to the assembler which is the case when using
+/// [`TransactionKernel::assembler()`][kasm]. The procedures of this component are:
+/// - `mint`, which mints a non-fungible asset from the provided metadata word and creates a note
+///   for the provided recipient.
+/// - `burn`, which burns the provided asset.
+///
+/// The component stores the faucet metadata in its first storage slot and the number of minted
+/// assets which have not been burned yet in its second storage slot.
+///
+/// The data hash of a minted asset is the hash of its metadata, see
+/// [`BasicNonFungibleFaucet::asset_from_metadata`]. The issued assets are tracked by the
+/// transaction kernel in the reserved faucet slot, so the same metadata cannot be minted twice
+/// unless the asset was burned in the meantime.
+///
+/// The `mint` procedure can be called from a transaction script and requires authentication via
+/// the authentication component. The `burn` procedure can only be called from a note script and
+/// requires the calling note to contain the asset to be burned.
+/// This component must be combined with an authentication component.
+///
+/// This component supports accounts of type [`AccountType::NonFungibleFaucet`].
+///
+/// [kasm]: crate::transaction::TransactionKernel::assembler
+pub struct BasicNonFungibleFaucet {
+    symbol: TokenSymbol,
+    max_supply: Felt,
+}
+
+impl BasicNonFungibleFaucet {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of assets a faucet can mint.
+    ///
+    /// The number of minted assets is tracked as a `u32` in the component's storage.
+    pub const MAX_SUPPLY: u64 = u32::MAX as u64;
+
+    /// The index of the storage slot holding the faucet metadata in accounts created with
+    /// [`create_basic_non_fungible_faucet`].
+    pub const METADATA_SLOT: u8 = 4;
+
+    const MINT_PROC_NAME: &str = "mint";
+    const BURN_PROC_NAME: &str = "burn";
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [`BasicNonFungibleFaucet`] component from the given pieces of metadata.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - the max supply parameter is zero or exceeds [`Self::MAX_SUPPLY`].
+    pub fn new(symbol: TokenSymbol, max_supply: Felt) -> Result<Self, NonFungibleFaucetError> {
+        if max_supply == Felt::ZERO || max_supply.as_int() > Self::MAX_SUPPLY {
+            return Err(NonFungibleFaucetError::InvalidMaxSupply {
+                actual: max_supply.as_int(),
+                max: Self::MAX_SUPPLY,
+            });
+        }
+
+        Ok(Self { symbol, max_supply })
+    }
+
+    /// Attempts to create a new [`BasicNonFungibleFaucet`] component from the associated account
+    /// interface and storage.
+    ///
+    /// # Errors:
+    /// Returns an error if:
+    /// - the provided [`AccountInterface`] does not contain a
+    ///   [`AccountComponentInterface::BasicNonFungibleFaucet`] component.
+    /// - the max supply value is zero or exceeds [`Self::MAX_SUPPLY`].
+    /// - the token symbol encoded value exceeds the maximum value of
+    ///   [`TokenSymbol::MAX_ENCODED_VALUE`].
+    fn try_from_interface(
+        interface: AccountInterface,
+        storage: &AccountStorage,
+    ) -> Result<Self, NonFungibleFaucetError> {
+        for component in interface.components().iter() {
+            if let AccountComponentInterface::BasicNonFungibleFaucet(offset) = component {
+                let faucet_metadata = storage
+                    .get_item(*offset)
+                    .map_err(|_| NonFungibleFaucetError::InvalidStorageOffset(*offset))?;
+                let [max_supply, token_symbol, ..] = *faucet_metadata;
+
+                let token_symbol = TokenSymbol::try_from(token_symbol)
+                    .map_err(NonFungibleFaucetError::InvalidTokenSymbol)?;
+
+                return BasicNonFungibleFaucet::new(token_symbol, max_supply);
+            }
+        }
+
+        Err(NonFungibleFaucetError::NoAvailableInterface)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the symbol of the faucet.
+    pub fn symbol(&self) -> TokenSymbol {
+        self.symbol
+    }
+
+    /// Returns the max supply of the faucet.
+    pub fn max_supply(&self) -> Felt {
+        self.max_supply
+    }
+
+    /// Returns the digest of the `mint` account procedure.
+    pub fn mint_digest() -> Word {
+        *BASIC_NON_FUNGIBLE_FAUCET_MINT
+    }
+
+    /// Returns the digest of the `burn` account procedure.
+    pub fn burn_digest() -> Word {
+        *BASIC_NON_FUNGIBLE_FAUCET_BURN
+    }
+
+    /// Returns the non-fungible asset which the `mint` procedure of the faucet with the provided
+    /// ID creates from the provided metadata.
+    ///
+    /// # Errors
+    /// Returns an error if the provided faucet ID is not for a non-fungible asset faucet.
+    pub fn asset_from_metadata(
+        faucet_id: AccountId,
+        metadata: Word,
+    ) -> Result<NonFungibleAsset, AssetError> {
+        NonFungibleAsset::from_parts(
+            faucet_id.prefix(),
+            Hasher::hash_elements(metadata.as_elements()),
+        )
+    }
+}
+
+impl From<BasicNonFungibleFaucet> for AccountComponent {
+    fn from(faucet: BasicNonFungibleFaucet) -> Self {
+        // Note: data is stored as [a0, a1, a2, a3] but loaded onto the stack as
+        // [a3, a2, a1, a0, ...]
+        let metadata = Word::new([faucet.max_supply, faucet.symbol.into(), Felt::ZERO, Felt::ZERO]);
+        // The number of minted assets starts at zero.
+        let total_minted = Word::empty();
+
+        AccountComponent::new(
+            basic_non_fungible_faucet_library(),
+            vec![StorageSlot::Value(metadata), StorageSlot::Value(total_minted)],
+        )
+            .expect("basic non-fungible faucet component should satisfy the requirements of a valid account component")
+            .with_supported_type(AccountType::NonFungibleFaucet)
+    }
+}
+
+impl TryFrom<Account> for BasicNonFungibleFaucet {
+    type Error = NonFungibleFaucetError;
+
+    fn try_from(account: Account) -> Result<Self, Self::Error> {
+        let account_interface = AccountInterface::from(&account);
+
+        BasicNonFungibleFaucet::try_from_interface(account_interface, account.storage())
+    }
+}
+
+impl TryFrom<&Account> for BasicNonFungibleFaucet {
+    type Error = NonFungibleFaucetError;
+
+    fn try_from(account: &Account) -> Result<Self, Self::Error> {
+        let account_interface = AccountInterface::from(account);
+
+        BasicNonFungibleFaucet::try_from_interface(account_interface, account.storage())
+    }
+}
+
+/// Creates a new faucet account with basic non-fungible faucet interface, account storage type,
+/// specified authentication scheme, and provided meta data (token symbol, max supply).
+///
+/// The basic non-fungible faucet interface exposes two procedures:
+/// - `mint`, which mints a non-fungible asset and creates a note for the provided recipient.
+/// - `burn`, which burns the provided asset.
+///
+/// The `mint` procedure can be called from a transaction script and requires authentication via
+/// the specified authentication scheme. The `burn` procedure can only be called from a note script
+/// and requires the calling note to contain the asset to be burned.
+///
+/// The storage layout of the faucet account is:
+/// - Slot 0: Reserved slot for faucets, which tracks the issued non-fungible assets.
+/// - Slot 1: Public Key of the authentication component.
+/// - Slot 2: [num_tracked_procs, allow_unauthorized_output_notes, allow_unauthorized_input_notes,
+///   0].
+/// - Slot 3: A map with tracked procedure roots.
+/// - Slot 4: Metadata of the faucet.
+/// - Slot 5: [0, 0, 0, total_minted], i.e. the number of assets minted by the faucet which have not
+///   been burned yet.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    symbol: TokenSymbol,
+    max_supply: Felt,
+    account_storage_mode: AccountStorageMode,
+    auth_scheme: AuthScheme,
+) -> Result<Account, NonFungibleFaucetError> {
+    let mint_proc_root = BasicNonFungibleFaucet::mint_digest();
+
+    let auth_component: AccountComponent = match auth_scheme {
+        AuthScheme::RpoFalcon512 { pub_key } => AuthRpoFalcon512Acl::new(
+            pub_key,
+            AuthRpoFalcon512AclConfig::new()
+                .with_auth_trigger_procedures(vec![mint_proc_root])
+                .with_allow_unauthorized_input_notes(true),
+        )
+        .map_err(NonFungibleFaucetError::AccountError)?
+        .into(),
+        AuthScheme::NoAuth => {
+            return Err(NonFungibleFaucetError::UnsupportedAuthScheme(
+                "basic non-fungible faucets cannot be created with NoAuth authentication scheme"
+                    .into(),
+            ));
+        },
+        AuthScheme::RpoFalcon512Multisig { threshold: _, pub_keys: _ } => {
+            return Err(NonFungibleFaucetError::UnsupportedAuthScheme(
+                "basic non-fungible faucets do not support multisig authentication".into(),
+            ));
+        },
+        AuthScheme::Unknown => {
+            return Err(NonFungibleFaucetError::UnsupportedAuthScheme(
+                "basic non-fungible faucets cannot be created with Unknown authentication scheme"
+                    .into(),
+            ));
+        },
+    };
+
+    let account = AccountBuilder::new(init_seed)
+        .account_type(AccountType::NonFungibleFaucet)
+        .storage_mode(account_storage_mode)
+        .with_auth_component(auth_component)
+        .with_component(BasicNonFungibleFaucet::new(symbol, max_supply)?)
+        .build()
+        .map_err(NonFungibleFaucetError::AccountError)?;
+
+    Ok(account)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use miden_objects::{FieldElement, ONE, Word};
+
+    use super::{
+        AccountBuilder,
+        AccountStorageMode,
+        AccountType,
+        AuthScheme,
+        BasicNonFungibleFaucet,
+        Felt,
+        NonFungibleFaucetError,
+        TokenSymbol,
+        create_basic_non_fungible_faucet,
+    };
+    use crate::account::auth::AuthRpoFalcon512;
+    use crate::account::wallets::BasicWallet;
+
+    /// The storage slot of the falcon ACL auth component holding the tracked procedure roots in
+    /// accounts created with [`create_basic_non_fungible_faucet`].
+    const AUTH_TRACKED_PROCEDURES_SLOT: u8 = 3;
+
+    #[test]
+    fn non_fungible_faucet_contract_creation() {
+        let pub_key_word = Word::new([ONE; 4]);
+        let auth_scheme: AuthScheme = AuthScheme::RpoFalcon512 { pub_key: pub_key_word.into() };
+
+        let max_supply = Felt::new(10);
+        let token_symbol = TokenSymbol::try_from("NFT").unwrap();
+
+        let faucet_account = create_basic_non_fungible_faucet(
+            [7; 32],
+            token_symbol,
+            max_supply,
+            AccountStorageMode::Private,
+            auth_scheme,
+        )
+        .unwrap();
+
+        assert_eq!(faucet_account.account_type(), AccountType::NonFungibleFaucet);
+
+        // The faucet metadata is stored after the reserved slot and the slots of the auth
+        // component.
+        assert_eq!(
+            faucet_account
+                .storage()
+                .get_item(BasicNonFungibleFaucet::METADATA_SLOT)
+                .unwrap(),
+            [max_supply, token_symbol.into(), Felt::ZERO, Felt::ZERO].into()
+        );
+
+        // The tracked procedure roots map should contain the mint procedure root.
+        assert_eq!(
+            faucet_account
+                .storage()
+                .get_map_item(AUTH_TRACKED_PROCEDURES_SLOT, Word::empty())
+                .unwrap(),
+            BasicNonFungibleFaucet::mint_digest()
+        );
+
+        let faucet_component = BasicNonFungibleFaucet::try_from(&faucet_account).unwrap();
+        assert_eq!(faucet_component.symbol(), token_symbol);
+        assert_eq!(faucet_component.max_supply(), max_supply);
+    }
+
+    #[test]
+    fn non_fungible_faucet_create_from_account() {
+        let mock_word = Word::from([0, 1, 2, 3u32]);
+        let mock_public_key = miden_objects::account::PublicKeyCommitment::from(mock_word);
+        let mock_seed = mock_word.as_bytes();
+        let token_symbol = TokenSymbol::new("NFT").unwrap();
+
+        // the basic non-fungible faucet component is missing
+        let invalid_faucet_account = AccountBuilder::new(mock_seed)
+            .account_type(AccountType::NonFungibleFaucet)
+            .with_auth_component(AuthRpoFalcon512::new(mock_public_key))
+            .with_component(BasicWallet)
+            .build_existing()
+            .unwrap();
+
+        let err = BasicNonFungibleFaucet::try_from(invalid_faucet_account)
+            .err()
+            .expect("basic non-fungible faucet creation should fail");
+        assert_matches!(err, NonFungibleFaucetError::NoAvailableInterface);
+
+        // the component only supports non-fungible faucets
+        let result = AccountBuilder::new(mock_seed)
+            .account_type(AccountType::FungibleFaucet)
+            .with_auth_component(AuthRpoFalcon512::new(mock_public_key))
+            .with_component(BasicNonFungibleFaucet::new(token_symbol, Felt::new(5)).unwrap())
+            .build_existing();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn non_fungible_faucet_max_supply_validation() {
+        let token_symbol = TokenSymbol::new("NFT").unwrap();
+
+        assert_matches!(
+            BasicNonFungibleFaucet::new(token_symbol, Felt::ZERO).err(),
+            Some(NonFungibleFaucetError::InvalidMaxSupply { actual: 0, .. })
+        );
+        assert_matches!(
+            BasicNonFungibleFaucet::new(
+                token_symbol,
+                Felt::new(BasicNonFungibleFaucet::MAX_SUPPLY + 1)
+            )
+            .err(),
+            Some(NonFungibleFaucetError::InvalidMaxSupply { .. })
+        );
+        assert!(
+            BasicNonFungibleFaucet::new(
+                token_symbol,
+                Felt::new(BasicNonFungibleFaucet::MAX_SUPPLY)
+            )
+            .is_ok()
+        );
+    }
+
+    /// Check that the obtaining of the basic non-fungible faucet procedure digests does not panic.
+    #[test]
+    fn get_non_fungible_faucet_procedures() {
+        let _mint_digest = BasicNonFungibleFaucet::mint_digest();
+        let _burn_digest = BasicNonFungibleFaucet::burn_digest();
+    }
+}
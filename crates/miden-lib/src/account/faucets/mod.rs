@@ -7,9 +7,11 @@ use thiserror::Error;
 use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
 
 mod basic_fungible;
+mod basic_non_fungible;
 mod network_fungible;
 
 pub use basic_fungible::{BasicFungibleFaucet, create_basic_fungible_faucet};
+pub use basic_non_fungible::{BasicNonFungibleFaucet, create_basic_non_fungible_faucet};
 pub use network_fungible::{NetworkFungibleFaucet, create_network_fungible_faucet};
 
 // FUNGIBLE FAUCET
@@ -70,3 +72,25 @@ pub enum FungibleFaucetError {
     #[error("account is not a fungible faucet account")]
     NotAFungibleFaucetAccount,
 }
+
+// NON-FUNGIBLE FAUCET ERROR
+// ================================================================================================
+
+/// Basic non-fungible faucet related errors.
+#[derive(Debug, Error)]
+pub enum NonFungibleFaucetError {
+    #[error("faucet metadata max supply is {actual} but must be between 1 and {max}")]
+    InvalidMaxSupply { actual: u64, max: u64 },
+    #[error(
+        "account interface provided for faucet creation does not have basic non-fungible faucet component"
+    )]
+    NoAvailableInterface,
+    #[error("storage offset `{0}` is invalid")]
+    InvalidStorageOffset(u8),
+    #[error("invalid token symbol")]
+    InvalidTokenSymbol(#[source] TokenSymbolError),
+    #[error("unsupported authentication scheme: {0}")]
+    UnsupportedAuthScheme(String),
+    #[error("account creation failed")]
+    AccountError(#[source] AccountError),
+}
@@ -6,6 +6,7 @@ use miden_objects::account::{
     AccountId,
     AccountProcedureInfo,
     AccountStorage,
+    ComponentLayout,
     PublicKeyCommitment,
 };
 use miden_objects::note::PartialNote;
@@ -30,6 +31,13 @@ pub enum AccountComponentInterface {
     /// slot has a format of `[max_supply, faucet_decimals, token_symbol, 0]`.
     BasicFungibleFaucet(u8),
     /// Exposes procedures from the
+    /// [`BasicNonFungibleFaucet`][crate::account::faucets::BasicNonFungibleFaucet] module.
+    ///
+    /// Internal value holds the storage slot index where faucet metadata is stored. This metadata
+    /// slot has a format of `[max_supply, token_symbol, 0, 0]`. The slot following it has a format
+    /// of `[0, 0, 0, total_minted]`.
+    BasicNonFungibleFaucet(u8),
+    /// Exposes procedures from the
     /// [`NetworkFungibleFaucet`][crate::account::faucets::NetworkFungibleFaucet] module.
     ///
     /// Internal value holds the storage slot index where faucet metadata is stored. This metadata
@@ -75,6 +83,9 @@ impl AccountComponentInterface {
             AccountComponentInterface::BasicFungibleFaucet(_) => {
                 "Basic Fungible Faucet".to_string()
             },
+            AccountComponentInterface::BasicNonFungibleFaucet(_) => {
+                "Basic Non-Fungible Faucet".to_string()
+            },
             AccountComponentInterface::NetworkFungibleFaucet(_) => {
                 "Network Fungible Faucet".to_string()
             },
@@ -171,6 +182,40 @@ impl AccountComponentInterface {
         component_interface_vec
     }
 
+    /// Creates a vector of [AccountComponentInterface] instances from the provided procedures info
+    /// array, using the [ComponentLayout] to determine which procedures belong to which component.
+    ///
+    /// Unlike [`Self::from_procedures`], this does not rely on storage offsets to group the
+    /// procedures of custom components, so every component of the layout which is not a well
+    /// known component results in exactly one [`AccountComponentInterface::Custom`] interface.
+    pub fn from_component_layout(
+        procedures: &[AccountProcedureInfo],
+        component_layout: &ComponentLayout,
+    ) -> Vec<Self> {
+        let mut component_interface_vec = Vec::new();
+
+        for component_procedures in component_layout.iter() {
+            let mut procedures: BTreeMap<_, _> = procedures
+                .iter()
+                .filter(|procedure_info| component_procedures.contains(procedure_info.mast_root()))
+                .map(|procedure_info| (*procedure_info.mast_root(), procedure_info))
+                .collect();
+
+            WellKnownComponent::extract_well_known_components(
+                &mut procedures,
+                &mut component_interface_vec,
+            );
+
+            if !procedures.is_empty() {
+                component_interface_vec.push(AccountComponentInterface::Custom(
+                    procedures.into_values().copied().collect(),
+                ));
+            }
+        }
+
+        component_interface_vec
+    }
+
     /// Generates a body for the note creation of the `send_note` transaction script. The resulting
     /// code could use different procedures for note creation, which depends on the used interface.
     ///
@@ -1,6 +1,5 @@
 use alloc::collections::BTreeSet;
 use alloc::string::String;
-use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use miden_objects::Word;
@@ -11,9 +10,9 @@ use miden_objects::transaction::TransactionScript;
 use miden_processor::MastNodeExt;
 use thiserror::Error;
 
-use crate::AuthScheme;
 use crate::account::components::{
     basic_fungible_faucet_library,
+    basic_non_fungible_faucet_library,
     basic_wallet_library,
     network_fungible_faucet_library,
     no_auth_library,
@@ -24,6 +23,7 @@ use crate::account::components::{
 use crate::errors::ScriptBuilderError;
 use crate::note::WellKnownNote;
 use crate::utils::ScriptBuilder;
+use crate::{AuthScheme, MidenLib};
 
 #[cfg(test)]
 mod test;
@@ -128,6 +128,32 @@ impl AccountInterface {
         }
     }
 
+    /// Returns the MAST root of the first procedure of this account interface which the script of
+    /// the provided note calls, i.e. the procedure through which the note enters the account.
+    ///
+    /// The note script's MAST is traversed in program order, with the `true` branch of
+    /// conditionals visited before the `false` branch, including the procedures of the
+    /// [`MidenLib`] the script references, e.g. `active_note::add_assets_to_account`.
+    ///
+    /// Returns `None` if the note is not compatible with this account interface or its script
+    /// does not call any procedure of this account interface.
+    pub fn find_note_entry_point(&self, note: &Note) -> Option<Word> {
+        if self.is_compatible_with(note) == NoteAccountCompatibility::No {
+            return None;
+        }
+
+        let account_procedures = self.get_procedure_digests();
+        let miden_lib = MidenLib::default();
+        let note_script = note.script();
+
+        find_first_call(
+            note_script.entrypoint(),
+            &note_script.mast(),
+            &[miden_lib.mast_forest()],
+            &account_procedures,
+        )
+    }
+
     /// Returns a digests set of all procedures from all account component interfaces.
     pub(crate) fn get_procedure_digests(&self) -> BTreeSet<Word> {
         let mut component_proc_digests = BTreeSet::new();
@@ -141,6 +167,11 @@ impl AccountInterface {
                     component_proc_digests
                         .extend(basic_fungible_faucet_library().mast_forest().procedure_digests());
                 },
+                AccountComponentInterface::BasicNonFungibleFaucet(_) => {
+                    component_proc_digests.extend(
+                        basic_non_fungible_faucet_library().mast_forest().procedure_digests(),
+                    );
+                },
                 AccountComponentInterface::NetworkFungibleFaucet(_) => {
                     component_proc_digests.extend(
                         network_fungible_faucet_library().mast_forest().procedure_digests(),
@@ -285,7 +316,13 @@ impl AccountInterface {
 
 impl From<&Account> for AccountInterface {
     fn from(account: &Account) -> Self {
-        let components = AccountComponentInterface::from_procedures(account.code().procedures());
+        let procedures = account.code().procedures();
+        let components = match account.code().component_layout() {
+            Some(component_layout) => {
+                AccountComponentInterface::from_component_layout(procedures, component_layout)
+            },
+            None => AccountComponentInterface::from_procedures(procedures),
+        };
         let mut auth = Vec::new();
 
         // Find the auth component and extract all auth schemes from it
@@ -337,70 +374,125 @@ fn verify_note_script_compatibility(
     note_script: &NoteScript,
     account_procedures: BTreeSet<Word>,
 ) -> NoteAccountCompatibility {
-    // collect call branches of the note script
-    let branches = collect_call_branches(note_script);
-
     // if none of the branches are compatible with the target account, return a `CheckResult::No`
-    if !branches.iter().any(|call_targets| call_targets.is_subset(&account_procedures)) {
+    if !note_script
+        .call_branches()
+        .iter()
+        .any(|call_targets| call_targets.is_subset(&account_procedures))
+    {
         return NoteAccountCompatibility::No;
     }
 
     NoteAccountCompatibility::Maybe
 }
 
-/// Collect call branches by recursively traversing through program execution branches and
-/// accumulating call targets.
-fn collect_call_branches(note_script: &NoteScript) -> Vec<BTreeSet<Word>> {
-    let mut branches = vec![BTreeSet::new()];
+/// Collects the procedures called by the provided note script, like
+/// [`NoteScript::procedure_roots`], but additionally traverses procedures which the note script
+/// references as external nodes if they are part of one of the provided `libraries`.
+///
+/// Also returns the [`UnresolvedCode`] which could not be traversed and whose calls are therefore
+/// missing from the returned set.
+pub(crate) fn collect_calls_with_libraries(
+    note_script: &NoteScript,
+    libraries: &[&MastForest],
+) -> (BTreeSet<Word>, UnresolvedCode) {
+    let mut calls = BTreeSet::new();
+    let mut unresolved = UnresolvedCode::default();
+
+    recursively_collect_calls(
+        note_script.entrypoint(),
+        &mut calls,
+        &mut unresolved,
+        &note_script.mast(),
+        libraries,
+    );
+    (calls, unresolved)
+}
 
-    let entry_node = note_script.entrypoint();
-    recursively_collect_call_branches(entry_node, &mut branches, &note_script.mast());
-    branches
+/// The code encountered by [`collect_calls_with_libraries`] which cannot be traversed statically.
+#[derive(Debug, Default)]
+pub(crate) struct UnresolvedCode {
+    /// Whether the code invokes procedures via `dynexec` or `dyncall`.
+    pub dynamic_calls: bool,
+    /// The digests of the external procedures which are not part of any of the provided libraries.
+    pub external_procedures: BTreeSet<Word>,
 }
 
-/// Generates a list of calls invoked in each execution branch of the provided code block.
-fn recursively_collect_call_branches(
+/// Collects the procedures called by the provided code block, in any of its execution branches.
+fn recursively_collect_calls(
     mast_node_id: MastNodeId,
-    branches: &mut Vec<BTreeSet<Word>>,
-    note_script_forest: &Arc<MastForest>,
+    calls: &mut BTreeSet<Word>,
+    unresolved: &mut UnresolvedCode,
+    forest: &MastForest,
+    libraries: &[&MastForest],
 ) {
-    let mast_node = &note_script_forest[mast_node_id];
-
-    match mast_node {
-        MastNode::Block(_) => {},
+    match &forest[mast_node_id] {
         MastNode::Join(join_node) => {
-            recursively_collect_call_branches(join_node.first(), branches, note_script_forest);
-            recursively_collect_call_branches(join_node.second(), branches, note_script_forest);
+            recursively_collect_calls(join_node.first(), calls, unresolved, forest, libraries);
+            recursively_collect_calls(join_node.second(), calls, unresolved, forest, libraries);
         },
         MastNode::Split(split_node) => {
-            let current_branch = branches.last().expect("at least one execution branch").clone();
-            recursively_collect_call_branches(split_node.on_false(), branches, note_script_forest);
-
-            // If the previous branch had additional calls we need to create a new branch
-            if branches.last().expect("at least one execution branch").len() > current_branch.len()
-            {
-                branches.push(current_branch);
-            }
-
-            recursively_collect_call_branches(split_node.on_true(), branches, note_script_forest);
+            recursively_collect_calls(split_node.on_true(), calls, unresolved, forest, libraries);
+            recursively_collect_calls(split_node.on_false(), calls, unresolved, forest, libraries);
         },
         MastNode::Loop(loop_node) => {
-            recursively_collect_call_branches(loop_node.body(), branches, note_script_forest);
+            recursively_collect_calls(loop_node.body(), calls, unresolved, forest, libraries);
+        },
+        MastNode::Call(call_node) if !call_node.is_syscall() => {
+            calls.insert(forest[call_node.callee()].digest());
+        },
+        MastNode::Dyn(_) => {
+            unresolved.dynamic_calls = true;
         },
-        MastNode::Call(call_node) => {
-            if call_node.is_syscall() {
-                return;
+        MastNode::External(external_node) => {
+            let digest = external_node.digest();
+            match libraries.iter().find_map(|library| {
+                library.find_procedure_root(digest).map(|root| (*library, root))
+            }) {
+                Some((library, root)) => {
+                    recursively_collect_calls(root, calls, unresolved, library, libraries)
+                },
+                None => {
+                    unresolved.external_procedures.insert(digest);
+                },
             }
+        },
+        MastNode::Block(_) | MastNode::Call(_) => {},
+    }
+}
 
-            let callee_digest = note_script_forest[call_node.callee()].digest();
-
-            branches
-                .last_mut()
-                .expect("at least one execution branch")
-                .insert(callee_digest);
+/// Returns the digest of the first procedure in the provided set of `procedures` which is called
+/// by the code block with the provided ID, traversing the code in program order.
+///
+/// Procedures which the code references as external nodes are traversed if they are part of one of
+/// the provided `libraries`.
+fn find_first_call(
+    mast_node_id: MastNodeId,
+    forest: &MastForest,
+    libraries: &[&MastForest],
+    procedures: &BTreeSet<Word>,
+) -> Option<Word> {
+    match &forest[mast_node_id] {
+        MastNode::Join(join_node) => {
+            find_first_call(join_node.first(), forest, libraries, procedures)
+                .or_else(|| find_first_call(join_node.second(), forest, libraries, procedures))
+        },
+        MastNode::Split(split_node) => {
+            find_first_call(split_node.on_true(), forest, libraries, procedures)
+                .or_else(|| find_first_call(split_node.on_false(), forest, libraries, procedures))
+        },
+        MastNode::Loop(loop_node) => {
+            find_first_call(loop_node.body(), forest, libraries, procedures)
+        },
+        MastNode::Call(call_node) if !call_node.is_syscall() => {
+            let callee_digest = forest[call_node.callee()].digest();
+            procedures.contains(&callee_digest).then_some(callee_digest)
         },
-        MastNode::Dyn(_) => {},
-        MastNode::External(_) => {},
+        MastNode::External(external_node) => libraries.iter().find_map(|library| {
+            let root = library.find_procedure_root(external_node.digest())?;
+            find_first_call(root, library, libraries, procedures)
+        }),
+        MastNode::Block(_) | MastNode::Call(_) | MastNode::Dyn(_) => None,
     }
 }
 
@@ -4,7 +4,9 @@ use alloc::vec::Vec;
 
 use assert_matches::assert_matches;
 use miden_objects::account::{
+    Account,
     AccountBuilder,
+    AccountCode,
     AccountComponent,
     AccountType,
     PublicKeyCommitment,
@@ -27,6 +29,7 @@ use miden_objects::note::{
 use miden_objects::testing::account_id::{
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2,
+    ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
 };
 use miden_objects::{AccountError, Felt, NoteError, Word, ZERO};
 
@@ -44,7 +47,7 @@ use crate::account::interface::{
     NoteAccountCompatibility,
 };
 use crate::account::wallets::BasicWallet;
-use crate::note::{create_p2id_note, create_p2ide_note, create_swap_note};
+use crate::note::{create_p2id_note, create_p2ide_note, create_p2mid_note, create_swap_note};
 use crate::testing::account_interface::get_public_keys_from_account;
 use crate::transaction::TransactionKernel;
 use crate::utils::ScriptBuilder;
@@ -102,6 +105,19 @@ fn test_basic_wallet_default_notes() {
     )
     .unwrap();
 
+    let p2mid_note = create_p2mid_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        vec![
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2.try_into().unwrap(),
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE.try_into().unwrap(),
+        ],
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        Default::default(),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )
+    .unwrap();
+
     let offered_asset = NonFungibleAsset::mock(&[5, 6, 7, 8]);
     let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
 
@@ -126,6 +142,10 @@ fn test_basic_wallet_default_notes() {
         NoteAccountCompatibility::Maybe,
         wallet_account_interface.is_compatible_with(&p2ide_note)
     );
+    assert_eq!(
+        NoteAccountCompatibility::Maybe,
+        wallet_account_interface.is_compatible_with(&p2mid_note)
+    );
     assert_eq!(
         NoteAccountCompatibility::Maybe,
         wallet_account_interface.is_compatible_with(&swap_note)
@@ -140,6 +160,10 @@ fn test_basic_wallet_default_notes() {
         NoteAccountCompatibility::No,
         faucet_account_interface.is_compatible_with(&p2ide_note)
     );
+    assert_eq!(
+        NoteAccountCompatibility::No,
+        faucet_account_interface.is_compatible_with(&p2mid_note)
+    );
     assert_eq!(
         NoteAccountCompatibility::No,
         faucet_account_interface.is_compatible_with(&swap_note)
@@ -247,6 +271,68 @@ fn test_required_asset_same_as_offered() {
     assert_matches!(result, Err(NoteError::Other { error_msg, .. }) if error_msg == "requested asset same as offered asset".into());
 }
 
+/// Checks that [`AccountInterface::from`] uses the component layout of the account code to group
+/// the procedures of custom components, and falls back to grouping them by storage offset if the
+/// account code has no layout.
+#[test]
+fn test_account_interface_uses_component_layout() {
+    let component1 = AccountComponent::compile(
+        "export.foo push.1 drop end",
+        TransactionKernel::assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+    let component2 = AccountComponent::compile(
+        "export.bar push.2 drop end",
+        TransactionKernel::assembler(),
+        vec![],
+    )
+    .unwrap()
+    .with_supports_all_types();
+
+    let mock_seed = Word::from([0, 1, 2, 3u32]).as_bytes();
+    let account = AccountBuilder::new(mock_seed)
+        .with_auth_component(get_mock_auth_component())
+        .with_component(component1.clone())
+        .with_component(component2.clone())
+        .build_existing()
+        .unwrap();
+
+    // Both custom components have no storage, so their procedures share the same storage offset
+    // and can only be told apart using the layout.
+    let custom_components = |interface: &AccountInterface| -> Vec<Vec<Word>> {
+        interface
+            .components()
+            .iter()
+            .filter_map(|component| match component {
+                AccountComponentInterface::Custom(procedures) => {
+                    Some(procedures.iter().map(|procedure| *procedure.mast_root()).collect())
+                },
+                _ => None,
+            })
+            .collect()
+    };
+
+    let interface = AccountInterface::from(&account);
+    assert_eq!(interface.components().len(), 3);
+    assert_eq!(
+        custom_components(&interface),
+        vec![
+            component1.mast_forest().procedure_digests().collect::<Vec<_>>(),
+            component2.mast_forest().procedure_digests().collect::<Vec<_>>()
+        ]
+    );
+
+    let (id, vault, storage, code, nonce, seed) = account.into_parts();
+    let code_without_layout = AccountCode::from_parts(code.mast(), code.procedures().to_vec());
+    let account = Account::new_unchecked(id, vault, storage, code_without_layout, nonce, seed);
+
+    let interface = AccountInterface::from(&account);
+    assert_eq!(interface.components().len(), 2);
+    assert_eq!(custom_components(&interface).concat().len(), 2);
+}
+
 // CUSTOM NOTES
 // ================================================================================================
 
@@ -671,6 +757,88 @@ fn test_custom_account_multiple_components_custom_notes() {
     );
 }
 
+// NOTE ENTRY POINT
+// ================================================================================================
+
+#[test]
+fn test_find_note_entry_point() {
+    let mock_seed = Word::from([0, 1, 2, 3u32]).as_bytes();
+    let wallet_account = AccountBuilder::new(mock_seed)
+        .with_auth_component(get_mock_auth_component())
+        .with_component(BasicWallet)
+        .build_existing()
+        .expect("failed to create wallet account");
+    let wallet_account_interface = AccountInterface::from(&wallet_account);
+
+    let mock_seed = Word::from([4, 5, 6, 7u32]).as_bytes();
+    let faucet_account = AccountBuilder::new(mock_seed)
+        .account_type(AccountType::FungibleFaucet)
+        .with_auth_component(get_mock_auth_component())
+        .with_component(
+            BasicFungibleFaucet::new(
+                TokenSymbol::new("POL").expect("invalid token symbol"),
+                10,
+                Felt::new(100),
+            )
+            .expect("failed to create a fungible faucet component"),
+        )
+        .build_existing()
+        .expect("failed to create faucet account");
+    let faucet_account_interface = AccountInterface::from(&faucet_account);
+
+    // the P2ID note enters the account through the wallet's `receive_asset` procedure
+    let p2id_note = create_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        wallet_account.id(),
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        Default::default(),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )
+    .unwrap();
+    assert_eq!(
+        wallet_account_interface.find_note_entry_point(&p2id_note),
+        Some(BasicWallet::receive_asset_digest())
+    );
+    assert_eq!(faucet_account_interface.find_note_entry_point(&p2id_note), None);
+
+    // the first procedure of the account called by a custom note is its entry point
+    let source_code = "
+        use.miden::contracts::wallets::basic->wallet
+        use.miden::contracts::faucets::basic_fungible->fungible_faucet
+
+        begin
+            push.1
+            if.true
+                call.fungible_faucet::burn
+            else
+                call.wallet::receive_asset
+                call.wallet::move_asset_to_note
+            end
+        end
+    ";
+    let note_script = ScriptBuilder::default().compile_note_script(source_code).unwrap();
+    let recipient = NoteRecipient::new(Word::empty(), note_script, NoteInputs::default());
+    let metadata = NoteMetadata::new(
+        wallet_account.id(),
+        NoteType::Public,
+        NoteTag::from_account_id(wallet_account.id()),
+        NoteExecutionHint::always(),
+        Default::default(),
+    )
+    .unwrap();
+    let custom_note = Note::new(NoteAssets::default(), metadata, recipient);
+
+    assert_eq!(
+        faucet_account_interface.find_note_entry_point(&custom_note),
+        Some(BasicFungibleFaucet::burn_digest())
+    );
+    assert_eq!(
+        wallet_account_interface.find_note_entry_point(&custom_note),
+        Some(BasicWallet::receive_asset_digest())
+    );
+}
+
 // HELPER TRAIT
 // ================================================================================================
 
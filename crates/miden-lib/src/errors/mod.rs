@@ -1,4 +1,3 @@
-#[cfg(any(feature = "testing", test))]
 #[rustfmt::skip]
 pub mod tx_kernel_errors;
 
@@ -13,4 +12,9 @@ mod script_builder_errors;
 pub use script_builder_errors::ScriptBuilderError;
 
 mod transaction_errors;
-pub use transaction_errors::{TransactionEventError, TransactionTraceParsingError};
+pub use transaction_errors::{
+    TransactionAdviceValidationError,
+    TransactionEventError,
+    TransactionIntentError,
+    TransactionTraceParsingError,
+};
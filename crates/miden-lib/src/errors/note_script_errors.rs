@@ -1,3 +1,5 @@
+use miden_objects::Felt;
+
 use crate::errors::MasmError;
 
 // This file is generated by build.rs, do not modify manually.
@@ -15,6 +17,10 @@ pub const ERR_AUTH_PROCEDURE_CALLED_FROM_WRONG_CONTEXT: MasmError = MasmError::f
 
 /// Error Message: "burn requires exactly 1 note asset"
 pub const ERR_BASIC_FUNGIBLE_BURN_WRONG_NUMBER_OF_ASSETS: MasmError = MasmError::from_static_str("burn requires exactly 1 note asset");
+/// Error Message: "burn requires exactly 1 note asset"
+pub const ERR_BASIC_NON_FUNGIBLE_BURN_WRONG_NUMBER_OF_ASSETS: MasmError = MasmError::from_static_str("burn requires exactly 1 note asset");
+/// Error Message: "mint would cause the maximum supply of the non-fungible faucet to be exceeded"
+pub const ERR_BASIC_NON_FUNGIBLE_MINT_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED: MasmError = MasmError::from_static_str("mint would cause the maximum supply of the non-fungible faucet to be exceeded");
 
 /// Error Message: "number of approvers must be equal to or greater than threshold"
 pub const ERR_MALFORMED_MULTISIG_CONFIG: MasmError = MasmError::from_static_str("number of approvers must be equal to or greater than threshold");
@@ -22,6 +28,15 @@ pub const ERR_MALFORMED_MULTISIG_CONFIG: MasmError = MasmError::from_static_str(
 /// Error Message: "MINT script expects exactly 9 note inputs"
 pub const ERR_MINT_WRONG_NUMBER_OF_INPUTS: MasmError = MasmError::from_static_str("MINT script expects exactly 9 note inputs");
 
+/// Error Message: "public key is already an approver of the multisig account"
+pub const ERR_MULTISIG_APPROVER_ALREADY_EXISTS: MasmError = MasmError::from_static_str("public key is already an approver of the multisig account");
+/// Error Message: "public key is not an approver of the multisig account"
+pub const ERR_MULTISIG_APPROVER_NOT_FOUND: MasmError = MasmError::from_static_str("public key is not an approver of the multisig account");
+/// Error Message: "approver public key must not be the empty word"
+pub const ERR_MULTISIG_APPROVER_PUBLIC_KEY_EMPTY: MasmError = MasmError::from_static_str("approver public key must not be the empty word");
+/// Error Message: "total weight of the approvers must be equal to or greater than threshold"
+pub const ERR_MULTISIG_THRESHOLD_EXCEEDS_TOTAL_WEIGHT: MasmError = MasmError::from_static_str("total weight of the approvers must be equal to or greater than threshold");
+
 /// Error Message: "note sender is not the owner of the faucet who can mint assets"
 pub const ERR_ONLY_OWNER_CAN_MINT: MasmError = MasmError::from_static_str("note sender is not the owner of the faucet who can mint assets");
 
@@ -41,6 +56,13 @@ pub const ERR_P2ID_TARGET_ACCT_MISMATCH: MasmError = MasmError::from_static_str(
 /// Error Message: "P2ID note expects exactly 2 note inputs"
 pub const ERR_P2ID_WRONG_NUMBER_OF_INPUTS: MasmError = MasmError::from_static_str("P2ID note expects exactly 2 note inputs");
 
+/// Error Message: "P2MID note expects between 1 and 8 target accounts"
+pub const ERR_P2MID_INVALID_NUMBER_OF_TARGETS: MasmError = MasmError::from_static_str("P2MID note expects between 1 and 8 target accounts");
+/// Error Message: "P2MID's target account addresses do not contain the transaction address"
+pub const ERR_P2MID_TARGET_ACCT_MISMATCH: MasmError = MasmError::from_static_str("P2MID's target account addresses do not contain the transaction address");
+/// Error Message: "P2MID note expects exactly 1 + 2 * num_targets note inputs"
+pub const ERR_P2MID_WRONG_NUMBER_OF_INPUTS: MasmError = MasmError::from_static_str("P2MID note expects exactly 1 + 2 * num_targets note inputs");
+
 /// Error Message: "SWAP script requires exactly 1 note asset"
 pub const ERR_SWAP_WRONG_NUMBER_OF_ASSETS: MasmError = MasmError::from_static_str("SWAP script requires exactly 1 note asset");
 /// Error Message: "SWAP script expects exactly 12 note inputs"
@@ -48,3 +70,37 @@ pub const ERR_SWAP_WRONG_NUMBER_OF_INPUTS: MasmError = MasmError::from_static_st
 
 /// Error Message: "number of approvers or threshold must not be zero"
 pub const ERR_ZERO_IN_MULTISIG_CONFIG: MasmError = MasmError::from_static_str("number of approvers or threshold must not be zero");
+
+/// All errors of this category.
+pub static NOTE_SCRIPT_ERRORS: [MasmError; 24] = [
+    ERR_AUTH_PROCEDURE_CALLED_FROM_WRONG_CONTEXT,
+    ERR_BASIC_FUNGIBLE_BURN_WRONG_NUMBER_OF_ASSETS,
+    ERR_BASIC_NON_FUNGIBLE_BURN_WRONG_NUMBER_OF_ASSETS,
+    ERR_BASIC_NON_FUNGIBLE_MINT_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+    ERR_MALFORMED_MULTISIG_CONFIG,
+    ERR_MINT_WRONG_NUMBER_OF_INPUTS,
+    ERR_MULTISIG_APPROVER_ALREADY_EXISTS,
+    ERR_MULTISIG_APPROVER_NOT_FOUND,
+    ERR_MULTISIG_APPROVER_PUBLIC_KEY_EMPTY,
+    ERR_MULTISIG_THRESHOLD_EXCEEDS_TOTAL_WEIGHT,
+    ERR_ONLY_OWNER_CAN_MINT,
+    ERR_P2IDE_RECLAIM_ACCT_IS_NOT_SENDER,
+    ERR_P2IDE_RECLAIM_DISABLED,
+    ERR_P2IDE_RECLAIM_HEIGHT_NOT_REACHED,
+    ERR_P2IDE_TIMELOCK_HEIGHT_NOT_REACHED,
+    ERR_P2IDE_WRONG_NUMBER_OF_INPUTS,
+    ERR_P2ID_TARGET_ACCT_MISMATCH,
+    ERR_P2ID_WRONG_NUMBER_OF_INPUTS,
+    ERR_P2MID_INVALID_NUMBER_OF_TARGETS,
+    ERR_P2MID_TARGET_ACCT_MISMATCH,
+    ERR_P2MID_WRONG_NUMBER_OF_INPUTS,
+    ERR_SWAP_WRONG_NUMBER_OF_ASSETS,
+    ERR_SWAP_WRONG_NUMBER_OF_INPUTS,
+    ERR_ZERO_IN_MULTISIG_CONFIG,
+];
+
+/// Returns the message of the error in this category with the provided error code, or `None` if
+/// no such error exists.
+pub fn message_for_code(code: Felt) -> Option<&'static str> {
+    NOTE_SCRIPT_ERRORS.iter().find(|error| error.code() == code).map(MasmError::message)
+}
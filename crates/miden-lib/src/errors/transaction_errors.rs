@@ -1,7 +1,14 @@
 use miden_core::EventId;
+use miden_objects::account::AccountId;
+use miden_objects::{AssetError, NoteError, Word};
 use thiserror::Error;
 
-use crate::transaction::TransactionEvent;
+use crate::account::interface::AccountInterfaceError;
+use crate::transaction::{
+    TransactionAdviceMapEntry,
+    TransactionAdviceMapMismatch,
+    TransactionEvent,
+};
 
 // TRANSACTION EVENT PARSING ERROR
 // ================================================================================================
@@ -26,3 +33,45 @@ pub enum TransactionTraceParsingError {
     #[error("trace id {0} is an unknown transaction kernel trace")]
     UnknownTransactionTrace(u32),
 }
+
+// TRANSACTION INTENT ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionIntentError {
+    #[error("account {0} does not expose the basic wallet interface and cannot pay assets")]
+    PayFromNonWallet(AccountId),
+    #[error("account {0} is a fungible faucet and cannot pay assets")]
+    PayFromFaucet(AccountId),
+    #[error(
+        "account {0} does not expose the basic fungible faucet interface and cannot mint assets"
+    )]
+    MintFromNonFaucet(AccountId),
+    #[error("failed to create the minted asset")]
+    InvalidMintedAsset(#[source] AssetError),
+    #[error("failed to create output note")]
+    OutputNoteCreationFailed(#[source] NoteError),
+    #[error("failed to build the transaction script of the intent")]
+    TransactionScriptBuildFailed(#[source] AccountInterfaceError),
+}
+
+// TRANSACTION ADVICE VALIDATION ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum TransactionAdviceValidationError {
+    #[error("failed to build the expected transaction advice inputs")]
+    ExpectedAdviceInputsConflict(#[source] TransactionAdviceMapMismatch),
+    #[error("advice stack does not match the expected transaction inputs at index {index}")]
+    AdviceStackMismatch { index: usize },
+    #[error("advice map entry {entry:?} under key {key} is missing")]
+    MissingMapEntry {
+        entry: TransactionAdviceMapEntry,
+        key: Word,
+    },
+    #[error("advice map entry {entry:?} under key {key} does not match the transaction inputs")]
+    MalformedMapEntry {
+        entry: TransactionAdviceMapEntry,
+        key: Word,
+    },
+}
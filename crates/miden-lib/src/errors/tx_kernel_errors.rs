@@ -1,3 +1,5 @@
+use miden_objects::Felt;
+
 use crate::errors::MasmError;
 
 // This file is generated by build.rs, do not modify manually.
@@ -226,6 +228,8 @@ pub const ERR_PROLOGUE_VERIFICATION_BASE_FEE_MUST_BE_U32: MasmError = MasmError:
 
 /// Error Message: "failed to approve multisig transaction as it was already executed"
 pub const ERR_TX_ALREADY_EXECUTED: MasmError = MasmError::from_static_str("failed to approve multisig transaction as it was already executed");
+/// Error Message: "custom event ID must be a u32 value"
+pub const ERR_TX_CUSTOM_EVENT_ID_NOT_U32: MasmError = MasmError::from_static_str("custom event ID must be a u32 value");
 /// Error Message: "transaction expiration block delta must be within 0x1 and 0xFFFF"
 pub const ERR_TX_INVALID_EXPIRATION_DELTA: MasmError = MasmError::from_static_str("transaction expiration block delta must be within 0x1 and 0xFFFF");
 /// Error Message: "number of output notes in the transaction exceeds the maximum limit of 1024"
@@ -251,3 +255,128 @@ pub const ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND: MasmError = MasmErro
 pub const ERR_VAULT_PEEK_BALANCE_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_ASSET: MasmError = MasmError::from_static_str("peek_balance can only be called on a fungible asset");
 /// Error Message: "failed to remove fungible asset from the asset vault due to the initial value being invalid"
 pub const ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID: MasmError = MasmError::from_static_str("failed to remove fungible asset from the asset vault due to the initial value being invalid");
+
+/// All errors of this category.
+pub static TX_KERNEL_ERRORS: [MasmError; 115] = [
+    ERR_ACCOUNT_CODE_COMMITMENT_MISMATCH,
+    ERR_ACCOUNT_CODE_IS_NOT_UPDATABLE,
+    ERR_ACCOUNT_DELTA_NONCE_MUST_BE_INCREMENTED_IF_VAULT_OR_STORAGE_CHANGED,
+    ERR_ACCOUNT_ID_NON_PUBLIC_NETWORK_ACCOUNT,
+    ERR_ACCOUNT_ID_SUFFIX_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO,
+    ERR_ACCOUNT_ID_SUFFIX_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO,
+    ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE,
+    ERR_ACCOUNT_ID_UNKNOWN_VERSION,
+    ERR_ACCOUNT_INVALID_STORAGE_OFFSET_FOR_SIZE,
+    ERR_ACCOUNT_IS_NOT_NATIVE,
+    ERR_ACCOUNT_NONCE_AT_MAX,
+    ERR_ACCOUNT_NONCE_CAN_ONLY_BE_INCREMENTED_ONCE,
+    ERR_ACCOUNT_PROC_INDEX_OUT_OF_BOUNDS,
+    ERR_ACCOUNT_PROC_NOT_AUTH_PROC,
+    ERR_ACCOUNT_PROC_NOT_PART_OF_ACCOUNT_CODE,
+    ERR_ACCOUNT_READING_MAP_VALUE_FROM_NON_MAP_SLOT,
+    ERR_ACCOUNT_SEED_AND_COMMITMENT_DIGEST_MISMATCH,
+    ERR_ACCOUNT_SETTING_MAP_ITEM_ON_NON_MAP_SLOT,
+    ERR_ACCOUNT_SETTING_VALUE_ITEM_ON_NON_VALUE_SLOT,
+    ERR_ACCOUNT_STACK_OVERFLOW,
+    ERR_ACCOUNT_STACK_UNDERFLOW,
+    ERR_ACCOUNT_STORAGE_COMMITMENT_MISMATCH,
+    ERR_ACCOUNT_STORAGE_MAP_ENTRIES_DO_NOT_MATCH_MAP_ROOT,
+    ERR_ACCOUNT_STORAGE_SLOT_INDEX_OUT_OF_BOUNDS,
+    ERR_ACCOUNT_TOO_MANY_PROCEDURES,
+    ERR_ACCOUNT_TOO_MANY_STORAGE_SLOTS,
+    ERR_EPILOGUE_EXECUTED_TRANSACTION_IS_EMPTY,
+    ERR_EPILOGUE_NONCE_CANNOT_BE_0,
+    ERR_EPILOGUE_TOTAL_NUMBER_OF_ASSETS_MUST_STAY_THE_SAME,
+    ERR_FAUCET_BURN_CANNOT_EXCEED_EXISTING_TOTAL_SUPPLY,
+    ERR_FAUCET_BURN_NON_FUNGIBLE_ASSET_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET,
+    ERR_FAUCET_INVALID_STORAGE_OFFSET,
+    ERR_FAUCET_IS_NF_ASSET_ISSUED_PROC_CAN_ONLY_BE_CALLED_ON_NON_FUNGIBLE_FAUCET,
+    ERR_FAUCET_NEW_TOTAL_SUPPLY_WOULD_EXCEED_MAX_ASSET_AMOUNT,
+    ERR_FAUCET_NON_FUNGIBLE_ASSET_ALREADY_ISSUED,
+    ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND,
+    ERR_FAUCET_STORAGE_DATA_SLOT_IS_RESERVED,
+    ERR_FAUCET_TOTAL_ISSUANCE_PROC_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_FAUCET,
+    ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT,
+    ERR_FOREIGN_ACCOUNT_ID_IS_ZERO,
+    ERR_FOREIGN_ACCOUNT_INVALID_COMMITMENT,
+    ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
+    ERR_FUNGIBLE_ASSET_AMOUNT_EXCEEDS_MAX_ALLOWED_AMOUNT,
+    ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+    ERR_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN,
+    ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ONE_MUST_BE_ZERO,
+    ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_TWO_AND_THREE_MUST_BE_FUNGIBLE_FAUCET_ID,
+    ERR_FUNGIBLE_ASSET_FORMAT_ELEMENT_ZERO_MUST_BE_WITHIN_LIMITS,
+    ERR_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID,
+    ERR_INPUT_NOTE_INDEX_OUT_OF_BOUNDS,
+    ERR_KERNEL_PROCEDURE_OFFSET_OUT_OF_BOUNDS,
+    ERR_LINK_MAP_CANNOT_BE_EMPTY_ON_ABSENCE_AFTER_ENTRY,
+    ERR_LINK_MAP_ENTRY_PTR_IS_NOT_ENTRY_ALIGNED,
+    ERR_LINK_MAP_ENTRY_PTR_IS_OUTSIDE_VALID_MEMORY_REGION,
+    ERR_LINK_MAP_MAP_PTR_IN_ENTRY_DOES_NOT_MATCH_EXPECTED_MAP_PTR,
+    ERR_LINK_MAP_MAX_ENTRIES_EXCEEDED,
+    ERR_LINK_MAP_PROVIDED_KEY_NOT_EQUAL_TO_ENTRY_KEY,
+    ERR_LINK_MAP_PROVIDED_KEY_NOT_GREATER_THAN_ENTRY_KEY,
+    ERR_LINK_MAP_PROVIDED_KEY_NOT_LESS_THAN_ENTRY_KEY,
+    ERR_NON_FUNGIBLE_ASSET_ALREADY_EXISTS,
+    ERR_NON_FUNGIBLE_ASSET_FAUCET_IS_NOT_ORIGIN,
+    ERR_NON_FUNGIBLE_ASSET_FORMAT_ELEMENT_THREE_MUST_BE_FUNGIBLE_FAUCET_ID,
+    ERR_NON_FUNGIBLE_ASSET_FORMAT_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO,
+    ERR_NON_FUNGIBLE_ASSET_PROVIDED_FAUCET_ID_IS_INVALID,
+    ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_ASSETS_WHILE_NO_NOTE_BEING_PROCESSED,
+    ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_INPUTS_WHILE_NO_NOTE_BEING_PROCESSED,
+    ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_METADATA_WHILE_NO_NOTE_BEING_PROCESSED,
+    ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_RECIPIENT_WHILE_NO_NOTE_BEING_PROCESSED,
+    ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SCRIPT_ROOT_WHILE_NO_NOTE_BEING_PROCESSED,
+    ERR_NOTE_ATTEMPT_TO_ACCESS_NOTE_SERIAL_NUMBER_WHILE_NO_NOTE_BEING_PROCESSED,
+    ERR_NOTE_DATA_DOES_NOT_MATCH_COMMITMENT,
+    ERR_NOTE_FUNGIBLE_MAX_AMOUNT_EXCEEDED,
+    ERR_NOTE_INVALID_INDEX,
+    ERR_NOTE_INVALID_NOTE_TYPE_FOR_NOTE_TAG_PREFIX,
+    ERR_NOTE_INVALID_NUMBER_OF_INPUTS,
+    ERR_NOTE_INVALID_TYPE,
+    ERR_NOTE_NUM_OF_ASSETS_EXCEED_LIMIT,
+    ERR_NOTE_TAG_MUST_BE_U32,
+    ERR_OUTPUT_NOTE_INDEX_OUT_OF_BOUNDS,
+    ERR_PROLOGUE_EXISTING_ACCOUNT_MUST_HAVE_NON_ZERO_NONCE,
+    ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_COMMITMENT,
+    ERR_PROLOGUE_GLOBAL_INPUTS_PROVIDED_DO_NOT_MATCH_BLOCK_NUMBER_COMMITMENT,
+    ERR_PROLOGUE_INPUT_NOTES_COMMITMENT_MISMATCH,
+    ERR_PROLOGUE_KERNEL_PROCEDURE_COMMITMENT_MISMATCH,
+    ERR_PROLOGUE_MISMATCH_OF_ACCOUNT_IDS_FROM_GLOBAL_INPUTS_AND_ADVICE_PROVIDER,
+    ERR_PROLOGUE_MISMATCH_OF_REFERENCE_BLOCK_MMR_AND_NOTE_AUTHENTICATION_MMR,
+    ERR_PROLOGUE_NATIVE_ASSET_ID_IS_NOT_FUNGIBLE,
+    ERR_PROLOGUE_NEW_ACCOUNT_NONCE_MUST_BE_ZERO,
+    ERR_PROLOGUE_NEW_ACCOUNT_VAULT_MUST_BE_EMPTY,
+    ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE,
+    ERR_PROLOGUE_NEW_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_EMPTY,
+    ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_INVALID_TYPE,
+    ERR_PROLOGUE_NEW_NON_FUNGIBLE_FAUCET_RESERVED_SLOT_MUST_BE_VALID_EMPTY_SMT,
+    ERR_PROLOGUE_NOTE_AUTHENTICATION_FAILED,
+    ERR_PROLOGUE_NOTE_INPUTS_LEN_EXCEEDED_LIMIT,
+    ERR_PROLOGUE_NUMBER_OF_INPUT_NOTES_EXCEEDS_LIMIT,
+    ERR_PROLOGUE_NUMBER_OF_NOTE_ASSETS_EXCEEDS_LIMIT,
+    ERR_PROLOGUE_NUMBER_OF_NOTE_INPUTS_EXCEEDED_LIMIT,
+    ERR_PROLOGUE_PROVIDED_ACCOUNT_DATA_DOES_NOT_MATCH_ON_CHAIN_COMMITMENT,
+    ERR_PROLOGUE_PROVIDED_INPUT_ASSETS_INFO_DOES_NOT_MATCH_ITS_COMMITMENT,
+    ERR_PROLOGUE_VERIFICATION_BASE_FEE_MUST_BE_U32,
+    ERR_TX_ALREADY_EXECUTED,
+    ERR_TX_CUSTOM_EVENT_ID_NOT_U32,
+    ERR_TX_INVALID_EXPIRATION_DELTA,
+    ERR_TX_NUMBER_OF_OUTPUT_NOTES_EXCEEDS_LIMIT,
+    ERR_TX_TRANSACTION_SCRIPT_IS_MISSING,
+    ERR_VAULT_ADD_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID,
+    ERR_VAULT_FUNGIBLE_ASSET_AMOUNT_LESS_THAN_AMOUNT_TO_WITHDRAW,
+    ERR_VAULT_FUNGIBLE_MAX_AMOUNT_EXCEEDED,
+    ERR_VAULT_GET_BALANCE_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_ASSET,
+    ERR_VAULT_HAS_NON_FUNGIBLE_ASSET_PROC_CAN_BE_CALLED_ONLY_WITH_NON_FUNGIBLE_ASSET,
+    ERR_VAULT_NON_FUNGIBLE_ASSET_ALREADY_EXISTS,
+    ERR_VAULT_NON_FUNGIBLE_ASSET_TO_REMOVE_NOT_FOUND,
+    ERR_VAULT_PEEK_BALANCE_CAN_ONLY_BE_CALLED_ON_FUNGIBLE_ASSET,
+    ERR_VAULT_REMOVE_FUNGIBLE_ASSET_FAILED_INITIAL_VALUE_INVALID,
+];
+
+/// Returns the message of the error in this category with the provided error code, or `None` if
+/// no such error exists.
+pub fn message_for_code(code: Felt) -> Option<&'static str> {
+    TX_KERNEL_ERRORS.iter().find(|error| error.code() == code).map(MasmError::message)
+}
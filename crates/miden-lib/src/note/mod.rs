@@ -1,7 +1,8 @@
+use alloc::format;
 use alloc::vec::Vec;
 
-use miden_objects::account::AccountId;
-use miden_objects::asset::Asset;
+use miden_objects::account::{Account, AccountId};
+use miden_objects::asset::{Asset, FungibleAsset};
 use miden_objects::block::BlockNumber;
 use miden_objects::crypto::rand::FeltRng;
 use miden_objects::note::{
@@ -9,6 +10,7 @@ use miden_objects::note::{
     NoteAssets,
     NoteDetails,
     NoteExecutionHint,
+    NoteId,
     NoteInputs,
     NoteMetadata,
     NoteRecipient,
@@ -18,11 +20,19 @@ use miden_objects::note::{
 use miden_objects::{Felt, NoteError, Word};
 use utils::build_swap_tag;
 
+use crate::account::interface::{AccountComponentInterface, AccountInterface};
+
 pub mod utils;
 
 mod well_known_note;
 pub use well_known_note::{NoteConsumptionStatus, WellKnownNote};
 
+mod p2ide_status;
+pub use p2ide_status::P2ideStatus;
+
+mod script_analysis;
+pub use script_analysis::{NoteScriptAnalysis, RiskReport};
+
 // STANDARDIZED SCRIPTS
 // ================================================================================================
 
@@ -94,6 +104,145 @@ pub fn create_p2ide_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a P2IDE cancel note - a marker note signalling that the sender of the P2IDE note with
+/// the provided ID cancelled it.
+///
+/// The cancel note is a public P2ID note without assets from the `sender` to itself, whose serial
+/// number is the ID of the cancelled note. Its ID is therefore determined by the cancelled note and
+/// can be computed via [`p2ide_cancel_note_id`], and the sender can consume it to clean it up. The
+/// returned note's tag is set to the `target`'s account ID, so that the target of the cancelled
+/// note discovers it.
+///
+/// Cancellation is cooperative: the cancelled note stays consumable by the target until the sender
+/// reclaims it, see [`P2ideStatus`].
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2ID` script fails.
+pub fn create_p2ide_cancel_note(
+    original_note_id: NoteId,
+    sender: AccountId,
+    target: AccountId,
+) -> Result<Note, NoteError> {
+    let recipient = utils::build_p2id_recipient(sender, original_note_id.as_word())?;
+    let tag = NoteTag::from_account_id(target);
+
+    let metadata = NoteMetadata::new(
+        sender,
+        NoteType::Public,
+        tag,
+        NoteExecutionHint::always(),
+        Felt::new(0),
+    )?;
+
+    Ok(Note::new(NoteAssets::default(), metadata, recipient))
+}
+
+/// Returns the ID of the cancel note the sender of the provided P2IDE note would emit to cancel
+/// it, see [`create_p2ide_cancel_note`].
+///
+/// Note that the ID of a note does not commit to its sender, so only cancel notes whose sender is
+/// the sender of `original` should be taken into account.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2ID` script fails.
+pub fn p2ide_cancel_note_id(original: &Note) -> Result<NoteId, NoteError> {
+    let recipient =
+        utils::build_p2id_recipient(original.metadata().sender(), original.id().as_word())?;
+
+    Ok(NoteId::new(recipient.digest(), NoteAssets::default().commitment()))
+}
+
+/// Returns the ID of the target account of the provided note if it is a P2IDE note with valid
+/// inputs, or `None` otherwise.
+pub fn p2ide_target(note: &Note) -> Option<AccountId> {
+    if !matches!(WellKnownNote::from_note(note), Some(WellKnownNote::P2IDE)) {
+        return None;
+    }
+
+    well_known_note::parse_p2ide_inputs(note.inputs().values())
+        .ok()
+        .map(|(target, ..)| target)
+}
+
+/// Generates a P2MID note - Pay-to-Multiple-ID note.
+///
+/// This script enables the transfer of assets from the `sender` account to any one of the
+/// `targets` accounts, e.g. the accounts of multiple devices of the same user. The note can be
+/// consumed only once, by whichever of the target accounts consumes it first.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the first target's account ID.
+///
+/// # Errors
+/// Returns an error if:
+/// - `targets` is empty or contains more than [`WellKnownNote::P2MID_MAX_NUM_TARGETS`] account IDs.
+/// - deserialization or compilation of the `P2MID` script fails.
+pub fn create_p2mid_note<R: FeltRng>(
+    sender: AccountId,
+    targets: Vec<AccountId>,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<Note, NoteError> {
+    let serial_num = rng.draw_word();
+    let recipient = utils::build_p2mid_recipient(&targets, serial_num)?;
+
+    let tag = NoteTag::from_account_id(targets[0]);
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, NoteExecutionHint::always(), aux)?;
+    let vault = NoteAssets::new(assets)?;
+
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Generates a P2ID change note which sends the part of `asset` exceeding `keep_amount` from the
+/// `wallet` to the `recipient`, and returns the note together with the asset kept by the wallet.
+///
+/// This covers the common pattern of a wallet receiving an asset, keeping some of it and returning
+/// the rest, e.g. to the sender of the received note. The change note is created via
+/// [`create_p2id_note`], so the passed-in `rng` is used to generate its serial number.
+///
+/// # Errors
+/// Returns an error if:
+/// - the `wallet` does not expose the [`BasicWallet`](crate::account::wallets::BasicWallet)
+///   interface, which is required to move the change to the note.
+/// - `keep_amount` is not less than the amount of `asset`, i.e. there is no change to return.
+/// - deserialization or compilation of the `P2ID` script fails.
+pub fn create_asset_split_note<R: FeltRng>(
+    wallet: &Account,
+    asset: FungibleAsset,
+    keep_amount: u64,
+    recipient: AccountId,
+    note_type: NoteType,
+    aux: Felt,
+    rng: &mut R,
+) -> Result<(Note, FungibleAsset), NoteError> {
+    let interface = AccountInterface::from(wallet);
+    if !interface.components().contains(&AccountComponentInterface::BasicWallet) {
+        return Err(NoteError::other(format!(
+            "account {} does not expose the basic wallet interface",
+            wallet.id()
+        )));
+    }
+
+    if keep_amount >= asset.amount() {
+        return Err(NoteError::other(format!(
+            "keep amount {keep_amount} must be less than the asset amount {}",
+            asset.amount()
+        )));
+    }
+
+    let kept_asset = FungibleAsset::new(asset.faucet_id(), keep_amount)
+        .expect("keep amount should be less than a valid asset amount");
+    let change_asset = asset.sub(kept_asset).expect("keep amount should be less than the amount");
+
+    let note =
+        create_p2id_note(wallet.id(), recipient, vec![change_asset.into()], note_type, aux, rng)?;
+
+    Ok((note, kept_asset))
+}
+
 /// Generates a SWAP note - swap of assets between two accounts - and returns the note as well as
 /// [NoteDetails] for the payback note.
 ///
@@ -0,0 +1,209 @@
+use miden_objects::NoteError;
+use miden_objects::block::BlockNumber;
+use miden_objects::note::Note;
+
+use super::well_known_note::{WellKnownNote, parse_p2ide_inputs};
+
+// P2IDE STATUS
+// ================================================================================================
+
+/// Describes the state of a P2IDE note from the point of view of a wallet tracking it.
+///
+/// Cancellation of a P2IDE note is cooperative: the note itself remains consumable by the target
+/// account after the sender emitted a cancel note for it (see
+/// [`create_p2ide_cancel_note`](super::create_p2ide_cancel_note)), but a target which has seen the
+/// cancel note should stop trying to consume it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum P2ideStatus {
+    /// The timelock height of the note has not been reached yet, so the note cannot be consumed by
+    /// anyone.
+    TimelockPending,
+    /// The note can be consumed by the target account, but not be reclaimed by the sender yet.
+    Consumable,
+    /// The reclaim height of the note has been reached, so the note can be reclaimed by the
+    /// sender. The target account can still consume the note until it is reclaimed.
+    ReclaimableBySender,
+    /// The sender of the note emitted a cancel note for it, so the target account should not try
+    /// to consume it anymore.
+    Cancelled,
+}
+
+impl P2ideStatus {
+    /// Returns the status of the provided P2IDE note at the provided chain tip.
+    ///
+    /// `cancel_seen` specifies whether a cancel note for `original` was seen, in which case the
+    /// note is [`P2ideStatus::Cancelled`] regardless of its timelock and reclaim heights.
+    /// Otherwise, the status is determined from the note inputs:
+    /// - before the timelock height, the note is [`P2ideStatus::TimelockPending`].
+    /// - at or after the reclaim height (if reclaiming is enabled), the note is
+    ///   [`P2ideStatus::ReclaimableBySender`].
+    /// - otherwise, the note is [`P2ideStatus::Consumable`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the provided note is not a P2IDE note.
+    /// - the inputs of the provided note are not valid P2IDE note inputs.
+    pub fn evaluate(
+        original: &Note,
+        chain_tip: BlockNumber,
+        cancel_seen: bool,
+    ) -> Result<Self, NoteError> {
+        if !matches!(WellKnownNote::from_note(original), Some(WellKnownNote::P2IDE)) {
+            return Err(NoteError::other("note is not a P2IDE note"));
+        }
+
+        let (_, reclaim_height, timelock_height) =
+            parse_p2ide_inputs(original.inputs().values())
+                .map_err(|err| NoteError::other_with_source("invalid P2IDE note inputs", err))?;
+
+        if cancel_seen {
+            return Ok(Self::Cancelled);
+        }
+
+        let chain_tip = chain_tip.as_u32();
+        if chain_tip < timelock_height {
+            Ok(Self::TimelockPending)
+        } else if reclaim_height != 0 && chain_tip >= reclaim_height {
+            Ok(Self::ReclaimableBySender)
+        } else {
+            Ok(Self::Consumable)
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use miden_objects::account::AccountId;
+    use miden_objects::crypto::rand::RpoRandomCoin;
+    use miden_objects::note::{NoteTag, NoteType};
+    use miden_objects::testing::account_id::{
+        ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    };
+    use miden_objects::{Felt, Word};
+
+    use super::*;
+    use crate::note::{
+        create_p2id_note,
+        create_p2ide_cancel_note,
+        create_p2ide_note,
+        p2ide_cancel_note_id,
+        p2ide_target,
+    };
+
+    fn p2ide_note(reclaim_height: Option<u32>, timelock_height: Option<u32>) -> Note {
+        let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+        create_p2ide_note(
+            sender,
+            target,
+            vec![],
+            reclaim_height.map(BlockNumber::from),
+            timelock_height.map(BlockNumber::from),
+            NoteType::Public,
+            Felt::new(0),
+            &mut RpoRandomCoin::new(Word::empty()),
+        )
+        .unwrap()
+    }
+
+    fn status_at(note: &Note, chain_tip: u32) -> P2ideStatus {
+        P2ideStatus::evaluate(note, BlockNumber::from(chain_tip), false).unwrap()
+    }
+
+    #[test]
+    fn p2ide_status_timelock_before_reclaim() {
+        let note = p2ide_note(Some(20), Some(10));
+
+        assert_eq!(status_at(&note, 0), P2ideStatus::TimelockPending);
+        assert_eq!(status_at(&note, 9), P2ideStatus::TimelockPending);
+        assert_eq!(status_at(&note, 10), P2ideStatus::Consumable);
+        assert_eq!(status_at(&note, 19), P2ideStatus::Consumable);
+        assert_eq!(status_at(&note, 20), P2ideStatus::ReclaimableBySender);
+        assert_eq!(status_at(&note, 100), P2ideStatus::ReclaimableBySender);
+    }
+
+    #[test]
+    fn p2ide_status_without_timelock_or_reclaim() {
+        // Without a timelock the note is consumable right away.
+        let note = p2ide_note(Some(5), None);
+        assert_eq!(status_at(&note, 0), P2ideStatus::Consumable);
+        assert_eq!(status_at(&note, 5), P2ideStatus::ReclaimableBySender);
+
+        // Without a reclaim height the note is never reclaimable.
+        let note = p2ide_note(None, Some(5));
+        assert_eq!(status_at(&note, 4), P2ideStatus::TimelockPending);
+        assert_eq!(status_at(&note, 5), P2ideStatus::Consumable);
+        assert_eq!(status_at(&note, u32::MAX), P2ideStatus::Consumable);
+
+        // A reclaim height before the timelock height only takes effect once the timelock expired.
+        let note = p2ide_note(Some(5), Some(10));
+        assert_eq!(status_at(&note, 5), P2ideStatus::TimelockPending);
+        assert_eq!(status_at(&note, 10), P2ideStatus::ReclaimableBySender);
+    }
+
+    #[test]
+    fn p2ide_status_cancelled() {
+        let note = p2ide_note(Some(20), Some(10));
+
+        for chain_tip in [0, 10, 20] {
+            assert_eq!(
+                P2ideStatus::evaluate(&note, BlockNumber::from(chain_tip), true).unwrap(),
+                P2ideStatus::Cancelled
+            );
+        }
+    }
+
+    #[test]
+    fn p2ide_status_rejects_non_p2ide_notes() {
+        let sender = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let note = create_p2id_note(
+            sender,
+            sender,
+            vec![],
+            NoteType::Public,
+            Felt::new(0),
+            &mut RpoRandomCoin::new(Word::empty()),
+        )
+        .unwrap();
+
+        assert!(P2ideStatus::evaluate(&note, BlockNumber::from(0), false).is_err());
+        assert_eq!(p2ide_target(&note), None);
+    }
+
+    #[test]
+    fn p2ide_note_target() {
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+        assert_eq!(p2ide_target(&p2ide_note(Some(20), Some(10))), Some(target));
+    }
+
+    #[test]
+    fn p2ide_cancel_note() {
+        let note = p2ide_note(Some(20), Some(10));
+        let sender = note.metadata().sender();
+        let target =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+        let cancel_note = create_p2ide_cancel_note(note.id(), sender, target).unwrap();
+
+        assert_eq!(cancel_note.id(), p2ide_cancel_note_id(&note).unwrap());
+        assert_eq!(cancel_note.metadata().sender(), sender);
+        assert_eq!(cancel_note.metadata().note_type(), NoteType::Public);
+        assert_eq!(cancel_note.metadata().tag(), NoteTag::from_account_id(target));
+        assert!(cancel_note.assets().is_empty());
+
+        // The cancel note of a different note has a different ID.
+        let other_note = p2ide_note(Some(21), Some(10));
+        assert_ne!(cancel_note.id(), p2ide_cancel_note_id(&other_note).unwrap());
+    }
+}
@@ -0,0 +1,246 @@
+use alloc::vec::Vec;
+
+use miden_objects::Word;
+use miden_objects::note::NoteScript;
+
+use crate::account::faucets::{BasicFungibleFaucet, BasicNonFungibleFaucet, NetworkFungibleFaucet};
+use crate::account::interface::collect_calls_with_libraries;
+use crate::account::wallets::BasicWallet;
+use crate::{MidenLib, StdLibrary};
+
+// NOTE SCRIPT ANALYSIS
+// ================================================================================================
+
+/// A static analysis of the account procedures a [`NoteScript`] may call, e.g. to warn wallet
+/// users about notes which can move assets out of their account.
+///
+/// The analysis traverses all execution branches of the note script's MAST, including the
+/// procedures of the [`MidenLib`] and [`StdLibrary`] it references, and collects the procedures
+/// invoked via `call`. Kernel procedures are invoked via `syscall` and are not part of the
+/// analysis.
+///
+/// Procedures invoked via `dynexec` or `dyncall` and external procedures which are not part of
+/// either library cannot be analyzed statically. Their presence is flagged in the [`RiskReport`],
+/// in which case the report may be incomplete.
+pub struct NoteScriptAnalysis;
+
+impl NoteScriptAnalysis {
+    /// Analyzes the provided note script and returns a [`RiskReport`] listing which of the known
+    /// asset-affecting account procedures are reachable from the script.
+    ///
+    /// The known procedures are:
+    /// - `move_asset_to_note` of the [`BasicWallet`], which sends assets out of the account.
+    /// - `receive_asset` of the [`BasicWallet`], which adds assets to the account.
+    /// - `distribute` of the [`BasicFungibleFaucet`] and [`NetworkFungibleFaucet`] and `mint` of
+    ///   the [`BasicNonFungibleFaucet`], which mint new assets.
+    /// - `burn` of all of the above faucets, which burns assets.
+    ///
+    /// The roots of all other called procedures are listed in [`RiskReport::unknown_calls`].
+    pub fn analyze(note_script: &NoteScript) -> RiskReport {
+        let send_procedures = [BasicWallet::move_asset_to_note_digest()];
+        let receive_procedures = [BasicWallet::receive_asset_digest()];
+        let mint_procedures = [
+            BasicFungibleFaucet::distribute_digest(),
+            NetworkFungibleFaucet::distribute_digest(),
+            BasicNonFungibleFaucet::mint_digest(),
+        ];
+        let burn_procedures = [
+            BasicFungibleFaucet::burn_digest(),
+            NetworkFungibleFaucet::burn_digest(),
+            BasicNonFungibleFaucet::burn_digest(),
+        ];
+
+        let miden_lib = MidenLib::default();
+        let std_lib = StdLibrary::default();
+        let (reachable_calls, unresolved) = collect_calls_with_libraries(
+            note_script,
+            &[miden_lib.mast_forest(), std_lib.mast_forest()],
+        );
+        let is_reachable = |procedures: &[Word]| {
+            procedures.iter().any(|procedure| reachable_calls.contains(procedure))
+        };
+
+        let unknown_calls = reachable_calls
+            .iter()
+            .filter(|procedure| {
+                ![
+                    send_procedures.as_slice(),
+                    &receive_procedures,
+                    &mint_procedures,
+                    &burn_procedures,
+                ]
+                .iter()
+                .any(|procedures| procedures.contains(procedure))
+            })
+            .copied()
+            .collect();
+
+        RiskReport {
+            can_send_assets: is_reachable(&send_procedures),
+            can_receive_assets: is_reachable(&receive_procedures),
+            can_mint: is_reachable(&mint_procedures),
+            can_burn: is_reachable(&burn_procedures),
+            unknown_calls,
+            has_dynamic_calls: unresolved.dynamic_calls,
+            unresolved_procedures: unresolved.external_procedures.into_iter().collect(),
+        }
+    }
+}
+
+// RISK REPORT
+// ================================================================================================
+
+/// The result of a [`NoteScriptAnalysis`], describing how a note script may affect the assets of
+/// the account consuming the note.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RiskReport {
+    /// Whether the note script may move assets from the account to an output note.
+    pub can_send_assets: bool,
+    /// Whether the note script may add assets to the account.
+    pub can_receive_assets: bool,
+    /// Whether the note script may mint assets when consumed by a faucet.
+    pub can_mint: bool,
+    /// Whether the note script may burn assets when consumed by a faucet.
+    pub can_burn: bool,
+    /// The roots of the called procedures whose effect on the assets of the account is unknown,
+    /// in ascending order.
+    pub unknown_calls: Vec<Word>,
+    /// Whether the note script invokes procedures via `dynexec` or `dyncall`, whose targets cannot
+    /// be determined statically.
+    pub has_dynamic_calls: bool,
+    /// The roots of the procedures executed by the note script which are neither part of the note
+    /// script nor of the [`MidenLib`] or [`StdLibrary`] and could therefore not be analyzed, in
+    /// ascending order.
+    pub unresolved_procedures: Vec<Word>,
+}
+
+impl RiskReport {
+    /// Returns `true` if parts of the note script could not be analyzed, i.e. if the note script
+    /// may call procedures which are not reported.
+    pub fn is_incomplete(&self) -> bool {
+        self.has_dynamic_calls || !self.unresolved_procedures.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use miden_objects::assembly::diagnostics::NamedSource;
+
+    use super::*;
+    use crate::note::WellKnownNote;
+    use crate::transaction::TransactionKernel;
+    use crate::utils::ScriptBuilder;
+
+    #[test]
+    fn analyze_well_known_note_scripts() {
+        let p2id_report = NoteScriptAnalysis::analyze(&WellKnownNote::P2ID.script());
+        assert_eq!(
+            p2id_report,
+            RiskReport {
+                can_receive_assets: true,
+                ..Default::default()
+            }
+        );
+
+        let swap_report = NoteScriptAnalysis::analyze(&WellKnownNote::SWAP.script());
+        assert_eq!(
+            swap_report,
+            RiskReport {
+                can_send_assets: true,
+                can_receive_assets: true,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn analyze_custom_note_script() {
+        let custom_library = TransactionKernel::assembler()
+            .assemble_library([NamedSource::new(
+                "custom::component",
+                "
+                export.drain
+                    push.1 drop
+                end
+                ",
+            )])
+            .unwrap();
+        let drain_root =
+            custom_library.get_procedure_root_by_name("custom::component::drain").unwrap();
+
+        let note_script = ScriptBuilder::default()
+            .with_dynamically_linked_library(&custom_library)
+            .unwrap()
+            .compile_note_script(
+                "
+                use.miden::contracts::faucets::basic_fungible->fungible_faucet
+                use.custom::component
+
+                begin
+                    push.1
+                    if.true
+                        call.fungible_faucet::burn
+                    else
+                        call.component::drain
+                    end
+                end
+                ",
+            )
+            .unwrap();
+
+        let report = NoteScriptAnalysis::analyze(&note_script);
+        assert_eq!(
+            report,
+            RiskReport {
+                can_burn: true,
+                unknown_calls: vec![drain_root],
+                ..Default::default()
+            }
+        );
+        assert!(!report.is_incomplete());
+    }
+
+    #[test]
+    fn analyze_note_script_with_unresolved_code() {
+        let custom_library = TransactionKernel::assembler()
+            .assemble_library([NamedSource::new(
+                "custom::helpers",
+                "
+                export.prepare
+                    push.1 drop
+                end
+                ",
+            )])
+            .unwrap();
+        let prepare_root =
+            custom_library.get_procedure_root_by_name("custom::helpers::prepare").unwrap();
+
+        let note_script = ScriptBuilder::default()
+            .with_dynamically_linked_library(&custom_library)
+            .unwrap()
+            .compile_note_script(
+                "
+                use.custom::helpers
+
+                begin
+                    exec.helpers::prepare
+                    push.4 dyncall
+                end
+                ",
+            )
+            .unwrap();
+
+        let report = NoteScriptAnalysis::analyze(&note_script);
+        assert_eq!(
+            report,
+            RiskReport {
+                has_dynamic_calls: true,
+                unresolved_procedures: vec![prepare_root],
+                ..Default::default()
+            }
+        );
+        assert!(report.is_incomplete());
+    }
+}
@@ -1,7 +1,18 @@
+use alloc::vec::Vec;
+
 use miden_objects::account::AccountId;
 use miden_objects::asset::Asset;
 use miden_objects::block::BlockNumber;
-use miden_objects::note::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteTag, NoteType};
+use miden_objects::note::{
+    NoteAssets,
+    NoteExecutionMode,
+    NoteId,
+    NoteInputs,
+    NoteRecipient,
+    NoteScript,
+    NoteTag,
+    NoteType,
+};
 use miden_objects::{Felt, NoteError, Word};
 
 use super::well_known_note::WellKnownNote;
@@ -45,6 +56,40 @@ pub fn build_p2ide_recipient(
     Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
 }
 
+/// Creates a [NoteRecipient] for the P2MID note.
+///
+/// Notes created with this recipient will be P2MID notes consumable by any one of the specified
+/// target accounts.
+///
+/// # Errors
+///
+/// Returns an error if `targets` is empty or contains more than
+/// [`WellKnownNote::P2MID_MAX_NUM_TARGETS`] account IDs.
+pub fn build_p2mid_recipient(
+    targets: &[AccountId],
+    serial_num: Word,
+) -> Result<NoteRecipient, NoteError> {
+    if targets.is_empty() || targets.len() > WellKnownNote::P2MID_MAX_NUM_TARGETS {
+        return Err(NoteError::other(format!(
+            "P2MID note must have between 1 and {} targets, but {} were provided",
+            WellKnownNote::P2MID_MAX_NUM_TARGETS,
+            targets.len()
+        )));
+    }
+
+    let note_script = WellKnownNote::P2MID.script();
+
+    let mut inputs = Vec::with_capacity(1 + 2 * targets.len());
+    inputs.push(Felt::from(targets.len() as u32));
+    for target in targets {
+        inputs.push(target.suffix());
+        inputs.push(target.prefix().as_felt());
+    }
+    let note_inputs = NoteInputs::new(inputs)?;
+
+    Ok(NoteRecipient::new(serial_num, note_script, note_inputs))
+}
+
 /// Returns a note tag for a swap note with the specified parameters.
 ///
 /// Use case ID for the returned tag is set to 0.
@@ -76,6 +121,20 @@ pub fn build_swap_tag(
     }
 }
 
+/// Returns the ID of the note with the provided serial number, script, inputs and assets.
+///
+/// This allows predicting the IDs of notes created during the execution of a transaction before
+/// executing it, e.g. to register them as expected output notes.
+pub fn predict_output_note_id(
+    serial_num: Word,
+    script: NoteScript,
+    inputs: NoteInputs,
+    assets: &NoteAssets,
+) -> NoteId {
+    let recipient = NoteRecipient::new(serial_num, script, inputs);
+    NoteId::new(recipient.digest(), assets.commitment())
+}
+
 #[cfg(test)]
 mod tests {
     use miden_objects::account::{AccountIdVersion, AccountStorageMode, AccountType};
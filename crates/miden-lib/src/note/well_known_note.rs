@@ -1,5 +1,6 @@
 use alloc::boxed::Box;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::error::Error;
 
 use miden_objects::account::AccountId;
@@ -31,6 +32,13 @@ static P2IDE_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     NoteScript::new(program)
 });
 
+// Initialize the P2MID note script only once
+static P2MID_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2MID.masb"));
+    let program = Program::read_from_bytes(bytes).expect("Shipped P2MID script is well-formed");
+    NoteScript::new(program)
+});
+
 // Initialize the SWAP note script only once
 static SWAP_SCRIPT: LazyLock<NoteScript> = LazyLock::new(|| {
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
@@ -72,6 +80,16 @@ fn p2ide_root() -> Word {
     P2IDE_SCRIPT.root()
 }
 
+/// Returns the P2MID (Pay-to-Multiple-ID) note script.
+fn p2mid() -> NoteScript {
+    P2MID_SCRIPT.clone()
+}
+
+/// Returns the P2MID (Pay-to-Multiple-ID) note script root.
+fn p2mid_root() -> Word {
+    P2MID_SCRIPT.root()
+}
+
 /// Returns the SWAP (Swap note) note script.
 fn swap() -> NoteScript {
     SWAP_SCRIPT.clone()
@@ -109,6 +127,7 @@ fn burn_root() -> Word {
 pub enum WellKnownNote {
     P2ID,
     P2IDE,
+    P2MID,
     SWAP,
     MINT,
     BURN,
@@ -118,12 +137,19 @@ impl WellKnownNote {
     // CONSTANTS
     // --------------------------------------------------------------------------------------------
 
+    /// The maximum number of target accounts of a P2MID note.
+    pub const P2MID_MAX_NUM_TARGETS: usize = 8;
+
     /// Expected number of inputs of the P2ID note.
     const P2ID_NUM_INPUTS: usize = 2;
 
     /// Expected number of inputs of the P2IDE note.
     const P2IDE_NUM_INPUTS: usize = 4;
 
+    /// Maximum number of inputs of the P2MID note, i.e. the number of targets followed by the
+    /// maximum number of target account IDs.
+    const P2MID_MAX_NUM_INPUTS: usize = 1 + 2 * Self::P2MID_MAX_NUM_TARGETS;
+
     /// Expected number of inputs of the SWAP note.
     const SWAP_NUM_INPUTS: usize = 10;
 
@@ -147,6 +173,9 @@ impl WellKnownNote {
         if note_script_root == p2ide_root() {
             return Some(Self::P2IDE);
         }
+        if note_script_root == p2mid_root() {
+            return Some(Self::P2MID);
+        }
         if note_script_root == swap_root() {
             return Some(Self::SWAP);
         }
@@ -164,10 +193,14 @@ impl WellKnownNote {
     // --------------------------------------------------------------------------------------------
 
     /// Returns the expected inputs number of the active note.
+    ///
+    /// For the P2MID note, whose number of inputs depends on the number of targets, the maximum
+    /// number of inputs is returned.
     pub fn num_expected_inputs(&self) -> usize {
         match self {
             Self::P2ID => Self::P2ID_NUM_INPUTS,
             Self::P2IDE => Self::P2IDE_NUM_INPUTS,
+            Self::P2MID => Self::P2MID_MAX_NUM_INPUTS,
             Self::SWAP => Self::SWAP_NUM_INPUTS,
             Self::MINT => Self::MINT_NUM_INPUTS,
             Self::BURN => Self::BURN_NUM_INPUTS,
@@ -179,6 +212,7 @@ impl WellKnownNote {
         match self {
             Self::P2ID => p2id(),
             Self::P2IDE => p2ide(),
+            Self::P2MID => p2mid(),
             Self::SWAP => swap(),
             Self::MINT => mint(),
             Self::BURN => burn(),
@@ -190,6 +224,7 @@ impl WellKnownNote {
         match self {
             Self::P2ID => p2id_root(),
             Self::P2IDE => p2ide_root(),
+            Self::P2MID => p2mid_root(),
             Self::SWAP => swap_root(),
             Self::MINT => mint_root(),
             Self::BURN => burn_root(),
@@ -205,9 +240,9 @@ impl WellKnownNote {
 
         let interface_proc_digests = account_interface.get_procedure_digests();
         match self {
-            Self::P2ID | &Self::P2IDE => {
-                // To consume P2ID and P2IDE notes, the `receive_asset` procedure must be present in
-                // the provided account interface.
+            Self::P2ID | &Self::P2IDE | Self::P2MID => {
+                // To consume P2ID, P2IDE and P2MID notes, the `receive_asset` procedure must be
+                // present in the provided account interface.
                 interface_proc_digests.contains(&BasicWallet::receive_asset_digest())
             },
             Self::SWAP => {
@@ -269,6 +304,10 @@ impl WellKnownNote {
     ///     - check that the target account is either the receiver account or the sender account.
     ///     - check that depending on whether the target account is sender or receiver, it could be
     ///       either consumed, or consumed after timelock height, or consumed after reclaim height.
+    /// - for `P2MID` note:
+    ///     - check that note inputs have correct number of values.
+    ///     - assertion that the target account ID is one of the account IDs provided by the note
+    ///       inputs.
     fn is_consumable_inner(
         &self,
         note: &Note,
@@ -285,6 +324,15 @@ impl WellKnownNote {
                     Ok(Some(NoteConsumptionStatus::NeverConsumable("account ID provided to the P2ID note inputs doesn't match the target account ID".into())))
                 }
             },
+            WellKnownNote::P2MID => {
+                let input_account_ids = parse_p2mid_inputs(note.inputs().values())?;
+
+                if input_account_ids.contains(&target_account_id) {
+                    Ok(Some(NoteConsumptionStatus::ConsumableWithAuthorization))
+                } else {
+                    Ok(Some(NoteConsumptionStatus::NeverConsumable("account IDs provided to the P2MID note inputs don't contain the target account ID".into())))
+                }
+            },
             WellKnownNote::P2IDE => {
                 let (receiver_account_id, reclaim_height, timelock_height) =
                     parse_p2ide_inputs(note.inputs().values())?;
@@ -367,7 +415,9 @@ fn parse_p2id_inputs(note_inputs: &[Felt]) -> Result<AccountId, StaticAnalysisEr
 /// - first two elements of the note inputs array does not form the valid account ID.
 /// - third note inputs array element (reclaim height) is not a valid u32 value.
 /// - fourth note inputs array element (timelock height) is not a valid u32 value.
-fn parse_p2ide_inputs(note_inputs: &[Felt]) -> Result<(AccountId, u32, u32), StaticAnalysisError> {
+pub(super) fn parse_p2ide_inputs(
+    note_inputs: &[Felt],
+) -> Result<(AccountId, u32, u32), StaticAnalysisError> {
     if note_inputs.len() != WellKnownNote::P2IDE.num_expected_inputs() {
         return Err(StaticAnalysisError::new(format!(
             "P2IDE note should have {} inputs, but {} was provided",
@@ -387,6 +437,38 @@ fn parse_p2ide_inputs(note_inputs: &[Felt]) -> Result<(AccountId, u32, u32), Sta
     Ok((receiver_account_id, reclaim_height, timelock_height))
 }
 
+/// Returns the target account IDs parsed from the provided P2MID note inputs.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - the first element of the note inputs array (number of targets) is zero or exceeds the maximum
+///   number of targets of the P2MID note.
+/// - the length of the provided note inputs array is not equal to `1 + 2 * num_targets`.
+/// - any pair of the following note input elements does not form a valid account ID.
+fn parse_p2mid_inputs(note_inputs: &[Felt]) -> Result<Vec<AccountId>, StaticAnalysisError> {
+    let num_targets = note_inputs
+        .first()
+        .and_then(|num_targets| usize::try_from(num_targets.as_int()).ok())
+        .filter(|num_targets| (1..=WellKnownNote::P2MID_MAX_NUM_TARGETS).contains(num_targets))
+        .ok_or_else(|| {
+            StaticAnalysisError::new(format!(
+                "P2MID note should have between 1 and {} targets",
+                WellKnownNote::P2MID_MAX_NUM_TARGETS
+            ))
+        })?;
+
+    if note_inputs.len() != 1 + 2 * num_targets {
+        return Err(StaticAnalysisError::new(format!(
+            "P2MID note with {num_targets} targets should have {} inputs, but {} was provided",
+            1 + 2 * num_targets,
+            note_inputs.len()
+        )));
+    }
+
+    note_inputs[1..].chunks_exact(2).map(try_read_account_id_from_inputs).collect()
+}
+
 /// Reads the account ID from the first two note input values.
 ///
 /// Returns None if the note input values used to construct the account ID are invalid.
@@ -420,6 +502,11 @@ pub enum NoteConsumptionStatus {
     Consumable,
     /// The note can be consumed by the account after the required block height is achieved.
     ConsumableAfter(BlockNumber),
+    /// The execution hint of the note indicates that it is not meant to be consumed at the
+    /// specified block height, e.g. because the note is only executable in a different block slot.
+    ///
+    /// Execution hints are advisory and not enforced by the transaction kernel.
+    NotYetConsumable,
     /// The note can be consumed by the account if proper authorization is provided.
     ConsumableWithAuthorization,
     /// The note cannot be consumed by the account at the specified conditions (i.e., block
@@ -427,11 +514,14 @@ pub enum NoteConsumptionStatus {
     UnconsumableConditions,
     /// The note cannot be consumed by the specified account under any conditions.
     NeverConsumable(Box<dyn Error + Send + Sync + 'static>),
+    /// The note was executed successfully, but its execution took more cycles than the per-note
+    /// cycle budget allowed.
+    ExceededCycleBudget { num_cycles: u32, max_cycles: u32 },
 }
 
 #[derive(thiserror::Error, Debug)]
 #[error("{message}")]
-struct StaticAnalysisError {
+pub(super) struct StaticAnalysisError {
     /// Stack size of `Box<str>` is smaller than String.
     message: Box<str>,
     /// thiserror will return this when calling Error::source on StaticAnalysisError.
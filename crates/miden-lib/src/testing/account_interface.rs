@@ -5,7 +5,10 @@ use miden_objects::account::Account;
 
 use crate::account::interface::AccountInterface;
 
-/// Helper function to extract public keys from an account
+/// Helper function to extract public keys from the current storage of an account.
+///
+/// For multisig accounts, the public keys reflect approver rotations applied to the account, e.g.
+/// via `add_approver` and `remove_approver`, in the order in which they are stored.
 pub fn get_public_keys_from_account(account: &Account) -> Vec<Word> {
     let interface: AccountInterface = account.into();
 
@@ -9,6 +9,18 @@ use super::TransactionEventError;
 // Include the generated event constants
 include!(concat!(env!("OUT_DIR"), "/assets/transaction_events.rs"));
 
+// TRANSACTION TRACES
+// ================================================================================================
+
+/// The ID of the trace emitted by the transaction kernel after an item has been read from the
+/// storage of the active account.
+///
+/// Expected stack state: `[VALUE, ...]`
+///
+/// Unlike events, traces are decorators, so they are not part of the kernel procedure roots and
+/// do not consume any cycles.
+pub const ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE: u32 = 131072;
+
 // TRANSACTION EVENT
 // ================================================================================================
 
@@ -78,13 +90,16 @@ pub enum TransactionEvent {
     LinkMapGet = LINK_MAP_GET,
 
     Unauthorized = AUTH_UNAUTHORIZED,
+
+    CustomEventEmitted = CUSTOM_EVENT_EMITTED,
 }
 
 impl TransactionEvent {
     /// Returns `true` if the event is privileged, i.e. it is only allowed to be emitted from the
     /// root context of the VM, which is where the transaction kernel executes.
     pub fn is_privileged(&self) -> bool {
-        let is_unprivileged = matches!(self, Self::AuthRequest | Self::Unauthorized);
+        let is_unprivileged =
+            matches!(self, Self::AuthRequest | Self::Unauthorized | Self::CustomEventEmitted);
         !is_unprivileged
     }
 
@@ -184,6 +199,8 @@ impl TryFrom<EventId> for TransactionEvent {
 
             AUTH_UNAUTHORIZED => Ok(TransactionEvent::Unauthorized),
 
+            CUSTOM_EVENT_EMITTED => Ok(TransactionEvent::CustomEventEmitted),
+
             _ => Err(TransactionEventError::InvalidTransactionEvent(value, name)),
         }
     }
@@ -1,9 +1,18 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
 use alloc::vec::Vec;
 
-use miden_objects::account::{AccountHeader, AccountId, PartialAccount};
+use miden_objects::account::{
+    AccountHeader,
+    AccountId,
+    PartialAccount,
+    StorageSlot,
+    StorageSlotType,
+};
 use miden_objects::block::AccountWitness;
 use miden_objects::crypto::SequentialCommit;
 use miden_objects::crypto::merkle::InnerNodeInfo;
+use miden_objects::note::NoteId;
 use miden_objects::transaction::{AccountInputs, InputNote, PartialBlockchain, TransactionInputs};
 use miden_objects::vm::AdviceInputs;
 use miden_objects::{EMPTY_WORD, Felt, FieldElement, Word, ZERO};
@@ -17,16 +26,35 @@ use super::TransactionKernel;
 
 /// Advice inputs wrapper for inputs that are meant to be used exclusively in the transaction
 /// kernel.
+///
+/// While the advice inputs are being built, the keys of the advice map entries required by the
+/// transaction kernel are recorded together with their kind, see [`Self::required_map_entries`].
 #[derive(Debug, Clone, Default)]
-pub struct TransactionAdviceInputs(AdviceInputs);
+pub struct TransactionAdviceInputs {
+    inputs: AdviceInputs,
+    required_map_entries: Vec<(TransactionAdviceMapEntry, Word)>,
+}
 
 impl TransactionAdviceInputs {
+    /// The advice map key under which the maximum fee of the transaction is stored, if the
+    /// transaction arguments specify one.
+    ///
+    /// The entry is laid out as follows:
+    ///
+    /// > {MAX_FEE_ADVICE_KEY: [max_fee, 0, 0, 0]}
+    ///
+    /// This allows account components, e.g. authentication components, to inspect the maximum
+    /// fee the account owner is willing to pay. If no maximum fee was specified, the entry is not
+    /// present.
+    pub const MAX_FEE_ADVICE_KEY: Word =
+        Word::new([Felt::new(0x006d_6178_5f66_6565), ZERO, ZERO, ZERO]);
+
     /// Creates a [`TransactionAdviceInputs`].
     ///
     /// The created advice inputs will be populated with the data required for executing a
     /// transaction with the specified transaction inputs.
     pub fn new(tx_inputs: &TransactionInputs) -> Result<Self, TransactionAdviceMapMismatch> {
-        let mut inputs = TransactionAdviceInputs(tx_inputs.advice_inputs().clone());
+        let mut inputs = TransactionAdviceInputs::from(tx_inputs.advice_inputs().clone());
 
         inputs.build_stack(tx_inputs);
         inputs.add_kernel_commitment();
@@ -35,24 +63,23 @@ impl TransactionAdviceInputs {
 
         // Add the script's MAST forest's advice inputs.
         if let Some(tx_script) = tx_inputs.tx_args().tx_script() {
-            inputs.extend_map(
-                tx_script
-                    .mast()
-                    .advice_map()
-                    .iter()
-                    .map(|(key, values)| (*key, values.to_vec())),
-            );
+            inputs
+                .extend_map(tx_script.inputs().iter().map(|(key, values)| (*key, values.to_vec())));
         }
 
         // Inject native account.
         let partial_native_acc = tx_inputs.account();
-        inputs.add_account(partial_native_acc)?;
+        inputs.add_account(partial_native_acc, true)?;
 
         // If a seed was provided, extend the map appropriately.
         if let Some(seed) = tx_inputs.account().seed() {
             // ACCOUNT_ID |-> ACCOUNT_SEED
             let account_id_key = Self::account_id_map_key(partial_native_acc.id());
-            inputs.add_map_entry(account_id_key, seed.to_vec());
+            inputs.add_required_map_entry(
+                TransactionAdviceMapEntry::AccountSeed,
+                account_id_key,
+                seed.to_vec(),
+            );
         }
 
         // if the account is new, insert the storage map entries into the advice provider.
@@ -64,10 +91,24 @@ impl TransactionAdviceInputs {
                         value.as_elements().iter().chain(key.as_elements().iter()).copied()
                     })
                     .collect();
-                inputs.add_map_entry(storage_map.root(), map_entries);
+                inputs.add_required_map_entry(
+                    TransactionAdviceMapEntry::AccountStorageMapEntries,
+                    storage_map.root(),
+                    map_entries,
+                );
             }
         }
 
+        // If a maximum fee was provided, make it available to the account.
+        if let Some(max_fee) = tx_inputs.tx_args().max_fee() {
+            // MAX_FEE_ADVICE_KEY |-> [max_fee, 0, 0, 0]
+            inputs.add_required_map_entry(
+                TransactionAdviceMapEntry::MaxFee,
+                Self::MAX_FEE_ADVICE_KEY,
+                vec![Felt::new(max_fee), ZERO, ZERO, ZERO],
+            );
+        }
+
         // Extend with extra user-supplied advice.
         inputs.extend(tx_inputs.tx_args().advice_inputs().clone());
 
@@ -76,32 +117,93 @@ impl TransactionAdviceInputs {
 
     /// Returns a reference to the underlying advice inputs.
     pub fn as_advice_inputs(&self) -> &AdviceInputs {
-        &self.0
+        &self.inputs
     }
 
     /// Converts these transaction advice inputs into the underlying advice inputs.
     pub fn into_advice_inputs(self) -> AdviceInputs {
-        self.0
+        self.inputs
+    }
+
+    /// Returns the keys of the advice map entries which are required to execute a transaction,
+    /// together with the kind of each entry, as recorded while building these advice inputs.
+    ///
+    /// Entries which are only required by particular code, e.g. the advice maps of MAST forests,
+    /// are not included.
+    pub fn required_map_entries(&self) -> &[(TransactionAdviceMapEntry, Word)] {
+        &self.required_map_entries
     }
 
     /// Consumes self and returns an iterator of [`AdviceMutation`]s in arbitrary order.
     pub fn into_advice_mutations(self) -> impl Iterator<Item = AdviceMutation> {
         [
-            AdviceMutation::ExtendMap { other: self.0.map },
+            AdviceMutation::ExtendMap { other: self.inputs.map },
             AdviceMutation::ExtendMerkleStore {
-                infos: self.0.store.inner_nodes().collect(),
+                infos: self.inputs.store.inner_nodes().collect(),
             },
-            AdviceMutation::ExtendStack { values: self.0.stack },
+            AdviceMutation::ExtendStack { values: self.inputs.stack },
         ]
         .into_iter()
     }
 
+    /// Returns the keys of the advice map entries and Merkle store nodes holding the data of the
+    /// foreign accounts contained in the provided advice inputs.
+    ///
+    /// Foreign accounts are identified by their `ACCOUNT_ID |-> [ID_AND_NONCE, ...]` entry as added
+    /// by [`Self::add_foreign_accounts`]. The returned keys cover this entry, the code and storage
+    /// entries of the account, and the nodes and leaves of its vault and storage maps.
+    pub fn foreign_account_keys(advice_inputs: &AdviceInputs) -> BTreeSet<Word> {
+        let mut keys = BTreeSet::new();
+
+        for (key, values) in advice_inputs.map.iter() {
+            // ACCOUNT_ID |-> [ID_AND_NONCE, VAULT_ROOT, STORAGE_COMMITMENT, CODE_COMMITMENT]
+            let key_elements = key.as_elements();
+            if values.len() != 16
+                || key_elements[2..] != [ZERO, ZERO]
+                || values[..3] != [key_elements[0], key_elements[1], ZERO]
+            {
+                continue;
+            }
+
+            let vault_root = word_from_elements(&values[4..8]);
+            let storage_commitment = word_from_elements(&values[8..12]);
+            let code_commitment = word_from_elements(&values[12..16]);
+            keys.extend([*key, storage_commitment, code_commitment]);
+
+            // STORAGE_COMMITMENT |-> [[STORAGE_SLOT_DATA]]
+            let mut roots = vec![vault_root];
+            if let Some(slots) = advice_inputs.map.get(&storage_commitment) {
+                roots.extend(
+                    slots
+                        .chunks_exact(StorageSlot::NUM_ELEMENTS_PER_STORAGE_SLOT)
+                        .filter(|slot| {
+                            word_from_elements(&slot[4..8]) == StorageSlotType::Map.as_word()
+                        })
+                        .map(|slot| word_from_elements(&slot[..4])),
+                );
+            }
+
+            // the leaves of the vault and storage maps are stored in the advice map under their
+            // hashes
+            for node in advice_inputs.store.subset(roots.iter()).inner_nodes() {
+                keys.insert(node.value);
+                keys.extend(
+                    [node.left, node.right]
+                        .into_iter()
+                        .filter(|child| advice_inputs.map.get(child).is_some()),
+                );
+            }
+        }
+
+        keys
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
     /// Extends these advice inputs with the provided advice inputs.
     pub fn extend(&mut self, adv_inputs: AdviceInputs) {
-        self.0.extend(adv_inputs);
+        self.inputs.extend(adv_inputs);
     }
 
     /// Adds the provided account inputs into the advice inputs.
@@ -110,7 +212,7 @@ impl TransactionAdviceInputs {
         foreign_account_inputs: impl IntoIterator<Item = &'inputs AccountInputs>,
     ) -> Result<(), TransactionAdviceMapMismatch> {
         for foreign_acc in foreign_account_inputs {
-            self.add_account(foreign_acc.account())?;
+            self.add_account(foreign_acc.account(), false)?;
             self.add_account_witness(foreign_acc.witness());
 
             // for foreign accounts, we need to insert the id to state mapping
@@ -224,7 +326,11 @@ impl TransactionAdviceInputs {
         let peaks = mmr.peaks();
         let mut elements = vec![Felt::new(peaks.num_leaves() as u64), ZERO, ZERO, ZERO];
         elements.extend(peaks.flatten_and_pad_peaks());
-        self.add_map_entry(peaks.hash_peaks(), elements);
+        self.add_required_map_entry(
+            TransactionAdviceMapEntry::BlockchainPeaks,
+            peaks.hash_peaks(),
+            elements,
+        );
     }
 
     // KERNEL INJECTIONS
@@ -236,7 +342,11 @@ impl TransactionAdviceInputs {
     /// - The commitment of the kernel |-> array of the kernel's procedure roots.
     fn add_kernel_commitment(&mut self) {
         // insert the kernel commitment with its procedure roots into the advice map
-        self.add_map_entry(TransactionKernel.to_commitment(), TransactionKernel.to_elements());
+        self.add_required_map_entry(
+            TransactionAdviceMapEntry::KernelProcedures,
+            TransactionKernel.to_commitment(),
+            TransactionKernel.to_elements(),
+        );
     }
 
     // ACCOUNT INJECTION
@@ -253,21 +363,30 @@ impl TransactionAdviceInputs {
     /// - The account code commitment |-> procedures vector.
     /// - The leaf hash |-> (key, value), for all leaves of the partial vault.
     /// - If present, the Merkle leaves associated with the account storage maps.
+    ///
+    /// The advice map entries are recorded as required entries if `is_native` is true.
     fn add_account(
         &mut self,
         account: &PartialAccount,
+        is_native: bool,
     ) -> Result<(), TransactionAdviceMapMismatch> {
+        let mut account_entries = Vec::new();
+
         // --- account code -------------------------------------------------------
 
         // CODE_COMMITMENT -> [[ACCOUNT_PROCEDURE_DATA]]
         let code = account.code();
-        self.add_map_entry(code.commitment(), code.as_elements());
+        account_entries.push((
+            TransactionAdviceMapEntry::AccountCode,
+            code.commitment(),
+            code.as_elements(),
+        ));
 
         // Extend the advice map with the account code's advice inputs.
         // This ensures that the advice map is available during the note script execution when it
         // calls the account's code that relies on the it's advice map data (data segments) loaded
         // into the advice provider
-        self.0.map.merge(account.code().mast().advice_map()).map_err(
+        self.inputs.map.merge(account.code().mast().advice_map()).map_err(
             |((key, existing_val), incoming_val)| TransactionAdviceMapMismatch {
                 key,
                 existing_val: existing_val.to_vec(),
@@ -279,17 +398,37 @@ impl TransactionAdviceInputs {
 
         // STORAGE_COMMITMENT |-> [[STORAGE_SLOT_DATA]]
         let storage_header = account.storage().header();
-        self.add_map_entry(storage_header.compute_commitment(), storage_header.as_elements());
+        account_entries.push((
+            TransactionAdviceMapEntry::AccountStorageHeader,
+            storage_header.compute_commitment(),
+            storage_header.as_elements(),
+        ));
 
         // populate Merkle store and advice map with nodes info needed to access storage map entries
         self.extend_merkle_store(account.storage().inner_nodes());
-        self.extend_map(account.storage().leaves().map(|leaf| (leaf.hash(), leaf.to_elements())));
+        account_entries.extend(account.storage().leaves().map(|leaf| {
+            (
+                TransactionAdviceMapEntry::AccountStorageMapLeaf,
+                leaf.hash(),
+                leaf.to_elements(),
+            )
+        }));
 
         // --- account vault ------------------------------------------------------
 
         // populate Merkle store and advice map with nodes info needed to access vault assets
         self.extend_merkle_store(account.vault().inner_nodes());
-        self.extend_map(account.vault().leaves().map(|leaf| (leaf.hash(), leaf.to_elements())));
+        account_entries.extend(account.vault().leaves().map(|leaf| {
+            (TransactionAdviceMapEntry::AccountVaultLeaf, leaf.hash(), leaf.to_elements())
+        }));
+
+        for (entry, key, values) in account_entries {
+            if is_native {
+                self.add_required_map_entry(entry, key, values);
+            } else {
+                self.add_map_entry(key, values);
+            }
+        }
 
         Ok(())
     }
@@ -344,8 +483,16 @@ impl TransactionAdviceInputs {
             let note_arg = tx_inputs.tx_args().get_note_args(note.id()).unwrap_or(&EMPTY_WORD);
 
             // recipient inputs / assets commitments
-            self.add_map_entry(recipient.inputs().commitment(), recipient.inputs().to_elements());
-            self.add_map_entry(assets.commitment(), assets.to_padded_assets());
+            self.add_required_map_entry(
+                TransactionAdviceMapEntry::NoteInputs(note.id()),
+                recipient.inputs().commitment(),
+                recipient.inputs().to_elements(),
+            );
+            self.add_required_map_entry(
+                TransactionAdviceMapEntry::NoteAssets(note.id()),
+                assets.commitment(),
+                assets.to_padded_assets(),
+            );
 
             // note details / metadata
             note_data.extend(recipient.serial_num());
@@ -360,7 +507,7 @@ impl TransactionAdviceInputs {
 
             // authentication vs unauthenticated
             match input_note {
-                InputNote::Authenticated { note, proof } => {
+                InputNote::Authenticated { note, proof, .. } => {
                     // Push the `is_authenticated` flag
                     note_data.push(Felt::ONE);
 
@@ -388,7 +535,7 @@ impl TransactionAdviceInputs {
                 },
             }
 
-            self.0.map.merge(note.script().mast().advice_map()).map_err(
+            self.inputs.map.merge(note.script().mast().advice_map()).map_err(
                 |((key, existing_val), incoming_val)| TransactionAdviceMapMismatch {
                     key,
                     existing_val: existing_val.to_vec(),
@@ -397,7 +544,11 @@ impl TransactionAdviceInputs {
             )?;
         }
 
-        self.add_map_entry(tx_inputs.input_notes().commitment(), note_data);
+        self.add_required_map_entry(
+            TransactionAdviceMapEntry::InputNotes,
+            tx_inputs.input_notes().commitment(),
+            note_data,
+        );
 
         Ok(())
     }
@@ -407,22 +558,34 @@ impl TransactionAdviceInputs {
 
     /// Extends the map of values with the given argument, replacing previously inserted items.
     fn extend_map(&mut self, iter: impl IntoIterator<Item = (Word, Vec<Felt>)>) {
-        self.0.map.extend(iter);
+        self.inputs.map.extend(iter);
     }
 
     fn add_map_entry(&mut self, key: Word, values: Vec<Felt>) {
-        self.0.map.extend([(key, values)]);
+        self.inputs.map.extend([(key, values)]);
+    }
+
+    /// Adds the provided entry to the advice map and records its key as required by the
+    /// transaction kernel.
+    fn add_required_map_entry(
+        &mut self,
+        entry: TransactionAdviceMapEntry,
+        key: Word,
+        values: Vec<Felt>,
+    ) {
+        self.add_map_entry(key, values);
+        self.required_map_entries.push((entry, key));
     }
 
     /// Extends the stack with the given elements.
     fn extend_stack(&mut self, iter: impl IntoIterator<Item = Felt>) {
-        self.0.stack.extend(iter);
+        self.inputs.stack.extend(iter);
     }
 
     /// Extends the [`MerkleStore`](miden_objects::crypto::merkle::MerkleStore) with the given
     /// nodes.
     fn extend_merkle_store(&mut self, iter: impl Iterator<Item = InnerNodeInfo>) {
-        self.0.store.extend(iter);
+        self.inputs.store.extend(iter);
     }
 
     /// Returns the advice map key where:
@@ -433,21 +596,63 @@ impl TransactionAdviceInputs {
     }
 }
 
+/// Returns the word made up of the first four of the provided elements.
+fn word_from_elements(elements: &[Felt]) -> Word {
+    Word::new([elements[0], elements[1], elements[2], elements[3]])
+}
+
 // CONVERSIONS
 // ================================================================================================
 
 impl From<TransactionAdviceInputs> for AdviceInputs {
     fn from(wrapper: TransactionAdviceInputs) -> Self {
-        wrapper.0
+        wrapper.inputs
     }
 }
 
 impl From<AdviceInputs> for TransactionAdviceInputs {
     fn from(inner: AdviceInputs) -> Self {
-        Self(inner)
+        Self {
+            inputs: inner,
+            required_map_entries: Vec::new(),
+        }
     }
 }
 
+// ADVICE MAP ENTRY
+// ================================================================================================
+
+/// The kind of an advice map entry required by the transaction kernel, see
+/// [`TransactionKernel::build_inputs`] for the layout of the entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionAdviceMapEntry {
+    /// The kernel commitment |-> the kernel procedure roots.
+    KernelProcedures,
+    /// The commitment to the MMR peaks of the partial blockchain |-> the number of blocks and the
+    /// MMR peaks.
+    BlockchainPeaks,
+    /// The native account code commitment |-> the account procedures.
+    AccountCode,
+    /// The native account storage commitment |-> the storage slot types and values.
+    AccountStorageHeader,
+    /// The hash of a leaf of a storage map of the native account |-> the leaf entries.
+    AccountStorageMapLeaf,
+    /// The hash of a leaf of the native account vault |-> the leaf entries.
+    AccountVaultLeaf,
+    /// The native account ID |-> the account seed, for new accounts.
+    AccountSeed,
+    /// The root of a storage map of a new native account |-> the map entries.
+    AccountStorageMapEntries,
+    /// The input notes commitment |-> the data of all input notes.
+    InputNotes,
+    /// The inputs commitment of the input note with the given ID |-> the note inputs.
+    NoteInputs(NoteId),
+    /// The assets commitment of the input note with the given ID |-> the padded note assets.
+    NoteAssets(NoteId),
+    /// [`TransactionAdviceInputs::MAX_FEE_ADVICE_KEY`] |-> the maximum fee, if one was specified.
+    MaxFee,
+}
+
 // CONFLICT ERROR
 // ================================================================================================
 
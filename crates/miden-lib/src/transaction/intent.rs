@@ -0,0 +1,236 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use miden_objects::ZERO;
+use miden_objects::account::{Account, AccountId};
+use miden_objects::asset::{Asset, FungibleAsset};
+use miden_objects::crypto::rand::FeltRng;
+use miden_objects::note::{Note, NoteId, NoteType, PartialNote};
+use miden_objects::transaction::{OutputNote, TransactionArgs};
+
+use crate::account::interface::{AccountComponentInterface, AccountInterface};
+use crate::errors::TransactionIntentError;
+use crate::note::create_p2id_note;
+
+// TRANSACTION INTENT BUILDER
+// ================================================================================================
+
+/// A builder of high-level transaction intents, e.g. "send X tokens to Y", which takes care of
+/// generating the transaction script, the expected output notes and the [`TransactionArgs`] of
+/// the transaction.
+///
+/// Assets are transferred via P2ID notes created by the account for which the intent is built:
+/// - [`Self::pay`] moves assets from the vault of an account exposing the
+///   [`BasicWallet`](AccountComponentInterface::BasicWallet) interface to a new note.
+/// - [`Self::mint`] distributes newly minted assets of an account exposing the
+///   [`BasicFungibleFaucet`](AccountComponentInterface::BasicFungibleFaucet) interface to a new
+///   note.
+///
+/// The serial numbers of the created notes are drawn from the provided `rng`.
+pub struct TransactionIntentBuilder<R> {
+    interface: AccountInterface,
+    rng: R,
+    output_notes: Vec<Note>,
+    input_note_ids: Vec<NoteId>,
+    expiration_delta: Option<u16>,
+    in_debug_mode: bool,
+}
+
+impl<R: FeltRng> TransactionIntentBuilder<R> {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Creates a new [`TransactionIntentBuilder`] for transactions executed against the provided
+    /// account.
+    pub fn new(account: &Account, rng: R) -> Self {
+        Self {
+            interface: AccountInterface::from(account),
+            rng,
+            output_notes: Vec::new(),
+            input_note_ids: Vec::new(),
+            expiration_delta: None,
+            in_debug_mode: false,
+        }
+    }
+
+    // OPERATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the payment of the provided assets to the `to` account via a new P2ID note of the
+    /// provided type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the account does not expose the basic wallet interface.
+    /// - the account is a fungible faucet.
+    /// - the P2ID note cannot be created, e.g. because the assets contain duplicates.
+    pub fn pay(
+        mut self,
+        to: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+    ) -> Result<Self, TransactionIntentError> {
+        let account_id = *self.interface.id();
+        if self.is_fungible_faucet() {
+            return Err(TransactionIntentError::PayFromFaucet(account_id));
+        }
+        if !self.interface.components().contains(&AccountComponentInterface::BasicWallet) {
+            return Err(TransactionIntentError::PayFromNonWallet(account_id));
+        }
+
+        self.add_p2id_note(to, assets, note_type)?;
+
+        Ok(self)
+    }
+
+    /// Adds the consumption of the notes with the provided IDs.
+    ///
+    /// The notes themselves must be provided to the executor together with the
+    /// [`TransactionArgs`] of the built [`TransactionIntent`].
+    pub fn consume(mut self, notes: Vec<NoteId>) -> Self {
+        self.input_note_ids.extend(notes);
+        self
+    }
+
+    /// Adds the minting of `amount` units of the asset issued by the account to the `to` account
+    /// via a new P2ID note of the provided type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the account does not expose the basic fungible faucet interface.
+    /// - the amount exceeds the maximum amount of a fungible asset.
+    /// - the P2ID note cannot be created.
+    pub fn mint(
+        mut self,
+        to: AccountId,
+        amount: u64,
+        note_type: NoteType,
+    ) -> Result<Self, TransactionIntentError> {
+        let account_id = *self.interface.id();
+        if !self.is_fungible_faucet() {
+            return Err(TransactionIntentError::MintFromNonFaucet(account_id));
+        }
+
+        let asset = FungibleAsset::new(account_id, amount)
+            .map_err(TransactionIntentError::InvalidMintedAsset)?;
+        self.add_p2id_note(to, vec![asset.into()], note_type)?;
+
+        Ok(self)
+    }
+
+    /// Sets the expiration delta of the transaction, i.e. the number of blocks after its
+    /// reference block by which the transaction must be included into the chain.
+    #[must_use]
+    pub fn with_expiration_delta(mut self, expiration_delta: u16) -> Self {
+        self.expiration_delta = Some(expiration_delta);
+        self
+    }
+
+    /// Compiles the transaction script of the intent in debug mode.
+    #[must_use]
+    pub fn with_debug_mode(mut self) -> Self {
+        self.in_debug_mode = true;
+        self
+    }
+
+    /// Builds the [`TransactionIntent`].
+    ///
+    /// A transaction script is generated only if the intent creates notes or sets an expiration
+    /// delta. The advice inputs of the resulting [`TransactionArgs`] contain the recipients of all
+    /// created notes, so they are output as [`OutputNote::Full`] by the executed transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction script cannot be generated or compiled.
+    pub fn build(self) -> Result<TransactionIntent, TransactionIntentError> {
+        let mut tx_args = TransactionArgs::default();
+
+        if !self.output_notes.is_empty() || self.expiration_delta.is_some() {
+            let partial_notes: Vec<PartialNote> =
+                self.output_notes.iter().cloned().map(PartialNote::from).collect();
+            let tx_script = self
+                .interface
+                .build_send_notes_script(&partial_notes, self.expiration_delta, self.in_debug_mode)
+                .map_err(TransactionIntentError::TransactionScriptBuildFailed)?;
+            tx_args = tx_args.with_tx_script(tx_script);
+        }
+        tx_args.extend_output_note_recipients(&self.output_notes);
+
+        let expected_output_notes = self.output_notes.into_iter().map(OutputNote::Full).collect();
+
+        Ok(TransactionIntent {
+            tx_args,
+            expected_output_notes,
+            input_note_ids: self.input_note_ids,
+        })
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns `true` if the account exposes the basic fungible faucet interface.
+    fn is_fungible_faucet(&self) -> bool {
+        self.interface
+            .components()
+            .iter()
+            .any(|component| matches!(component, AccountComponentInterface::BasicFungibleFaucet(_)))
+    }
+
+    /// Creates a P2ID note sending the provided assets from the account to the `to` account and
+    /// adds it to the output notes of the intent.
+    fn add_p2id_note(
+        &mut self,
+        to: AccountId,
+        assets: Vec<Asset>,
+        note_type: NoteType,
+    ) -> Result<(), TransactionIntentError> {
+        let note =
+            create_p2id_note(*self.interface.id(), to, assets, note_type, ZERO, &mut self.rng)
+                .map_err(TransactionIntentError::OutputNoteCreationFailed)?;
+        self.output_notes.push(note);
+
+        Ok(())
+    }
+}
+
+// TRANSACTION INTENT
+// ================================================================================================
+
+/// A transaction intent built by a [`TransactionIntentBuilder`].
+///
+/// It contains everything needed to execute the intent via
+/// `TransactionExecutor::execute_transaction`: the [`TransactionArgs`] of the transaction and the
+/// IDs of the notes it consumes, as well as the notes the executed transaction is expected to
+/// output.
+#[derive(Debug, Clone)]
+pub struct TransactionIntent {
+    tx_args: TransactionArgs,
+    expected_output_notes: Vec<OutputNote>,
+    input_note_ids: Vec<NoteId>,
+}
+
+impl TransactionIntent {
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns the notes the executed transaction is expected to output, in the order in which
+    /// they are created.
+    pub fn expected_output_notes(&self) -> &[OutputNote] {
+        &self.expected_output_notes
+    }
+
+    /// Returns the IDs of the notes consumed by the transaction.
+    pub fn input_note_ids(&self) -> &[NoteId] {
+        &self.input_note_ids
+    }
+
+    /// Consumes self and returns the transaction arguments, the expected output notes and the IDs
+    /// of the consumed notes.
+    pub fn into_parts(self) -> (TransactionArgs, Vec<OutputNote>, Vec<NoteId>) {
+        (self.tx_args, self.expected_output_notes, self.input_note_ids)
+    }
+}
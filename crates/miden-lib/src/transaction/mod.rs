@@ -22,21 +22,34 @@ use super::MidenLib;
 pub mod memory;
 
 mod events;
-pub use events::{EventId, TransactionEvent};
+pub use events::{ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE, EventId, TransactionEvent};
 
 mod inputs;
-pub use inputs::{TransactionAdviceInputs, TransactionAdviceMapMismatch};
+pub use inputs::{
+    TransactionAdviceInputs,
+    TransactionAdviceMapEntry,
+    TransactionAdviceMapMismatch,
+};
 
 mod outputs;
 pub use outputs::{
     ACCOUNT_UPDATE_COMMITMENT_WORD_IDX,
+    AccountHeaderExt,
     EXPIRATION_BLOCK_ELEMENT_IDX,
     FEE_ASSET_WORD_IDX,
     OUTPUT_NOTES_COMMITMENT_WORD_IDX,
     parse_final_account_header,
 };
 
-pub use crate::errors::{TransactionEventError, TransactionTraceParsingError};
+mod intent;
+pub use intent::{TransactionIntent, TransactionIntentBuilder};
+
+pub use crate::errors::{
+    TransactionAdviceValidationError,
+    TransactionEventError,
+    TransactionIntentError,
+    TransactionTraceParsingError,
+};
 
 mod kernel_procedures;
 use kernel_procedures::KERNEL_PROCEDURES;
@@ -119,9 +132,31 @@ impl TransactionKernel {
         ProgramInfo::new(program_hash, kernel)
     }
 
-    /// Transforms the provided [`TransactionInputs`] into stack and advice
-    /// inputs needed to execute a transaction kernel for a specific transaction.
-    pub fn prepare_inputs(
+    /// Transforms the provided [`TransactionInputs`] into stack and advice inputs needed to
+    /// execute a transaction kernel for a specific transaction.
+    ///
+    /// The stack inputs are built by [`Self::build_input_stack`]. The advice inputs are laid out
+    /// as follows:
+    /// - The advice stack contains the block header data, the header of the native account, the
+    ///   number of input notes and the transaction script root, script arguments and auth
+    ///   arguments, in the order they are processed by the prologue.
+    /// - The advice map contains the entries described by [`TransactionAdviceMapEntry`]: the kernel
+    ///   procedures, the partial blockchain peaks, the code, storage and vault data of the native
+    ///   account, the seed of new accounts, the data, inputs and assets of the input notes and the
+    ///   maximum fee. Additionally, it contains the advice maps of the account code, note script
+    ///   and transaction script MAST forests.
+    /// - The Merkle store contains the authentication paths of the partial blockchain, the partial
+    ///   vault and storage maps of the native account and the authenticated input notes.
+    ///
+    /// The advice inputs of the transaction inputs and transaction arguments are included as
+    /// well. [`Self::validate_advice`] can be used to check that some advice inputs contain the
+    /// data required for the provided transaction inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the advice maps of the MAST forests and the transaction inputs contain
+    /// conflicting entries.
+    pub fn build_inputs(
         tx_inputs: &TransactionInputs,
     ) -> Result<(StackInputs, TransactionAdviceInputs), TransactionAdviceMapMismatch> {
         let account = tx_inputs.account();
@@ -139,6 +174,53 @@ impl TransactionKernel {
         Ok((stack_inputs, tx_advice_inputs))
     }
 
+    /// Validates that the provided advice inputs contain the data required to execute a
+    /// transaction with the provided transaction inputs, i.e. that they are consistent with the
+    /// advice inputs built by [`Self::build_inputs`].
+    ///
+    /// The advice stack must match exactly, and each required advice map entry described by
+    /// [`TransactionAdviceMapEntry`] must be present with the expected value. Additional advice
+    /// map entries are allowed. The Merkle store is not validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the advice inputs for the provided transaction inputs cannot be built.
+    /// - the advice stack does not match the expected advice stack.
+    /// - a required advice map entry is missing or has an unexpected value, in which case the error
+    ///   identifies the entry.
+    pub fn validate_advice(
+        tx_inputs: &TransactionInputs,
+        advice: &AdviceInputs,
+    ) -> Result<(), TransactionAdviceValidationError> {
+        let expected_inputs = TransactionAdviceInputs::new(tx_inputs)
+            .map_err(TransactionAdviceValidationError::ExpectedAdviceInputsConflict)?;
+        let required_map_entries = expected_inputs.required_map_entries().to_vec();
+        let expected = expected_inputs.into_advice_inputs();
+
+        if advice.stack != expected.stack {
+            let index = advice
+                .stack
+                .iter()
+                .zip(expected.stack.iter())
+                .position(|(actual, expected)| actual != expected)
+                .unwrap_or(advice.stack.len().min(expected.stack.len()));
+            return Err(TransactionAdviceValidationError::AdviceStackMismatch { index });
+        }
+
+        for (entry, key) in required_map_entries {
+            let Some(actual_value) = advice.map.get(&key) else {
+                return Err(TransactionAdviceValidationError::MissingMapEntry { entry, key });
+            };
+
+            if expected.map.get(&key) != Some(actual_value) {
+                return Err(TransactionAdviceValidationError::MalformedMapEntry { entry, key });
+            }
+        }
+
+        Ok(())
+    }
+
     // ASSEMBLER CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
 
@@ -350,17 +432,9 @@ impl TransactionKernel {
         let (output_notes_commitment, account_update_commitment, fee, expiration_block_num) =
             Self::parse_output_stack(stack)?;
 
-        let (final_account_commitment, account_delta_commitment) =
-            Self::parse_account_update_commitment(account_update_commitment, advice_inputs)?;
-
         // parse final account state
-        let final_account_data = advice_inputs
-            .map
-            .get(&final_account_commitment)
-            .ok_or(TransactionOutputError::FinalAccountCommitmentMissingInAdviceMap)?;
-
-        let account = parse_final_account_header(final_account_data)
-            .map_err(TransactionOutputError::FinalAccountHeaderParseFailure)?;
+        let (account, account_delta_commitment) =
+            outputs::parse_final_account(account_update_commitment, advice_inputs)?;
 
         // validate output notes
         let output_notes = OutputNotes::new(output_notes)?;
@@ -1,5 +1,6 @@
 use miden_objects::account::{AccountHeader, AccountId};
-use miden_objects::{AccountError, Felt, WORD_SIZE, Word, WordError};
+use miden_objects::vm::{AdviceInputs, StackOutputs};
+use miden_objects::{AccountError, Felt, TransactionOutputError, WORD_SIZE, Word, WordError};
 
 use super::memory::{
     ACCT_CODE_COMMITMENT_OFFSET,
@@ -10,6 +11,7 @@ use super::memory::{
     ACCT_VAULT_ROOT_OFFSET,
     MemoryOffset,
 };
+use crate::transaction::TransactionKernel;
 use crate::transaction::memory::{ACCT_ID_PREFIX_IDX, ACCT_ID_SUFFIX_IDX};
 
 // STACK OUTPUTS
@@ -56,6 +58,62 @@ pub fn parse_final_account_header(elements: &[Felt]) -> Result<AccountHeader, Ac
     Ok(AccountHeader::new(id, nonce, vault_root, storage_commitment, code_commitment))
 }
 
+/// Extension trait for constructing an [`AccountHeader`] from the outputs of the transaction
+/// kernel.
+pub trait AccountHeaderExt: Sized {
+    /// Returns the header of the final state of the native account, parsed from the provided
+    /// output stack and advice inputs of a transaction kernel execution.
+    ///
+    /// The account update commitment on the output stack is expected to open to the final account
+    /// commitment and the account delta commitment in the advice map, and the final account
+    /// commitment is expected to open to the final account header data, see
+    /// [`TransactionKernel::from_transaction_parts`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the output stack is invalid.
+    /// - the account update commitment or final account commitment is missing in the advice map or
+    ///   its data is inconsistent with the commitment.
+    /// - the final account header data cannot be parsed.
+    fn try_from_stack_outputs(
+        outputs: &StackOutputs,
+        advice: &AdviceInputs,
+    ) -> Result<Self, TransactionOutputError>;
+}
+
+impl AccountHeaderExt for AccountHeader {
+    fn try_from_stack_outputs(
+        outputs: &StackOutputs,
+        advice: &AdviceInputs,
+    ) -> Result<Self, TransactionOutputError> {
+        let (_, account_update_commitment, ..) = TransactionKernel::parse_output_stack(outputs)?;
+        let (account, _) = parse_final_account(account_update_commitment, advice)?;
+
+        Ok(account)
+    }
+}
+
+/// Returns the final account header and the account delta commitment which the provided account
+/// update commitment opens to in the advice map.
+pub(super) fn parse_final_account(
+    account_update_commitment: Word,
+    advice: &AdviceInputs,
+) -> Result<(AccountHeader, Word), TransactionOutputError> {
+    let (final_account_commitment, account_delta_commitment) =
+        TransactionKernel::parse_account_update_commitment(account_update_commitment, advice)?;
+
+    let final_account_data = advice
+        .map
+        .get(&final_account_commitment)
+        .ok_or(TransactionOutputError::FinalAccountCommitmentMissingInAdviceMap)?;
+
+    let account = parse_final_account_header(final_account_data)
+        .map_err(TransactionOutputError::FinalAccountHeaderParseFailure)?;
+
+    Ok((account, account_delta_commitment))
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -5,15 +5,13 @@ use miden_objects::Word;
 use miden_objects::account::{AccountId, AccountIdVersion, AccountStorageMode, AccountType};
 use rand::{Rng, SeedableRng};
 
-/// Running this benchmark with --no-default-features will use the single-threaded account seed
-/// computation.
-///
-/// Passing --features concurrent will use the multi-threaded account seed computation.
+/// Compares the single-threaded account seed computation against the multi-threaded one, which
+/// uses all threads of the rayon thread pool.
 ///
 /// To produce a flamegraph, run with the `--profile-time` argument.
 ///
 /// ```sh
-/// cargo bench -p miden-objects --no-default-features -- --profile-time 10
+/// cargo bench -p miden-objects -- --profile-time 10
 /// ```
 ///
 /// The flamegraph will be saved as `target/criterion/grind-seed/Grind regular public account
@@ -44,6 +42,19 @@ fn grind_account_seed(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("Grind regular public account seed in parallel", |bench| {
+        bench.iter(|| {
+            AccountId::compute_account_seed_parallel(
+                rng.random(),
+                AccountType::RegularAccountImmutableCode,
+                AccountStorageMode::Public,
+                AccountIdVersion::Version0,
+                Word::empty(),
+                Word::empty(),
+            )
+        })
+    });
+
     group.finish();
 }
 
@@ -22,6 +22,7 @@ use miden_core::utils::{ByteReader, Deserializable, Serializable};
 use miden_crypto::utils::hex_to_bytes;
 use miden_processor::DeserializationError;
 
+use crate::account::{Account, AccountComponent};
 use crate::address::NetworkId;
 use crate::errors::AccountIdError;
 use crate::{AccountError, Word};
@@ -148,6 +149,22 @@ impl AccountId {
         }
     }
 
+    /// Creates an [`AccountId`] from the provided prefix and suffix.
+    ///
+    /// This is equivalent to [`AccountId::try_from`] with `[prefix, suffix]` for contexts in which
+    /// the prefix was already parsed separately, e.g. from a note tag or an asset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the ID constraints are not met. See the [constraints
+    /// documentation](AccountId#constraints) for details.
+    pub fn from_prefix_and_suffix(
+        prefix: AccountIdPrefix,
+        suffix: Felt,
+    ) -> Result<Self, AccountIdError> {
+        Self::try_from([prefix.as_felt(), suffix])
+    }
+
     /// Constructs an [`AccountId`] for testing purposes with the given account type, storage
     /// mode.
     ///
@@ -202,6 +219,101 @@ impl AccountId {
         }
     }
 
+    /// Grinds an account seed like [`Self::compute_account_seed`], but distributes the search
+    /// across all threads of the [`rayon`] thread pool.
+    ///
+    /// Each thread grinds from a distinct starting seed derived from `init_seed` and the seed
+    /// found first is returned. Unlike [`Self::compute_account_seed`], the returned seed is
+    /// therefore not deterministic.
+    #[cfg(feature = "std")]
+    pub fn compute_account_seed_parallel(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        code_commitment: Word,
+        storage_commitment: Word,
+    ) -> Result<Word, AccountError> {
+        match version {
+            AccountIdVersion::Version0 => AccountIdV0::compute_account_seed_parallel(
+                init_seed,
+                account_type,
+                storage_mode,
+                version,
+                code_commitment,
+                storage_commitment,
+            ),
+        }
+    }
+
+    /// Computes the [`AccountId`] of a new account built from the provided components, together
+    /// with the seed from which it is derived, without building the account itself.
+    ///
+    /// The code and storage commitments are computed from `components` in the same way as when
+    /// building an account, and the seed is ground from `init_seed` as in
+    /// [`Self::compute_account_seed`]. The components must be provided in the order in which they
+    /// are merged into the account, i.e. starting with the auth component, for the result to match
+    /// the ID of an account built by [`AccountBuilder`](crate::account::AccountBuilder) from the
+    /// same inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - any of the components does not support `account_type`.
+    /// - the components cannot be merged into valid account code or storage.
+    /// - seed grinding fails.
+    pub fn preview(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        components: &[AccountComponent],
+    ) -> Result<(Self, Word), AccountError> {
+        let (code, storage) = Account::initialize_from_components(account_type, components)?;
+        let code_commitment = code.commitment();
+        let storage_commitment = storage.commitment();
+
+        let seed = Self::compute_account_seed(
+            init_seed,
+            account_type,
+            storage_mode,
+            version,
+            code_commitment,
+            storage_commitment,
+        )?;
+        let account_id = Self::new(seed, version, code_commitment, storage_commitment)
+            .expect("compute_account_seed should provide a suitable seed");
+
+        Ok((account_id, seed))
+    }
+
+    /// Validates that this ID is derived from the provided `seed` for an account with the
+    /// provided code and storage commitments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the ID derived from the seed and commitments is invalid.
+    /// - the ID derived from the seed and commitments does not match this ID.
+    pub fn validate_seed_for(
+        &self,
+        code_commitment: Word,
+        storage_commitment: Word,
+        seed: Word,
+    ) -> Result<(), AccountError> {
+        let account_id = Self::new(seed, self.version(), code_commitment, storage_commitment)
+            .map_err(AccountError::SeedConvertsToInvalidAccountId)?;
+
+        if account_id != *self {
+            return Err(AccountError::AccountIdSeedMismatch {
+                expected: *self,
+                actual: account_id,
+            });
+        }
+
+        Ok(())
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -257,6 +369,11 @@ impl AccountId {
         }
     }
 
+    /// Returns `true` if this account ID has the provided version, `false` otherwise.
+    pub fn is_version(self, version: AccountIdVersion) -> bool {
+        self.version() == version
+    }
+
     /// Creates an [`AccountId`] from a hex string. Assumes the string starts with "0x" and
     /// that the hexadecimal characters are big-endian encoded.
     pub fn from_hex(hex_str: &str) -> Result<Self, AccountIdError> {
@@ -331,6 +448,18 @@ impl AccountId {
             AccountId::V0(account_id) => account_id.suffix(),
         }
     }
+
+    /// Returns the ID with the same prefix and the next valid suffix, or `None` if the suffix
+    /// cannot be incremented any further.
+    ///
+    /// Since the lower 8 bits of the suffix must be zero, the suffix is incremented by 256. All IDs
+    /// enumerated this way share the prefix of this ID, which is useful to check how prefix
+    /// collisions are handled, e.g. by the account tree.
+    pub fn next_suffix(&self) -> Option<AccountId> {
+        match self {
+            AccountId::V0(account_id) => account_id.next_suffix().map(AccountId::V0),
+        }
+    }
 }
 
 // CONVERSIONS FROM ACCOUNT ID
@@ -499,6 +628,45 @@ mod tests {
         AccountIdBuilder,
     };
 
+    #[test]
+    fn compute_account_seed_parallel_finds_valid_seed() -> anyhow::Result<()> {
+        let code_commitment = Word::from([1, 2, 3, 4u32]);
+        let storage_commitment = Word::from([5, 6, 7, 8u32]);
+
+        for (account_type, storage_mode) in [
+            (AccountType::RegularAccountImmutableCode, AccountStorageMode::Public),
+            (AccountType::FungibleFaucet, AccountStorageMode::Network),
+            (AccountType::NonFungibleFaucet, AccountStorageMode::Private),
+        ] {
+            let seed = AccountId::compute_account_seed_parallel(
+                [3; 32],
+                account_type,
+                storage_mode,
+                AccountIdVersion::Version0,
+                code_commitment,
+                storage_commitment,
+            )?;
+            let account_id = AccountId::new(
+                seed,
+                AccountIdVersion::Version0,
+                code_commitment,
+                storage_commitment,
+            )?;
+
+            assert_eq!(account_id.account_type(), account_type);
+            assert_eq!(account_id.storage_mode(), storage_mode);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_account_id_version() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        assert_eq!(account_id.version(), AccountIdVersion::Version0);
+        assert!(account_id.is_version(AccountIdVersion::Version0));
+    }
+
     #[test]
     fn test_account_id_wrapper_conversion_roundtrip() {
         for (idx, account_id) in [
@@ -521,6 +689,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_account_id_from_prefix_and_suffix() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+        assert_eq!(
+            AccountId::from_prefix_and_suffix(account_id.prefix(), account_id.suffix()).unwrap(),
+            account_id
+        );
+
+        // The most significant bit of the suffix must be zero.
+        let invalid_suffix = Felt::try_from(account_id.suffix().as_int() | (1 << 63)).unwrap();
+        assert_matches!(
+            AccountId::from_prefix_and_suffix(account_id.prefix(), invalid_suffix),
+            Err(AccountIdError::AccountIdSuffixMostSignificantBitMustBeZero)
+        );
+    }
+
+    #[test]
+    fn test_account_id_next_suffix() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+        let next_id = account_id.next_suffix().unwrap();
+        assert_eq!(next_id.prefix(), account_id.prefix());
+        assert_eq!(next_id.suffix().as_int(), account_id.suffix().as_int() + 256);
+        assert_eq!(AccountId::try_from(<[Felt; 2]>::from(next_id)).unwrap(), next_id);
+
+        let last_id = AccountId::new_unchecked([
+            account_id.prefix().as_felt(),
+            Felt::new(i64::MAX as u64 & !0xff),
+        ]);
+        assert!(last_id.next_suffix().is_none());
+    }
+
     #[test]
     fn bech32_encode_decode_roundtrip() -> anyhow::Result<()> {
         // We use this to check that encoding does not panic even when using the longest possible
@@ -8,9 +8,8 @@ use crate::{AccountError, Felt, Word};
 /// Finds and returns a seed suitable for creating an account ID for the specified account type
 /// using the provided initial seed as a starting point.
 ///
-/// This currently always uses a single thread. This method used to either use a single- or
-/// multi-threaded implementation based on a compile-time feature flag. The multi-threaded
-/// implementation was removed in commit dab6159318832fc537bb35abf251870a9129ac8c in PR 1061.
+/// This always uses a single thread, see [`compute_account_seed_parallel`] for a multi-threaded
+/// implementation.
 pub(super) fn compute_account_seed(
     init_seed: [u8; 32],
     account_type: AccountType,
@@ -19,17 +18,28 @@ pub(super) fn compute_account_seed(
     code_commitment: Word,
     storage_commitment: Word,
 ) -> Result<Word, AccountError> {
-    compute_account_seed_single(
-        init_seed,
+    let seed = grind_account_seed(
+        seed_from_bytes(init_seed),
         account_type,
         storage_mode,
         version,
         code_commitment,
         storage_commitment,
+        || false,
     )
+    .expect("grinding should only stop once a seed was found");
+
+    Ok(seed)
 }
 
-fn compute_account_seed_single(
+/// Finds and returns a seed suitable for creating an account ID for the specified account type
+/// using the provided initial seed as a starting point.
+///
+/// The search is distributed across [`rayon::current_num_threads`] workers, each of which grinds
+/// from a distinct starting seed derived from `init_seed`. The seed found first by any worker is
+/// returned, so the result may differ between invocations with the same `init_seed`.
+#[cfg(feature = "std")]
+pub(super) fn compute_account_seed_parallel(
     init_seed: [u8; 32],
     account_type: AccountType,
     storage_mode: AccountStorageMode,
@@ -37,14 +47,65 @@ fn compute_account_seed_single(
     code_commitment: Word,
     storage_commitment: Word,
 ) -> Result<Word, AccountError> {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+
+    let init_seed = seed_from_bytes(init_seed);
+    let is_found = AtomicBool::new(false);
+
+    let seed = (0..rayon::current_num_threads() as u64)
+        .into_par_iter()
+        .find_map_any(|worker_idx| {
+            // Offset the first element of the seed by the worker index, so that every worker
+            // grinds its own sequence of seeds.
+            let mut worker_seed = init_seed;
+            worker_seed[0] += Felt::new(worker_idx);
+
+            let seed = grind_account_seed(
+                worker_seed,
+                account_type,
+                storage_mode,
+                version,
+                code_commitment,
+                storage_commitment,
+                || is_found.load(Ordering::Relaxed),
+            )?;
+            is_found.store(true, Ordering::Relaxed);
+
+            Some(seed)
+        })
+        .expect("at least one worker should find a seed");
+
+    Ok(seed)
+}
+
+/// Converts the provided bytes into the seed from which grinding starts.
+fn seed_from_bytes(init_seed: [u8; 32]) -> Word {
     let init_seed: Vec<[u8; 8]> =
         init_seed.chunks(8).map(|chunk| chunk.try_into().unwrap()).collect();
-    let mut current_seed: Word = Word::from([
+    Word::from([
         Felt::new(u64::from_le_bytes(init_seed[0])),
         Felt::new(u64::from_le_bytes(init_seed[1])),
         Felt::new(u64::from_le_bytes(init_seed[2])),
         Felt::new(u64::from_le_bytes(init_seed[3])),
-    ]);
+    ])
+}
+
+/// Grinds seeds starting from `init_seed` until one satisfies the specified account type, storage
+/// mode and version, and returns it.
+///
+/// Returns `None` if `should_stop` returns `true` before a seed was found.
+fn grind_account_seed(
+    init_seed: Word,
+    account_type: AccountType,
+    storage_mode: AccountStorageMode,
+    version: AccountIdVersion,
+    code_commitment: Word,
+    storage_commitment: Word,
+    should_stop: impl Fn() -> bool,
+) -> Option<Word> {
+    let mut current_seed = init_seed;
     let mut current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
 
     // loop until we have a seed that satisfies the specified account type.
@@ -62,9 +123,13 @@ fn compute_account_seed_single(
             && computed_version == version
             && is_suffix_msb_zero
         {
-            return Ok(current_seed);
+            return Some(current_seed);
         };
 
+        if should_stop() {
+            return None;
+        }
+
         current_seed = current_digest;
         current_digest = compute_digest(current_seed, code_commitment, storage_commitment);
     }
@@ -64,6 +64,10 @@ impl AccountIdV0 {
     /// The bit at index 5 of the prefix encodes whether the account is a faucet.
     pub(crate) const IS_FAUCET_MASK: u64 = 0b10 << Self::TYPE_SHIFT;
 
+    /// The smallest difference between two valid suffixes, since the lower 8 bits of the suffix
+    /// must be zero.
+    const SUFFIX_QUANTUM: u64 = 1 << 8;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -163,6 +167,28 @@ impl AccountIdV0 {
         )
     }
 
+    /// See
+    /// [`AccountId::compute_account_seed_parallel`](super::AccountId::compute_account_seed_parallel)
+    /// for details.
+    #[cfg(feature = "std")]
+    pub fn compute_account_seed_parallel(
+        init_seed: [u8; 32],
+        account_type: AccountType,
+        storage_mode: AccountStorageMode,
+        version: AccountIdVersion,
+        code_commitment: Word,
+        storage_commitment: Word,
+    ) -> Result<Word, AccountError> {
+        crate::account::account_id::seed::compute_account_seed_parallel(
+            init_seed,
+            account_type,
+            storage_mode,
+            version,
+            code_commitment,
+            storage_commitment,
+        )
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -292,6 +318,15 @@ impl AccountIdV0 {
     pub const fn suffix(&self) -> Felt {
         self.suffix
     }
+
+    /// See [`AccountId::next_suffix`](super::AccountId::next_suffix) for details.
+    pub fn next_suffix(&self) -> Option<Self> {
+        let suffix = self.suffix.as_int().checked_add(Self::SUFFIX_QUANTUM)?;
+        let suffix = Felt::try_from(suffix).ok()?;
+        validate_suffix(suffix).ok()?;
+
+        Some(Self { prefix: self.prefix, suffix })
+    }
 }
 
 // CONVERSIONS FROM ACCOUNT ID
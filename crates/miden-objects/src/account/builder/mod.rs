@@ -1,4 +1,6 @@
 use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::format;
 use alloc::vec::Vec;
 
 use miden_core::FieldElement;
@@ -52,6 +54,15 @@ use crate::{AccountError, Felt, Word};
 ///
 /// Faucet accounts have a protocol-reserved storage slot which is at index 0. This means
 /// user-defined storage slots start at index 1.
+///
+/// Components which declare dependencies, see [`AccountComponent::with_dependency`], are moved
+/// after the components they depend on. Components which do not depend on each other keep the
+/// order in which they were added. The resulting storage offset of each component is reported by
+/// the [`ComponentLayout`](crate::account::ComponentLayout) of the account code.
+///
+/// Components added with [`AccountBuilder::with_component_at_offset`] are instead placed such
+/// that their storage starts at the requested slot index. The remaining components fill the slots
+/// before and in between them in the order in which they were added.
 #[derive(Debug, Clone)]
 pub struct AccountBuilder {
     #[cfg(any(feature = "testing", test))]
@@ -59,11 +70,14 @@ pub struct AccountBuilder {
     #[cfg(any(feature = "testing", test))]
     nonce: Option<Felt>,
     components: Vec<AccountComponent>,
+    /// storage_offset |-> component
+    pinned_components: BTreeMap<u8, AccountComponent>,
     auth_component: Option<AccountComponent>,
     account_type: AccountType,
     storage_mode: AccountStorageMode,
     init_seed: [u8; 32],
     id_version: AccountIdVersion,
+    code_size_limit: Option<usize>,
 }
 
 impl AccountBuilder {
@@ -78,11 +92,13 @@ impl AccountBuilder {
             #[cfg(any(feature = "testing", test))]
             nonce: None,
             components: vec![],
+            pinned_components: BTreeMap::new(),
             auth_component: None,
             init_seed,
             account_type: AccountType::RegularAccountUpdatableCode,
             storage_mode: AccountStorageMode::Private,
             id_version: AccountIdVersion::Version0,
+            code_size_limit: None,
         }
     }
 
@@ -113,6 +129,52 @@ impl AccountBuilder {
         self
     }
 
+    /// Adds an [`AccountComponent`] to the builder whose storage slots will start at the provided
+    /// storage `offset` of the built account.
+    ///
+    /// This is useful to assert exact slot assignments, e.g. when testing the interoperability of
+    /// components. The components added with [`AccountBuilder::with_component`] are placed before
+    /// and in between the components added with this method, in the order in which they were
+    /// added. Building the account fails if they cannot fill all slots up to the requested offset
+    /// exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The component's storage slots do not fit into the account storage at the given offset.
+    /// - The component's storage slots overlap with the ones of a component previously added at an
+    ///   offset.
+    pub fn with_component_at_offset(
+        mut self,
+        account_component: impl Into<AccountComponent>,
+        offset: u8,
+    ) -> Result<Self, AccountError> {
+        let account_component = account_component.into();
+
+        let end = u16::from(offset) + u16::from(account_component.storage_slot_count());
+        if end as usize > AccountStorage::MAX_NUM_STORAGE_SLOTS {
+            return Err(AccountError::StorageOffsetPlusSizeOutOfBounds(end));
+        }
+
+        let overlapping_offset = self.pinned_components.iter().find_map(|(other_offset, other)| {
+            let other_end = u16::from(*other_offset) + u16::from(other.storage_slot_count());
+            let overlaps = offset == *other_offset
+                || (u16::from(offset) < other_end && u16::from(*other_offset) < end);
+            overlaps.then_some(*other_offset)
+        });
+        if let Some(other_offset) = overlapping_offset {
+            return Err(AccountError::BuildError(
+                format!(
+                    "component at storage offset {offset} overlaps with the component at storage offset {other_offset}"
+                ),
+                None,
+            ));
+        }
+
+        self.pinned_components.insert(offset, account_component);
+        Ok(self)
+    }
+
     /// Adds a designated authentication [`AccountComponent`] to the builder.
     ///
     /// This component may contain multiple procedures, but is expected to contain exactly one
@@ -126,6 +188,16 @@ impl AccountBuilder {
         self
     }
 
+    /// Sets the maximum size of the account code in bytes.
+    ///
+    /// Building the account fails if the estimated serialized size of its code, see
+    /// [`AccountCodeMetrics::size_bytes`](crate::account::AccountCodeMetrics::size_bytes), exceeds
+    /// this limit.
+    pub fn with_code_size_limit(mut self, size_limit_bytes: usize) -> Self {
+        self.code_size_limit = Some(size_limit_bytes);
+        self
+    }
+
     /// Builds the common parts of testing and non-testing code.
     fn build_inner(&mut self) -> Result<(AssetVault, AccountCode, AccountStorage), AccountError> {
         #[cfg(any(feature = "testing", test))]
@@ -141,8 +213,7 @@ impl AccountBuilder {
             .take()
             .ok_or(AccountError::BuildError("auth component must be set".into(), None))?;
 
-        let mut components = vec![auth_component];
-        components.append(&mut self.components);
+        let components = self.arrange_components(auth_component)?;
 
         let (code, storage) = Account::initialize_from_components(self.account_type, &components)
             .map_err(|err| {
@@ -152,9 +223,84 @@ impl AccountBuilder {
             )
         })?;
 
+        if let Some(size_limit) = self.code_size_limit {
+            let code_size = code.metrics().size_bytes();
+            if code_size > size_limit {
+                return Err(AccountError::BuildError(
+                    format!(
+                        "account code size estimate of {code_size} bytes exceeds the limit of {size_limit} bytes"
+                    ),
+                    None,
+                ));
+            }
+        }
+
         Ok((vault, code, storage))
     }
 
+    /// Returns the components in the order in which they are merged, starting with the provided
+    /// auth component, such that all components added at an offset are placed at that offset and
+    /// all other components are placed after the components they depend on.
+    fn arrange_components(
+        &mut self,
+        auth_component: AccountComponent,
+    ) -> Result<Vec<AccountComponent>, AccountError> {
+        let reserved_slots: u16 = if self.account_type.is_faucet() { 1 } else { 0 };
+        let mut offset = reserved_slots + u16::from(auth_component.storage_slot_count());
+
+        let unpinned_components = sort_components_by_dependencies(
+            core::mem::take(&mut self.components),
+            &auth_component,
+            self.pinned_components.values(),
+        )?;
+        let mut unpinned_components: VecDeque<_> = unpinned_components.into();
+        let mut pinned_components =
+            core::mem::take(&mut self.pinned_components).into_iter().peekable();
+        let mut components = vec![auth_component];
+
+        loop {
+            let next_pinned_offset = pinned_components.peek().map(|(offset, _)| u16::from(*offset));
+
+            match next_pinned_offset {
+                Some(pinned_offset) if pinned_offset == offset => {
+                    let (_, component) = pinned_components.next().expect("peeked component");
+                    offset += u16::from(component.storage_slot_count());
+                    components.push(component);
+                    continue;
+                },
+                Some(pinned_offset) if pinned_offset < offset => {
+                    return Err(AccountError::BuildError(
+                        format!(
+                            "component cannot be placed at storage offset {pinned_offset} since the preceding components occupy the storage up to offset {offset}"
+                        ),
+                        None,
+                    ));
+                },
+                _ => {},
+            }
+
+            match unpinned_components.pop_front() {
+                Some(component) => {
+                    offset += u16::from(component.storage_slot_count());
+                    components.push(component);
+                },
+                None => match next_pinned_offset {
+                    Some(pinned_offset) => {
+                        return Err(AccountError::BuildError(
+                            format!(
+                                "component cannot be placed at storage offset {pinned_offset} since the preceding components only occupy the storage up to offset {offset}"
+                            ),
+                            None,
+                        ));
+                    },
+                    None => break,
+                },
+            }
+        }
+
+        Ok(components)
+    }
+
     /// Grinds a new [`AccountId`] using the `init_seed` as a starting point.
     fn grind_account_id(
         &self,
@@ -190,6 +336,8 @@ impl AccountBuilder {
     /// - Two or more libraries export a procedure with the same MAST root.
     /// - Authentication component is missing.
     /// - Multiple authentication procedures are found.
+    /// - A dependency of a component is not satisfied by any other component or the dependencies of
+    ///   the components form a cycle.
     /// - The number of [`StorageSlot`](crate::account::StorageSlot)s of all components exceeds 255.
     /// - [`MastForest::merge`](miden_processor::MastForest::merge) fails on the given components.
     /// - If duplicate assets were added to the builder (only under the `testing` feature).
@@ -232,6 +380,79 @@ impl AccountBuilder {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Sorts the provided components topologically by their declared dependencies, such that each
+/// component is placed after the components it depends on.
+///
+/// Components which do not depend on each other keep the order in which they were provided. The
+/// dependencies of all components must be satisfied by one of the other provided components,
+/// the auth component or the components added at an offset. The latter are not part of the
+/// sorting since their position is fixed.
+fn sort_components_by_dependencies<'a>(
+    components: Vec<AccountComponent>,
+    auth_component: &'a AccountComponent,
+    pinned_components: impl Iterator<Item = &'a AccountComponent>,
+) -> Result<Vec<AccountComponent>, AccountError> {
+    let fixed_components: Vec<&AccountComponent> =
+        core::iter::once(auth_component).chain(pinned_components).collect();
+
+    // Check that the dependencies of all components can be satisfied.
+    for (component_idx, component) in
+        fixed_components.iter().copied().chain(components.iter()).enumerate()
+    {
+        for dependency in component.dependencies() {
+            let is_satisfied =
+                fixed_components.iter().copied().chain(components.iter()).enumerate().any(
+                    |(other_idx, other)| other_idx != component_idx && dependency.matches(other),
+                );
+            if !is_satisfied {
+                return Err(AccountError::AccountComponentMissingDependency(dependency.clone()));
+            }
+        }
+    }
+
+    // component index |-> indices of the components it depends on
+    let mut dependencies: Vec<BTreeSet<usize>> = components
+        .iter()
+        .enumerate()
+        .map(|(component_idx, component)| {
+            components
+                .iter()
+                .enumerate()
+                .filter(|(other_idx, other)| {
+                    *other_idx != component_idx
+                        && component
+                            .dependencies()
+                            .iter()
+                            .any(|dependency| dependency.matches(other))
+                })
+                .map(|(other_idx, _)| other_idx)
+                .collect()
+        })
+        .collect();
+
+    // Repeatedly take the first remaining component whose dependencies have all been taken, which
+    // keeps the relative order of independent components.
+    let mut components: Vec<Option<AccountComponent>> = components.into_iter().map(Some).collect();
+    let mut sorted_components = Vec::with_capacity(components.len());
+    while sorted_components.len() < components.len() {
+        let next_idx = components
+            .iter()
+            .zip(dependencies.iter())
+            .position(|(component, dependencies)| component.is_some() && dependencies.is_empty())
+            .ok_or(AccountError::AccountComponentDependencyCycle)?;
+
+        sorted_components.push(components[next_idx].take().expect("component should not be taken"));
+        for component_dependencies in dependencies.iter_mut() {
+            component_dependencies.remove(&next_idx);
+        }
+    }
+
+    Ok(sorted_components)
+}
+
 #[cfg(any(feature = "testing", test))]
 impl AccountBuilder {
     /// Adds all the assets to the account's [`AssetVault`]. This method is optional.
@@ -288,10 +509,11 @@ mod tests {
     use assert_matches::assert_matches;
     use miden_assembly::{Assembler, Library};
     use miden_core::FieldElement;
+    use miden_core::utils::Serializable;
     use miden_processor::MastNodeExt;
 
     use super::*;
-    use crate::account::StorageSlot;
+    use crate::account::{ComponentDependency, StorageSlot};
     use crate::testing::noop_auth_component::NoopAuthComponent;
 
     const CUSTOM_CODE1: &str = "
@@ -420,6 +642,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn account_id_preview_matches_built_account() -> anyhow::Result<()> {
+        let init_seed = [7; 32];
+        let account_type = AccountType::RegularAccountImmutableCode;
+        let storage_mode = AccountStorageMode::Public;
+
+        let components: Vec<AccountComponent> = vec![
+            NoopAuthComponent.into(),
+            CustomComponent1 { slot0: 5 }.into(),
+            CustomComponent2 { slot0: 6, slot1: 7 }.into(),
+        ];
+        let (preview_id, preview_seed) = AccountId::preview(
+            init_seed,
+            account_type,
+            storage_mode,
+            AccountIdVersion::Version0,
+            &components,
+        )?;
+
+        let account = Account::builder(init_seed)
+            .account_type(account_type)
+            .storage_mode(storage_mode)
+            .with_auth_component(NoopAuthComponent)
+            .with_component(CustomComponent1 { slot0: 5 })
+            .with_component(CustomComponent2 { slot0: 6, slot1: 7 })
+            .build()?;
+
+        assert_eq!(preview_id, account.id());
+        assert_eq!(Some(preview_seed), account.seed());
+        preview_id.validate_seed_for(
+            account.code().commitment(),
+            account.storage().commitment(),
+            preview_seed,
+        )?;
+        assert_matches!(
+            preview_id.validate_seed_for(account.code().commitment(), Word::empty(), preview_seed),
+            Err(AccountError::AccountIdSeedMismatch { .. })
+                | Err(AccountError::SeedConvertsToInvalidAccountId(_))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_builder_code_metrics_and_size_limit() -> anyhow::Result<()> {
+        let builder = Account::builder([5; 32]).with_auth_component(NoopAuthComponent);
+        let account1 = builder.clone().with_component(CustomComponent1 { slot0: 0 }).build()?;
+        let account2 = builder
+            .clone()
+            .with_component(CustomComponent1 { slot0: 0 })
+            .with_component(CustomComponent2 { slot0: 0, slot1: 0 })
+            .build()?;
+
+        let metrics1 = account1.code().metrics();
+        let metrics2 = account2.code().metrics();
+        assert_eq!(metrics1.num_procedures(), 2);
+        assert_eq!(metrics2.num_procedures(), 3);
+        assert!(metrics2.num_mast_nodes() > metrics1.num_mast_nodes());
+        assert!(metrics2.size_bytes() > metrics1.size_bytes());
+
+        let component_procedures: Vec<usize> = metrics2
+            .components()
+            .expect("code built from components should have a layout")
+            .iter()
+            .map(|component| component.num_procedures())
+            .collect();
+        assert_eq!(component_procedures, [1, 1, 1]);
+
+        // A limit equal to the size estimate is fine, but one byte less fails the build.
+        builder
+            .clone()
+            .with_component(CustomComponent1 { slot0: 0 })
+            .with_component(CustomComponent2 { slot0: 0, slot1: 0 })
+            .with_code_size_limit(metrics2.size_bytes())
+            .build()?;
+        let error = builder
+            .with_component(CustomComponent1 { slot0: 0 })
+            .with_component(CustomComponent2 { slot0: 0, slot1: 0 })
+            .with_code_size_limit(metrics2.size_bytes() - 1)
+            .build()
+            .unwrap_err();
+        assert_matches!(error, AccountError::BuildError(msg, _) if msg.contains("exceeds the limit"));
+
+        Ok(())
+    }
+
     #[test]
     fn account_builder_non_empty_vault_on_new_account() {
         let storage_slot0 = 25;
@@ -434,5 +742,123 @@ mod tests {
         assert_matches!(build_error, AccountError::BuildError(msg, _) if msg == "account asset vault must be empty on new accounts")
     }
 
+    #[test]
+    fn account_builder_with_component_at_offset() -> anyhow::Result<()> {
+        let account = Account::builder([5; 32])
+            .with_auth_component(NoopAuthComponent)
+            .with_component_at_offset(CustomComponent2 { slot0: 12, slot1: 42 }, 1)?
+            .with_component(CustomComponent1 { slot0: 25 })
+            .build()?;
+
+        let [foo_info, bar_info] = [&*CUSTOM_LIBRARY1, &*CUSTOM_LIBRARY2].map(|library| {
+            let root = library.mast_forest()
+                [library.get_export_node_id(&library.exports().next().unwrap().name)]
+            .digest();
+            *account
+                .code()
+                .procedures()
+                .iter()
+                .find(|info| info.mast_root() == &root)
+                .unwrap()
+        });
+        assert_eq!(foo_info.storage_offset(), 0);
+        assert_eq!(bar_info.storage_offset(), 1);
+        assert_eq!(bar_info.storage_size(), 2);
+
+        assert_eq!(account.storage().get_item(0)?[0], Felt::new(25));
+        assert_eq!(account.storage().get_item(1)?[3], Felt::new(12));
+        assert_eq!(account.storage().get_item(2)?[3], Felt::new(42));
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_builder_with_component_at_invalid_offset() -> anyhow::Result<()> {
+        let error = Account::builder([5; 32])
+            .with_component_at_offset(CustomComponent2 { slot0: 0, slot1: 0 }, 254)
+            .unwrap_err();
+        assert_matches!(error, AccountError::StorageOffsetPlusSizeOutOfBounds(256));
+
+        let error = Account::builder([5; 32])
+            .with_component_at_offset(CustomComponent2 { slot0: 0, slot1: 0 }, 1)?
+            .with_component_at_offset(CustomComponent1 { slot0: 0 }, 2)
+            .unwrap_err();
+        assert_matches!(error, AccountError::BuildError(msg, _) if msg.contains("overlaps"));
+
+        // The unpinned component only occupies slot 0, so slots 1 and 2 would be empty.
+        let error = Account::builder([5; 32])
+            .with_auth_component(NoopAuthComponent)
+            .with_component_at_offset(CustomComponent2 { slot0: 0, slot1: 0 }, 3)?
+            .with_component(CustomComponent1 { slot0: 0 })
+            .build()
+            .unwrap_err();
+        assert_matches!(error, AccountError::BuildError(msg, _) if msg.contains("offset 3"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_builder_sorts_components_by_dependencies() -> anyhow::Result<()> {
+        // Component 2 depends on component 1 but is added first.
+        let component2 = AccountComponent::from(CustomComponent2 { slot0: 12, slot1: 42 })
+            .with_dependency(*CUSTOM_LIBRARY1.digest());
+        let account = Account::builder([5; 32])
+            .with_auth_component(NoopAuthComponent)
+            .with_component(component2)
+            .with_component(CustomComponent1 { slot0: 25 })
+            .build()?;
+
+        assert_eq!(account.code().storage_offset_of_component(0), Some(0));
+        assert_eq!(account.code().storage_offset_of_component(1), Some(0));
+        assert_eq!(account.code().storage_offset_of_component(2), Some(1));
+        assert_eq!(
+            account.code().procedures_of_component(1).unwrap(),
+            AccountComponent::from(CustomComponent1 { slot0: 0 })
+                .get_procedures()
+                .into_iter()
+                .map(|(root, _)| root)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(account.storage().get_item(0)?[0], Felt::new(25));
+        assert_eq!(account.storage().get_item(1)?[3], Felt::new(12));
+
+        // The component layout including the storage offsets survives a serialization roundtrip.
+        let code = AccountCode::from_bytes(&account.code().to_bytes())?;
+        assert_eq!(code.component_layout(), account.code().component_layout());
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_builder_with_invalid_dependencies() {
+        let error = Account::builder([5; 32])
+            .with_auth_component(NoopAuthComponent)
+            .with_component(
+                AccountComponent::from(CustomComponent1 { slot0: 0 })
+                    .with_dependency("miden::missing"),
+            )
+            .build()
+            .unwrap_err();
+        assert_matches!(
+            error,
+            AccountError::AccountComponentMissingDependency(ComponentDependency::ModulePath(path))
+                if path == "miden::missing"
+        );
+
+        let error = Account::builder([5; 32])
+            .with_auth_component(NoopAuthComponent)
+            .with_component(
+                AccountComponent::from(CustomComponent1 { slot0: 0 })
+                    .with_dependency(*CUSTOM_LIBRARY2.digest()),
+            )
+            .with_component(
+                AccountComponent::from(CustomComponent2 { slot0: 0, slot1: 0 })
+                    .with_dependency(*CUSTOM_LIBRARY1.digest()),
+            )
+            .build()
+            .unwrap_err();
+        assert_matches!(error, AccountError::AccountComponentDependencyCycle);
+    }
+
     // TODO: Test that a BlockHeader with a number which is not a multiple of 2^16 returns an error.
 }
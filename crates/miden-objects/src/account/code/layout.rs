@@ -0,0 +1,152 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::Word;
+use crate::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+
+// COMPONENT LAYOUT
+// ================================================================================================
+
+/// Describes which procedures of an [`AccountCode`](super::AccountCode) originate from which
+/// [`AccountComponent`](crate::account::AccountComponent).
+///
+/// The layout stores the MAST roots of the procedures of each component in the order in which the
+/// components were merged into the account code, i.e. the component at index 0 is always the
+/// authentication component. Additionally, it stores the index of the account storage slot at
+/// which the storage of each component starts.
+///
+/// The layout is not part of the account code commitment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComponentLayout {
+    components: Vec<Vec<Word>>,
+    storage_offsets: Vec<u8>,
+}
+
+impl ComponentLayout {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The version of the serialized layout format, which is written before the layout data.
+    ///
+    /// Version `1` contains the procedure roots and the storage offset of each component.
+    pub const SERIALIZATION_VERSION: u8 = 1;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [`ComponentLayout`] instantiated from the storage offset and the procedure
+    /// roots of each component.
+    pub fn new(components: Vec<(u8, Vec<Word>)>) -> Self {
+        let (storage_offsets, components) = components.into_iter().unzip();
+        Self { components, storage_offsets }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of components described by this layout.
+    pub fn num_components(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Returns the procedure roots of the component at the provided index, or `None` if the index
+    /// is out of bounds.
+    pub fn procedures_of_component(&self, component_idx: usize) -> Option<&[Word]> {
+        self.components.get(component_idx).map(Vec::as_slice)
+    }
+
+    /// Returns the index of the storage slot at which the storage of the component at the
+    /// provided index starts, or `None` if the index is out of bounds.
+    ///
+    /// For components without storage, this is the offset at which their storage would start.
+    pub fn storage_offset_of_component(&self, component_idx: usize) -> Option<u8> {
+        self.storage_offsets.get(component_idx).copied()
+    }
+
+    /// Returns the index of the component which contains the procedure with the provided MAST
+    /// root, or `None` if no component contains it.
+    pub fn component_of_procedure(&self, proc_root: Word) -> Option<usize> {
+        self.components.iter().position(|procedures| procedures.contains(&proc_root))
+    }
+
+    /// Returns an iterator over the procedure roots of all components.
+    pub fn iter(&self) -> impl Iterator<Item = &[Word]> {
+        self.components.iter().map(Vec::as_slice)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ComponentLayout {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(Self::SERIALIZATION_VERSION);
+        self.components.write_into(target);
+        self.storage_offsets.write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        0u8.get_size_hint() + self.components.get_size_hint() + self.storage_offsets.get_size_hint()
+    }
+}
+
+impl Deserializable for ComponentLayout {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u8()?;
+        if version != Self::SERIALIZATION_VERSION {
+            return Err(DeserializationError::InvalidValue(format!(
+                "unsupported component layout serialization version {version}, expected {}",
+                Self::SERIALIZATION_VERSION
+            )));
+        }
+
+        let components: Vec<Vec<Word>> = source.read()?;
+        let storage_offsets: Vec<u8> = source.read()?;
+        if storage_offsets.len() != components.len() {
+            return Err(DeserializationError::InvalidValue(format!(
+                "component layout contains {} components but {} storage offsets",
+                components.len(),
+                storage_offsets.len()
+            )));
+        }
+
+        Ok(Self { components, storage_offsets })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::ComponentLayout;
+    use crate::Word;
+    use crate::utils::serde::{Deserializable, DeserializationError, Serializable};
+
+    #[test]
+    fn component_layout_serde_is_versioned() {
+        let layout = ComponentLayout::new(vec![
+            (0, vec![Word::from([1, 2, 3, 4u32])]),
+            (1, vec![Word::from([5, 6, 7, 8u32]), Word::from([9, 10, 11, 12u32])]),
+        ]);
+
+        let mut bytes = layout.to_bytes();
+        assert_eq!(bytes[0], ComponentLayout::SERIALIZATION_VERSION);
+        assert_eq!(bytes.len(), layout.get_size_hint());
+        assert_eq!(ComponentLayout::read_from_bytes(&bytes).unwrap(), layout);
+
+        bytes[0] = ComponentLayout::SERIALIZATION_VERSION + 1;
+        assert_matches!(
+            ComponentLayout::read_from_bytes(&bytes),
+            Err(DeserializationError::InvalidValue(_))
+        );
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+
+// ACCOUNT CODE METRICS
+// ================================================================================================
+
+/// Size metrics of an [`AccountCode`](super::AccountCode).
+///
+/// These metrics allow checking how close a composed account code is to the protocol limits, such
+/// as [`AccountCode::MAX_NUM_PROCEDURES`](super::AccountCode::MAX_NUM_PROCEDURES), before it is
+/// deployed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountCodeMetrics {
+    num_procedures: usize,
+    num_mast_nodes: usize,
+    size_bytes: usize,
+    components: Option<Vec<ComponentMetrics>>,
+}
+
+impl AccountCodeMetrics {
+    /// Returns a new [`AccountCodeMetrics`] instantiated from the provided parts.
+    pub(super) fn new(
+        num_procedures: usize,
+        num_mast_nodes: usize,
+        size_bytes: usize,
+        components: Option<Vec<ComponentMetrics>>,
+    ) -> Self {
+        Self {
+            num_procedures,
+            num_mast_nodes,
+            size_bytes,
+            components,
+        }
+    }
+
+    /// Returns the number of procedures exported by the account code.
+    pub fn num_procedures(&self) -> usize {
+        self.num_procedures
+    }
+
+    /// Returns the number of nodes in the MAST forest of the account code.
+    pub fn num_mast_nodes(&self) -> usize {
+        self.num_mast_nodes
+    }
+
+    /// Returns an estimate of the size of the serialized account code in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.size_bytes
+    }
+
+    /// Returns the metrics of each component the account code was built from, or `None` if the
+    /// account code does not have a [`ComponentLayout`](super::ComponentLayout).
+    pub fn components(&self) -> Option<&[ComponentMetrics]> {
+        self.components.as_deref()
+    }
+}
+
+// COMPONENT METRICS
+// ================================================================================================
+
+/// Size metrics of a single component of an [`AccountCode`](super::AccountCode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ComponentMetrics {
+    num_procedures: usize,
+}
+
+impl ComponentMetrics {
+    /// Returns a new [`ComponentMetrics`] instantiated from the provided parts.
+    pub(super) fn new(num_procedures: usize) -> Self {
+        Self { num_procedures }
+    }
+
+    /// Returns the number of procedures contributed by the component.
+    pub fn num_procedures(&self) -> usize {
+        self.num_procedures
+    }
+}
@@ -2,7 +2,8 @@ use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use miden_core::mast::MastForest;
+use miden_core::Operation;
+use miden_core::mast::{MastForest, MastNode, MastNodeExt, Remapping, SubtreeIterator};
 use miden_core::prettier::PrettyPrint;
 
 use super::{
@@ -18,6 +19,12 @@ use super::{
 use crate::Word;
 use crate::account::{AccountComponent, AccountType};
 
+pub mod layout;
+use layout::ComponentLayout;
+
+pub mod metrics;
+use metrics::{AccountCodeMetrics, ComponentMetrics};
+
 pub mod procedure;
 use procedure::{AccountProcedureInfo, PrintableProcedure};
 
@@ -38,11 +45,15 @@ use procedure::{AccountProcedureInfo, PrintableProcedure};
 /// ```text
 /// [PROCEDURE_MAST_ROOT, storage_offset, 0, 0, storage_size]
 /// ```
+///
+/// Account code built from components additionally keeps a [`ComponentLayout`] which records the
+/// procedures contributed by each component. The layout is not part of the commitment.
 #[derive(Debug, Clone)]
 pub struct AccountCode {
     mast: Arc<MastForest>,
     procedures: Vec<AccountProcedureInfo>,
     commitment: Word,
+    component_layout: Option<Arc<ComponentLayout>>,
 }
 
 impl AccountCode {
@@ -96,18 +107,33 @@ impl AccountCode {
 
         let first_component =
             components_iter.next().ok_or(AccountError::AccountCodeNoAuthComponent)?;
-        builder.add_auth_component(first_component)?;
+        let mut storage_offsets = vec![builder.add_auth_component(first_component)?];
 
         for component in components_iter {
-            builder.add_component(component)?;
+            storage_offsets.push(builder.add_component(component)?);
         }
 
+        let component_layout = ComponentLayout::new(
+            storage_offsets
+                .into_iter()
+                .zip(components)
+                .map(|(storage_offset, component)| {
+                    let proc_roots = component
+                        .get_procedures()
+                        .into_iter()
+                        .map(|(proc_root, _)| proc_root)
+                        .collect();
+                    (storage_offset, proc_roots)
+                })
+                .collect(),
+        );
         let procedures = builder.build()?;
 
         Ok(Self {
             commitment: build_procedure_commitment(&procedures),
             procedures,
             mast: Arc::new(merged_mast_forest),
+            component_layout: Some(Arc::new(component_layout)),
         })
     }
 
@@ -122,6 +148,9 @@ impl AccountCode {
     /// Returns a new definition of an account's interface instantiated from the provided
     /// [MastForest] and a list of [AccountProcedureInfo]s.
     ///
+    /// The returned account code does not have a [`ComponentLayout`], see
+    /// [`Self::with_component_layout`].
+    ///
     /// # Panics
     /// Panics if:
     /// - The number of procedures is smaller than 1 or greater than 256.
@@ -135,9 +164,105 @@ impl AccountCode {
             commitment: build_procedure_commitment(&procedures),
             procedures,
             mast,
+            component_layout: None,
         }
     }
 
+    /// Sets the [`ComponentLayout`] of this account code.
+    ///
+    /// # Panics
+    /// Panics if the layout references a procedure that is not defined in this account code.
+    pub fn with_component_layout(mut self, component_layout: ComponentLayout) -> Self {
+        assert!(
+            component_layout
+                .iter()
+                .flatten()
+                .all(|proc_root| self.has_procedure(*proc_root)),
+            "component layout references procedures that are not part of the account code"
+        );
+
+        self.component_layout = Some(Arc::new(component_layout));
+        self
+    }
+
+    /// Returns a new [`AccountCode`] for an account with updatable code, in which all non-auth
+    /// procedures of `base` are replaced by the procedures of `new_procedures`.
+    ///
+    /// The auth procedure of `base` (i.e. its first procedure) is retained with its storage offset
+    /// and size, and the procedures of `new_procedures` are placed in storage after the storage
+    /// of the auth procedure. The MAST forest of the returned code is the merge of the part of the
+    /// MAST forest of `base` defining the auth procedure and of the MAST forest of
+    /// `new_procedures`, so the replaced procedures are not part of it.
+    ///
+    /// Note that the storage of the account must be updated by the caller to match the storage
+    /// layout of `new_procedures`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `new_procedures` does not support [`AccountType::RegularAccountUpdatableCode`].
+    /// - `new_procedures` contains an authentication procedure.
+    /// - `new_procedures` exports the auth procedure of `base` or the storage of `new_procedures`
+    ///   does not fit after the storage of the auth procedure.
+    /// - the MAST forest of `base` does not contain its auth procedure.
+    /// - [`MastForest::merge`] fails or the auth procedure is not the first procedure of the merged
+    ///   code.
+    pub fn merge_updatable(
+        base: &AccountCode,
+        new_procedures: AccountComponent,
+    ) -> Result<AccountCode, AccountError> {
+        let account_type = AccountType::RegularAccountUpdatableCode;
+        super::validate_components_support_account_type(
+            core::slice::from_ref(&new_procedures),
+            account_type,
+        )?;
+
+        let auth_procedure = *base.get_procedure_by_index(0);
+        let auth_root = *auth_procedure.mast_root();
+
+        // Only the auth procedure of the base code is kept, so the nodes of the replaced procedures
+        // must not end up in the merged MAST forest.
+        let auth_mast_forest = retain_procedures(&base.mast, &[auth_root])?;
+        let (merged_mast_forest, _) =
+            MastForest::merge([&auth_mast_forest, new_procedures.mast_forest()])
+                .map_err(AccountError::AccountComponentMastForestMergeError)?;
+
+        let mut builder = ProcedureInfoBuilder::new(account_type);
+        builder.proc_root_set.insert(*auth_procedure.mast_root());
+        builder.procedures.push(auth_procedure);
+        builder.storage_offset = auth_procedure
+            .storage_offset()
+            .checked_add(auth_procedure.storage_size())
+            .expect("account procedure info constructor should have validated offset and size");
+        let new_procedures_offset = builder.add_component(&new_procedures)?;
+        let procedures = builder.build()?;
+
+        if procedures.first().map(|procedure| *procedure.mast_root()) != Some(auth_root)
+            || merged_mast_forest.find_procedure_root(auth_root).is_none()
+        {
+            return Err(AccountError::AccountCodeMissingAuthProcedure(auth_root));
+        }
+
+        let component_layout = ComponentLayout::new(vec![
+            (auth_procedure.storage_offset(), vec![*auth_procedure.mast_root()]),
+            (
+                new_procedures_offset,
+                new_procedures
+                    .get_procedures()
+                    .into_iter()
+                    .map(|(proc_root, _)| proc_root)
+                    .collect(),
+            ),
+        ]);
+
+        Ok(Self {
+            commitment: build_procedure_commitment(&procedures),
+            procedures,
+            mast: Arc::new(merged_mast_forest),
+            component_layout: Some(Arc::new(component_layout)),
+        })
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -166,11 +291,80 @@ impl AccountCode {
         self.procedures.len()
     }
 
+    /// Returns size metrics of this account code, see [`AccountCodeMetrics`].
+    ///
+    /// The per-component breakdown is only available if the account code has a
+    /// [`ComponentLayout`].
+    pub fn metrics(&self) -> AccountCodeMetrics {
+        let components = self.component_layout().map(|layout| {
+            layout
+                .iter()
+                .map(|procedures| ComponentMetrics::new(procedures.len()))
+                .collect()
+        });
+
+        AccountCodeMetrics::new(
+            self.num_procedures(),
+            self.mast.num_nodes() as usize,
+            self.get_size_hint(),
+            components,
+        )
+    }
+
+    /// Returns the [`ComponentLayout`] of this account code, or `None` if the code was not built
+    /// from components.
+    pub fn component_layout(&self) -> Option<&ComponentLayout> {
+        self.component_layout.as_deref()
+    }
+
+    /// Returns the number of components this account code was built from, or `None` if the
+    /// component layout is not known.
+    pub fn component_count(&self) -> Option<usize> {
+        self.component_layout().map(ComponentLayout::num_components)
+    }
+
+    /// Returns the MAST roots of the procedures of the component at the provided index, or `None`
+    /// if the component layout is not known or the index is out of bounds.
+    pub fn procedures_of_component(&self, component_idx: usize) -> Option<&[Word]> {
+        self.component_layout()?.procedures_of_component(component_idx)
+    }
+
+    /// Returns the index of the storage slot at which the storage of the component at the provided
+    /// index starts, or `None` if the component layout is not known or the index is out of bounds.
+    pub fn storage_offset_of_component(&self, component_idx: usize) -> Option<u8> {
+        self.component_layout()?.storage_offset_of_component(component_idx)
+    }
+
+    /// Returns the highest index of a storage slot accessible by the procedures of this account
+    /// code, or `None` if no procedure accesses storage.
+    ///
+    /// The index is the maximum of `storage_offset + storage_size - 1` over all procedures with a
+    /// non-zero storage size.
+    pub fn max_storage_slot_used(&self) -> Option<u8> {
+        self.procedures
+            .iter()
+            .filter(|procedure| procedure.storage_size() > 0)
+            .map(|procedure| procedure.storage_offset() + (procedure.storage_size() - 1))
+            .max()
+    }
+
     /// Returns true if a procedure with the specified MAST root is defined in this account code.
     pub fn has_procedure(&self, mast_root: Word) -> bool {
         self.procedures.iter().any(|procedure| procedure.mast_root() == &mast_root)
     }
 
+    /// Returns true if a procedure with the specified MAST root is defined in this account code.
+    ///
+    /// This is equivalent to [`AccountCode::has_procedure`], but takes the root by reference.
+    pub fn has_procedure_by_root(&self, root: &Word) -> bool {
+        self.has_procedure(*root)
+    }
+
+    /// Returns true if this account code defines a procedure at the specified index.
+    pub fn has_procedure_at_index(&self, index: usize) -> bool {
+        index < self.procedures.len()
+    }
+
     /// Returns information about the procedure at the specified index.
     ///
     /// # Panics
@@ -257,13 +451,26 @@ impl Eq for AccountCode {}
 // SERIALIZATION
 // ================================================================================================
 
+/// The marker preceding account code which is serialized with a [`ComponentLayout`].
+///
+/// Account code without a layout is serialized in the format used before the layout was
+/// introduced, which starts with the magic bytes of the serialized [`MastForest`]. The marker is
+/// chosen such that it can never be confused with the first of these bytes.
+const COMPONENT_LAYOUT_MARKER: u8 = 0xff;
+
 impl Serializable for AccountCode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        if self.component_layout.is_some() {
+            target.write_u8(COMPONENT_LAYOUT_MARKER);
+        }
         self.mast.write_into(target);
         // since the number of procedures is guaranteed to be between 1 and 256, we can store the
         // number as a single byte - but we do have to subtract 1 to store 256 as 255.
         target.write_u8((self.procedures.len() - 1) as u8);
         target.write_many(self.procedures());
+        if let Some(component_layout) = self.component_layout() {
+            component_layout.write_into(target);
+        }
     }
 
     fn get_size_hint(&self) -> usize {
@@ -279,17 +486,29 @@ impl Serializable for AccountCode {
             size += procedure.get_size_hint();
         }
 
-        size
+        match self.component_layout() {
+            Some(component_layout) => size + u8_size + component_layout.get_size_hint(),
+            None => size,
+        }
     }
 }
 
 impl Deserializable for AccountCode {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let has_component_layout = source.peek_u8()? == COMPONENT_LAYOUT_MARKER;
+        if has_component_layout {
+            source.read_u8()?;
+        }
+
         let module = Arc::new(MastForest::read_from(source)?);
         let num_procedures = (source.read_u8()? as usize) + 1;
         let procedures = source.read_many::<AccountProcedureInfo>(num_procedures)?;
+        let mut code = Self::from_parts(module, procedures);
+        if has_component_layout {
+            code.component_layout = Some(Arc::new(source.read()?));
+        }
 
-        Ok(Self::from_parts(module, procedures))
+        Ok(code)
     }
 }
 
@@ -347,11 +566,13 @@ impl ProcedureInfoBuilder {
         }
     }
 
-    fn add_auth_component(&mut self, component: &AccountComponent) -> Result<(), AccountError> {
+    /// Adds the procedures of the auth component and returns the storage offset of its storage.
+    fn add_auth_component(&mut self, component: &AccountComponent) -> Result<u8, AccountError> {
+        let storage_offset = self.storage_offset;
         let mut auth_proc_count = 0;
 
         for (proc_root, is_auth) in component.get_procedures() {
-            self.add_procedure(proc_root, component.storage_size())?;
+            self.add_procedure(proc_root, component.storage_slot_count())?;
             if is_auth {
                 let auth_proc_idx = self.procedures.len() - 1;
                 self.procedures.swap(0, auth_proc_idx);
@@ -365,26 +586,28 @@ impl ProcedureInfoBuilder {
             return Err(AccountError::AccountComponentMultipleAuthProcedures);
         }
 
-        self.storage_offset = self.storage_offset.checked_add(component.storage_size()).expect(
+        self.storage_offset = self.storage_offset.checked_add(component.storage_slot_count()).expect(
             "account procedure info constructor should return an error if the addition overflows",
         );
 
-        Ok(())
+        Ok(storage_offset)
     }
 
-    fn add_component(&mut self, component: &AccountComponent) -> Result<(), AccountError> {
+    /// Adds the procedures of a non-auth component and returns the storage offset of its storage.
+    fn add_component(&mut self, component: &AccountComponent) -> Result<u8, AccountError> {
+        let storage_offset = self.storage_offset;
         for (proc_mast_root, is_auth) in component.get_procedures() {
             if is_auth {
                 return Err(AccountError::AccountCodeMultipleAuthComponents);
             }
-            self.add_procedure(proc_mast_root, component.storage_size())?;
+            self.add_procedure(proc_mast_root, component.storage_slot_count())?;
         }
 
-        self.storage_offset = self.storage_offset.checked_add(component.storage_size()).expect(
+        self.storage_offset = self.storage_offset.checked_add(component.storage_slot_count()).expect(
             "account procedure info constructor should return an error if the addition overflows",
         );
 
-        Ok(())
+        Ok(storage_offset)
     }
 
     fn add_procedure(
@@ -431,6 +654,67 @@ impl ProcedureInfoBuilder {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns a new [`MastForest`] which only contains the procedures of `mast_forest` with the
+/// provided MAST roots, together with all nodes, decorators and error messages these procedures
+/// depend on.
+///
+/// # Errors
+///
+/// Returns an error if `mast_forest` does not contain one of the procedures or a node could not be
+/// added to the new forest.
+fn retain_procedures(
+    mast_forest: &MastForest,
+    procedure_roots: &[Word],
+) -> Result<MastForest, AccountError> {
+    let mut retained = MastForest::new();
+    *retained.advice_map_mut() = mast_forest.advice_map().clone();
+
+    // Decorators are copied as a whole, so that the decorator IDs of the copied nodes remain valid.
+    // Unused decorators are removed when the forest is merged.
+    for decorator in mast_forest.decorators() {
+        retained
+            .add_decorator(decorator.clone())
+            .map_err(AccountError::AccountComponentMastForestMergeError)?;
+    }
+
+    let mut remapping = Remapping::new();
+    for procedure_root in procedure_roots {
+        let root_id = mast_forest
+            .find_procedure_root(*procedure_root)
+            .ok_or(AccountError::AccountCodeMissingAuthProcedure(*procedure_root))?;
+
+        // The subtree is iterated in post-order, so the children of a node are always added
+        // before the node itself.
+        for node_id in SubtreeIterator::new(&root_id, mast_forest) {
+            if remapping.contains_key(&node_id) {
+                continue;
+            }
+
+            let node = mast_forest[node_id].remap_children(&remapping);
+            if let MastNode::Block(block) = &node {
+                for operation in block.operations() {
+                    if let Operation::Assert(err_code)
+                    | Operation::MpVerify(err_code)
+                    | Operation::U32assert2(err_code) = operation
+                        && let Some(msg) = mast_forest.resolve_error_message(*err_code)
+                    {
+                        retained.register_error(msg);
+                    }
+                }
+            }
+
+            let new_node_id = retained
+                .add_node(node)
+                .map_err(AccountError::AccountComponentMastForestMergeError)?;
+            remapping.insert(node_id, new_node_id);
+        }
+
+        retained.make_root(remapping[&root_id]);
+    }
+
+    Ok(retained)
+}
+
 /// Computes the commitment to the given procedures
 pub(crate) fn build_procedure_commitment(procedures: &[AccountProcedureInfo]) -> Word {
     let elements = procedures_as_elements(procedures);
@@ -447,16 +731,30 @@ pub(crate) fn procedures_as_elements(procedures: &[AccountProcedureInfo]) -> Vec
 
 #[cfg(test)]
 mod tests {
+    use alloc::collections::BTreeSet;
+    use alloc::vec::Vec;
 
     use assert_matches::assert_matches;
     use miden_assembly::Assembler;
-    use miden_core::Word;
+    use miden_core::{Felt, Word};
 
-    use super::{AccountCode, Deserializable, Serializable};
+    use super::{AccountCode, ByteWriter, Deserializable, Serializable};
     use crate::AccountError;
     use crate::account::code::build_procedure_commitment;
-    use crate::account::{AccountComponent, AccountType, StorageSlot};
+    use crate::account::{
+        Account,
+        AccountComponent,
+        AccountFile,
+        AccountId,
+        AccountStorage,
+        AccountType,
+        AuthSecretKey,
+        StorageSlot,
+    };
+    use crate::asset::AssetVault;
+    use crate::crypto::dsa::rpo_falcon512::SecretKey;
     use crate::testing::account_code::CODE;
+    use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
     use crate::testing::noop_auth_component::NoopAuthComponent;
 
     #[test]
@@ -474,6 +772,20 @@ mod tests {
         assert_eq!(procedure_root, code.commitment())
     }
 
+    #[test]
+    fn test_account_code_has_procedure() {
+        let code = AccountCode::mock();
+        let num_procedures = code.num_procedures();
+
+        for (index, procedure) in code.procedures().iter().enumerate() {
+            assert!(code.has_procedure_by_root(procedure.mast_root()));
+            assert!(code.has_procedure_at_index(index));
+        }
+
+        assert!(!code.has_procedure_by_root(&Word::from([1, 2, 3, 4u32])));
+        assert!(!code.has_procedure_at_index(num_procedures));
+    }
+
     #[test]
     fn test_account_code_procedure_offset_out_of_bounds() {
         let code1 = "export.foo add end";
@@ -511,6 +823,200 @@ mod tests {
         assert_matches!(err, AccountError::StorageOffsetPlusSizeOutOfBounds(256))
     }
 
+    #[test]
+    fn test_account_code_component_layout() {
+        let library1 = Assembler::default()
+            .assemble_library(["export.foo add end export.baz mul end"])
+            .unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar sub end"]).unwrap();
+
+        let auth_component: AccountComponent = NoopAuthComponent.into();
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![]).unwrap().with_supports_all_types();
+
+        let code = AccountCode::from_components(
+            &[auth_component.clone(), component1.clone(), component2.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+
+        assert_eq!(code.component_count(), Some(3));
+        assert_eq!(code.procedures_of_component(3), None);
+
+        let layout = code.component_layout().unwrap();
+        for (component_idx, component) in
+            [auth_component, component1, component2].iter().enumerate()
+        {
+            let expected_roots: Vec<Word> =
+                component.get_procedures().into_iter().map(|(root, _)| root).collect();
+            assert_eq!(code.procedures_of_component(component_idx).unwrap(), expected_roots);
+
+            for proc_root in expected_roots {
+                assert_eq!(layout.component_of_procedure(proc_root), Some(component_idx));
+            }
+        }
+
+        // The layout should survive a serialization roundtrip.
+        let deserialized = AccountCode::read_from_bytes(&code.to_bytes()).unwrap();
+        assert_eq!(deserialized.component_layout(), Some(layout));
+    }
+
+    #[test]
+    fn test_account_code_max_storage_slot_used() {
+        let library1 = Assembler::default().assemble_library(["export.foo add end"]).unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar sub end"]).unwrap();
+
+        let auth_component: AccountComponent = NoopAuthComponent.into();
+        let component1 = AccountComponent::new(library1, vec![]).unwrap().with_supports_all_types();
+        let component2 =
+            AccountComponent::new(library2, vec![StorageSlot::Value(Word::empty()); 3])
+                .unwrap()
+                .with_supports_all_types();
+
+        let code = AccountCode::from_components(
+            &[auth_component.clone(), component1.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        assert_eq!(code.max_storage_slot_used(), None);
+
+        // The slots of component 2 start at offset 0 since the other components have no storage.
+        let code = AccountCode::from_components(
+            &[auth_component, component1, component2],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        assert_eq!(code.max_storage_slot_used(), Some(2));
+    }
+
+    #[test]
+    fn test_account_code_merge_updatable() {
+        let library1 = Assembler::default()
+            .assemble_library(["export.foo add end export.baz mul end"])
+            .unwrap();
+        let library2 = Assembler::default().assemble_library(["export.bar sub end"]).unwrap();
+
+        let auth_component: AccountComponent = NoopAuthComponent.into();
+        let component1 =
+            AccountComponent::new(library1, vec![StorageSlot::Value(Word::empty()); 2])
+                .unwrap()
+                .with_supports_all_types();
+        let component2 = AccountComponent::new(library2, vec![StorageSlot::Value(Word::empty())])
+            .unwrap()
+            .with_supports_all_types();
+
+        let base = AccountCode::from_components(
+            &[auth_component.clone(), component1.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        let auth_procedure = *base.get_procedure_by_index(0);
+
+        let merged = AccountCode::merge_updatable(&base, component2.clone()).unwrap();
+
+        // The merged code commits to the same procedures as code built from the auth component and
+        // the new component.
+        let expected = AccountCode::from_components(
+            &[auth_component.clone(), component2.clone()],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        assert_eq!(merged.commitment(), expected.commitment());
+        assert_eq!(*merged.get_procedure_by_index(0), auth_procedure);
+        assert_eq!(merged.component_count(), Some(2));
+        for (proc_root, _) in component1.get_procedures() {
+            assert!(!merged.has_procedure(proc_root));
+            assert!(merged.mast().find_procedure_root(proc_root).is_none());
+        }
+        // The replaced procedures are not part of the MAST forest, so it is not larger than the
+        // forest of code built from the auth component and the new component.
+        assert!(merged.mast().num_nodes() <= expected.mast().num_nodes());
+        assert_eq!(
+            merged.mast().procedure_digests().collect::<BTreeSet<_>>(),
+            expected.mast().procedure_digests().collect::<BTreeSet<_>>()
+        );
+        for (proc_root, _) in component2.get_procedures() {
+            assert!(merged.has_procedure(proc_root));
+            assert!(merged.mast().find_procedure_root(proc_root).is_some());
+        }
+
+        // The new procedures must not contain an auth procedure.
+        let err = AccountCode::merge_updatable(&base, auth_component.clone()).unwrap_err();
+        assert_matches!(err, AccountError::AccountCodeMultipleAuthComponents);
+
+        // The new procedures must support accounts with updatable code.
+        let component3 = AccountComponent::new(
+            Assembler::default().assemble_library(["export.qux div end"]).unwrap(),
+            vec![],
+        )
+        .unwrap()
+        .with_supported_type(AccountType::RegularAccountImmutableCode);
+        let err = AccountCode::merge_updatable(&base, component3).unwrap_err();
+        assert_matches!(err, AccountError::UnsupportedComponentForAccountType { .. });
+    }
+
+    #[test]
+    fn test_account_code_deserialize_without_component_layout() {
+        let code = AccountCode::mock();
+
+        // Serialize the code in the format used before the component layout was introduced.
+        let mut bytes = Vec::new();
+        code.mast().write_into(&mut bytes);
+        bytes.push((code.num_procedures() - 1) as u8);
+        bytes.write_many(code.procedures());
+
+        let deserialized = AccountCode::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, code);
+        assert!(deserialized.component_layout().is_none());
+        assert!(deserialized.component_count().is_none());
+
+        // Account code without a layout is still serialized in that format.
+        assert_eq!(deserialized.to_bytes(), bytes);
+    }
+
+    #[test]
+    fn test_account_serde_with_and_without_component_layout() {
+        let code_with_layout = AccountCode::mock();
+        assert!(code_with_layout.component_layout().is_some());
+        let code_without_layout = AccountCode::from_parts(
+            code_with_layout.mast(),
+            code_with_layout.procedures().to_vec(),
+        );
+
+        // The layout is not part of the commitment, so it does not affect equality.
+        assert_eq!(code_with_layout, code_without_layout);
+
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        for code in [code_with_layout, code_without_layout] {
+            assert_eq!(code.get_size_hint(), code.to_bytes().len());
+
+            let account = Account::new_existing(
+                account_id,
+                AssetVault::default(),
+                AccountStorage::mock(),
+                code.clone(),
+                Felt::new(1),
+            );
+
+            // Account code is followed by more data in both the account and the account file, so
+            // the layout must be read back correctly regardless of whether it is present.
+            let deserialized = Account::read_from_bytes(&account.to_bytes()).unwrap();
+            assert_eq!(deserialized, account);
+            assert_eq!(deserialized.code().component_layout(), code.component_layout());
+
+            let account_file =
+                AccountFile::new(account, vec![AuthSecretKey::RpoFalcon512(SecretKey::new())]);
+            let deserialized = AccountFile::read_from_bytes(&account_file.to_bytes()).unwrap();
+            assert_eq!(deserialized.account, account_file.account);
+            assert_eq!(deserialized.account.code().component_layout(), code.component_layout());
+            assert_eq!(
+                deserialized.auth_secret_keys.to_bytes(),
+                account_file.auth_secret_keys.to_bytes()
+            );
+        }
+    }
+
     #[test]
     fn test_account_code_only_auth_component() {
         let err = AccountCode::from_components(
@@ -1,5 +1,7 @@
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::fmt;
 
 use miden_assembly::ast::QualifiedProcedureName;
 use miden_assembly::{Assembler, Library, Parse};
@@ -10,7 +12,8 @@ use miden_processor::MastForest;
 mod template;
 pub use template::*;
 
-use crate::account::{AccountType, StorageSlot};
+use crate::account::{AccountType, SlotName, StorageSlot};
+use crate::errors::AccountComponentTemplateError;
 use crate::{AccountError, Word};
 
 // IMPLEMENTATIONS
@@ -63,11 +66,17 @@ impl TryFrom<Package> for AccountComponentTemplate {
 /// would only specify support for [`AccountType::FungibleFaucet`]. Using it to instantiate a
 /// regular account would fail. By default, the set of supported types is empty, so each component
 /// is forced to explicitly define what it supports.
+///
+/// Components can declare [`ComponentDependency`]s on other components, e.g. because they call
+/// into their procedures. The [`AccountBuilder`](crate::account::AccountBuilder) places every
+/// component after the components it depends on.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AccountComponent {
     pub(super) library: Library,
     pub(super) storage_slots: Vec<StorageSlot>,
     pub(super) supported_types: BTreeSet<AccountType>,
+    pub(super) slot_names: BTreeMap<SlotName, u8>,
+    pub(super) dependencies: Vec<ComponentDependency>,
 }
 
 impl AccountComponent {
@@ -97,6 +106,8 @@ impl AccountComponent {
             library: code,
             storage_slots,
             supported_types: BTreeSet::new(),
+            slot_names: BTreeMap::new(),
+            dependencies: Vec::new(),
         })
     }
 
@@ -128,6 +139,11 @@ impl AccountComponent {
     /// The template's component metadata might contain placeholders, which can be replaced by
     /// mapping storage placeholders to values through the `init_storage_data` parameter.
     ///
+    /// The names of the storage entries are registered as the component's slot names, with the
+    /// `.` separators of the entry names replaced by `::` (e.g. `token_metadata.max_supply`
+    /// becomes `token_metadata::max_supply`). Multi-slot entries are named after their first
+    /// slot. Entries whose names do not form a valid [`SlotName`] cannot be accessed by name.
+    ///
     /// # Errors
     ///
     /// - If any of the component's storage entries cannot be transformed into a valid storage slot.
@@ -138,15 +154,26 @@ impl AccountComponent {
         init_storage_data: &InitStorageData,
     ) -> Result<AccountComponent, AccountError> {
         let mut storage_slots = vec![];
+        let mut slot_names = BTreeMap::new();
         for storage_entry in template.metadata().storage_entries() {
             let entry_storage_slots = storage_entry
                 .try_build_storage_slots(init_storage_data)
                 .map_err(AccountError::AccountComponentTemplateInstantiationError)?;
             storage_slots.extend(entry_storage_slots);
+
+            if let Some(slot_name) = storage_entry
+                .name()
+                .and_then(|name| SlotName::new(name.as_str().replace('.', "::")).ok())
+            {
+                slot_names.insert(slot_name, storage_entry.slot_indices().start);
+            }
         }
 
-        Ok(AccountComponent::new(template.library().clone(), storage_slots)?
-            .with_supported_types(template.metadata().supported_types().clone()))
+        let mut component = AccountComponent::new(template.library().clone(), storage_slots)?
+            .with_supported_types(template.metadata().supported_types().clone());
+        component.slot_names = slot_names;
+
+        Ok(component)
     }
 
     /// Creates an [`AccountComponent`] from a [`Package`] using [`InitStorageData`].
@@ -174,11 +201,60 @@ impl AccountComponent {
         Self::from_template(&template, init_storage_data)
     }
 
+    /// Sets the initial values of the component's value slots to the values provided by
+    /// `init_storage_data` and returns the resulting component.
+    ///
+    /// Each entry of `init_storage_data` identifies a slot either by its index within the
+    /// component's storage (e.g. `0`) or by one of the component's slot names, with `.`
+    /// separators in place of `::` (e.g. `token_metadata.max_supply` identifies the slot named
+    /// `token_metadata::max_supply`). The values are parsed as hexadecimal [`Word`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - An entry's index is out of bounds of the component's storage slots.
+    /// - An entry's name is not a slot name of the component.
+    /// - Multiple entries identify the same slot.
+    /// - An entry identifies a slot which is not of type value.
+    /// - An entry's value cannot be parsed as a [`Word`].
+    pub fn with_init_storage(
+        mut self,
+        init_storage_data: InitStorageData,
+    ) -> Result<Self, AccountError> {
+        let mut initialized_slots = BTreeSet::new();
+        for (entry_name, value) in init_storage_data.placeholders() {
+            let index = self.init_storage_slot_index(entry_name)?;
+            if !initialized_slots.insert(index) {
+                return Err(AccountError::AccountComponentTemplateInstantiationError(
+                    AccountComponentTemplateError::DuplicateSlot(index),
+                ));
+            }
+
+            let slot = &mut self.storage_slots[index as usize];
+            if !matches!(slot, StorageSlot::Value(_)) {
+                return Err(AccountError::StorageSlotNotValue(index));
+            }
+
+            let value = TEMPLATE_REGISTRY
+                .try_parse_word(&TemplateType::native_word(), value)
+                .map_err(|err| {
+                    AccountError::AccountComponentTemplateInstantiationError(
+                        AccountComponentTemplateError::StorageValueParsingError(err),
+                    )
+                })?;
+            *slot = StorageSlot::Value(value);
+        }
+
+        Ok(self)
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns the number of storage slots accessible from this component.
-    pub fn storage_size(&self) -> u8 {
+    /// Returns the number of storage slots of this component.
+    ///
+    /// Since a component can have at most 255 storage slots, the count always fits into a `u8`.
+    pub fn storage_slot_count(&self) -> u8 {
         u8::try_from(self.storage_slots.len())
             .expect("storage slots len should fit in u8 per the constructor")
     }
@@ -198,6 +274,12 @@ impl AccountComponent {
         self.storage_slots.as_slice()
     }
 
+    /// Returns the names of the storage slots of this component, mapped to their indices within the
+    /// component's storage.
+    pub fn slot_names(&self) -> &BTreeMap<SlotName, u8> {
+        &self.slot_names
+    }
+
     /// Returns a reference to the supported [`AccountType`]s.
     pub fn supported_types(&self) -> &BTreeSet<AccountType> {
         &self.supported_types
@@ -208,6 +290,12 @@ impl AccountComponent {
         self.supported_types.contains(&account_type)
     }
 
+    /// Returns the components this component depends on, in the order in which they were
+    /// declared.
+    pub fn dependencies(&self) -> &[ComponentDependency] {
+        &self.dependencies
+    }
+
     /// Returns a vector of tuples (digest, is_auth) for all procedures in this component.
     pub(crate) fn get_procedures(&self) -> Vec<(Word, bool)> {
         let mut procedures = Vec::new();
@@ -229,6 +317,33 @@ impl AccountComponent {
         self.library.get_procedure_root_by_name(proc_name)
     }
 
+    /// Returns the index of the storage slot identified by the provided init storage entry name,
+    /// which is either the index of the slot or its slot name with `.` separators.
+    fn init_storage_slot_index(&self, entry_name: &StorageValueName) -> Result<u8, AccountError> {
+        if let Ok(index) = entry_name.as_str().parse::<u8>() {
+            if index >= self.storage_slot_count() {
+                return Err(AccountError::StorageIndexOutOfBounds {
+                    slots_len: self.storage_slot_count(),
+                    index,
+                });
+            }
+
+            return Ok(index);
+        }
+
+        let slot_name = SlotName::new(entry_name.as_str().replace('.', "::")).map_err(|err| {
+            AccountError::other_with_source(
+                format!("init storage entry name `{entry_name}` is not a valid slot name"),
+                err,
+            )
+        })?;
+
+        self.slot_names
+            .get(&slot_name)
+            .copied()
+            .ok_or(AccountError::StorageSlotNameNotFound(slot_name))
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -260,6 +375,19 @@ impl AccountComponent {
         ]);
         self
     }
+
+    /// Declares that this component depends on the component identified by `dependency`.
+    ///
+    /// The dependency is identified either by the digest of the component's [`Library`] (given as
+    /// a [`Word`]) or by the path of one of the library's modules (given as a string). Declaring
+    /// the same dependency multiple times has no additional effect.
+    pub fn with_dependency(mut self, dependency: impl Into<ComponentDependency>) -> Self {
+        let dependency = dependency.into();
+        if !self.dependencies.contains(&dependency) {
+            self.dependencies.push(dependency);
+        }
+        self
+    }
 }
 
 impl From<AccountComponent> for Library {
@@ -268,18 +396,77 @@ impl From<AccountComponent> for Library {
     }
 }
 
+// COMPONENT DEPENDENCY
+// ================================================================================================
+
+/// Identifies an [`AccountComponent`] another component depends on, see
+/// [`AccountComponent::with_dependency`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComponentDependency {
+    /// The component whose library has the given digest.
+    LibraryDigest(Word),
+    /// The component whose library contains a module with the given path, e.g.
+    /// `miden::contracts::wallets::basic`.
+    ModulePath(String),
+}
+
+impl ComponentDependency {
+    /// Returns `true` if the provided component is identified by this dependency, `false`
+    /// otherwise.
+    pub fn matches(&self, component: &AccountComponent) -> bool {
+        match self {
+            ComponentDependency::LibraryDigest(digest) => component.library.digest() == digest,
+            ComponentDependency::ModulePath(path) => component
+                .library
+                .module_infos()
+                .any(|module| module.path().to_string() == *path),
+        }
+    }
+}
+
+impl From<Word> for ComponentDependency {
+    fn from(library_digest: Word) -> Self {
+        ComponentDependency::LibraryDigest(library_digest)
+    }
+}
+
+impl From<&str> for ComponentDependency {
+    fn from(module_path: &str) -> Self {
+        ComponentDependency::ModulePath(module_path.to_string())
+    }
+}
+
+impl From<String> for ComponentDependency {
+    fn from(module_path: String) -> Self {
+        ComponentDependency::ModulePath(module_path)
+    }
+}
+
+impl fmt::Display for ComponentDependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ComponentDependency::LibraryDigest(digest) => {
+                write!(f, "component with library digest {digest}")
+            },
+            ComponentDependency::ModulePath(path) => write!(f, "component with module {path}"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::collections::BTreeSet;
-    use alloc::string::ToString;
+    use alloc::string::{String, ToString};
     use alloc::sync::Arc;
 
+    use assert_matches::assert_matches;
     use miden_assembly::Assembler;
     use miden_core::utils::Serializable;
     use miden_mast_package::{MastArtifact, Package, PackageManifest, Section};
     use semver::Version;
 
     use super::*;
+    use crate::account::StorageMap;
     use crate::testing::account_code::CODE;
 
     #[test]
@@ -374,7 +561,7 @@ mod tests {
             AccountComponent::from_package_with_init_data(&package, &init_data).unwrap();
 
         // Verify the component was created correctly
-        assert_eq!(component.storage_size(), 0);
+        assert_eq!(component.storage_slot_count(), 0);
         assert!(component.supports_type(AccountType::RegularAccountImmutableCode));
         assert!(component.supports_type(AccountType::RegularAccountUpdatableCode));
         assert!(!component.supports_type(AccountType::FungibleFaucet));
@@ -395,4 +582,73 @@ mod tests {
         let error_msg = result.unwrap_err().to_string();
         assert!(error_msg.contains("package does not contain account component metadata"));
     }
+
+    #[test]
+    fn test_with_init_storage() {
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let mut component = AccountComponent::new(
+            library,
+            vec![
+                StorageSlot::Value(Word::empty()),
+                StorageSlot::Value(Word::empty()),
+                StorageSlot::Map(StorageMap::new()),
+            ],
+        )
+        .unwrap();
+        component.slot_names.insert(SlotName::new("test::slot").unwrap(), 1);
+
+        let first_value = Word::from([1, 2, 3, 4u32]);
+        let second_value = Word::from([5, 6, 7, 8u32]);
+        let init_storage_data = |entries: &[(&str, String)]| {
+            InitStorageData::new(
+                entries
+                    .iter()
+                    .map(|(name, value)| (StorageValueName::new(*name).unwrap(), value.clone())),
+            )
+        };
+
+        let initialized = component
+            .clone()
+            .with_init_storage(init_storage_data(&[
+                ("0", first_value.to_hex()),
+                ("test.slot", second_value.to_hex()),
+            ]))
+            .unwrap();
+        assert_eq!(initialized.storage_slots()[0], StorageSlot::Value(first_value));
+        assert_eq!(initialized.storage_slots()[1], StorageSlot::Value(second_value));
+
+        assert_matches!(
+            component
+                .clone()
+                .with_init_storage(init_storage_data(&[("3", first_value.to_hex())])),
+            Err(AccountError::StorageIndexOutOfBounds { slots_len: 3, index: 3 })
+        );
+        assert_matches!(
+            component.clone().with_init_storage(init_storage_data(&[
+                ("1", first_value.to_hex()),
+                ("test.slot", second_value.to_hex()),
+            ])),
+            Err(AccountError::AccountComponentTemplateInstantiationError(
+                AccountComponentTemplateError::DuplicateSlot(1)
+            ))
+        );
+        assert_matches!(
+            component
+                .clone()
+                .with_init_storage(init_storage_data(&[("2", first_value.to_hex())])),
+            Err(AccountError::StorageSlotNotValue(2))
+        );
+        assert_matches!(
+            component
+                .clone()
+                .with_init_storage(init_storage_data(&[("test.unknown", first_value.to_hex())])),
+            Err(AccountError::StorageSlotNameNotFound(_))
+        );
+        assert_matches!(
+            component.with_init_storage(init_storage_data(&[("0", "not a word".to_string())])),
+            Err(AccountError::AccountComponentTemplateInstantiationError(
+                AccountComponentTemplateError::StorageValueParsingError(_)
+            ))
+        );
+    }
 }
@@ -9,6 +9,7 @@ use miden_processor::DeserializationError;
 use semver::Version;
 
 use super::AccountType;
+use crate::account::AccountStorage;
 use crate::errors::AccountComponentTemplateError;
 
 mod storage;
@@ -239,6 +240,43 @@ impl AccountComponentMetadata {
         &self.storage
     }
 
+    /// Extracts the [`InitStorageData`] of a component instantiated from this template from the
+    /// provided account storage, in which the component's storage starts at `offset`.
+    ///
+    /// The values of all placeholders are read from the current state of the component's storage
+    /// slots, so instantiating the template with the returned data again reproduces the current
+    /// storage of the component, e.g. to re-create an account on another network.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The storage does not contain all the storage slots of the component.
+    /// - The type of any storage slot does not match the storage entry of the template.
+    /// - A value fixed by the template does not match the value in storage.
+    /// - The key of any storage map entry is templated, or a storage map contains entries which are
+    ///   not defined by the template.
+    /// - The value in storage cannot be represented as a value of the placeholder's type.
+    pub fn extract_init_data(
+        &self,
+        storage: &AccountStorage,
+        offset: u8,
+    ) -> Result<InitStorageData, AccountComponentTemplateError> {
+        let mut placeholder_values = BTreeMap::new();
+        for entry in self.storage_entries() {
+            let slot_indices = entry.slot_indices();
+            let start = usize::from(offset) + usize::from(slot_indices.start);
+            let end = usize::from(offset) + usize::from(slot_indices.end);
+            let storage_slots = storage
+                .slots()
+                .get(start..end)
+                .ok_or(AccountComponentTemplateError::StorageSlotNotFound(end - 1))?;
+
+            entry.extract_placeholder_values(storage_slots, &mut placeholder_values)?;
+        }
+
+        Ok(InitStorageData::new(placeholder_values))
+    }
+
     /// Validate the [AccountComponentMetadata].
     ///
     /// # Errors
@@ -1,10 +1,16 @@
 use alloc::boxed::Box;
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::iter;
 
-use super::placeholder::{PlaceholderTypeRequirement, TEMPLATE_REGISTRY, TemplateType};
+use super::placeholder::{
+    PlaceholderTypeRequirement,
+    TEMPLATE_REGISTRY,
+    TemplateFelt,
+    TemplateType,
+    TemplateTypeError,
+};
 use super::{
     FieldIdentifier,
     InitStorageData,
@@ -14,6 +20,7 @@ use super::{
 };
 use crate::account::StorageMap;
 use crate::account::component::template::AccountComponentTemplateError;
+use crate::asset::TokenSymbol;
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use crate::{Felt, FieldElement, Word};
 
@@ -193,6 +200,50 @@ impl WordRepresentation {
         }
     }
 
+    /// Extracts the values of the word's placeholders from the provided `word` into
+    /// `placeholder_values`.
+    ///
+    /// This is the inverse of [`Self::try_build_word`], so building the word from the extracted
+    /// values results in the provided `word`.
+    pub(crate) fn extract_placeholder_values(
+        &self,
+        word: Word,
+        placeholder_prefix: StorageValueName,
+        placeholder_values: &mut BTreeMap<StorageValueName, String>,
+    ) -> Result<(), AccountComponentTemplateError> {
+        match self {
+            WordRepresentation::Template { identifier, r#type } => {
+                let placeholder_path = placeholder_prefix.with_suffix(&identifier.name);
+                let value = word.to_hex();
+
+                let parsed_value = TEMPLATE_REGISTRY
+                    .try_parse_word(r#type, &value)
+                    .map_err(AccountComponentTemplateError::StorageValueParsingError)?;
+                if parsed_value != word {
+                    return Err(AccountComponentTemplateError::PlaceholderValueNotExtractable(
+                        placeholder_path,
+                    ));
+                }
+
+                placeholder_values.insert(placeholder_path, value);
+            },
+            WordRepresentation::Value { value, identifier } => {
+                let placeholder = placeholder_prefix.with_suffix(
+                    identifier.as_ref().map(|id| &id.name).unwrap_or(&StorageValueName::empty()),
+                );
+                for (felt_repr, felt) in value.iter().zip(word) {
+                    felt_repr.extract_placeholder_value(
+                        felt,
+                        placeholder.clone(),
+                        placeholder_values,
+                    )?;
+                }
+            },
+        }
+
+        Ok(())
+    }
+
     /// Validates that the defined type exists and all the inner felt types exist as well
     pub(crate) fn validate(&self) -> Result<(), AccountComponentTemplateError> {
         // Check that type exists in registry
@@ -375,6 +426,56 @@ impl FeltRepresentation {
         }
     }
 
+    /// Extracts the value of the felt's placeholder from the provided `felt` into
+    /// `placeholder_values`.
+    ///
+    /// This is the inverse of [`Self::try_build_felt`], so building the felt from the extracted
+    /// value results in the provided `felt`.
+    pub(crate) fn extract_placeholder_value(
+        &self,
+        felt: Felt,
+        placeholder_prefix: StorageValueName,
+        placeholder_values: &mut BTreeMap<StorageValueName, String>,
+    ) -> Result<(), AccountComponentTemplateError> {
+        match self {
+            FeltRepresentation::Template { identifier, r#type } => {
+                let placeholder_key = placeholder_prefix.with_suffix(&identifier.name);
+                let value = if *r#type == TokenSymbol::type_name() {
+                    TokenSymbol::try_from(felt).and_then(|symbol| symbol.to_string()).map_err(
+                        |err| {
+                            AccountComponentTemplateError::StorageValueParsingError(
+                                TemplateTypeError::parse(felt.to_string(), r#type.clone(), err),
+                            )
+                        },
+                    )?
+                } else {
+                    felt.as_int().to_string()
+                };
+
+                let parsed_value = TEMPLATE_REGISTRY
+                    .try_parse_felt(r#type, &value)
+                    .map_err(AccountComponentTemplateError::StorageValueParsingError)?;
+                if parsed_value != felt {
+                    return Err(AccountComponentTemplateError::PlaceholderValueNotExtractable(
+                        placeholder_key,
+                    ));
+                }
+
+                placeholder_values.insert(placeholder_key, value);
+            },
+            FeltRepresentation::Value { value, .. } => {
+                if *value != felt {
+                    return Err(AccountComponentTemplateError::FixedStorageValueMismatch {
+                        expected: *value,
+                        actual: felt,
+                    });
+                }
+            },
+        }
+
+        Ok(())
+    }
+
     /// Returns an iterator over the felt's template.
     ///
     /// For [`FeltRepresentation::Value`], these is an empty set; for
@@ -555,6 +656,50 @@ impl MapRepresentation {
             .map_err(|err| AccountComponentTemplateError::StorageMapHasDuplicateKeys(Box::new(err)))
     }
 
+    /// Extracts the values of the map's placeholders from the provided `storage_map` into
+    /// `placeholder_values`.
+    ///
+    /// This is the inverse of [`Self::try_build_map`]. Since the entries of the map are looked up
+    /// by their keys, only the values of the map entries can be templated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The key of any map entry is templated.
+    /// - The storage map contains entries whose keys are not defined by the template.
+    /// - The value of any map entry cannot be extracted.
+    pub(crate) fn extract_placeholder_values(
+        &self,
+        storage_map: &StorageMap,
+        placeholder_values: &mut BTreeMap<StorageValueName, String>,
+    ) -> Result<(), AccountComponentTemplateError> {
+        let mut keys = BTreeSet::new();
+        for map_entry in self.entries() {
+            let key = map_entry
+                .key()
+                .try_build_word(&InitStorageData::default(), self.identifier.name.clone())
+                .map_err(|_| {
+                    AccountComponentTemplateError::MapKeyNotExtractable(
+                        self.identifier.name.clone(),
+                    )
+                })?;
+            map_entry.value().extract_placeholder_values(
+                storage_map.get(&key),
+                self.identifier.name.clone(),
+                placeholder_values,
+            )?;
+            keys.insert(key);
+        }
+
+        if storage_map.entries().any(|(key, _)| !keys.contains(key)) {
+            return Err(AccountComponentTemplateError::StorageMapHasUntemplatedEntries(
+                self.identifier.name.clone(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Validates map keys by checking for duplicates.
     ///
     /// Because keys can be represented in a variety of ways, the `to_string()` implementation is
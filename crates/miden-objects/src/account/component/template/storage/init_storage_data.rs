@@ -7,8 +7,9 @@ use super::StorageValueName;
 /// [AccountComponent](crate::account::AccountComponent) from a
 /// [template](crate::account::AccountComponentTemplate).
 ///
-/// An [`InitStorageData`] can be created from a TOML string when the `std` feature flag is set.
-#[derive(Clone, Debug, Default)]
+/// An [`InitStorageData`] can be created from and serialized into a TOML string when the `std`
+/// feature flag is set.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct InitStorageData {
     /// A mapping of storage placeholder names to their corresponding storage values.
     storage_placeholders: BTreeMap<StorageValueName, String>,
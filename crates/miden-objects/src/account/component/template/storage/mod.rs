@@ -1,4 +1,5 @@
 use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::ops::Range;
@@ -12,9 +13,10 @@ pub use entry_content::*;
 
 use super::AccountComponentTemplateError;
 use crate::Word;
-use crate::account::StorageSlot;
+use crate::account::{StorageSlot, StorageSlotType};
 
 mod placeholder;
+pub(crate) use placeholder::TEMPLATE_REGISTRY;
 pub use placeholder::{
     PlaceholderTypeRequirement,
     StorageValueName,
@@ -236,6 +238,65 @@ impl StorageEntry {
         }
     }
 
+    /// Extracts the values of the storage entry's placeholders from the provided storage slots
+    /// into `placeholder_values`.
+    ///
+    /// `storage_slots` are the slots the entry covers, as returned by [`Self::slot_indices`]. This
+    /// is the inverse of [`Self::try_build_storage_slots`], so building the storage slots from the
+    /// extracted values results in the provided `storage_slots`.
+    pub(crate) fn extract_placeholder_values(
+        &self,
+        storage_slots: &[StorageSlot],
+        placeholder_values: &mut BTreeMap<StorageValueName, String>,
+    ) -> Result<(), AccountComponentTemplateError> {
+        match self {
+            StorageEntry::Value { slot, word_entry } => match storage_slots {
+                [StorageSlot::Value(word)] => word_entry.extract_placeholder_values(
+                    *word,
+                    StorageValueName::empty(),
+                    placeholder_values,
+                ),
+                _ => Err(AccountComponentTemplateError::StorageSlotTypeMismatch {
+                    slot: *slot,
+                    expected: StorageSlotType::Value,
+                }),
+            },
+            StorageEntry::Map { slot, map } => match storage_slots {
+                [StorageSlot::Map(storage_map)] => {
+                    map.extract_placeholder_values(storage_map, placeholder_values)
+                },
+                _ => Err(AccountComponentTemplateError::StorageSlotTypeMismatch {
+                    slot: *slot,
+                    expected: StorageSlotType::Map,
+                }),
+            },
+            StorageEntry::MultiSlot { slots, word_entries } => match word_entries {
+                MultiWordRepresentation::Value { identifier, values } => {
+                    for ((slot, word_repr), storage_slot) in
+                        slots.clone().zip(values).zip(storage_slots)
+                    {
+                        let StorageSlot::Value(word) = storage_slot else {
+                            return Err(AccountComponentTemplateError::StorageSlotTypeMismatch {
+                                slot,
+                                expected: StorageSlotType::Value,
+                            });
+                        };
+
+                        for (felt_repr, felt) in word_repr.iter().zip(*word) {
+                            felt_repr.extract_placeholder_value(
+                                felt,
+                                identifier.name.clone(),
+                                placeholder_values,
+                            )?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            },
+        }
+    }
+
     /// Validates the storage entry for internal consistency.
     pub(super) fn validate(&self) -> Result<(), AccountComponentTemplateError> {
         match self {
@@ -394,6 +455,7 @@ mod tests {
     use crate::account::{
         AccountComponent,
         AccountComponentTemplate,
+        AccountStorage,
         AccountType,
         FeltRepresentation,
         StorageEntry,
@@ -812,4 +874,119 @@ mod tests {
             _ => panic!("expected multislot"),
         }
     }
+
+    #[test]
+    fn extract_init_data_roundtrip() {
+        let toml_text = r#"
+        name = "Test Component"
+        description = "This is a test component"
+        version = "1.0.1"
+        supported-types = ["FungibleFaucet"]
+
+        [[storage]]
+        name = "map_entry"
+        slot = 0
+        values = [
+            { key = "0x1", value = { name = "first", type = "word" } },
+            { key = "0x2", value = ["0x1", "0x2", { name = "second", type = "u32" }, "0"] },
+        ]
+
+        [[storage]]
+        name = "token_metadata"
+        slot = 1
+        value = [
+            { type = "felt", name = "max_supply" },
+            { type = "token_symbol", name = "symbol" },
+            { type = "u8", name = "decimals" },
+            { value = "0" },
+        ]
+
+        [[storage]]
+        name = "multi"
+        slots = [2, 3]
+        values = [
+            [{ type = "u16", name = "a" }, "1", "2", "3"],
+            ["4", "5", "6", { type = "felt", name = "b" }],
+        ]
+
+        [[storage]]
+        name = "owner"
+        slot = 4
+        type = "auth::rpo_falcon512::pub_key"
+        "#;
+
+        let metadata = AccountComponentMetadata::from_toml(toml_text).unwrap();
+        let library = Assembler::default().assemble_library([CODE]).unwrap();
+        let template = AccountComponentTemplate::new(metadata, library);
+
+        let init_data = InitStorageData::from_toml(
+            r#"
+            owner = "0x0000000000000000000000000000000000000000000000000000000000000123"
+            [map_entry]
+            first = "0x0000000000000000000000000000000000000000000000000000000000000abc"
+            second = "7"
+            [token_metadata]
+            max_supply = "1000"
+            symbol = "TST"
+            decimals = "8"
+            [multi]
+            a = "9"
+            b = "10"
+            "#,
+        )
+        .unwrap();
+        let component = AccountComponent::from_template(&template, &init_data).unwrap();
+        let mut storage = AccountStorage::new(component.storage_slots().to_vec()).unwrap();
+
+        // the extracted data of the unmodified storage matches the data used for instantiation,
+        // since words are extracted as full-length hex strings
+        let extracted = template.metadata().extract_init_data(&storage, 0).unwrap();
+        assert_eq!(extracted, init_data);
+
+        // mutate a map value, a value slot and a multi-slot value
+        storage.set_map_item(0, word!("0x1"), word!("0xdef")).unwrap();
+        let symbol = storage.get_item(1).unwrap()[1];
+        storage
+            .set_item(1, Word::from([Felt::new(5000), symbol, Felt::new(10), Felt::new(0)]))
+            .unwrap();
+        storage.set_item(3, Word::from([4u32, 5, 6, 42])).unwrap();
+
+        let extracted = template.metadata().extract_init_data(&storage, 0).unwrap();
+        assert_eq!(
+            extracted
+                .get(&StorageValueName::new("token_metadata.max_supply").unwrap())
+                .unwrap(),
+            "5000"
+        );
+        assert_eq!(
+            extracted.get(&StorageValueName::new("token_metadata.symbol").unwrap()).unwrap(),
+            "TST"
+        );
+        assert_eq!(extracted.get(&StorageValueName::new("multi.b").unwrap()).unwrap(), "42");
+
+        // the extracted data survives a TOML roundtrip
+        let toml = extracted.as_toml().unwrap();
+        assert_eq!(InitStorageData::from_toml(&toml).unwrap(), extracted);
+
+        // re-instantiating the template results in the same storage
+        let component = AccountComponent::from_template(&template, &extracted).unwrap();
+        let recreated_storage = AccountStorage::new(component.storage_slots().to_vec()).unwrap();
+        assert_eq!(recreated_storage.commitment(), storage.commitment());
+
+        // values fixed by the template cannot be extracted if they were changed
+        let mut modified_storage = storage.clone();
+        modified_storage.set_item(2, Word::from([9u32, 1, 2, 100])).unwrap();
+        assert_matches::assert_matches!(
+            template.metadata().extract_init_data(&modified_storage, 0),
+            Err(AccountComponentTemplateError::FixedStorageValueMismatch { .. })
+        );
+
+        // map entries which are not defined by the template cannot be extracted
+        let mut modified_storage = storage.clone();
+        modified_storage.set_map_item(0, word!("0x3"), word!("0x1")).unwrap();
+        assert_matches::assert_matches!(
+            template.metadata().extract_init_data(&modified_storage, 0),
+            Err(AccountComponentTemplateError::StorageMapHasUntemplatedEntries(_))
+        );
+    }
 }
@@ -431,6 +431,42 @@ impl InitStorageData {
         Ok(InitStorageData::new(placeholders))
     }
 
+    /// Serializes the [`InitStorageData`] into a TOML string.
+    ///
+    /// This is the inverse of [`Self::from_toml`]: dot-separated storage value names are turned
+    /// into nested tables, and all values are serialized as strings.
+    ///
+    /// # Errors
+    ///
+    /// - If a storage value name is also the prefix of another storage value name, since such a key
+    ///   cannot hold both a value and a nested table.
+    pub fn as_toml(&self) -> Result<String, InitStorageDataError> {
+        let mut root = toml::Table::new();
+        for (name, value) in self.placeholders() {
+            let mut segments: Vec<&str> = name.as_str().split('.').collect();
+            // SAFETY: splitting a string always yields at least one segment
+            let value_key = segments.pop().expect("name should have at least one segment");
+
+            let mut table = &mut root;
+            for segment in segments {
+                table = table
+                    .entry(segment)
+                    .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                    .as_table_mut()
+                    .ok_or_else(|| InitStorageDataError::ConflictingKeys(name.to_string()))?;
+            }
+
+            if table
+                .insert(value_key.to_string(), toml::Value::String(value.clone()))
+                .is_some()
+            {
+                return Err(InitStorageDataError::ConflictingKeys(name.to_string()));
+            }
+        }
+
+        Ok(toml::to_string(&root)?)
+    }
+
     /// Recursively flattens a TOML `Value` into a flat mapping.
     ///
     /// When recursing into nested tables, keys are combined using
@@ -476,6 +512,12 @@ pub enum InitStorageDataError {
     #[error("failed to parse TOML")]
     InvalidToml(#[from] toml::de::Error),
 
+    #[error("failed to serialize TOML")]
+    TomlSerialization(#[from] toml::ser::Error),
+
+    #[error("storage value name `{0}` conflicts with a nested storage value name")]
+    ConflictingKeys(String),
+
     #[error("empty table encountered for key `{0}`")]
     EmptyTable(String),
 
@@ -667,4 +709,26 @@ mod tests {
         assert_matches::assert_matches!(result, InitStorageDataError::InvalidToml(_));
         assert!(result.source().unwrap().to_string().contains("duplicate"));
     }
+
+    #[test]
+    fn as_toml_roundtrip() {
+        let storage = InitStorageData::new([
+            (StorageValueName::new("a.b").unwrap(), "0xb".to_string()),
+            (StorageValueName::new("a.c.d").unwrap(), "0xd".to_string()),
+            (StorageValueName::new("x").unwrap(), "42".to_string()),
+        ]);
+
+        let toml_str = storage.as_toml().unwrap();
+        assert_eq!(InitStorageData::from_toml(&toml_str).unwrap(), storage);
+
+        // a value name cannot be the prefix of another value name
+        let storage = InitStorageData::new([
+            (StorageValueName::new("a").unwrap(), "0xa".to_string()),
+            (StorageValueName::new("a.b").unwrap(), "0xb".to_string()),
+        ]);
+        assert_matches::assert_matches!(
+            storage.as_toml().unwrap_err(),
+            InitStorageDataError::ConflictingKeys(_)
+        );
+    }
 }
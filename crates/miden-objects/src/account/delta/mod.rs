@@ -1,21 +1,25 @@
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::string::ToString;
 use alloc::vec::Vec;
+use core::fmt;
 
 use crate::account::{
     Account,
     AccountCode,
     AccountId,
     AccountStorage,
+    StorageMap,
     StorageSlot,
     StorageSlotType,
 };
-use crate::asset::AssetVault;
+use crate::asset::{Asset, AssetVault, AssetVaultKey};
 use crate::crypto::SequentialCommit;
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use crate::{AccountDeltaError, AccountError, Felt, Word, ZERO};
 
 mod storage;
-pub use storage::{AccountStorageDelta, StorageMapDelta};
+pub use storage::{AccountStorageDelta, StorageMapDelta, StorageSlotTypeChange};
 
 mod vault;
 pub use vault::{
@@ -88,6 +92,27 @@ impl AccountDelta {
         })
     }
 
+    /// Returns the [`AccountDelta`] which transitions the provided initial state of an account to
+    /// the provided final state, computed from the two states directly rather than by executing a
+    /// transaction.
+    ///
+    /// If the initial state is a new account, the delta is a full state delta, analogous to the
+    /// delta the transaction kernel computes for new accounts. See [`Account::diff`] for the same
+    /// functionality as a method on the initial state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the IDs of the two states differ.
+    /// - the nonce of the final state is smaller than the nonce of the initial state.
+    /// - the code of the two states differs.
+    /// - the number of storage slots of the two states differs or a map slot of the initial state
+    ///   is a value slot in the final state.
+    /// - the states differ in their storage or vault, but not in their nonce.
+    pub fn from_accounts(initial: &Account, final_state: &Account) -> Result<Self, AccountError> {
+        compute_account_delta(initial, final_state)
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -175,6 +200,29 @@ impl AccountDelta {
         self.code.as_ref()
     }
 
+    /// Returns a summary of the changes contained in this delta.
+    ///
+    /// This is intended for logging and monitoring, see [`AccountDeltaStats`].
+    pub fn stats(&self) -> AccountDeltaStats {
+        // a slot which was converted into a map slot counts as a changed map slot, regardless of
+        // whether any entries were set in it
+        let changed_map_slots = self
+            .storage
+            .maps()
+            .keys()
+            .chain(self.storage.slot_type_changes().keys())
+            .collect::<BTreeSet<_>>()
+            .len();
+
+        AccountDeltaStats {
+            changed_value_slots: self.storage.values().len(),
+            changed_map_slots,
+            fungible_faucets_affected: self.vault.fungible().num_assets(),
+            non_fungible_deltas: self.vault.non_fungible().num_assets(),
+            nonce_delta: self.nonce_delta,
+        }
+    }
+
     /// Converts this storage delta into individual delta components.
     pub fn into_parts(self) -> (AccountStorageDelta, AccountVaultDelta, Option<AccountCode>, Felt) {
         (self.storage, self.vault, self.code, self.nonce_delta)
@@ -182,6 +230,12 @@ impl AccountDelta {
 
     /// Computes the commitment to the account delta.
     ///
+    /// A proven transaction commits to the commitment of the account delta before the fee was
+    /// removed from the vault, see
+    /// [`TxAccountUpdate::account_delta_commitment`](crate::transaction::TxAccountUpdate::account_delta_commitment).
+    /// To verify the delta of a public account update against it, the fee asset needs to be added
+    /// back to the vault delta before computing the commitment.
+    ///
     /// # Computation
     ///
     /// The delta is a sequential hash over a vector of field elements which starts out empty and
@@ -404,6 +458,42 @@ impl SequentialCommit for AccountDelta {
     }
 }
 
+// ACCOUNT DELTA STATS
+// ================================================================================================
+
+/// A summary of the changes contained in an [`AccountDelta`], as returned by
+/// [`AccountDelta::stats`].
+///
+/// The summary is meant for observability tooling, e.g. to log what a transaction did in a single
+/// line through its [`Display`](fmt::Display) implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountDeltaStats {
+    /// The number of value storage slots which were updated.
+    pub changed_value_slots: usize,
+    /// The number of map storage slots which were updated or created by a slot type change.
+    pub changed_map_slots: usize,
+    /// The number of faucets whose fungible assets were added to or removed from the vault.
+    pub fungible_faucets_affected: usize,
+    /// The number of non-fungible assets which were added to or removed from the vault.
+    pub non_fungible_deltas: usize,
+    /// The value by which the nonce was incremented.
+    pub nonce_delta: Felt,
+}
+
+impl fmt::Display for AccountDeltaStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value slots: {}, map slots: {}, fungible faucets: {}, non-fungible assets: {}, nonce delta: {}",
+            self.changed_value_slots,
+            self.changed_map_slots,
+            self.fungible_faucets_affected,
+            self.non_fungible_deltas,
+            self.nonce_delta
+        )
+    }
+}
+
 // ACCOUNT UPDATE DETAILS
 // ================================================================================================
 
@@ -425,7 +515,7 @@ pub enum AccountUpdateDetails {
     Private,
 
     /// The state update details of non-private accounts.
-    Delta(AccountDelta),
+    Delta(Box<AccountDelta>),
 }
 
 impl AccountUpdateDetails {
@@ -443,7 +533,7 @@ impl AccountUpdateDetails {
                 AccountUpdateDetails::Private
             },
             (AccountUpdateDetails::Delta(mut delta), AccountUpdateDetails::Delta(new_delta)) => {
-                delta.merge(new_delta)?;
+                delta.merge(*new_delta)?;
                 AccountUpdateDetails::Delta(delta)
             },
             (left, right) => {
@@ -536,7 +626,7 @@ impl Deserializable for AccountUpdateDetails {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         match u8::read_from(source)? {
             0 => Ok(Self::Private),
-            1 => Ok(Self::Delta(AccountDelta::read_from(source)?)),
+            1 => Ok(Self::Delta(Box::new(AccountDelta::read_from(source)?))),
             variant => Err(DeserializationError::InvalidValue(format!(
                 "Unknown variant {variant} for AccountDetails"
             ))),
@@ -547,6 +637,146 @@ impl Deserializable for AccountUpdateDetails {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Computes the delta between the provided initial and final states of an account.
+///
+/// This is the shared implementation of [`AccountDelta::from_accounts`] and [`Account::diff`],
+/// see the former for details.
+pub(super) fn compute_account_delta(
+    initial: &Account,
+    final_state: &Account,
+) -> Result<AccountDelta, AccountError> {
+    if initial.id() != final_state.id() {
+        return Err(AccountError::DiffAccountIdMismatch {
+            initial: initial.id(),
+            final_state: final_state.id(),
+        });
+    }
+
+    if final_state.nonce().as_int() < initial.nonce().as_int() {
+        return Err(AccountError::DiffNonceDecreased {
+            initial: initial.nonce(),
+            final_state: final_state.nonce(),
+        });
+    }
+
+    // a delta only carries the code of new accounts, so it cannot represent code changes
+    if initial.code().commitment() != final_state.code().commitment() {
+        return Err(AccountError::DiffCodeChanged);
+    }
+
+    // The delta of a new account is computed against an empty account with the same storage
+    // layout, so that it contains the account's entire state, matching the kernel's behavior.
+    let is_new = initial.is_new();
+    let storage = compute_storage_delta(initial.storage(), final_state.storage(), is_new)?;
+    let empty_vault = AssetVault::default();
+    let initial_vault = if is_new { &empty_vault } else { initial.vault() };
+    let vault = compute_vault_delta(initial_vault, final_state.vault())?;
+
+    let code = is_new.then(|| final_state.code().clone());
+    let nonce_delta = final_state.nonce() - initial.nonce();
+
+    AccountDelta::new(initial.id(), storage, vault, nonce_delta)
+        .map(|delta| delta.with_code(code))
+        .map_err(|err| AccountError::DiffInvalidDelta(Box::new(err)))
+}
+
+/// Computes the storage delta between the provided initial and final storage.
+///
+/// If `initial_is_empty` is `true`, the slots of the initial storage are treated as empty.
+fn compute_storage_delta(
+    initial: &AccountStorage,
+    final_storage: &AccountStorage,
+    initial_is_empty: bool,
+) -> Result<AccountStorageDelta, AccountError> {
+    if initial.num_slots() != final_storage.num_slots() {
+        return Err(AccountError::DiffStorageLayoutMismatch);
+    }
+
+    let empty_map = StorageMap::default();
+    let mut delta = AccountStorageDelta::new();
+    for (slot_index, (initial_slot, final_slot)) in
+        (0..=u8::MAX).zip(initial.slots().iter().zip(final_storage.slots()))
+    {
+        match (initial_slot, final_slot) {
+            (StorageSlot::Value(initial_value), StorageSlot::Value(final_value)) => {
+                let initial_value = if initial_is_empty {
+                    Word::empty()
+                } else {
+                    *initial_value
+                };
+                if initial_value != *final_value {
+                    delta.set_item(slot_index, *final_value);
+                }
+            },
+            (StorageSlot::Map(initial_map), StorageSlot::Map(final_map)) => {
+                let initial_map = if initial_is_empty { &empty_map } else { initial_map };
+                let keys: BTreeSet<&Word> =
+                    initial_map.entries().chain(final_map.entries()).map(|(key, _)| key).collect();
+                for key in keys {
+                    let final_value = final_map.get(key);
+                    if initial_map.get(key) != final_value {
+                        delta.set_map_item(slot_index, *key, final_value);
+                    }
+                }
+            },
+            (StorageSlot::Value(_), StorageSlot::Map(final_map)) => {
+                delta
+                    .convert_slot_to_map(
+                        slot_index,
+                        final_map.entries().map(|(key, value)| (*key, *value)),
+                    )
+                    .map_err(|err| AccountError::DiffInvalidDelta(Box::new(err)))?;
+            },
+            (StorageSlot::Map(_), StorageSlot::Value(_)) => {
+                return Err(AccountError::DiffStorageLayoutMismatch);
+            },
+        }
+    }
+
+    Ok(delta)
+}
+
+/// Computes the vault delta between the provided initial and final vault.
+fn compute_vault_delta(
+    initial: &AssetVault,
+    final_vault: &AssetVault,
+) -> Result<AccountVaultDelta, AccountError> {
+    let initial_assets: BTreeMap<AssetVaultKey, Asset> =
+        initial.assets().map(|asset| (asset.vault_key(), asset)).collect();
+    let final_assets: BTreeMap<AssetVaultKey, Asset> =
+        final_vault.assets().map(|asset| (asset.vault_key(), asset)).collect();
+
+    let mut delta = AccountVaultDelta::default();
+    for (vault_key, initial_asset) in initial_assets.iter() {
+        match (initial_asset, final_assets.get(vault_key)) {
+            (Asset::Fungible(initial_asset), Some(Asset::Fungible(final_asset))) => {
+                if final_asset.amount() == initial_asset.amount() {
+                    Ok(())
+                } else if let Some(added) = final_asset.checked_sub(*initial_asset) {
+                    delta.add_asset(added.into())
+                } else if let Some(removed) = initial_asset.checked_sub(*final_asset) {
+                    delta.remove_asset(removed.into())
+                } else {
+                    Ok(())
+                }
+            },
+            (_, Some(_)) => Ok(()),
+            (_, None) => delta.remove_asset(*initial_asset),
+        }
+        .map_err(|err| AccountError::DiffInvalidDelta(Box::new(err)))?;
+    }
+
+    for (vault_key, final_asset) in final_assets.iter() {
+        if !initial_assets.contains_key(vault_key) {
+            delta
+                .add_asset(*final_asset)
+                .map_err(|err| AccountError::DiffInvalidDelta(Box::new(err)))?;
+        }
+    }
+
+    Ok(delta)
+}
+
 /// Checks if the nonce was updated correctly given the provided storage and vault deltas.
 ///
 /// # Errors
@@ -570,6 +800,8 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
+    use alloc::string::ToString;
 
     use assert_matches::assert_matches;
     use miden_core::utils::Serializable;
@@ -580,12 +812,14 @@ mod tests {
     use crate::account::{
         Account,
         AccountCode,
+        AccountComponent,
         AccountId,
         AccountStorage,
         AccountStorageMode,
         AccountType,
         StorageMapDelta,
     };
+    use crate::assembly::Assembler;
     use crate::asset::{
         Asset,
         AssetVault,
@@ -594,11 +828,15 @@ mod tests {
         NonFungibleAssetDetails,
     };
     use crate::testing::account_id::{
+        ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET,
         ACCOUNT_ID_PRIVATE_SENDER,
         ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
         AccountIdBuilder,
     };
-    use crate::{AccountDeltaError, ONE, Word, ZERO};
+    use crate::testing::constants::NON_FUNGIBLE_ASSET_DATA;
+    use crate::testing::noop_auth_component::NoopAuthComponent;
+    use crate::testing::storage::{STORAGE_INDEX_0, STORAGE_INDEX_2, STORAGE_LEAVES_2};
+    use crate::{AccountDeltaError, AccountError, ONE, Word, ZERO};
 
     #[test]
     fn account_delta_nonce_validation() {
@@ -645,6 +883,144 @@ mod tests {
         });
     }
 
+    #[test]
+    fn account_delta_stats() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap();
+
+        let empty_delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::new(),
+            AccountVaultDelta::default(),
+            ZERO,
+        )
+        .unwrap();
+        let stats = empty_delta.stats();
+        assert_eq!(stats.changed_value_slots, 0);
+        assert_eq!(stats.changed_map_slots, 0);
+        assert_eq!(stats.fungible_faucets_affected, 0);
+        assert_eq!(stats.non_fungible_deltas, 0);
+        assert_eq!(stats.nonce_delta, ZERO);
+
+        let storage_delta = AccountStorageDelta::from_iters(
+            [1],
+            [(2, Word::from([1, 1, 1, 1u32]))],
+            [(4, StorageMapDelta::from_iters([Word::from([1, 1, 1, 0u32])], []))],
+        );
+        let fungible: Asset = FungibleAsset::mock(10);
+        let non_fungible: Asset = NonFungibleAsset::mock(&[1, 2, 3]);
+        let vault_delta = AccountVaultDelta::from_iters([fungible, non_fungible], []);
+
+        let delta =
+            AccountDelta::new(account_id, storage_delta, vault_delta, Felt::new(2)).unwrap();
+        let stats = delta.stats();
+        assert_eq!(stats.changed_value_slots, 2);
+        assert_eq!(stats.changed_map_slots, 1);
+        assert_eq!(stats.fungible_faucets_affected, 1);
+        assert_eq!(stats.non_fungible_deltas, 1);
+        assert_eq!(stats.nonce_delta, Felt::new(2));
+        assert_eq!(
+            stats.to_string(),
+            "value slots: 2, map slots: 1, fungible faucets: 1, non-fungible assets: 1, nonce delta: 2"
+        );
+    }
+
+    #[test]
+    fn account_delta_from_accounts() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE).unwrap();
+        let initial = Account::new_existing(
+            account_id,
+            AssetVault::mock(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            ONE,
+        );
+
+        let storage_delta = AccountStorageDelta::from_iters(
+            [],
+            [(STORAGE_INDEX_0, Word::from([9, 9, 9, 9u32]))],
+            [(
+                STORAGE_INDEX_2,
+                StorageMapDelta::from_iters(
+                    [STORAGE_LEAVES_2[0].0],
+                    [(Word::from([1, 2, 3, 4u32]), Word::from([5, 6, 7, 8u32]))],
+                ),
+            )],
+        );
+        let added_asset = FungibleAsset::new(
+            AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap(),
+            50,
+        )
+        .unwrap();
+        let vault_delta = AccountVaultDelta::from_iters(
+            [added_asset.into()],
+            [FungibleAsset::mock(10), NonFungibleAsset::mock(&NON_FUNGIBLE_ASSET_DATA)],
+        );
+        let expected_delta =
+            AccountDelta::new(account_id, storage_delta, vault_delta, Felt::new(2)).unwrap();
+
+        let mut final_state = initial.clone();
+        final_state.apply_delta(&expected_delta).unwrap();
+
+        assert_eq!(AccountDelta::from_accounts(&initial, &final_state).unwrap(), expected_delta);
+        assert_eq!(initial.diff(&final_state).unwrap(), expected_delta);
+        assert!(initial.diff(&initial).unwrap().is_empty());
+
+        // the delta of a new account contains its entire state and can be converted into the
+        // final account
+        let new_account = Account::new_unchecked(
+            account_id,
+            AssetVault::mock(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            ZERO,
+            None,
+        );
+        let mut final_state = new_account.clone();
+        final_state.increment_nonce(ONE).unwrap();
+        let delta = new_account.diff(&final_state).unwrap();
+        assert!(delta.is_full_state());
+        assert_eq!(Account::try_from(&delta).unwrap(), final_state);
+
+        assert_matches!(
+            final_state.diff(&new_account).unwrap_err(),
+            AccountError::DiffNonceDecreased { .. }
+        );
+        let other_account = Account::new_existing(
+            AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap(),
+            AssetVault::mock(),
+            AccountStorage::mock(),
+            AccountCode::mock(),
+            ONE,
+        );
+        assert_matches!(
+            initial.diff(&other_account).unwrap_err(),
+            AccountError::DiffAccountIdMismatch { .. }
+        );
+
+        // deltas cannot represent code changes
+        let other_component =
+            AccountComponent::compile("export.baz push.1 drop end", Assembler::default(), vec![])
+                .unwrap()
+                .with_supports_all_types();
+        let other_code = AccountCode::from_components(
+            &[NoopAuthComponent.into(), other_component],
+            AccountType::RegularAccountUpdatableCode,
+        )
+        .unwrap();
+        let upgraded_account = Account::new_existing(
+            account_id,
+            AssetVault::mock(),
+            AccountStorage::mock(),
+            other_code,
+            Felt::new(2),
+        );
+        assert_matches!(
+            initial.diff(&upgraded_account).unwrap_err(),
+            AccountError::DiffCodeChanged
+        );
+    }
+
     #[test]
     fn account_update_details_size_hint() {
         // AccountDelta
@@ -728,7 +1104,7 @@ mod tests {
         let update_details_private = AccountUpdateDetails::Private;
         assert_eq!(update_details_private.to_bytes().len(), update_details_private.get_size_hint());
 
-        let update_details_delta = AccountUpdateDetails::Delta(account_delta);
+        let update_details_delta = AccountUpdateDetails::Delta(Box::new(account_delta));
         assert_eq!(update_details_delta.to_bytes().len(), update_details_delta.get_size_hint());
     }
 }
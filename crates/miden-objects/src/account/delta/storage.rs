@@ -1,5 +1,6 @@
 use alloc::collections::BTreeMap;
 use alloc::collections::btree_map::Entry;
+use alloc::format;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
@@ -12,25 +13,30 @@ use super::{
     Serializable,
     Word,
 };
-use crate::account::{StorageMap, StorageSlotType};
-use crate::{EMPTY_WORD, Felt, LexicographicWord, ZERO};
+use crate::account::{AccountStorage, StorageMap, StorageSlotType};
+use crate::{AccountError, EMPTY_WORD, Felt, LexicographicWord, ZERO};
 
 // ACCOUNT STORAGE DELTA
 // ================================================================================================
 
 /// [AccountStorageDelta] stores the differences between two states of account storage.
 ///
-/// The delta consists of two maps:
+/// The delta consists of three maps:
 /// - A map containing the updates to value storage slots. The keys in this map are indexes of the
 ///   updated storage slots and the values are the new values for these slots.
 /// - A map containing updates to storage maps. The keys in this map are indexes of the updated
 ///   storage slots and the values are corresponding storage map delta objects.
+/// - A map containing the changes to the types of storage slots. The keys in this map are indexes
+///   of the changed storage slots and the values describe the type changes. A slot converted into a
+///   map slot starts out as an empty map, and its initial entries are tracked as map updates.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct AccountStorageDelta {
     /// The updates to the value slots of the account.
     values: BTreeMap<u8, Word>,
     /// The updates to the map slots of the account.
     maps: BTreeMap<u8, StorageMapDelta>,
+    /// The changes to the slot types of the account.
+    slot_type_changes: BTreeMap<u8, StorageSlotTypeChange>,
 }
 
 impl AccountStorageDelta {
@@ -39,6 +45,7 @@ impl AccountStorageDelta {
         Self {
             values: BTreeMap::new(),
             maps: BTreeMap::new(),
+            slot_type_changes: BTreeMap::new(),
         }
     }
 
@@ -53,15 +60,24 @@ impl AccountStorageDelta {
         values: BTreeMap<u8, Word>,
         maps: BTreeMap<u8, StorageMapDelta>,
     ) -> Result<Self, AccountDeltaError> {
-        let delta = Self { values, maps };
+        let delta = Self {
+            values,
+            maps,
+            slot_type_changes: BTreeMap::new(),
+        };
         delta.validate()?;
 
         Ok(delta)
     }
 
-    /// Returns the slot type of the provided slot index or `None` if no such slot exists.
+    /// Returns the slot type the provided slot index must have for this delta to apply or `None`
+    /// if no such slot exists.
+    ///
+    /// For slots whose type is changed by this delta, this is the type before the change.
     pub(crate) fn slot_type(&self, slot_index: u8) -> Option<StorageSlotType> {
-        if self.values().contains_key(&slot_index) {
+        if let Some(type_change) = self.slot_type_changes.get(&slot_index) {
+            Some(type_change.from_type())
+        } else if self.values().contains_key(&slot_index) {
             Some(StorageSlotType::Value)
         } else if self.maps().contains_key(&slot_index) {
             Some(StorageSlotType::Map)
@@ -80,9 +96,30 @@ impl AccountStorageDelta {
         &self.maps
     }
 
-    /// Returns true if storage delta contains no updates.
+    /// Returns a reference to the slot type changes in this storage delta.
+    pub fn slot_type_changes(&self) -> &BTreeMap<u8, StorageSlotTypeChange> {
+        &self.slot_type_changes
+    }
+
+    /// Returns the number of slots modified by this storage delta.
+    ///
+    /// This counts every slot exactly once, regardless of whether its value was updated (which
+    /// includes clearing it), its map entries were updated or its type was changed.
+    pub fn num_changes(&self) -> usize {
+        let num_type_changes_only = self
+            .slot_type_changes
+            .keys()
+            .filter(|slot_index| {
+                !self.values.contains_key(slot_index) && !self.maps.contains_key(slot_index)
+            })
+            .count();
+
+        self.values.len() + self.maps.len() + num_type_changes_only
+    }
+
+    /// Returns true if storage delta contains no updates, i.e. if [`Self::num_changes`] is zero.
     pub fn is_empty(&self) -> bool {
-        self.values.is_empty() && self.maps.is_empty()
+        self.num_changes() == 0
     }
 
     /// Tracks a slot change
@@ -95,8 +132,47 @@ impl AccountStorageDelta {
         self.maps.entry(slot_index).or_default().insert(key, new_value);
     }
 
+    /// Tracks the conversion of a value slot into a map slot containing the provided initial
+    /// entries.
+    ///
+    /// Any value update previously tracked for the slot is discarded, since the conversion
+    /// overwrites the value of the slot.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The type of the slot was already changed or the slot was already updated as a map slot,
+    ///   which means the slot is not a value slot anymore.
+    pub fn convert_slot_to_map(
+        &mut self,
+        slot_index: u8,
+        initial_entries: impl IntoIterator<Item = (Word, Word)>,
+    ) -> Result<(), AccountDeltaError> {
+        self.track_slot_type_change(slot_index, StorageSlotTypeChange::ValueToMap)?;
+
+        let map_delta = self.maps.entry(slot_index).or_default();
+        for (key, value) in initial_entries {
+            map_delta.insert(key, value);
+        }
+
+        Ok(())
+    }
+
     /// Merges another delta into this one, overwriting any existing values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The other delta changes the type of a slot whose type is already changed by this delta or
+    ///   which is updated as a map slot by this delta.
+    /// - The merged delta updates a slot as both a value and a map slot.
     pub fn merge(&mut self, other: Self) -> Result<(), AccountDeltaError> {
+        // Slot type changes must be merged first, since they overwrite the value updates of this
+        // delta that happened before the change.
+        for (slot, type_change) in other.slot_type_changes {
+            self.track_slot_type_change(slot, type_change)?;
+        }
+
         self.values.extend(other.values);
 
         // merge maps
@@ -112,6 +188,22 @@ impl AccountStorageDelta {
         self.validate()
     }
 
+    /// Tracks a change of the type of the provided slot and discards any value update of the slot.
+    fn track_slot_type_change(
+        &mut self,
+        slot_index: u8,
+        type_change: StorageSlotTypeChange,
+    ) -> Result<(), AccountDeltaError> {
+        if self.slot_type_changes.contains_key(&slot_index) || self.maps.contains_key(&slot_index) {
+            return Err(AccountDeltaError::ConflictingStorageSlotTypeChange(slot_index));
+        }
+
+        self.values.remove(&slot_index);
+        self.slot_type_changes.insert(slot_index, type_change);
+
+        Ok(())
+    }
+
     /// Checks whether this storage delta is valid.
     ///
     /// # Errors
@@ -119,8 +211,9 @@ impl AccountStorageDelta {
     /// Returns an error if:
     /// - Any of the updated slot is referenced from both maps, which means a slot is treated as
     ///   both a value and a map slot.
+    /// - Any slot converted into a map slot is updated as a value slot.
     fn validate(&self) -> Result<(), AccountDeltaError> {
-        for slot in self.maps.keys() {
+        for slot in self.maps.keys().chain(self.slot_type_changes.keys()) {
             if self.values.contains_key(slot) {
                 return Err(AccountDeltaError::StorageSlotUsedAsDifferentTypes(*slot));
             }
@@ -141,6 +234,10 @@ impl AccountStorageDelta {
 
     /// Appends the storage slots delta to the given `elements` from which the delta commitment will
     /// be computed.
+    ///
+    /// Slot type changes cannot be made by the transaction kernel and are therefore not part of the
+    /// commitment. The initial entries of a slot converted into a map slot are committed to like
+    /// any other map update.
     pub(super) fn append_delta_elements(&self, elements: &mut Vec<Felt>) {
         const DOMAIN_VALUE: Felt = Felt::new(2);
         const DOMAIN_MAP: Felt = Felt::new(3);
@@ -188,9 +285,54 @@ impl AccountStorageDelta {
         }
     }
 
+    /// Applies this delta to the provided account storage.
+    ///
+    /// All updated slot indices are validated against the provided storage before any change is
+    /// applied, so the storage is left unmodified if the delta does not fit the storage layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any updated slot index is out of bounds for the provided storage.
+    /// - A value update targets a slot which is not a value slot.
+    /// - A map update targets a slot which is not a map slot, unless the slot is converted into a
+    ///   map slot by this delta.
+    /// - A slot type change targets a slot whose type does not match the type before the change.
+    pub fn apply(&self, storage: &mut AccountStorage) -> Result<(), AccountError> {
+        let slots_len = storage.num_slots();
+
+        let updated_slots = (0..=u8::MAX)
+            .filter_map(|index| self.slot_type(index).map(|slot_type| (index, slot_type)));
+
+        for (index, expected_slot_type) in updated_slots {
+            let slot = storage
+                .slots()
+                .get(index as usize)
+                .ok_or(AccountError::StorageIndexOutOfBounds { slots_len, index })?;
+
+            match (expected_slot_type, slot.slot_type()) {
+                (StorageSlotType::Value, StorageSlotType::Map) => {
+                    return Err(AccountError::StorageSlotNotValue(index));
+                },
+                (StorageSlotType::Map, StorageSlotType::Value) => {
+                    return Err(AccountError::StorageSlotNotMap(index));
+                },
+                _ => (),
+            }
+        }
+
+        storage.apply_delta(self)
+    }
+
     /// Consumes self and returns the underlying parts of the storage delta.
-    pub fn into_parts(self) -> (BTreeMap<u8, Word>, BTreeMap<u8, StorageMapDelta>) {
-        (self.values, self.maps)
+    pub fn into_parts(
+        self,
+    ) -> (
+        BTreeMap<u8, Word>,
+        BTreeMap<u8, StorageMapDelta>,
+        BTreeMap<u8, StorageSlotTypeChange>,
+    ) {
+        (self.values, self.maps, self.slot_type_changes)
     }
 }
 
@@ -213,6 +355,7 @@ impl AccountStorageDelta {
                 cleared_values.into_iter().map(|key| (key, EMPTY_WORD)).chain(updated_values),
             ),
             maps: BTreeMap::from_iter(updated_maps),
+            slot_type_changes: BTreeMap::new(),
         }
     }
 }
@@ -230,6 +373,9 @@ impl Serializable for AccountStorageDelta {
 
         target.write_u8(self.maps.len() as u8);
         target.write_many(self.maps.iter());
+
+        target.write_u8(self.slot_type_changes.len() as u8);
+        target.write_many(self.slot_type_changes.iter());
     }
 
     fn get_size_hint(&self) -> usize {
@@ -244,13 +390,15 @@ impl Serializable for AccountStorageDelta {
         }
 
         // Length Prefixes
-        u8_size * 3 +
+        u8_size * 4 +
         // Cleared Slots
         self.cleared_slots().count() * u8_size +
         // Updated Slots
         self.updated_slots().count() * (u8_size + word_size) +
         // Storage Map Delta
-        storage_map_delta_size
+        storage_map_delta_size +
+        // Slot Type Changes
+        self.slot_type_changes.len() * (u8_size + u8_size)
     }
 }
 
@@ -273,8 +421,66 @@ impl Deserializable for AccountStorageDelta {
         let num_maps = source.read_u8()? as usize;
         let maps = source.read_many::<(u8, StorageMapDelta)>(num_maps)?.into_iter().collect();
 
-        Self::from_parts(values, maps)
-            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        let num_slot_type_changes = source.read_u8()? as usize;
+        let slot_type_changes = source
+            .read_many::<(u8, StorageSlotTypeChange)>(num_slot_type_changes)?
+            .into_iter()
+            .collect();
+
+        let delta = Self { values, maps, slot_type_changes };
+        delta
+            .validate()
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(delta)
+    }
+}
+
+// STORAGE SLOT TYPE CHANGE
+// ================================================================================================
+
+/// Describes a change of the type of a storage slot tracked by an [`AccountStorageDelta`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum StorageSlotTypeChange {
+    /// A value slot was converted into an initially empty map slot.
+    ValueToMap = 0,
+}
+
+impl StorageSlotTypeChange {
+    /// Returns the type of the slot before the change.
+    pub fn from_type(&self) -> StorageSlotType {
+        match self {
+            StorageSlotTypeChange::ValueToMap => StorageSlotType::Value,
+        }
+    }
+
+    /// Returns the type of the slot after the change.
+    pub fn to_type(&self) -> StorageSlotType {
+        match self {
+            StorageSlotTypeChange::ValueToMap => StorageSlotType::Map,
+        }
+    }
+}
+
+impl Serializable for StorageSlotTypeChange {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(*self as u8);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        0u8.get_size_hint()
+    }
+}
+
+impl Deserializable for StorageSlotTypeChange {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(StorageSlotTypeChange::ValueToMap),
+            other => Err(DeserializationError::InvalidValue(format!(
+                "unknown storage slot type change {other}"
+            ))),
+        }
     }
 }
 
@@ -438,11 +644,13 @@ impl Deserializable for StorageMapDelta {
 #[cfg(test)]
 mod tests {
     use anyhow::Context;
+    use assert_matches::assert_matches;
 
-    use super::{AccountStorageDelta, Deserializable, Serializable};
-    use crate::account::StorageMapDelta;
+    use super::{AccountStorageDelta, Deserializable, Serializable, StorageSlotTypeChange};
+    use crate::account::{AccountStorage, StorageMap, StorageMapDelta, StorageSlot};
+    use crate::errors::AccountDeltaError;
     use crate::testing::storage::AccountStorageDeltaBuilder;
-    use crate::{ONE, Word, ZERO};
+    use crate::{AccountError, ONE, Word, ZERO};
 
     #[test]
     fn account_storage_delta_validation() {
@@ -479,6 +687,71 @@ mod tests {
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
     }
 
+    #[test]
+    fn account_storage_delta_num_changes() -> anyhow::Result<()> {
+        let empty_delta = AccountStorageDelta::new();
+        assert_eq!(empty_delta.num_changes(), 0);
+        assert!(empty_delta.is_empty());
+
+        let value = Word::from([ONE, ONE, ONE, ONE]);
+        let mut delta = AccountStorageDelta::from_iters(
+            [1, 3],
+            [(2, value)],
+            [(4, StorageMapDelta::from_iters([], [(value, value)]))],
+        );
+        assert_eq!(delta.num_changes(), 4);
+        assert!(!delta.is_empty());
+
+        // a converted slot is counted once, although it is tracked as a type change and a map
+        // update
+        delta.convert_slot_to_map(5, [(value, value)])?;
+        assert_eq!(delta.num_changes(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_storage_delta() -> anyhow::Result<()> {
+        let value = Word::from([ONE, ONE, ONE, ONE]);
+        let map_key = Word::from([ONE, ZERO, ZERO, ZERO]);
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::empty()),
+            StorageSlot::Map(StorageMap::new()),
+        ])?;
+
+        let delta = AccountStorageDelta::from_iters(
+            [],
+            [(0, value)],
+            [(1, StorageMapDelta::from_iters([], [(map_key, value)]))],
+        );
+        delta.apply(&mut storage)?;
+        assert_eq!(storage.get_item(0)?, value);
+        assert_eq!(storage.get_map_item(1, map_key)?, value);
+
+        // An out of bounds slot index should not change the storage, even if other updates are
+        // valid.
+        let original_storage = storage.clone();
+        let delta = AccountStorageDelta::from_iters([], [(0, Word::empty()), (2, value)], []);
+        assert_matches!(
+            delta.apply(&mut storage).unwrap_err(),
+            AccountError::StorageIndexOutOfBounds { slots_len: 2, index: 2 }
+        );
+        assert_eq!(storage, original_storage);
+
+        // Updates must match the slot types of the storage.
+        let delta = AccountStorageDelta::from_iters([], [(1, value)], []);
+        assert_matches!(
+            delta.apply(&mut storage).unwrap_err(),
+            AccountError::StorageSlotNotValue(1)
+        );
+
+        let delta = AccountStorageDelta::from_iters([], [], [(0, StorageMapDelta::default())]);
+        assert_matches!(delta.apply(&mut storage).unwrap_err(), AccountError::StorageSlotNotMap(0));
+        assert_eq!(storage, original_storage);
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_empty() {
         let storage_delta = AccountStorageDelta::new();
@@ -521,6 +794,103 @@ mod tests {
         assert_eq!(deserialized, storage_delta);
     }
 
+    #[test]
+    fn test_serde_account_storage_delta_with_slot_type_change() -> anyhow::Result<()> {
+        let mut storage_delta = AccountStorageDelta::from_iters([1], [], []);
+        storage_delta
+            .convert_slot_to_map(2, [(Word::empty(), Word::from([ONE, ONE, ONE, ONE]))])?;
+        storage_delta.convert_slot_to_map(3, [])?;
+        assert_eq!(
+            storage_delta.slot_type_changes().get(&2),
+            Some(&StorageSlotTypeChange::ValueToMap)
+        );
+
+        let serialized = storage_delta.to_bytes();
+        assert_eq!(serialized.len(), storage_delta.get_size_hint());
+        let deserialized = AccountStorageDelta::read_from_bytes(&serialized)?;
+        assert_eq!(deserialized, storage_delta);
+
+        Ok(())
+    }
+
+    #[test]
+    fn apply_storage_delta_with_slot_type_change() -> anyhow::Result<()> {
+        let value = Word::from([ONE, ONE, ONE, ONE]);
+        let map_key = Word::from([ONE, ZERO, ZERO, ZERO]);
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(value),
+            StorageSlot::Map(StorageMap::new()),
+        ])?;
+        let original_storage = storage.clone();
+
+        let mut delta = AccountStorageDelta::new();
+        delta.convert_slot_to_map(0, [(map_key, value)])?;
+        delta.apply(&mut storage)?;
+        assert_eq!(
+            storage.slots()[0],
+            StorageSlot::Map(StorageMap::with_entries([(map_key, value)])?)
+        );
+
+        // Only value slots can be converted into map slots.
+        let mut storage = original_storage.clone();
+        let mut delta = AccountStorageDelta::new();
+        delta.convert_slot_to_map(1, [])?;
+        assert_matches!(
+            delta.apply(&mut storage).unwrap_err(),
+            AccountError::StorageSlotNotValue(1)
+        );
+        assert_eq!(storage, original_storage);
+
+        Ok(())
+    }
+
+    #[test]
+    fn merge_slot_type_changes() -> anyhow::Result<()> {
+        let value = Word::from([ONE, ONE, ONE, ONE]);
+        let map_key = Word::from([ONE, ZERO, ZERO, ZERO]);
+
+        // A conversion overwrites a previous value update of the same slot.
+        let mut delta = AccountStorageDelta::from_iters([], [(0, value)], []);
+        let mut conversion = AccountStorageDelta::new();
+        conversion.convert_slot_to_map(0, [(map_key, value)])?;
+        delta.merge(conversion.clone())?;
+        assert_eq!(delta, conversion);
+
+        // Map updates after the conversion are merged into the initial entries.
+        let map_update = AccountStorageDelta::from_iters(
+            [],
+            [],
+            [(0, StorageMapDelta::from_iters([map_key], []))],
+        );
+        delta.merge(map_update)?;
+        assert_eq!(delta.maps().get(&0), Some(&StorageMapDelta::from_iters([map_key], [])));
+
+        // Converting the same slot twice conflicts.
+        assert_matches!(
+            delta.clone().merge(conversion.clone()).unwrap_err(),
+            AccountDeltaError::ConflictingStorageSlotTypeChange(0)
+        );
+
+        // Converting a slot which was updated as a map slot conflicts.
+        let mut map_delta = AccountStorageDelta::from_iters(
+            [],
+            [],
+            [(0, StorageMapDelta::from_iters([], [(map_key, value)]))],
+        );
+        assert_matches!(
+            map_delta.merge(conversion).unwrap_err(),
+            AccountDeltaError::ConflictingStorageSlotTypeChange(0)
+        );
+
+        // Updating a converted slot as a value slot is invalid.
+        assert_matches!(
+            delta.merge(AccountStorageDelta::from_iters([], [(0, value)], [])).unwrap_err(),
+            AccountDeltaError::StorageSlotUsedAsDifferentTypes(0)
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_serde_storage_map_delta() {
         let storage_map_delta = StorageMapDelta::default();
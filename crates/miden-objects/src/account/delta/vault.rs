@@ -53,6 +53,24 @@ impl AccountVaultDelta {
         &self.non_fungible
     }
 
+    /// Returns an iterator over the faucet IDs and amounts of fungible assets whose balance
+    /// increased in this delta.
+    pub fn fungible_inflow(&self) -> impl Iterator<Item = (AccountId, u64)> + '_ {
+        self.fungible
+            .iter()
+            .filter(|&(_, &amount)| amount > 0)
+            .map(|(&faucet_id, &amount)| (faucet_id, amount.unsigned_abs()))
+    }
+
+    /// Returns an iterator over the faucet IDs and amounts of fungible assets whose balance
+    /// decreased in this delta.
+    pub fn fungible_outflow(&self) -> impl Iterator<Item = (AccountId, u64)> + '_ {
+        self.fungible
+            .iter()
+            .filter(|&(_, &amount)| amount < 0)
+            .map(|(&faucet_id, &amount)| (faucet_id, amount.unsigned_abs()))
+    }
+
     /// Returns true if this vault delta contains no updates.
     pub fn is_empty(&self) -> bool {
         self.fungible.is_empty() && self.non_fungible.is_empty()
@@ -544,6 +562,8 @@ pub enum NonFungibleDeltaAction {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use super::{AccountVaultDelta, Deserializable, Serializable};
     use crate::account::{AccountId, AccountIdPrefix};
     use crate::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
@@ -573,6 +593,20 @@ mod tests {
         assert!(!AccountVaultDelta::from_iters([], [asset]).is_empty());
     }
 
+    #[test]
+    fn fungible_inflow_and_outflow() {
+        let faucet_0 = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let faucet_1 = AccountId::try_from(ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET).unwrap();
+        let added: Asset = FungibleAsset::new(faucet_0, 100).unwrap().into();
+        let removed: Asset = FungibleAsset::new(faucet_1, 40).unwrap().into();
+        let non_fungible = NonFungibleAsset::mock(&[1, 2, 3]);
+
+        let delta = AccountVaultDelta::from_iters([added, non_fungible], [removed]);
+
+        assert_eq!(delta.fungible_inflow().collect::<Vec<_>>(), vec![(faucet_0, 100)]);
+        assert_eq!(delta.fungible_outflow().collect::<Vec<_>>(), vec![(faucet_1, 40)]);
+    }
+
     #[rstest::rstest]
     #[case::pos_pos(50, 50, Some(100))]
     #[case::neg_neg(-50, -50, Some(-100))]
@@ -15,7 +15,9 @@ use super::super::utils::serde::{
     DeserializationError,
     Serializable,
 };
-use super::{Account, AuthSecretKey};
+use super::{Account, AuthSecretKey, PublicKeyCommitment};
+use crate::crypto::dsa::rpo_falcon512::{SecretKey, Signature};
+use crate::{Hasher, Word};
 
 const MAGIC: &str = "acct";
 
@@ -40,6 +42,34 @@ impl AccountFile {
     pub fn new(account: Account, auth_keys: Vec<AuthSecretKey>) -> Self {
         Self { account, auth_secret_keys: auth_keys }
     }
+
+    /// Signs the serialized account file with the provided RpoFalcon512 secret key and returns the
+    /// serialized signature.
+    ///
+    /// The signed message is the RPO256 hash of the serialized account file. The signature can be
+    /// checked with [`verify_signature`].
+    pub fn sign(&self, key: &SecretKey) -> Vec<u8> {
+        let message = Hasher::hash(&self.to_bytes());
+        key.sign(message).to_bytes()
+    }
+}
+
+/// Returns `true` if `sig` is a valid RpoFalcon512 signature over `data` produced by the secret key
+/// whose public key commitment is `pubkey`.
+///
+/// The message is expected to be the RPO256 hash of `data`, as produced by [`AccountFile::sign`].
+/// Returns `false` if the signature cannot be deserialized.
+pub fn verify_signature(data: &[u8], sig: &[u8], pubkey: &PublicKeyCommitment) -> bool {
+    let Ok(signature) = Signature::read_from_bytes(sig) else {
+        return false;
+    };
+
+    let public_key = signature.public_key();
+    if public_key.to_commitment() != Word::from(*pubkey) {
+        return false;
+    }
+
+    signature.verify(Hasher::hash(data), public_key)
 }
 
 #[cfg(feature = "std")]
@@ -104,8 +134,16 @@ mod tests {
     #[cfg(feature = "std")]
     use tempfile::tempdir;
 
-    use super::AccountFile;
-    use crate::account::{Account, AccountCode, AccountId, AuthSecretKey, Felt, storage};
+    use super::{AccountFile, verify_signature};
+    use crate::account::{
+        Account,
+        AccountCode,
+        AccountId,
+        AuthSecretKey,
+        Felt,
+        PublicKeyCommitment,
+        storage,
+    };
     use crate::asset::AssetVault;
     use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
 
@@ -152,4 +190,27 @@ mod tests {
             account_file.auth_secret_keys.to_bytes()
         );
     }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let account_file = build_account_file();
+        let secret_key = SecretKey::new();
+        let pubkey = PublicKeyCommitment::from(secret_key.public_key());
+
+        let data = account_file.to_bytes();
+        let signature = account_file.sign(&secret_key);
+        assert!(verify_signature(&data, &signature, &pubkey));
+
+        // a signature from a different key must be rejected
+        let other_pubkey = PublicKeyCommitment::from(SecretKey::new().public_key());
+        assert!(!verify_signature(&data, &signature, &other_pubkey));
+
+        // tampered data must be rejected
+        let mut tampered = data.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(!verify_signature(&tampered, &signature, &pubkey));
+
+        // malformed signatures must be rejected
+        assert!(!verify_signature(&data, &signature[1..], &pubkey));
+    }
 }
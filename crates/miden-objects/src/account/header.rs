@@ -45,6 +45,20 @@ impl AccountHeader {
         }
     }
 
+    /// Creates a new [AccountHeader] describing the current state of the provided account.
+    ///
+    /// The header is built from the commitments the account already maintains, so this does not
+    /// require serializing the account.
+    pub fn from_account(account: &Account) -> Self {
+        Self {
+            id: account.id(),
+            nonce: account.nonce(),
+            vault_root: account.vault().root(),
+            storage_commitment: account.storage().commitment(),
+            code_commitment: account.code().commitment(),
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -109,6 +123,35 @@ impl AccountHeader {
         ]
         .concat()
     }
+
+    // COMPARISON
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an [`AccountHeaderDiff`] describing which components of this header differ from
+    /// the provided header.
+    ///
+    /// The account IDs of the headers are not compared, so this is only meaningful for two headers
+    /// of the same account, e.g. the initial and the final header of a transaction.
+    pub fn diff(&self, other: &Self) -> AccountHeaderDiff {
+        AccountHeaderDiff {
+            nonce_changed: self.nonce != other.nonce,
+            vault_root_changed: self.vault_root != other.vault_root,
+            storage_commitment_changed: self.storage_commitment != other.storage_commitment,
+            code_commitment_changed: self.code_commitment != other.code_commitment,
+        }
+    }
+
+    /// Returns `true` if the commitment of this header is equal to the provided account
+    /// commitment.
+    pub fn matches_commitment(&self, account_commitment: Word) -> bool {
+        self.commitment() == account_commitment
+    }
+
+    /// Returns `true` if this header describes the current state of the provided account, i.e. if
+    /// its commitment is equal to [`Account::commitment`].
+    pub fn matches_account(&self, account: &Account) -> bool {
+        self.matches_commitment(account.commitment())
+    }
 }
 
 impl From<PartialAccount> for AccountHeader {
@@ -137,16 +180,40 @@ impl From<Account> for AccountHeader {
 
 impl From<&Account> for AccountHeader {
     fn from(account: &Account) -> Self {
-        Self {
-            id: account.id(),
-            nonce: account.nonce(),
-            vault_root: account.vault().root(),
-            storage_commitment: account.storage().commitment(),
-            code_commitment: account.code().commitment(),
-        }
+        Self::from_account(account)
+    }
+}
+
+// ACCOUNT HEADER DIFF
+// ================================================================================================
+
+/// Describes which components of two [`AccountHeader`]s of the same account differ, as returned by
+/// [`AccountHeader::diff`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccountHeaderDiff {
+    /// Whether the nonces differ.
+    pub nonce_changed: bool,
+    /// Whether the vault roots differ.
+    pub vault_root_changed: bool,
+    /// Whether the storage commitments differ.
+    pub storage_commitment_changed: bool,
+    /// Whether the code commitments differ.
+    pub code_commitment_changed: bool,
+}
+
+impl AccountHeaderDiff {
+    /// Returns `true` if none of the header components differ.
+    pub fn is_empty(&self) -> bool {
+        !(self.nonce_changed
+            || self.vault_root_changed
+            || self.storage_commitment_changed
+            || self.code_commitment_changed)
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
 impl Serializable for AccountHeader {
     fn write_into<W: miden_core::utils::ByteWriter>(&self, target: &mut W) {
         self.id.write_into(target);
@@ -185,7 +252,7 @@ mod tests {
     use miden_core::Felt;
     use miden_core::utils::{Deserializable, Serializable};
 
-    use super::AccountHeader;
+    use super::{AccountHeader, AccountHeaderDiff};
     use crate::Word;
     use crate::account::StorageSlot;
     use crate::account::tests::build_account;
@@ -205,4 +272,55 @@ mod tests {
         let deserialized_header = AccountHeader::read_from_bytes(&header_bytes).unwrap();
         assert_eq!(deserialized_header, account_header);
     }
+
+    #[test]
+    fn account_header_from_account() {
+        let account = build_account(
+            vec![FungibleAsset::mock(99)],
+            Felt::new(3),
+            vec![StorageSlot::Value(Word::from([1, 2, 3, 4u32]))],
+        );
+        let header = AccountHeader::from_account(&account);
+
+        assert_eq!(header.id(), account.id());
+        assert_eq!(header.nonce(), account.nonce());
+        assert_eq!(header.vault_root(), account.vault().root());
+        assert_eq!(header.storage_commitment(), account.storage().commitment());
+        assert_eq!(header.code_commitment(), account.code().commitment());
+        assert_eq!(header.commitment(), account.commitment());
+
+        let deserialized_header = AccountHeader::read_from_bytes(&header.to_bytes()).unwrap();
+        assert_eq!(deserialized_header, header);
+    }
+
+    #[test]
+    fn account_header_diff() {
+        let word = Word::from([1, 2, 3, 4u32]);
+        let account = build_account(
+            vec![FungibleAsset::mock(99)],
+            Felt::new(1),
+            vec![StorageSlot::Value(word)],
+        );
+        let header = AccountHeader::from(&account);
+
+        assert!(header.diff(&header).is_empty());
+        assert!(header.matches_account(&account));
+
+        let mut updated_account = account.clone();
+        updated_account.increment_nonce(Felt::new(1)).unwrap();
+        updated_account.storage_mut().set_item(0, Word::from([5, 6, 7, 8u32])).unwrap();
+        let updated_header = AccountHeader::from(&updated_account);
+
+        assert_eq!(
+            header.diff(&updated_header),
+            AccountHeaderDiff {
+                nonce_changed: true,
+                vault_root_changed: false,
+                storage_commitment_changed: true,
+                code_commitment_changed: false,
+            }
+        );
+        assert!(!header.matches_account(&updated_account));
+        assert!(updated_header.matches_commitment(updated_account.commitment()));
+    }
 }
@@ -1,5 +1,6 @@
 use alloc::collections::BTreeMap;
 use alloc::string::ToString;
+use core::cmp::Ordering;
 
 use miden_core::LexicographicWord;
 
@@ -33,6 +34,8 @@ pub use builder::AccountBuilder;
 
 pub mod code;
 pub use code::AccountCode;
+pub use code::layout::ComponentLayout;
+pub use code::metrics::{AccountCodeMetrics, ComponentMetrics};
 pub use code::procedure::AccountProcedureInfo;
 
 pub mod component;
@@ -40,6 +43,7 @@ pub use component::{
     AccountComponent,
     AccountComponentMetadata,
     AccountComponentTemplate,
+    ComponentDependency,
     FeltRepresentation,
     InitStorageData,
     MapEntry,
@@ -56,12 +60,14 @@ pub use component::{
 pub mod delta;
 pub use delta::{
     AccountDelta,
+    AccountDeltaStats,
     AccountStorageDelta,
     AccountVaultDelta,
     FungibleAssetDelta,
     NonFungibleAssetDelta,
     NonFungibleDeltaAction,
     StorageMapDelta,
+    StorageSlotTypeChange,
 };
 
 mod storage;
@@ -78,10 +84,10 @@ pub use storage::{
 };
 
 mod header;
-pub use header::AccountHeader;
+pub use header::{AccountHeader, AccountHeaderDiff};
 
 mod file;
-pub use file::AccountFile;
+pub use file::{AccountFile, verify_signature};
 
 mod partial;
 pub use partial::PartialAccount;
@@ -293,6 +299,15 @@ impl Account {
         self.seed
     }
 
+    /// Returns the key by which accounts are ordered, which is the account ID followed by the
+    /// nonce.
+    ///
+    /// This gives a deterministic ordering of accounts, e.g. in sorted collections, in which the
+    /// states of the same account are ordered by their nonce.
+    pub fn canonical_sort_key(&self) -> (AccountId, Felt) {
+        (self.id, self.nonce)
+    }
+
     /// Returns true if this account can issue assets.
     pub fn is_faucet(&self) -> bool {
         self.id.is_faucet()
@@ -332,6 +347,14 @@ impl Account {
         self.nonce == ZERO
     }
 
+    /// Returns the [`AccountDelta`] which transitions this account to the provided final state.
+    ///
+    /// This is equivalent to [`AccountDelta::from_accounts`] with this account as the initial
+    /// state, see there for details and errors.
+    pub fn diff(&self, final_state: &Account) -> Result<AccountDelta, AccountError> {
+        delta::compute_account_delta(self, final_state)
+    }
+
     /// Decomposes the account into the underlying account components.
     pub fn into_parts(
         self,
@@ -420,6 +443,28 @@ impl Account {
     }
 }
 
+impl Ord for Account {
+    /// Orders accounts by their [`Account::canonical_sort_key`].
+    ///
+    /// Accounts with the same ID and nonce are ordered by their commitment and seed, so that the
+    /// ordering is consistent with [`PartialEq`].
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (id, nonce) = self.canonical_sort_key();
+        let (other_id, other_nonce) = other.canonical_sort_key();
+
+        id.cmp(&other_id)
+            .then_with(|| nonce.as_int().cmp(&other_nonce.as_int()))
+            .then_with(|| self.commitment().cmp(&other.commitment()))
+            .then_with(|| self.seed.cmp(&other.seed))
+    }
+}
+
+impl PartialOrd for Account {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl TryFrom<Account> for AccountDelta {
     type Error = AccountError;
 
@@ -572,20 +617,7 @@ pub(super) fn validate_account_seed(
     let account_is_new = nonce == ZERO;
 
     match (account_is_new, seed) {
-        (true, Some(seed)) => {
-            let account_id =
-                AccountId::new(seed, id.version(), code_commitment, storage_commitment)
-                    .map_err(AccountError::SeedConvertsToInvalidAccountId)?;
-
-            if account_id != id {
-                return Err(AccountError::AccountIdSeedMismatch {
-                    expected: id,
-                    actual: account_id,
-                });
-            }
-
-            Ok(())
-        },
+        (true, Some(seed)) => id.validate_seed_for(code_commitment, storage_commitment, seed),
         (true, None) => Err(AccountError::NewAccountMissingSeed),
         (false, Some(_)) => Err(AccountError::ExistingAccountWithSeed),
         (false, None) => Ok(()),
@@ -614,12 +646,13 @@ fn validate_components_support_account_type(
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
     use alloc::vec::Vec;
 
     use assert_matches::assert_matches;
     use miden_assembly::Assembler;
     use miden_core::FieldElement;
-    use miden_crypto::utils::{Deserializable, Serializable};
+    use miden_crypto::utils::{ByteWriter, Deserializable, DeserializationError, Serializable};
     use miden_crypto::{Felt, Word};
 
     use super::{
@@ -859,6 +892,37 @@ mod tests {
         Account::new_existing(id, vault, storage, code, nonce)
     }
 
+    #[test]
+    fn test_account_ordering() {
+        let account_nonce_1 = build_account(vec![], Felt::new(1), vec![]);
+        let account_nonce_2 = build_account(vec![], Felt::new(2), vec![]);
+        let account_nonce_2_with_asset =
+            build_account(vec![FungibleAsset::mock(10)], Felt::new(2), vec![]);
+        let other_account = Account::new_existing(
+            AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER).unwrap(),
+            AssetVault::default(),
+            AccountStorage::new(vec![]).unwrap(),
+            AccountCode::mock(),
+            Felt::new(1),
+        );
+
+        assert_eq!(account_nonce_1.canonical_sort_key(), (account_nonce_1.id(), Felt::new(1)));
+        assert!(account_nonce_1 < account_nonce_2);
+        assert_eq!(
+            account_nonce_1.id() < other_account.id(),
+            account_nonce_1 < other_account,
+            "accounts should be ordered by ID first"
+        );
+
+        // Distinct accounts with the same sort key are not considered equal by the ordering.
+        assert_eq!(
+            account_nonce_2.canonical_sort_key(),
+            account_nonce_2_with_asset.canonical_sort_key()
+        );
+        assert_ne!(account_nonce_2.cmp(&account_nonce_2_with_asset), core::cmp::Ordering::Equal);
+        assert_eq!(account_nonce_2.cmp(&account_nonce_2.clone()), core::cmp::Ordering::Equal);
+    }
+
     /// Tests that initializing code and storage from a component which does not support the given
     /// account type returns an error.
     #[test]
@@ -992,4 +1056,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn account_deserialization_rejects_malformed_input() {
+        let storage_slot = StorageSlot::Value(Word::from([1, 2, 3, 4u32]));
+        let account =
+            build_account(vec![FungibleAsset::mock(99)], Felt::new(1), vec![storage_slot]);
+        let bytes = account.to_bytes();
+
+        // truncated input
+        for len in (0..bytes.len()).step_by(7) {
+            assert!(Account::read_from_bytes(&bytes[..len]).is_err());
+        }
+
+        // an inflated number of vault assets is rejected before any assets are read
+        let id_len = account.id().get_size_hint();
+        let mut inflated = bytes[..id_len].to_vec();
+        inflated.write_usize(1 << 60);
+        inflated.extend_from_slice(&bytes[id_len + 1..]);
+        assert_matches!(
+            Account::read_from_bytes(&inflated),
+            Err(DeserializationError::InvalidValue(msg)) if msg.contains(&(1usize << 60).to_string())
+        );
+    }
 }
@@ -120,6 +120,16 @@ impl AccountStorageHeader {
         }
     }
 
+    /// Indicates whether the slot at `index` is empty, i.e. whether its value is the empty value of
+    /// its type, see [`StorageSlotType::empty_value`].
+    ///
+    /// # Errors
+    /// - If `index` exceeds the slot count.
+    pub fn is_slot_empty(&self, index: usize) -> Result<bool, AccountError> {
+        let (slot_type, value) = self.slot(index)?;
+        Ok(*value == slot_type.empty_value())
+    }
+
     /// Converts storage slots of this account storage header into a vector of field elements.
     ///
     /// This is done by first converting each storage slot into exactly 8 elements as follows:
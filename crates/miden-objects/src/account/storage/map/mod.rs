@@ -1,4 +1,5 @@
 use alloc::collections::BTreeMap;
+use alloc::format;
 
 use miden_core::EMPTY_WORD;
 use miden_crypto::merkle::EmptySubtreeRoots;
@@ -237,16 +238,47 @@ impl Serializable for StorageMap {
 
 impl Deserializable for StorageMap {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let map = BTreeMap::read_from(source)?;
+        let num_entries = source.read_usize()?;
+
+        // Each entry consists of a key and a value word, so reject counts which cannot be backed
+        // by the remaining input before reading any entries.
+        num_entries
+            .checked_mul(2 * Word::SERIALIZED_SIZE)
+            .and_then(|min_num_bytes| source.check_eor(min_num_bytes).ok())
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue(format!(
+                    "storage map claims to contain {num_entries} entries which exceeds the remaining input"
+                ))
+            })?;
+
+        // Read the entries one at a time rather than pre-allocating memory for the claimed count.
+        let mut map = BTreeMap::new();
+        for _ in 0..num_entries {
+            let key = Word::read_from(source)?;
+            let value = Word::read_from(source)?;
+            map.insert(key, value);
+        }
+
         Ok(Self::from_btree_map(map))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
     use assert_matches::assert_matches;
 
-    use super::{Deserializable, EMPTY_STORAGE_MAP_ROOT, Serializable, StorageMap, Word};
+    use super::{
+        ByteWriter,
+        Deserializable,
+        DeserializationError,
+        EMPTY_STORAGE_MAP_ROOT,
+        Serializable,
+        StorageMap,
+        Word,
+    };
     use crate::errors::StorageMapError;
 
     #[test]
@@ -290,4 +322,31 @@ mod tests {
         let error = StorageMap::with_entries(storage_map_leaves_2).unwrap_err();
         assert_matches!(error, StorageMapError::DuplicateKey { .. });
     }
+
+    #[test]
+    fn storage_map_deserialization_rejects_malformed_input() {
+        let storage_map = StorageMap::with_entries([
+            (Word::from([101, 102, 103, 104u32]), Word::from([1, 2, 3, 4u32])),
+            (Word::from([105, 106, 107, 108u32]), Word::from([5, 6, 7, 8u32])),
+        ])
+        .unwrap();
+        let bytes = storage_map.to_bytes();
+
+        // truncated input
+        for len in 0..bytes.len() {
+            assert!(StorageMap::read_from_bytes(&bytes[..len]).is_err());
+        }
+
+        // inflated entry counts are rejected before any entries are read
+        for num_entries in [3, 1 << 32, 1 << 60, usize::MAX] {
+            let mut inflated = Vec::new();
+            inflated.write_usize(num_entries);
+            inflated.extend_from_slice(&bytes[1..]);
+
+            assert_matches!(
+                StorageMap::read_from_bytes(&inflated),
+                Err(DeserializationError::InvalidValue(msg)) if msg.contains(&num_entries.to_string())
+            );
+        }
+    }
 }
@@ -12,7 +12,8 @@ use miden_crypto::merkle::{
     SmtProof,
 };
 
-use crate::account::{StorageMap, StorageMapWitness};
+use crate::AccountError;
+use crate::account::{StorageMap, StorageMapDelta, StorageMapWitness};
 use crate::utils::serde::{ByteReader, DeserializationError};
 
 /// A partial representation of a [`StorageMap`], containing only proofs for a subset of the
@@ -159,6 +160,38 @@ impl PartialStorageMap {
         self.entries.extend(witness.entries().map(|(key, value)| (*key, *value)));
         self.partial_smt.add_proof(SmtProof::from(witness))
     }
+
+    /// Applies the provided [`StorageMapDelta`] to this partial storage map, updating the values of
+    /// the tracked keys as well as the root of the map.
+    ///
+    /// This allows keeping the proofs of a partial storage map synchronized with the full storage
+    /// map without having to rebuild them from the full map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the delta updates a key which is not tracked by this partial storage map. In this case,
+    ///   the map is left unchanged.
+    /// - updating a key would exceed the maximum number of entries in a leaf.
+    pub fn apply_delta(&mut self, delta: &StorageMapDelta) -> Result<(), AccountError> {
+        // Check that all keys are tracked before updating any of them, so a delta which cannot be
+        // applied does not leave the map in a partially updated state.
+        if let Some(untracked_key) =
+            delta.entries().keys().find(|key| self.get(key.inner()).is_none())
+        {
+            return Err(AccountError::StorageMapKeyNotTracked(untracked_key.into_inner()));
+        }
+
+        for (key, value) in delta.entries() {
+            let raw_key = key.into_inner();
+            self.partial_smt
+                .insert(StorageMap::hash_key(raw_key), *value)
+                .map_err(AccountError::MaxNumStorageMapLeavesExceeded)?;
+            self.entries.insert(raw_key, *value);
+        }
+
+        Ok(())
+    }
 }
 
 impl Serializable for PartialStorageMap {
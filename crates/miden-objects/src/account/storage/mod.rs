@@ -1,3 +1,4 @@
+use alloc::collections::BTreeMap;
 use alloc::string::ToString;
 use alloc::vec::Vec;
 
@@ -11,6 +12,7 @@ use super::{
     Felt,
     Hasher,
     Serializable,
+    StorageSlotTypeChange,
     Word,
 };
 use crate::account::{AccountComponent, AccountType};
@@ -39,9 +41,15 @@ pub use partial::PartialStorage;
 /// - [StorageSlot::Map]: contains a [StorageMap] which is a key-value map where both keys and
 ///   values are [Word]s. The value of a storage slot containing a map is the commitment to the
 ///   underlying map.
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
+///
+/// Slots can additionally be addressed by their [SlotName] if the components from which the
+/// storage was created define names for them (see [AccountStorage::slot_names]). Slot names are
+/// serialized with the storage, but they are not part of the storage commitment and are therefore
+/// not considered for equality.
+#[derive(Debug, Clone, Default)]
 pub struct AccountStorage {
     slots: Vec<StorageSlot>,
+    slot_names: BTreeMap<SlotName, u8>,
 }
 
 impl AccountStorage {
@@ -64,7 +72,7 @@ impl AccountStorage {
             return Err(AccountError::StorageTooManySlots(num_slots as u64));
         }
 
-        Ok(Self { slots })
+        Ok(Self { slots, slot_names: BTreeMap::new() })
     }
 
     /// Creates an [`AccountStorage`] from the provided components' storage slots.
@@ -76,10 +84,14 @@ impl AccountStorage {
     /// If the storage needs to be initialized with certain values in that slot, those can be added
     /// after construction with the standard set methods for items and maps.
     ///
+    /// The slot names of the components are registered with the index of the respective slot in
+    /// the account storage.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The number of [`StorageSlot`]s of all components exceeds 255.
+    /// - Two components define the same slot name.
     pub(super) fn from_components(
         components: &[AccountComponent],
         account_type: AccountType,
@@ -90,10 +102,25 @@ impl AccountStorage {
             _ => vec![],
         };
 
-        storage_slots
-            .extend(components.iter().flat_map(|component| component.storage_slots()).cloned());
+        let mut slot_names = BTreeMap::new();
+        for component in components {
+            for (slot_name, component_index) in component.slot_names() {
+                let index = storage_slots.len() + *component_index as usize;
+                if slot_names.insert(slot_name.clone(), index).is_some() {
+                    return Err(AccountError::DuplicateStorageSlotName(slot_name.clone()));
+                }
+            }
+            storage_slots.extend(component.storage_slots().iter().cloned());
+        }
+
+        let mut storage = Self::new(storage_slots)?;
+        // The indices fit into a u8 since the number of slots was validated by the constructor.
+        storage.slot_names = slot_names
+            .into_iter()
+            .map(|(slot_name, index)| (slot_name, index as u8))
+            .collect();
 
-        Self::new(storage_slots)
+        Ok(storage)
     }
 
     // PUBLIC ACCESSORS
@@ -116,6 +143,28 @@ impl AccountStorage {
         &self.slots
     }
 
+    /// Returns an iterator over the indices and maps of all map slots, in ascending order of their
+    /// index.
+    pub fn iter_maps(&self) -> impl Iterator<Item = (u8, &StorageMap)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| match slot {
+            // SAFETY: The constructors of account storage ensure that the number of slots fits
+            // into a u8.
+            StorageSlot::Map(map) => Some((index as u8, map)),
+            StorageSlot::Value(_) => None,
+        })
+    }
+
+    /// Returns an iterator over the indices and values of all value slots, in ascending order of
+    /// their index.
+    pub fn iter_values(&self) -> impl Iterator<Item = (u8, Word)> + '_ {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| match slot {
+            // SAFETY: The constructors of account storage ensure that the number of slots fits
+            // into a u8.
+            StorageSlot::Value(value) => Some((index as u8, *value)),
+            StorageSlot::Map(_) => None,
+        })
+    }
+
     /// Consumes self and returns the storage slots of the account storage.
     pub fn into_slots(self) -> Vec<StorageSlot> {
         self.slots
@@ -128,6 +177,14 @@ impl AccountStorage {
         )
     }
 
+    /// Returns the names of the storage slots, mapped to their indices in this storage.
+    ///
+    /// The map is populated from the slot names of the components this storage was created from,
+    /// see [`AccountComponent::slot_names`]. Storage created in other ways has no slot names.
+    pub fn slot_names(&self) -> &BTreeMap<SlotName, u8> {
+        &self.slot_names
+    }
+
     /// Returns an item from the storage at the specified index.
     ///
     /// # Errors:
@@ -142,6 +199,14 @@ impl AccountStorage {
             .map(|slot| slot.value())
     }
 
+    /// Returns an item from the storage slot with the specified name.
+    ///
+    /// # Errors:
+    /// - If no slot with the provided name exists.
+    pub fn get_item_by_name(&self, name: &SlotName) -> Result<Word, AccountError> {
+        self.get_item(self.slot_index_by_name(name)?)
+    }
+
     /// Returns a map item from a map located in storage at the specified index.
     ///
     /// # Errors:
@@ -178,6 +243,23 @@ impl AccountStorage {
     pub(super) fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
         let len = self.slots.len() as u8;
 
+        // change slot types, the initial entries of converted maps are applied as map updates
+        for (&idx, type_change) in delta.slot_type_changes().iter() {
+            let storage_slot = self
+                .slots
+                .get_mut(idx as usize)
+                .ok_or(AccountError::StorageIndexOutOfBounds { slots_len: len, index: idx })?;
+
+            match (type_change, &storage_slot) {
+                (StorageSlotTypeChange::ValueToMap, StorageSlot::Value(_)) => {
+                    *storage_slot = StorageSlot::empty_map();
+                },
+                (StorageSlotTypeChange::ValueToMap, _) => {
+                    return Err(AccountError::StorageSlotNotValue(idx));
+                },
+            }
+        }
+
         // update storage maps
         for (&idx, map) in delta.maps().iter() {
             let storage_slot = self
@@ -201,6 +283,52 @@ impl AccountStorage {
         Ok(())
     }
 
+    /// Converts the value slot at the specified index into a map slot containing the provided
+    /// initial entries and returns the delta describing the conversion.
+    ///
+    /// The previous value of the slot is discarded. If the initial entries contain the same key
+    /// multiple times, the last value for the key is used.
+    ///
+    /// After the conversion, the storage is identical to a storage created with the resulting map
+    /// at the specified index.
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    /// - If the [StorageSlot] is not [StorageSlotType::Value]
+    pub fn convert_slot_to_map(
+        &mut self,
+        index: u8,
+        initial_entries: &[(Word, Word)],
+    ) -> Result<AccountStorageDelta, AccountError> {
+        let mut delta = AccountStorageDelta::new();
+        delta
+            .convert_slot_to_map(index, initial_entries.iter().copied())
+            .expect("converting a slot of an empty delta should not conflict");
+
+        delta.apply(self)?;
+
+        Ok(delta)
+    }
+
+    /// Resets the storage slot at the specified index to an empty slot of the same type and returns
+    /// the previous contents of the slot.
+    ///
+    /// Value slots are reset to the empty word and map slots to an empty map, see
+    /// [`StorageSlot::empty_of_type`].
+    ///
+    /// # Errors:
+    /// - If the index is out of bounds
+    pub fn reset_slot(&mut self, index: u8) -> Result<StorageSlot, AccountError> {
+        let slots_len = self.num_slots();
+        let slot = self
+            .slots
+            .get_mut(index as usize)
+            .ok_or(AccountError::StorageIndexOutOfBounds { slots_len, index })?;
+        let empty_slot = StorageSlot::empty_of_type(slot.slot_type());
+
+        Ok(core::mem::replace(slot, empty_slot))
+    }
+
     /// Updates the value of the storage slot at the specified index.
     ///
     /// This method should be used only to update value slots. For updating values
@@ -232,6 +360,17 @@ impl AccountStorage {
         Ok(old_value)
     }
 
+    /// Updates the value of the storage slot with the specified name.
+    ///
+    /// See [AccountStorage::set_item()] for details.
+    ///
+    /// # Errors:
+    /// - If no slot with the provided name exists.
+    /// - If the [StorageSlot] is not [StorageSlotType::Value]
+    pub fn set_item_by_name(&mut self, name: &SlotName, value: Word) -> Result<(), AccountError> {
+        self.set_item(self.slot_index_by_name(name)?, value).map(|_| ())
+    }
+
     /// Updates the value of a key-value pair of a storage map at the specified index.
     ///
     /// This method should be used only to update storage maps. For updating values
@@ -269,8 +408,31 @@ impl AccountStorage {
 
         Ok((old_root, old_value))
     }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the slot with the provided name.
+    fn slot_index_by_name(&self, name: &SlotName) -> Result<u8, AccountError> {
+        self.slot_names
+            .get(name)
+            .copied()
+            .ok_or_else(|| AccountError::StorageSlotNameNotFound(name.clone()))
+    }
+}
+
+// EQUALITY
+// ================================================================================================
+
+/// Slot names are not part of the storage commitment, so they are not considered for equality.
+impl PartialEq for AccountStorage {
+    fn eq(&self, other: &Self) -> bool {
+        self.slots == other.slots
+    }
 }
 
+impl Eq for AccountStorage {}
+
 // ITERATORS
 // ================================================================================================
 
@@ -307,6 +469,7 @@ impl Serializable for AccountStorage {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write_u8(self.slots().len() as u8);
         target.write_many(self.slots());
+        self.slot_names.write_into(target);
     }
 
     fn get_size_hint(&self) -> usize {
@@ -318,16 +481,37 @@ impl Serializable for AccountStorage {
             size += slot.get_size_hint();
         }
 
-        size
+        size + self.slot_names.get_size_hint()
     }
 }
 
 impl Deserializable for AccountStorage {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // The number of slots is serialized as a u8, so it cannot exceed MAX_NUM_STORAGE_SLOTS.
         let num_slots = source.read_u8()? as usize;
-        let slots = source.read_many::<StorageSlot>(num_slots)?;
 
-        Self::new(slots).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        // Read the slots one at a time rather than pre-allocating memory for the claimed count.
+        let mut slots = Vec::new();
+        for _ in 0..num_slots {
+            slots.push(StorageSlot::read_from(source)?);
+        }
+
+        let mut storage =
+            Self::new(slots).map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let slot_names = BTreeMap::<SlotName, u8>::read_from(source)?;
+        if let Some((slot_name, index)) =
+            slot_names.iter().find(|(_, index)| **index as usize >= storage.slots.len())
+        {
+            return Err(DeserializationError::InvalidValue(format!(
+                "slot name {} refers to slot {index} but the storage has only {} slots",
+                slot_name.as_str(),
+                storage.slots.len()
+            )));
+        }
+        storage.slot_names = slot_names;
+
+        Ok(storage)
     }
 }
 
@@ -336,15 +520,37 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use assert_matches::assert_matches;
+
     use super::{
         AccountStorage,
+        ByteWriter,
         Deserializable,
+        DeserializationError,
         Serializable,
         StorageMap,
+        StorageSlotType,
         Word,
         build_slots_commitment,
     };
-    use crate::account::StorageSlot;
+    use crate::account::{
+        AccountComponent,
+        AccountComponentMetadata,
+        AccountComponentTemplate,
+        AccountType,
+        FeltRepresentation,
+        InitStorageData,
+        SlotName,
+        StorageEntry,
+        StorageSlot,
+        StorageValueName,
+        WordRepresentation,
+    };
+    use crate::testing::account_code::CODE;
+    use crate::{AccountError, Felt};
 
     #[test]
     fn test_serde_account_storage() {
@@ -363,10 +569,219 @@ mod tests {
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
 
+    #[test]
+    fn test_account_storage_iter_maps_and_values() {
+        let map = StorageMap::with_entries([(Word::from([1, 2, 3, 4u32]), Word::from([5u32; 4]))])
+            .unwrap();
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::from([1u32; 4])),
+            StorageSlot::Map(map.clone()),
+            StorageSlot::Value(Word::from([2u32; 4])),
+            StorageSlot::Map(StorageMap::default()),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            storage.iter_maps().collect::<Vec<_>>(),
+            [(1, &map), (3, &StorageMap::default())]
+        );
+        assert_eq!(
+            storage.iter_values().collect::<Vec<_>>(),
+            [(0, Word::from([1u32; 4])), (2, Word::from([2u32; 4]))]
+        );
+    }
+
+    #[test]
+    fn test_account_storage_reset_slot() -> anyhow::Result<()> {
+        let map = StorageMap::with_entries([(Word::from([1, 2, 3, 4u32]), Word::from([5u32; 4]))])?;
+        let value_slot = StorageSlot::Value(Word::from([1u32; 4]));
+        let map_slot = StorageSlot::Map(map);
+        let mut storage = AccountStorage::new(vec![value_slot.clone(), map_slot.clone()])?;
+        assert!(!storage.to_header().is_slot_empty(0)?);
+        assert!(!storage.to_header().is_slot_empty(1)?);
+
+        assert_eq!(storage.reset_slot(0)?, value_slot);
+        assert_eq!(storage.reset_slot(1)?, map_slot);
+        assert_matches!(
+            storage.reset_slot(2),
+            Err(AccountError::StorageIndexOutOfBounds { slots_len: 2, index: 2 })
+        );
+
+        let empty_storage = AccountStorage::new(vec![
+            StorageSlot::empty_of_type(StorageSlotType::Value),
+            StorageSlot::empty_of_type(StorageSlotType::Map),
+        ])?;
+        assert!(storage.slots().iter().all(StorageSlot::is_empty));
+        assert_eq!(
+            storage.to_header().slots().map(|(slot_type, _)| *slot_type).collect::<Vec<_>>(),
+            [StorageSlotType::Value, StorageSlotType::Map]
+        );
+        assert!(storage.to_header().is_slot_empty(0)?);
+        assert!(storage.to_header().is_slot_empty(1)?);
+        assert_eq!(storage.commitment(), empty_storage.commitment());
+
+        Ok(())
+    }
+
     #[test]
     fn test_account_storage_slots_commitment() {
         let storage = AccountStorage::mock();
         let storage_slots_commitment = build_slots_commitment(storage.slots());
         assert_eq!(storage_slots_commitment, storage.commitment())
     }
+
+    #[test]
+    fn test_convert_slot_to_map() -> anyhow::Result<()> {
+        let value = Word::from([1, 2, 3, 4u32]);
+        let entries = [(Word::from([5, 6, 7, 8u32]), value), (Word::from([9, 0, 0, 0u32]), value)];
+
+        let mut storage =
+            AccountStorage::new(vec![StorageSlot::Value(value), StorageSlot::Value(value)])?;
+        let original_storage = storage.clone();
+
+        let delta = storage.convert_slot_to_map(1, &entries)?;
+
+        // The converted storage should be identical to a storage built with the map in place.
+        let expected_storage = AccountStorage::new(vec![
+            StorageSlot::Value(value),
+            StorageSlot::Map(StorageMap::with_entries(entries)?),
+        ])?;
+        assert_eq!(storage, expected_storage);
+        assert_eq!(storage.commitment(), expected_storage.commitment());
+
+        // Applying the returned delta to the original storage should result in the same storage.
+        let mut applied_storage = original_storage.clone();
+        delta.apply(&mut applied_storage)?;
+        assert_eq!(applied_storage, expected_storage);
+
+        // Map slots and out of bounds slots cannot be converted.
+        assert!(matches!(
+            storage.convert_slot_to_map(1, &entries).unwrap_err(),
+            AccountError::StorageSlotNotValue(1)
+        ));
+        assert!(matches!(
+            storage.convert_slot_to_map(2, &entries).unwrap_err(),
+            AccountError::StorageIndexOutOfBounds { slots_len: 2, index: 2 }
+        ));
+        assert_eq!(storage, expected_storage);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_account_storage_slot_names() -> anyhow::Result<()> {
+        let value_entry = |slot: u8, name: &str, value: u64| -> anyhow::Result<StorageEntry> {
+            let felts = [0, 0, 0, value].map(|felt| FeltRepresentation::from(Felt::new(felt)));
+            let word =
+                WordRepresentation::new_value(felts, Some(StorageValueName::new(name)?.into()));
+            Ok(StorageEntry::new_value(slot, word))
+        };
+
+        let metadata = AccountComponentMetadata::new(
+            "test_component".into(),
+            "A test component".into(),
+            semver::Version::new(1, 0, 0),
+            [AccountType::FungibleFaucet].into_iter().collect(),
+            vec![
+                value_entry(0, "test.first", 1)?,
+                value_entry(1, "test.second", 2)?,
+                // a single segment is not a valid slot name, so the slot cannot be named
+                value_entry(2, "third", 3)?,
+            ],
+        )?;
+        let library = miden_assembly::Assembler::default().assemble_library([CODE]).unwrap();
+        let template = AccountComponentTemplate::new(metadata, library);
+        let component = AccountComponent::from_template(&template, &InitStorageData::default())?;
+
+        let first = SlotName::new("test::first")?;
+        let second = SlotName::new("test::second")?;
+
+        // The reserved faucet slot shifts the component slots by one.
+        let mut storage = AccountStorage::from_components(
+            core::slice::from_ref(&component),
+            AccountType::FungibleFaucet,
+        )?;
+        assert_eq!(storage.slot_names().len(), 2);
+        assert_eq!(storage.slot_names()[&first], 1);
+        assert_eq!(storage.slot_names()[&second], 2);
+        assert_eq!(storage.get_item_by_name(&first)?, Word::from([0, 0, 0, 1u32]));
+
+        storage.set_item_by_name(&second, Word::from([5, 6, 7, 8u32]))?;
+        assert_eq!(storage.get_item(2)?, Word::from([5, 6, 7, 8u32]));
+
+        let unknown = SlotName::new("test::unknown")?;
+        assert_matches::assert_matches!(
+            storage.get_item_by_name(&unknown).unwrap_err(),
+            AccountError::StorageSlotNameNotFound(name) if name == unknown
+        );
+
+        // Two components may not define the same slot name.
+        assert_matches::assert_matches!(
+            AccountStorage::from_components(&[component.clone(), component], AccountType::FungibleFaucet)
+                .unwrap_err(),
+            AccountError::DuplicateStorageSlotName(name) if name == first
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_serde_account_storage_slot_names() -> anyhow::Result<()> {
+        let first = SlotName::new("test::first")?;
+        let second = SlotName::new("test::second")?;
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::from([1, 2, 3, 4u32])),
+            StorageSlot::Map(StorageMap::default()),
+        ])?;
+        storage.slot_names = [(first.clone(), 0), (second.clone(), 1)].into_iter().collect();
+
+        let bytes = storage.to_bytes();
+        assert_eq!(bytes.len(), storage.get_size_hint());
+        let deserialized = AccountStorage::read_from_bytes(&bytes)?;
+        assert_eq!(deserialized, storage);
+        assert_eq!(deserialized.slot_names(), storage.slot_names());
+        assert_eq!(deserialized.get_item_by_name(&first)?, Word::from([1, 2, 3, 4u32]));
+
+        // Slot names referring to slots which do not exist are rejected.
+        storage.slot_names.insert(SlotName::new("test::third")?, 2);
+        assert_matches!(
+            AccountStorage::read_from_bytes(&storage.to_bytes()),
+            Err(DeserializationError::InvalidValue(_))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn account_storage_deserialization_rejects_malformed_input() {
+        let storage_map =
+            StorageMap::with_entries([(Word::from([1, 2, 3, 4u32]), Word::from([5, 6, 7, 8u32]))])
+                .unwrap();
+        let storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::from([1, 2, 3, 4u32])),
+            StorageSlot::Map(storage_map),
+        ])
+        .unwrap();
+        let bytes = storage.to_bytes();
+
+        // truncated input
+        for len in 0..bytes.len() {
+            assert!(AccountStorage::read_from_bytes(&bytes[..len]).is_err());
+        }
+
+        // an inflated slot count fails once the input is exhausted
+        let mut inflated = bytes.clone();
+        inflated[0] = u8::MAX;
+        assert!(AccountStorage::read_from_bytes(&inflated).is_err());
+
+        // an inflated number of map entries is rejected before any entries are read
+        let mut inflated = Vec::new();
+        inflated.write_u8(1);
+        StorageSlotType::Map.write_into(&mut inflated);
+        inflated.write_usize(1 << 60);
+        assert_matches::assert_matches!(
+            AccountStorage::read_from_bytes(&inflated),
+            Err(DeserializationError::InvalidValue(msg)) if msg.contains(&(1usize << 60).to_string())
+        );
+    }
 }
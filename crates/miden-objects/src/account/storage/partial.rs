@@ -1,12 +1,13 @@
 use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
 
 use miden_core::utils::{Deserializable, Serializable};
 use miden_crypto::Word;
 use miden_crypto::merkle::{InnerNodeInfo, SmtLeaf};
 
-use super::{AccountStorage, AccountStorageHeader, StorageSlot};
+use super::{AccountStorage, AccountStorageHeader, StorageMap, StorageSlot, StorageSlotType};
 use crate::AccountError;
-use crate::account::PartialStorageMap;
+use crate::account::{AccountStorageDelta, PartialStorageMap};
 
 /// A partial representation of an account storage, containing only a subset of the storage data.
 ///
@@ -79,11 +80,9 @@ impl PartialStorage {
         let commitment = header.compute_commitment();
 
         let mut maps = BTreeMap::new();
-        for slot in account_storage.slots() {
-            if let StorageSlot::Map(storage_map) = slot {
-                let partial_map = PartialStorageMap::new_minimal(storage_map);
-                maps.insert(partial_map.root(), partial_map);
-            }
+        for (_, storage_map) in account_storage.iter_maps() {
+            let partial_map = PartialStorageMap::new_minimal(storage_map);
+            maps.insert(partial_map.root(), partial_map);
         }
 
         PartialStorage { header, maps, commitment }
@@ -129,6 +128,105 @@ impl PartialStorage {
     pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> + '_ {
         self.maps().flat_map(|map| map.leaves()).map(|(_, leaf)| leaf)
     }
+
+    // MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the provided [`AccountStorageDelta`] to this partial storage, updating the storage
+    /// header, the tracked entries of the storage maps and the storage commitment.
+    ///
+    /// This allows a long-lived partial storage to be kept synchronized with the account by
+    /// consuming the deltas of its transactions, e.g. from proven transactions. Value slots are
+    /// always updated, while map updates must only touch keys tracked by the respective
+    /// [`PartialStorageMap`], see [`PartialStorageMap::apply_delta`]. A value slot converted into a
+    /// map slot starts out as an empty map of which all keys are tracked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Any updated slot index is out of bounds.
+    /// - A value update targets a slot which is not a value slot.
+    /// - A map update targets a slot which is not a map slot, unless the slot is converted into a
+    ///   map slot by this delta.
+    /// - A slot type change targets a slot whose type does not match the type before the change.
+    /// - A map update touches a key which is not tracked by this partial storage.
+    ///
+    /// If an error is returned, the partial storage is left unchanged.
+    pub fn apply_delta(&mut self, delta: &AccountStorageDelta) -> Result<(), AccountError> {
+        let mut slots: Vec<(StorageSlotType, Word)> = self.header.slots().copied().collect();
+
+        for (&index, type_change) in delta.slot_type_changes() {
+            let slot = slot_mut(&mut slots, index)?;
+            if slot.0 != type_change.from_type() {
+                return Err(match slot.0 {
+                    StorageSlotType::Map => AccountError::StorageSlotNotValue(index),
+                    StorageSlotType::Value => AccountError::StorageSlotNotMap(index),
+                });
+            }
+            *slot = (type_change.to_type(), StorageMap::new().root());
+        }
+
+        for (&index, &value) in delta.values() {
+            let slot = slot_mut(&mut slots, index)?;
+            if slot.0 != StorageSlotType::Value {
+                return Err(AccountError::StorageSlotNotValue(index));
+            }
+            slot.1 = value;
+        }
+
+        let mut updated_maps = Vec::with_capacity(delta.maps().len());
+        for (&index, map_delta) in delta.maps() {
+            let slot = slot_mut(&mut slots, index)?;
+            if slot.0 != StorageSlotType::Map {
+                return Err(AccountError::StorageSlotNotMap(index));
+            }
+
+            let mut partial_map = if delta.slot_type_changes().contains_key(&index) {
+                // The converted slot starts out as an empty map, so the keys of the delta can be
+                // tracked by opening them against an empty map.
+                let empty_map = StorageMap::new();
+                PartialStorageMap::from_witnesses(
+                    map_delta.entries().keys().map(|key| empty_map.open(key.inner())),
+                )
+                .expect("proofs opened against the same map should be consistent")
+            } else {
+                self.maps
+                    .get(&slot.1)
+                    .cloned()
+                    .ok_or(AccountError::StorageMapRootNotFound(slot.1))?
+            };
+            partial_map.apply_delta(map_delta)?;
+
+            slot.1 = partial_map.root();
+            updated_maps.push(partial_map);
+        }
+
+        self.header = AccountStorageHeader::new(slots);
+        self.commitment = self.header.compute_commitment();
+
+        for partial_map in updated_maps {
+            self.maps.insert(partial_map.root(), partial_map);
+        }
+        let map_roots: BTreeSet<_> = self.header.map_slot_roots().collect();
+        self.maps.retain(|root, _| map_roots.contains(root));
+
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a mutable reference to the slot at the provided index, or an error if the index is out
+/// of bounds.
+fn slot_mut(
+    slots: &mut [(StorageSlotType, Word)],
+    index: u8,
+) -> Result<&mut (StorageSlotType, Word), AccountError> {
+    let slots_len = slots.len() as u8;
+    slots
+        .get_mut(index as usize)
+        .ok_or(AccountError::StorageIndexOutOfBounds { slots_len, index })
 }
 
 impl Serializable for PartialStorage {
@@ -156,15 +254,26 @@ mod tests {
     use anyhow::Context;
     use miden_core::Word;
 
+    use crate::AccountError;
     use crate::account::{
         AccountStorage,
+        AccountStorageDelta,
         AccountStorageHeader,
         PartialStorage,
         PartialStorageMap,
         StorageMap,
+        StorageMapDelta,
         StorageSlot,
     };
 
+    /// Returns a storage map with 100 entries.
+    fn storage_map_with_100_entries() -> StorageMap {
+        StorageMap::with_entries(
+            (0..100u32).map(|i| (Word::from([i, 0, 0, 0]), Word::from([i; 4]))),
+        )
+        .unwrap()
+    }
+
     #[test]
     pub fn new_partial_storage() -> anyhow::Result<()> {
         let map_key_present: Word = [1u64, 2, 3, 4].try_into()?;
@@ -190,4 +299,87 @@ mod tests {
         assert!(retrieved_map.open(&map_key_present).is_ok());
         Ok(())
     }
+
+    #[test]
+    fn partial_storage_map_apply_delta() -> anyhow::Result<()> {
+        let mut map = storage_map_with_100_entries();
+        let tracked_key_0 = Word::from([3u32, 0, 0, 0]);
+        let tracked_key_1 = Word::from([42u32, 0, 0, 0]);
+        let mut partial_map = PartialStorageMap::from_witnesses([
+            map.open(&tracked_key_0),
+            map.open(&tracked_key_1),
+        ])?;
+        assert_eq!(partial_map.root(), map.root());
+
+        // A delta touching untracked keys cannot be applied and leaves the map unchanged.
+        let mut delta = StorageMapDelta::default();
+        delta.insert(tracked_key_0, Word::from([7u32; 4]));
+        for i in [10, 20, 30u32] {
+            delta.insert(Word::from([i, 0, 0, 0]), Word::from([9u32; 4]));
+        }
+        let untracked_key = Word::from([10u32, 0, 0, 0]);
+        assert!(matches!(
+            partial_map.apply_delta(&delta),
+            Err(AccountError::StorageMapKeyNotTracked(key)) if key == untracked_key
+        ));
+        assert_eq!(partial_map.root(), map.root());
+        assert_eq!(partial_map.get(&tracked_key_0), Some(Word::from([3u32; 4])));
+
+        // A delta touching only tracked keys updates the root to match the full map.
+        let mut delta = StorageMapDelta::default();
+        delta.insert(tracked_key_0, Word::from([7u32; 4]));
+        delta.insert(tracked_key_1, Word::empty());
+        partial_map.apply_delta(&delta)?;
+        map.insert(tracked_key_0, Word::from([7u32; 4]))?;
+        map.insert(tracked_key_1, Word::empty())?;
+
+        assert_eq!(partial_map.root(), map.root());
+        assert_eq!(partial_map.get(&tracked_key_0), Some(Word::from([7u32; 4])));
+        assert_eq!(partial_map.get(&tracked_key_1), Some(Word::empty()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_storage_apply_delta() -> anyhow::Result<()> {
+        let map = storage_map_with_100_entries();
+        let tracked_key = Word::from([3u32, 0, 0, 0]);
+        let mut storage = AccountStorage::new(vec![
+            StorageSlot::Value(Word::from([1u32; 4])),
+            StorageSlot::Map(map.clone()),
+            StorageSlot::Value(Word::empty()),
+        ])?;
+        let mut partial_storage = PartialStorage::new(
+            AccountStorageHeader::from(&storage),
+            [PartialStorageMap::from_witnesses([map.open(&tracked_key)])?],
+        )?;
+
+        // A delta touching an untracked key leaves the partial storage unchanged.
+        let mut delta = AccountStorageDelta::new();
+        delta.set_item(0, Word::from([2u32; 4]));
+        delta.set_map_item(1, Word::from([4u32, 0, 0, 0]), Word::from([5u32; 4]));
+        let partial_storage_before = partial_storage.clone();
+        assert!(matches!(
+            partial_storage.apply_delta(&delta),
+            Err(AccountError::StorageMapKeyNotTracked(_))
+        ));
+        assert_eq!(partial_storage, partial_storage_before);
+
+        // A delta updating values, tracked map keys and converting a slot into a map is applied.
+        let mut delta = AccountStorageDelta::new();
+        delta.set_item(0, Word::from([2u32; 4]));
+        delta.set_map_item(1, tracked_key, Word::from([5u32; 4]));
+        delta.convert_slot_to_map(2, [(Word::from([6u32; 4]), Word::from([7u32; 4]))])?;
+        partial_storage.apply_delta(&delta)?;
+        delta.apply(&mut storage)?;
+
+        assert_eq!(partial_storage.header(), &storage.to_header());
+        assert_eq!(partial_storage.commitment(), storage.commitment());
+        assert_eq!(partial_storage.maps().count(), 2);
+        for partial_map in partial_storage.maps() {
+            assert!(storage.to_header().map_slot_roots().any(|root| root == partial_map.root()));
+        }
+
+        Ok(())
+    }
 }
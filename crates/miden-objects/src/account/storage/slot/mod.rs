@@ -2,7 +2,6 @@ use miden_core::EMPTY_WORD;
 use miden_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 use miden_processor::DeserializationError;
 
-use super::map::EMPTY_STORAGE_MAP_ROOT;
 use super::{StorageMap, Word};
 
 mod slot_name;
@@ -32,17 +31,25 @@ impl StorageSlot {
 
     /// Returns true if this storage slot has a value equal the default of it's type
     pub fn is_default(&self) -> bool {
-        match self {
-            StorageSlot::Value(value) => *value == EMPTY_WORD,
-            StorageSlot::Map(map) => map.root() == EMPTY_STORAGE_MAP_ROOT,
-        }
+        self.is_empty()
+    }
+
+    /// Returns true if this storage slot is empty, i.e. if it is a value slot containing the empty
+    /// word or a map slot containing an empty map.
+    pub fn is_empty(&self) -> bool {
+        self.value() == self.default_word()
     }
 
     /// Returns the empty [Word] for a storage slot of this type
     pub fn default_word(&self) -> Word {
-        match self {
-            StorageSlot::Value(_) => EMPTY_WORD,
-            StorageSlot::Map(_) => EMPTY_STORAGE_MAP_ROOT,
+        self.slot_type().empty_value()
+    }
+
+    /// Returns an empty storage slot of the provided type, see [`StorageSlot::is_empty`].
+    pub fn empty_of_type(slot_type: StorageSlotType) -> Self {
+        match slot_type {
+            StorageSlotType::Value => Self::empty_value(),
+            StorageSlotType::Map => Self::empty_map(),
         }
     }
 
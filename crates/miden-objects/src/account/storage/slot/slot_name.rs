@@ -1,5 +1,8 @@
 use alloc::borrow::Cow;
-use alloc::string::String;
+use alloc::string::{String, ToString};
+
+use miden_core::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
+use miden_processor::DeserializationError;
 
 use crate::errors::SlotNameError;
 
@@ -178,6 +181,26 @@ impl SlotName {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for SlotName {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.as_str().write_into(target);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.as_str().get_size_hint()
+    }
+}
+
+impl Deserializable for SlotName {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let name: String = source.read()?;
+        Self::new(name).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
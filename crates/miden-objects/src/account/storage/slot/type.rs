@@ -1,5 +1,6 @@
 use alloc::string::{String, ToString};
 
+use crate::account::storage::map::EMPTY_STORAGE_MAP_ROOT;
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -7,7 +8,7 @@ use crate::utils::serde::{
     DeserializationError,
     Serializable,
 };
-use crate::{Felt, Word};
+use crate::{EMPTY_WORD, Felt, Word};
 
 // STORAGE SLOT TYPE
 // ================================================================================================
@@ -30,6 +31,15 @@ impl StorageSlotType {
         }
     }
 
+    /// Returns the value of an empty slot of this type, i.e. the empty word for value slots and
+    /// the root of an empty storage map for map slots.
+    pub fn empty_value(&self) -> Word {
+        match self {
+            StorageSlotType::Value => EMPTY_WORD,
+            StorageSlotType::Map => EMPTY_STORAGE_MAP_ROOT,
+        }
+    }
+
     /// Returns `true` if the slot is a value slot, `false` otherwise.
     pub fn is_value(&self) -> bool {
         matches!(self, Self::Value)
@@ -1,6 +1,6 @@
 use alloc::boxed::Box;
 use alloc::string::ToString;
-use core::fmt;
+use core::{fmt, ops};
 
 use super::vault::AssetVaultKey;
 use super::{AccountType, Asset, AssetError, Felt, Word, ZERO, is_not_a_non_fungible_asset};
@@ -100,12 +100,7 @@ impl FungibleAsset {
     /// - The total value of assets is greater than or equal to 2^63.
     #[allow(clippy::should_implement_trait)]
     pub fn add(self, other: Self) -> Result<Self, AssetError> {
-        if self.faucet_id != other.faucet_id {
-            return Err(AssetError::FungibleAssetInconsistentFaucetIds {
-                original_issuer: self.faucet_id,
-                other_issuer: other.faucet_id,
-            });
-        }
+        self.ensure_same_faucet(&other)?;
 
         let amount = self
             .amount
@@ -126,12 +121,7 @@ impl FungibleAsset {
     /// - The final amount would be negative.
     #[allow(clippy::should_implement_trait)]
     pub fn sub(self, other: Self) -> Result<Self, AssetError> {
-        if self.faucet_id != other.faucet_id {
-            return Err(AssetError::FungibleAssetInconsistentFaucetIds {
-                original_issuer: self.faucet_id,
-                other_issuer: other.faucet_id,
-            });
-        }
+        self.ensure_same_faucet(&other)?;
 
         let amount = self.amount.checked_sub(other.amount).ok_or(
             AssetError::FungibleAssetAmountNotSufficient {
@@ -143,9 +133,95 @@ impl FungibleAsset {
         Ok(FungibleAsset { faucet_id: self.faucet_id, amount })
     }
 
+    /// Adds two fungible assets together and returns the result, or `None` if the assets were not
+    /// issued by the same faucet or the total amount exceeds [`Self::MAX_AMOUNT`].
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.add(other).ok()
+    }
+
+    /// Adds two fungible assets together and returns the result, clamping the total amount to
+    /// [`Self::MAX_AMOUNT`].
+    ///
+    /// # Errors
+    /// Returns an error if the assets were not issued by the same faucet.
+    pub fn saturating_add(self, other: Self) -> Result<Self, AssetError> {
+        self.ensure_same_faucet(&other)?;
+
+        let amount = (self.amount + other.amount).min(Self::MAX_AMOUNT);
+        Ok(Self { faucet_id: self.faucet_id, amount })
+    }
+
+    /// Subtracts a fungible asset from another and returns the result, or `None` if the assets
+    /// were not issued by the same faucet or the final amount would be negative.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.sub(other).ok()
+    }
+
+    /// Subtracts a fungible asset from another and returns the result, clamping the final amount
+    /// to zero.
+    ///
+    /// # Errors
+    /// Returns an error if the assets were not issued by the same faucet.
+    pub fn saturating_sub(self, other: Self) -> Result<Self, AssetError> {
+        self.ensure_same_faucet(&other)?;
+
+        let amount = self.amount.saturating_sub(other.amount);
+        Ok(Self { faucet_id: self.faucet_id, amount })
+    }
+
+    /// Splits this asset into two assets issued by the same faucet, the first one containing
+    /// `amount` and the second one containing the remaining amount.
+    ///
+    /// # Errors
+    /// Returns an error if `amount` is greater than the amount of this asset.
+    pub fn split(self, amount: u64) -> Result<(Self, Self), AssetError> {
+        let remainder = self.amount.checked_sub(amount).ok_or(
+            AssetError::FungibleAssetAmountNotSufficient {
+                minuend: self.amount,
+                subtrahend: amount,
+            },
+        )?;
+
+        Ok((
+            Self { faucet_id: self.faucet_id, amount },
+            Self {
+                faucet_id: self.faucet_id,
+                amount: remainder,
+            },
+        ))
+    }
+
+    /// Adds all provided fungible assets together and returns the result, or `None` if the
+    /// iterator is empty.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The assets were not all issued by the same faucet.
+    /// - The total value of assets is greater than [`Self::MAX_AMOUNT`].
+    pub fn try_sum(assets: impl IntoIterator<Item = Self>) -> Result<Option<Self>, AssetError> {
+        let mut assets = assets.into_iter();
+        let Some(first) = assets.next() else {
+            return Ok(None);
+        };
+
+        assets.try_fold(first, Self::add).map(Some)
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns an error if this and the other asset were not issued by the same faucet.
+    fn ensure_same_faucet(&self, other: &Self) -> Result<(), AssetError> {
+        if self.faucet_id != other.faucet_id {
+            return Err(AssetError::FungibleAssetInconsistentFaucetIds {
+                original_issuer: self.faucet_id,
+                other_issuer: other.faucet_id,
+            });
+        }
+
+        Ok(())
+    }
+
     /// Validates this fungible asset.
     /// # Errors
     /// Returns an error if:
@@ -165,6 +241,28 @@ impl FungibleAsset {
     }
 }
 
+/// Adds two fungible assets together, see [`FungibleAsset::add`].
+///
+/// The operator returns a [`Result`] rather than panicking if the assets cannot be added.
+impl ops::Add for FungibleAsset {
+    type Output = Result<FungibleAsset, AssetError>;
+
+    fn add(self, other: Self) -> Self::Output {
+        FungibleAsset::add(self, other)
+    }
+}
+
+/// Subtracts a fungible asset from another, see [`FungibleAsset::sub`].
+///
+/// The operator returns a [`Result`] rather than panicking if the assets cannot be subtracted.
+impl ops::Sub for FungibleAsset {
+    type Output = Result<FungibleAsset, AssetError>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        FungibleAsset::sub(self, other)
+    }
+}
+
 impl From<FungibleAsset> for Word {
     fn from(asset: FungibleAsset) -> Self {
         let mut result = Word::empty();
@@ -255,6 +353,8 @@ impl FungibleAsset {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
+
     use super::*;
     use crate::account::AccountId;
     use crate::testing::account_id::{
@@ -297,4 +397,75 @@ mod tests {
         let err = FungibleAsset::read_from_bytes(&asset_bytes).unwrap_err();
         assert!(matches!(err, DeserializationError::InvalidValue(_)));
     }
+
+    #[test]
+    fn test_fungible_asset_arithmetic() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+        let asset = |amount| FungibleAsset::new(faucet_id, amount).unwrap();
+        let other_asset = FungibleAsset::new(other_faucet_id, 1).unwrap();
+        let max = FungibleAsset::MAX_AMOUNT;
+
+        // addition at the max amount boundary
+        assert_eq!(asset(max - 1).checked_add(asset(1)), Some(asset(max)));
+        assert_eq!(asset(max).checked_add(asset(1)), None);
+        assert_eq!(asset(max).saturating_add(asset(max)).unwrap(), asset(max));
+        assert_eq!((asset(max - 1) + asset(1)).unwrap(), asset(max));
+        assert_matches!(
+            (asset(max) + asset(1)).unwrap_err(),
+            AssetError::FungibleAssetAmountTooBig(amount) if amount == max + 1
+        );
+
+        // subtraction at the zero boundary
+        assert_eq!(asset(1).checked_sub(asset(1)), Some(asset(0)));
+        assert_eq!(asset(0).checked_sub(asset(1)), None);
+        assert_eq!(asset(1).saturating_sub(asset(max)).unwrap(), asset(0));
+        assert_eq!((asset(max) - asset(max)).unwrap(), asset(0));
+
+        // issuer mismatches
+        assert_eq!(asset(1).checked_add(other_asset), None);
+        assert_eq!(asset(1).checked_sub(other_asset), None);
+        for result in [
+            asset(1).saturating_add(other_asset),
+            asset(1).saturating_sub(other_asset),
+            asset(1) + other_asset,
+            asset(1) - other_asset,
+        ] {
+            assert_matches!(
+                result.unwrap_err(),
+                AssetError::FungibleAssetInconsistentFaucetIds { original_issuer, other_issuer }
+                    if original_issuer == faucet_id && other_issuer == other_faucet_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_fungible_asset_split_and_sum() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+        let asset = |amount| FungibleAsset::new(faucet_id, amount).unwrap();
+        let max = FungibleAsset::MAX_AMOUNT;
+
+        assert_eq!(asset(100).split(30).unwrap(), (asset(30), asset(70)));
+        assert_eq!(asset(max).split(max).unwrap(), (asset(max), asset(0)));
+        assert_matches!(
+            asset(100).split(101).unwrap_err(),
+            AssetError::FungibleAssetAmountNotSufficient { minuend: 100, subtrahend: 101 }
+        );
+
+        assert_eq!(FungibleAsset::try_sum([]).unwrap(), None);
+        assert_eq!(
+            FungibleAsset::try_sum([asset(1), asset(2), asset(max - 3)]).unwrap(),
+            Some(asset(max))
+        );
+        assert_matches!(
+            FungibleAsset::try_sum([asset(max), asset(1)]).unwrap_err(),
+            AssetError::FungibleAssetAmountTooBig(_)
+        );
+        assert_matches!(
+            FungibleAsset::try_sum([asset(1), FungibleAsset::new(other_faucet_id, 1).unwrap()])
+                .unwrap_err(),
+            AssetError::FungibleAssetInconsistentFaucetIds { .. }
+        );
+    }
 }
@@ -22,7 +22,7 @@ mod token_symbol;
 pub use token_symbol::TokenSymbol;
 
 mod vault;
-pub use vault::{AssetVault, AssetVaultKey, AssetWitness, PartialVault};
+pub use vault::{AssetVault, AssetVaultKey, AssetWitness, CombinedAssetWitness, PartialVault};
 
 // ASSET
 // ================================================================================================
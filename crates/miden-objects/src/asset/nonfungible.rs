@@ -125,6 +125,11 @@ impl NonFungibleAsset {
         AccountIdPrefix::new_unchecked(self.0[FAUCET_ID_POS_BE])
     }
 
+    /// Returns `true` if this asset was issued by the faucet with the provided ID prefix.
+    pub fn issuer_matches(&self, faucet: AccountIdPrefix) -> bool {
+        self.faucet_id_prefix() == faucet
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -303,4 +308,15 @@ mod tests {
         let err = NonFungibleAsset::read_from_bytes(&asset_bytes).unwrap_err();
         assert_matches!(err, DeserializationError::InvalidValue(msg) if msg.contains("must be of type NonFungibleFaucet"));
     }
+
+    #[test]
+    fn test_non_fungible_asset_issuer_matches() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET).unwrap();
+        let other_faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET_1).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id.prefix(), vec![1, 2, 3]).unwrap();
+        let asset = NonFungibleAsset::new(&details).unwrap();
+
+        assert!(asset.issuer_matches(faucet_id.prefix()));
+        assert!(!asset.issuer_matches(other_faucet_id.prefix()));
+    }
 }
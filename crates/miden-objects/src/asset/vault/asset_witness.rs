@@ -1,8 +1,11 @@
-use miden_crypto::merkle::{InnerNodeInfo, SmtLeaf, SmtProof};
+use miden_crypto::merkle::{InnerNodeInfo, PartialSmt, SmtLeaf, SmtProof};
 
 use super::vault_key::AssetVaultKey;
-use crate::AssetError;
 use crate::asset::Asset;
+use crate::{AssetError, AssetWitnessError, Word};
+
+// ASSET WITNESS
+// ================================================================================================
 
 /// A witness of an asset in an [`AssetVault`](super::AssetVault).
 ///
@@ -67,6 +70,55 @@ impl AssetWitness {
         })
     }
 
+    /// Returns `true` if this witness is a valid opening against the provided vault root, i.e. if
+    /// the root computed from the witness' leaf and merkle path is equal to `vault_root`.
+    ///
+    /// Note that this proves inclusion of all assets in the witness' leaf in the vault with the
+    /// provided root, as well as the absence of any other asset whose key maps to the same leaf.
+    pub fn verify_against_root(&self, vault_root: Word) -> bool {
+        self.0.compute_root() == vault_root
+    }
+
+    /// Verifies that this witness is a valid opening against the provided vault root.
+    ///
+    /// This is the fallible equivalent of [`Self::verify_against_root`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root computed from the witness' leaf and merkle path is not equal
+    /// to `vault_root`.
+    pub fn verify(&self, vault_root: Word) -> Result<(), AssetWitnessError> {
+        let actual = self.0.compute_root();
+        if actual != vault_root {
+            return Err(AssetWitnessError::RootMismatch { expected: vault_root, actual });
+        }
+
+        Ok(())
+    }
+
+    /// Combines the provided witnesses into a single [`CombinedAssetWitness`] proving all of
+    /// their assets at once.
+    ///
+    /// Inner nodes shared by the merkle paths of the witnesses are stored only once.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - no witnesses are provided.
+    /// - the witnesses do not all open against the same vault root.
+    pub fn combine(witnesses: &[AssetWitness]) -> Result<CombinedAssetWitness, AssetWitnessError> {
+        let (first, rest) = witnesses.split_first().ok_or(AssetWitnessError::NoWitnesses)?;
+        let root = first.0.compute_root();
+        for witness in rest {
+            witness.verify(root)?;
+        }
+
+        let partial_smt = PartialSmt::from_proofs(witnesses.iter().cloned().map(SmtProof::from))
+            .expect("proofs opening against the same root should be combinable");
+
+        Ok(CombinedAssetWitness(partial_smt))
+    }
+
     /// Returns an iterator over every inner node of this witness' merkle path.
     pub fn authenticated_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
         self.0
@@ -82,6 +134,50 @@ impl From<AssetWitness> for SmtProof {
     }
 }
 
+// COMBINED ASSET WITNESS
+// ================================================================================================
+
+/// A witness of multiple assets in an [`AssetVault`](super::AssetVault), built by combining
+/// individual [`AssetWitness`]es via [`AssetWitness::combine`].
+///
+/// It proves inclusion of all the assets of the combined witnesses in the vault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombinedAssetWitness(PartialSmt);
+
+impl CombinedAssetWitness {
+    /// Returns the vault root against which all combined witnesses open.
+    pub fn root(&self) -> Word {
+        self.0.root()
+    }
+
+    /// Returns an iterator over the [`Asset`]s in this witness.
+    pub fn assets(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.0.entries().map(|(_key, value)| {
+            Asset::try_from(value).expect("asset witness should track valid assets")
+        })
+    }
+
+    /// Verifies that this witness is a valid opening against the provided vault root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root of the combined witnesses is not equal to `vault_root`.
+    pub fn verify(&self, vault_root: Word) -> Result<(), AssetWitnessError> {
+        let actual = self.root();
+        if actual != vault_root {
+            return Err(AssetWitnessError::RootMismatch { expected: vault_root, actual });
+        }
+
+        Ok(())
+    }
+}
+
+impl From<CombinedAssetWitness> for PartialSmt {
+    fn from(witness: CombinedAssetWitness) -> Self {
+        witness.0
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -91,8 +187,7 @@ mod tests {
     use miden_crypto::merkle::Smt;
 
     use super::*;
-    use crate::Word;
-    use crate::asset::{FungibleAsset, NonFungibleAsset};
+    use crate::asset::{AssetVault, FungibleAsset, NonFungibleAsset};
 
     /// Tests that constructing an asset witness fails if any asset in the smt proof is invalid.
     #[test]
@@ -128,4 +223,33 @@ mod tests {
 
         Ok(())
     }
+
+    /// Tests that an asset witness verifies against the root of the vault it was opened from, but
+    /// not against the root of a different vault.
+    #[test]
+    fn asset_witness_verify_against_root() -> anyhow::Result<()> {
+        let fungible_asset = FungibleAsset::mock(500);
+        let non_fungible_asset = NonFungibleAsset::mock(&[1]);
+        let mut vault = AssetVault::new(&[fungible_asset, non_fungible_asset])?;
+
+        let witness = vault.open(fungible_asset.vault_key());
+        assert!(witness.verify_against_root(vault.root()));
+        witness.verify(vault.root())?;
+        assert_eq!(witness.find(fungible_asset.vault_key()), Some(fungible_asset));
+
+        // A witness of an asset that is not in the vault is a valid proof of absence.
+        let absent_asset = NonFungibleAsset::mock(&[2]);
+        assert!(vault.open(absent_asset.vault_key()).verify_against_root(vault.root()));
+
+        // After the vault is modified, the old witness should no longer verify.
+        vault.add_asset(absent_asset)?;
+        assert!(!witness.verify_against_root(vault.root()));
+        assert!(!witness.verify_against_root(Word::empty()));
+        assert_matches!(
+            witness.verify(vault.root()),
+            Err(AssetWitnessError::RootMismatch { expected, .. }) if expected == vault.root()
+        );
+
+        Ok(())
+    }
 }
@@ -1,4 +1,6 @@
+use alloc::format;
 use alloc::string::ToString;
+use alloc::vec::Vec;
 
 use miden_crypto::merkle::InnerNodeInfo;
 use miden_processor::SMT_DEPTH;
@@ -22,7 +24,7 @@ mod partial;
 pub use partial::PartialVault;
 
 mod asset_witness;
-pub use asset_witness::AssetWitness;
+pub use asset_witness::{AssetWitness, CombinedAssetWitness};
 
 mod vault_key;
 pub use vault_key::AssetVaultKey;
@@ -110,6 +112,17 @@ impl AssetVault {
         self.asset_tree.entries().map(|(_key, value)| Asset::new_unchecked(*value))
     }
 
+    /// Returns an iterator over the assets stored in the vault for which the provided predicate
+    /// returns `true`, e.g. `vault.assets_matching(Asset::is_fungible)`.
+    ///
+    /// The assets are filtered lazily while iterating.
+    pub fn assets_matching<'vault>(
+        &'vault self,
+        predicate: impl Fn(&Asset) -> bool + 'vault,
+    ) -> impl Iterator<Item = Asset> + 'vault {
+        self.assets().filter(move |asset| predicate(asset))
+    }
+
     /// Returns an iterator over the inner nodes of the underlying [`Smt`].
     pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
         self.asset_tree.inner_nodes()
@@ -328,31 +341,131 @@ impl AssetVault {
 // ================================================================================================
 
 impl Serializable for AssetVault {
+    /// Writes the number of assets followed by the assets of the vault.
+    ///
+    /// The assets are written one at a time while iterating over the entries of the asset tree, so
+    /// serialization does not allocate an intermediate collection of assets.
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
-        let num_assets = self.asset_tree.num_entries();
-        target.write_usize(num_assets);
-        target.write_many(self.assets());
-    }
-
-    fn get_size_hint(&self) -> usize {
-        let mut size = 0;
-        let mut count: usize = 0;
-
+        target.write_usize(self.num_assets());
         for asset in self.assets() {
-            size += asset.get_size_hint();
-            count += 1;
+            asset.write_into(target);
         }
+    }
 
-        size += count.get_size_hint();
-
-        size
+    fn get_size_hint(&self) -> usize {
+        let assets_size: usize = self.assets().map(|asset| asset.get_size_hint()).sum();
+        self.num_assets().get_size_hint() + assets_size
     }
 }
 
 impl Deserializable for AssetVault {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let num_assets = source.read_usize()?;
-        let assets = source.read_many::<Asset>(num_assets)?;
+
+        // Each asset occupies at least `FungibleAsset::SERIALIZED_SIZE` bytes, so reject counts
+        // which cannot be backed by the remaining input before reading any assets.
+        num_assets
+            .checked_mul(FungibleAsset::SERIALIZED_SIZE)
+            .and_then(|min_num_bytes| source.check_eor(min_num_bytes).ok())
+            .ok_or_else(|| {
+                DeserializationError::InvalidValue(format!(
+                    "asset vault claims to contain {num_assets} assets which exceeds the remaining input"
+                ))
+            })?;
+
+        // Read the assets one at a time rather than pre-allocating memory for the claimed count.
+        let mut assets = Vec::new();
+        for _ in 0..num_assets {
+            assets.push(Asset::read_from(source)?);
+        }
+
         Self::new(&assets).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::account::{AccountIdVersion, AccountStorageMode, AccountType};
+
+    #[test]
+    fn asset_vault_serde_with_many_assets() -> anyhow::Result<()> {
+        let assets: Vec<Asset> = (0..1000u16)
+            .map(|i| {
+                let mut bytes = [0u8; 15];
+                bytes[4..6].copy_from_slice(&i.to_be_bytes());
+                let faucet_id = AccountId::dummy(
+                    bytes,
+                    AccountIdVersion::Version0,
+                    AccountType::FungibleFaucet,
+                    AccountStorageMode::Public,
+                );
+                FungibleAsset::new(faucet_id, u64::from(i) + 1).map(Asset::from)
+            })
+            .collect::<Result<_, _>>()?;
+        let vault = AssetVault::new(&assets)?;
+
+        let bytes = vault.to_bytes();
+        assert_eq!(bytes.len(), vault.get_size_hint());
+        assert_eq!(AssetVault::read_from_bytes(&bytes)?, vault);
+
+        let empty_vault = AssetVault::default();
+        assert_eq!(empty_vault.to_bytes().len(), empty_vault.get_size_hint());
+
+        Ok(())
+    }
+
+    #[test]
+    fn asset_vault_deserialization_rejects_malformed_input() -> anyhow::Result<()> {
+        let vault = AssetVault::new(&[FungibleAsset::mock(100), NonFungibleAsset::mock(&[1, 2])])?;
+        let bytes = vault.to_bytes();
+
+        // truncated input
+        for len in 0..bytes.len() {
+            assert!(AssetVault::read_from_bytes(&bytes[..len]).is_err());
+        }
+
+        // inflated asset counts are rejected before any assets are read
+        for num_assets in [3, 1 << 32, 1 << 60, usize::MAX] {
+            let mut inflated = Vec::new();
+            inflated.write_usize(num_assets);
+            inflated.extend_from_slice(&bytes[1..]);
+
+            assert_matches::assert_matches!(
+                AssetVault::read_from_bytes(&inflated),
+                Err(DeserializationError::InvalidValue(msg)) if msg.contains(&num_assets.to_string())
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn assets_matching_filters_assets() -> anyhow::Result<()> {
+        let fungible_asset = FungibleAsset::mock(100);
+        let non_fungible_asset = NonFungibleAsset::mock(&[1, 2]);
+        let vault = AssetVault::new(&[fungible_asset, non_fungible_asset])?;
+
+        assert_eq!(vault.assets_matching(Asset::is_fungible).collect::<Vec<_>>(), [fungible_asset]);
+        assert_eq!(
+            vault.assets_matching(Asset::is_non_fungible).collect::<Vec<_>>(),
+            [non_fungible_asset]
+        );
+
+        let faucet_id_prefix = fungible_asset.faucet_id_prefix();
+        assert_eq!(
+            vault
+                .assets_matching(|asset| asset.faucet_id_prefix() == faucet_id_prefix)
+                .collect::<Vec<_>>(),
+            [fungible_asset]
+        );
+        assert_eq!(vault.assets_matching(|_| false).count(), 0);
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@ use miden_crypto::merkle::{InnerNodeInfo, MerkleError, PartialSmt, SmtLeaf, SmtP
 
 use super::{AssetVault, AssetVaultKey};
 use crate::Word;
+use crate::account::AccountId;
 use crate::asset::{Asset, AssetWitness};
 use crate::errors::PartialAssetVaultError;
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
@@ -37,6 +38,27 @@ impl PartialVault {
         Ok(PartialVault { partial_smt })
     }
 
+    /// Returns a new instance of a partial vault with the provided root, tracking the assets of the
+    /// provided witnesses.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - no witnesses are provided.
+    /// - any of the witnesses does not open against the provided root.
+    pub fn from_witnesses(
+        root: Word,
+        witnesses: &[AssetWitness],
+    ) -> Result<Self, PartialAssetVaultError> {
+        let combined_witness =
+            AssetWitness::combine(witnesses).map_err(PartialAssetVaultError::InvalidWitnesses)?;
+        combined_witness
+            .verify(root)
+            .map_err(PartialAssetVaultError::InvalidWitnesses)?;
+
+        Ok(PartialVault { partial_smt: combined_witness.into() })
+    }
+
     /// Converts an [`AssetVault`] into a partial vault representation.
     ///
     /// The resulting [`PartialVault`] will contain the _full_ merkle paths of the original asset
@@ -126,6 +148,24 @@ impl PartialVault {
         })
     }
 
+    /// Returns the balance of the asset issued by the specified faucet. If the vault does not
+    /// contain such an asset, 0 is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the specified ID is not an ID of a fungible asset faucet.
+    /// - the asset issued by the faucet is not tracked by this partial vault.
+    pub fn get_balance(&self, faucet_id: AccountId) -> Result<u64, PartialAssetVaultError> {
+        let vault_key = AssetVaultKey::from_account_id(faucet_id)
+            .ok_or(PartialAssetVaultError::NotAFungibleFaucetId(faucet_id))?;
+
+        match self.get(vault_key).map_err(PartialAssetVaultError::UntrackedAsset)? {
+            Some(asset) => Ok(asset.unwrap_fungible().amount()),
+            None => Ok(0),
+        }
+    }
+
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -190,10 +230,14 @@ impl Deserializable for PartialVault {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
+
     use assert_matches::assert_matches;
     use miden_crypto::merkle::Smt;
 
     use super::*;
+    use crate::AssetWitnessError;
+    use crate::account::{AccountIdVersion, AccountStorageMode, AccountType};
     use crate::asset::FungibleAsset;
 
     #[test]
@@ -227,4 +271,69 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn partial_vault_from_witnesses() -> anyhow::Result<()> {
+        let faucet_ids: Vec<AccountId> = (0..100u16)
+            .map(|i| {
+                let mut bytes = [0u8; 15];
+                bytes[4..6].copy_from_slice(&i.to_be_bytes());
+                AccountId::dummy(
+                    bytes,
+                    AccountIdVersion::Version0,
+                    AccountType::FungibleFaucet,
+                    AccountStorageMode::Public,
+                )
+            })
+            .collect();
+        let assets = faucet_ids
+            .iter()
+            .zip(1..)
+            .map(|(faucet_id, amount)| FungibleAsset::new(*faucet_id, amount).map(Asset::from))
+            .collect::<Result<Vec<_>, _>>()?;
+        let vault = AssetVault::new(&assets)?;
+
+        let opened_faucet_ids = [0, 17, 42, 73, 99].map(|idx| faucet_ids[idx]);
+        let witnesses = opened_faucet_ids
+            .map(|faucet_id| vault.open(AssetVaultKey::from_account_id(faucet_id).unwrap()));
+
+        let combined_witness = AssetWitness::combine(&witnesses)?;
+        assert_eq!(combined_witness.root(), vault.root());
+        assert_eq!(combined_witness.assets().count(), opened_faucet_ids.len());
+
+        let partial_vault = PartialVault::from_witnesses(vault.root(), &witnesses)?;
+        assert_eq!(partial_vault.root(), vault.root());
+        for faucet_id in opened_faucet_ids {
+            assert_eq!(partial_vault.get_balance(faucet_id)?, vault.get_balance(faucet_id)?);
+        }
+        assert_matches!(
+            partial_vault.get_balance(faucet_ids[1]),
+            Err(PartialAssetVaultError::UntrackedAsset(_))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_vault_from_witnesses_rejects_mismatched_roots() -> anyhow::Result<()> {
+        let vault0 = AssetVault::new(&[FungibleAsset::mock(100)])?;
+        let vault1 = AssetVault::new(&[FungibleAsset::mock(200)])?;
+        let vault_key = FungibleAsset::mock(100).vault_key();
+        let witnesses = [vault0.open(vault_key), vault1.open(vault_key)];
+
+        assert_matches!(
+            AssetWitness::combine(&witnesses),
+            Err(AssetWitnessError::RootMismatch { expected, actual }) => {
+                assert_eq!(expected, vault0.root());
+                assert_eq!(actual, vault1.root());
+            }
+        );
+        assert_matches!(AssetWitness::combine(&[]), Err(AssetWitnessError::NoWitnesses));
+        assert_matches!(
+            PartialVault::from_witnesses(vault1.root(), &witnesses[..1]),
+            Err(PartialAssetVaultError::InvalidWitnesses(AssetWitnessError::RootMismatch { .. }))
+        );
+
+        Ok(())
+    }
 }
@@ -1,12 +1,14 @@
+use alloc::boxed::Box;
 use core::fmt;
 
 use miden_crypto::merkle::LeafIndex;
 use miden_processor::SMT_DEPTH;
 
 use crate::Word;
-use crate::account::AccountType::FungibleFaucet;
+use crate::account::AccountType::{FungibleFaucet, NonFungibleFaucet};
 use crate::account::{AccountId, AccountIdPrefix};
 use crate::asset::{Asset, FungibleAsset, NonFungibleAsset};
+use crate::errors::AssetError;
 
 /// The key of an [`Asset`] in the asset vault.
 ///
@@ -43,6 +45,48 @@ impl AssetVaultKey {
         Self(value)
     }
 
+    /// Creates a new [`AssetVaultKey`] from the given [`Word`] after validating that it represents
+    /// a valid fungible or non-fungible asset key.
+    ///
+    /// This is useful for validating raw SMT keys, e.g. of a partial vault received from an
+    /// untrusted source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The word is a fungible asset key and the felts at index 3 and 2 are not a valid account ID
+    ///   of type [`AccountType::FungibleFaucet`](crate::account::AccountType::FungibleFaucet).
+    /// - The word is a non-fungible asset key and the felt at index 0 is not a valid account ID
+    ///   prefix of type
+    ///   [`AccountType::NonFungibleFaucet`](crate::account::AccountType::NonFungibleFaucet).
+    /// - The word is a non-fungible asset key and the felt at index 3 has the fungible bit set.
+    pub fn from_word(value: Word) -> Result<Self, AssetError> {
+        let key = Self(value);
+
+        if key.is_fungible() {
+            let faucet_id = AccountId::try_from([value[3], value[2]])
+                .map_err(|err| AssetError::InvalidFaucetAccountId(Box::new(err)))?;
+
+            if faucet_id.account_type() != FungibleFaucet {
+                return Err(AssetError::FungibleFaucetIdTypeMismatch(faucet_id));
+            }
+        } else {
+            let faucet_id_prefix = AccountIdPrefix::new(value[0])
+                .map_err(|err| AssetError::InvalidFaucetAccountId(Box::new(err)))?;
+
+            if faucet_id_prefix.account_type() != NonFungibleFaucet {
+                return Err(AssetError::NonFungibleFaucetIdTypeMismatch(faucet_id_prefix));
+            }
+
+            if AccountIdPrefix::clear_fungible_bit(faucet_id_prefix.version(), value[3]) != value[3]
+            {
+                return Err(AssetError::NonFungibleAssetVaultKeyFungibleBitSet(value));
+            }
+        }
+
+        Ok(key)
+    }
+
     /// Returns an [`AccountIdPrefix`] from the asset key.
     pub fn faucet_id_prefix(&self) -> AccountIdPrefix {
         if self.is_fungible() {
@@ -126,10 +170,12 @@ impl From<NonFungibleAsset> for AssetVaultKey {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use miden_core::Felt;
 
     use super::*;
     use crate::account::{AccountIdVersion, AccountStorageMode, AccountType};
+    use crate::asset::NonFungibleAssetDetails;
 
     fn make_non_fungible_key(prefix: u64) -> AssetVaultKey {
         let word = [Felt::new(prefix), Felt::new(11), Felt::new(22), Felt::new(33)].into();
@@ -173,4 +219,62 @@ mod tests {
         // faucet_id() should return the None
         assert_eq!(key.faucet_id(), None);
     }
+
+    #[test]
+    fn test_from_word() -> anyhow::Result<()> {
+        let fungible_faucet_id = AccountId::dummy(
+            [0xff; 15],
+            AccountIdVersion::Version0,
+            AccountType::FungibleFaucet,
+            AccountStorageMode::Public,
+        );
+        let non_fungible_faucet_id = AccountId::dummy(
+            [0xff; 15],
+            AccountIdVersion::Version0,
+            AccountType::NonFungibleFaucet,
+            AccountStorageMode::Public,
+        );
+        let regular_account_id = AccountId::dummy(
+            [0xff; 15],
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageMode::Public,
+        );
+
+        let fungible_key = AssetVaultKey::from_account_id(fungible_faucet_id).unwrap();
+        assert_eq!(AssetVaultKey::from_word(fungible_key.into()).unwrap(), fungible_key);
+
+        let non_fungible_key = NonFungibleAsset::new(&NonFungibleAssetDetails::new(
+            non_fungible_faucet_id.prefix(),
+            vec![1, 2, 3],
+        )?)?
+        .vault_key();
+        assert_eq!(AssetVaultKey::from_word(non_fungible_key.into()).unwrap(), non_fungible_key);
+
+        let regular_key = Word::from([
+            Felt::new(0),
+            Felt::new(0),
+            regular_account_id.suffix(),
+            regular_account_id.prefix().as_felt(),
+        ]);
+        assert_matches!(
+            AssetVaultKey::from_word(regular_key),
+            Err(AssetError::FungibleFaucetIdTypeMismatch(id)) if id == regular_account_id
+        );
+
+        let invalid_prefix_key = make_non_fungible_key(regular_account_id.prefix().as_u64()).into();
+        assert_matches!(
+            AssetVaultKey::from_word(invalid_prefix_key),
+            Err(AssetError::NonFungibleFaucetIdTypeMismatch(_))
+        );
+
+        let mut fungible_bit_set_key = Word::from(non_fungible_key);
+        fungible_bit_set_key[3] = non_fungible_faucet_id.prefix().as_felt();
+        assert_matches!(
+            AssetVaultKey::from_word(fungible_bit_set_key),
+            Err(AssetError::NonFungibleAssetVaultKeyFungibleBitSet(_))
+        );
+
+        Ok(())
+    }
 }
@@ -352,6 +352,26 @@ impl ProposedBatch {
         &self.output_notes
     }
 
+    /// Returns an estimate of the number of bytes the transactions of this batch take up when
+    /// serialized.
+    ///
+    /// The estimate is the sum of the serialized sizes of the account updates, input notes and
+    /// output notes of all transactions, as computed by their
+    /// [`Serializable::get_size_hint`] implementations. The remaining data of the transactions,
+    /// e.g. their proofs, are not included.
+    ///
+    /// This can be used to split oversized batches before submitting them.
+    pub fn estimate_size_bytes(&self) -> usize {
+        self.transactions
+            .iter()
+            .map(|tx| {
+                tx.account_update().get_size_hint()
+                    + tx.input_notes().get_size_hint()
+                    + tx.output_notes().get_size_hint()
+            })
+            .sum()
+    }
+
     /// Consumes the proposed batch and returns its underlying parts.
     #[allow(clippy::type_complexity)]
     pub fn into_parts(
@@ -649,6 +649,19 @@ impl AccountMutationSet {
     }
 }
 
+impl Serializable for AccountMutationSet {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.mutation_set.write_into(target);
+    }
+}
+
+impl Deserializable for AccountMutationSet {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mutation_set = MutationSet::<SMT_DEPTH, Word, Word>::read_from(source)?;
+        Ok(Self::new(mutation_set))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
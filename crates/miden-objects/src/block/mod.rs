@@ -19,7 +19,7 @@ pub use partial_account_tree::PartialAccountTree;
 pub mod account_tree;
 
 mod nullifier_tree;
-pub use nullifier_tree::NullifierTree;
+pub use nullifier_tree::{NullifierMutationSet, NullifierTree};
 
 mod blockchain;
 pub use blockchain::Blockchain;
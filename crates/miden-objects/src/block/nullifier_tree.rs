@@ -258,6 +258,19 @@ impl NullifierMutationSet {
     }
 }
 
+impl Serializable for NullifierMutationSet {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.mutation_set.write_into(target);
+    }
+}
+
+impl Deserializable for NullifierMutationSet {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mutation_set = MutationSet::<{ NullifierTree::DEPTH }, Word, Word>::read_from(source)?;
+        Ok(Self::new(mutation_set))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -22,7 +22,9 @@ use crate::account::{
     AccountIdPrefix,
     AccountStorage,
     AccountType,
+    ComponentDependency,
     SlotName,
+    StorageSlotType,
     StorageValueName,
     StorageValueNameError,
     TemplateTypeError,
@@ -53,6 +55,8 @@ pub enum AccountComponentTemplateError {
     DuplicatePlaceholderName(StorageValueName),
     #[error("slot {0} is defined multiple times")]
     DuplicateSlot(u8),
+    #[error("value {actual} in storage does not match the value {expected} fixed by the template")]
+    FixedStorageValueMismatch { expected: Felt, actual: Felt },
     #[error("storage value name is incorrect: {0}")]
     IncorrectStorageValueName(#[source] StorageValueNameError),
     #[error("type `{0}` is not valid for `{1}` slots")]
@@ -63,14 +67,24 @@ pub enum AccountComponentTemplateError {
     MultiSlotArityMismatch,
     #[error("multi-slot entry slot range should occupy more than one storage slot")]
     MultiSlotSpansOneSlot,
+    #[error("key of storage map `{0}` is templated and cannot be extracted from storage")]
+    MapKeyNotExtractable(StorageValueName),
     #[error("component storage slots are not contiguous ({0} is followed by {1})")]
     NonContiguousSlots(u8, u8),
+    #[error("value of placeholder `{0}` in storage cannot be represented as init storage data")]
+    PlaceholderValueNotExtractable(StorageValueName),
     #[error("storage value for placeholder `{0}` was not provided in the init storage data")]
     PlaceholderValueNotProvided(StorageValueName),
     #[error("error converting value into expected type: ")]
     StorageValueParsingError(#[source] TemplateTypeError),
     #[error("storage map contains duplicate keys")]
     StorageMapHasDuplicateKeys(#[source] Box<dyn Error + Send + Sync + 'static>),
+    #[error("storage map `{0}` contains entries which are not described by the template")]
+    StorageMapHasUntemplatedEntries(StorageValueName),
+    #[error("storage slot {0} does not exist in the account storage")]
+    StorageSlotNotFound(usize),
+    #[error("storage slot {slot} of the component is expected to be a {expected:?} slot")]
+    StorageSlotTypeMismatch { slot: u8, expected: StorageSlotType },
     #[error("component storage slots have to start at 0, but they start at {0}")]
     StorageSlotsDoNotStartAtZero(u8),
     #[cfg(feature = "std")]
@@ -94,6 +108,8 @@ pub enum AccountError {
     AccountCodeMultipleAuthComponents,
     #[error("account code must contain at least one non-auth procedure")]
     AccountCodeNoProcedures,
+    #[error("account code does not contain the auth procedure with MAST root {0}")]
+    AccountCodeMissingAuthProcedure(Word),
     #[error("account code contains {0} procedures but it may contain at most {max} procedures", max = AccountCode::MAX_NUM_PROCEDURES)]
     AccountCodeTooManyProcedures(usize),
     #[error("account procedure {0}'s storage offset {1} does not fit into u8")]
@@ -112,6 +128,10 @@ pub enum AccountError {
     AccountComponentTemplateInstantiationError(#[source] AccountComponentTemplateError),
     #[error("account component contains multiple authentication procedures")]
     AccountComponentMultipleAuthProcedures,
+    #[error("account component depends on the {0} which was not added to the account")]
+    AccountComponentMissingDependency(ComponentDependency),
+    #[error("dependencies of the account components form a cycle")]
+    AccountComponentDependencyCycle,
     #[error("failed to update asset vault")]
     AssetVaultUpdateError(#[source] AssetVaultError),
     #[error("account build error: {0}")]
@@ -144,12 +164,18 @@ pub enum AccountError {
     SeedConvertsToInvalidAccountId(#[source] AccountIdError),
     #[error("storage map root {0} not found in the account storage")]
     StorageMapRootNotFound(Word),
+    #[error("storage map key {0} is not tracked by the partial storage map")]
+    StorageMapKeyNotTracked(Word),
     #[error("storage slot at index {0} is not of type map")]
     StorageSlotNotMap(u8),
     #[error("storage slot at index {0} is not of type value")]
     StorageSlotNotValue(u8),
     #[error("storage slot index is {index} but the slots length is {slots_len}")]
     StorageIndexOutOfBounds { slots_len: u8, index: u8 },
+    #[error("storage slot with name {} not found", .0.as_str())]
+    StorageSlotNameNotFound(SlotName),
+    #[error("storage slot name {} is defined by multiple account components", .0.as_str())]
+    DuplicateStorageSlotName(SlotName),
     #[error("number of storage slots is {0} but max possible number is {max}", max = AccountStorage::MAX_NUM_STORAGE_SLOTS)]
     StorageTooManySlots(u64),
     #[error("procedure storage offset + size is {0} which exceeds the maximum value of {max}",
@@ -173,6 +199,27 @@ pub enum AccountError {
     ApplyFullStateDeltaToAccount,
     #[error("only account deltas representing a full account can be converted to a full account")]
     PartialStateDeltaToAccount,
+    #[error(
+        "cannot compute the delta between account {initial} and a state of account {final_state}"
+    )]
+    DiffAccountIdMismatch {
+        initial: AccountId,
+        final_state: AccountId,
+    },
+    #[error(
+        "cannot compute the delta to a state whose nonce {final_state} is smaller than the initial nonce {initial}"
+    )]
+    DiffNonceDecreased { initial: Felt, final_state: Felt },
+    #[error(
+        "cannot compute the delta between account states with different code since code updates are not supported"
+    )]
+    DiffCodeChanged,
+    #[error(
+        "cannot compute the delta between account states with incompatible storage slot layouts"
+    )]
+    DiffStorageLayoutMismatch,
+    #[error("delta between the account states is invalid")]
+    DiffInvalidDelta(#[source] Box<AccountDeltaError>),
     #[error("maximum number of storage map leaves exceeded")]
     MaxNumStorageMapLeavesExceeded(#[source] MerkleError),
     /// This variant can be used by methods that are not inherent to the account but want to return
@@ -328,6 +375,8 @@ pub enum AccountDeltaError {
     StorageSlotIndexOutOfBounds { slot_index: u8, num_slots: u8 },
     #[error("storage slot {0} was updated as a value and as a map")]
     StorageSlotUsedAsDifferentTypes(u8),
+    #[error("type of storage slot {0} cannot be changed since it conflicts with a previous update")]
+    ConflictingStorageSlotTypeChange(u8),
     #[error("non fungible vault can neither be added nor removed twice")]
     DuplicateNonFungibleVaultUpdate(NonFungibleAsset),
     #[error(
@@ -437,6 +486,8 @@ pub enum AssetError {
     NonFungibleFaucetIdTypeMismatch(AccountIdPrefix),
     #[error("asset vault key {actual} does not match expected asset vault key {expected}")]
     AssetVaultKeyMismatch { actual: Word, expected: Word },
+    #[error("non-fungible asset vault key {0} must not have the fungible bit set")]
+    NonFungibleAssetVaultKeyFungibleBitSet(Word),
 }
 
 // TOKEN SYMBOL ERROR
@@ -477,6 +528,19 @@ pub enum AssetVaultError {
     MaxLeafEntriesExceeded(#[source] MerkleError),
 }
 
+// ASSET WITNESS ERROR
+// ================================================================================================
+
+#[derive(Debug, Error)]
+pub enum AssetWitnessError {
+    #[error("at least one asset witness must be provided")]
+    NoWitnesses,
+    #[error(
+        "expected asset witness to open against vault root {expected} but it opens against {actual}"
+    )]
+    RootMismatch { expected: Word, actual: Word },
+}
+
 // PARTIAL ASSET VAULT ERROR
 // ================================================================================================
 
@@ -490,6 +554,10 @@ pub enum PartialAssetVaultError {
     FailedToAddProof(#[source] MerkleError),
     #[error("asset is not tracked in the partial vault")]
     UntrackedAsset(#[source] MerkleError),
+    #[error("failed to build partial vault from asset witnesses")]
+    InvalidWitnesses(#[source] AssetWitnessError),
+    #[error("faucet id {0} is not a fungible faucet id")]
+    NotAFungibleFaucetId(AccountId),
 }
 
 // NOTE ERROR
@@ -534,6 +602,10 @@ pub enum NoteError {
     },
     #[error("note network execution requires a public note but note is of type {0}")]
     NetworkExecutionRequiresPublicNote(NoteType),
+    #[error(
+        "note recipient commitment {actual} does not match the commitment {expected} computed from its serial number, script and inputs"
+    )]
+    NoteRecipientCommitmentMismatch { expected: Word, actual: Word },
     #[error("failed to assemble note script:\n{}", PrintDiagnostic::new(.0))]
     NoteScriptAssemblyError(Report),
     #[error("failed to deserialize note script")]
@@ -645,6 +717,10 @@ pub enum TransactionInputError {
     InputNoteBlockNotInPartialBlockchain(NoteId),
     #[error("input note with id {0} was not created in block {1}")]
     InputNoteNotInBlock(NoteId, BlockNumber),
+    #[error("header of block {1} in which input note with id {0} was created was not provided")]
+    InputNoteBlockHeaderMissing(NoteId, BlockNumber),
+    #[error("input note with id {note_id} is not executable at reference block {ref_block}")]
+    InputNoteNotExecutable { note_id: NoteId, ref_block: BlockNumber },
     #[error(
         "total number of input notes is {0} which exceeds the maximum of {MAX_INPUT_NOTES_PER_TX}"
     )]
@@ -871,6 +947,9 @@ pub enum ProposedBlockError {
     #[error("batch {batch_id} appears twice in the block inputs")]
     DuplicateBatch { batch_id: BatchId },
 
+    #[error("transaction {transaction_id} appears twice in the batches of the block")]
+    DuplicateTransaction { transaction_id: TransactionId },
+
     #[error(
         "batch {second_batch_id} consumes the note with nullifier {note_nullifier} that is also consumed by another batch {first_batch_id} in the block"
     )]
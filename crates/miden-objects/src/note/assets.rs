@@ -225,6 +225,11 @@ impl Serializable for NoteAssets {
         target.write_u8(self.assets.len().try_into().expect("Asset number must fit into `u8`"));
         target.write_many(&self.assets);
     }
+
+    fn get_size_hint(&self) -> usize {
+        let u8_size = 0u8.get_size_hint();
+        u8_size + self.assets.iter().map(Asset::get_size_hint).sum::<usize>()
+    }
 }
 
 impl Deserializable for NoteAssets {
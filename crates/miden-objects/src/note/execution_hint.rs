@@ -123,6 +123,9 @@ impl NoteExecutionHint {
 
     /// Returns whether the note execution conditions validate for the given `block_num`
     ///
+    /// Execution hints are advisory: they describe when the creator of a note intends it to be
+    /// consumed, but they are not enforced by the transaction kernel.
+    ///
     /// # Returns
     /// - `None` if we don't know whether the note can be consumed.
     /// - `Some(true)` if the note is consumable for the given `block_num`
@@ -133,7 +136,7 @@ impl NoteExecutionHint {
             NoteExecutionHint::None => None,
             NoteExecutionHint::Always => Some(true),
             NoteExecutionHint::AfterBlock { block_num: hint_block_num } => {
-                Some(block_num >= hint_block_num.as_u32())
+                Some(block_num > hint_block_num.as_u32())
             },
             NoteExecutionHint::OnBlockSlot { round_len, slot_len, slot_offset } => {
                 let round_len_blocks: u32 = 1 << round_len;
@@ -151,6 +154,15 @@ impl NoteExecutionHint {
         }
     }
 
+    /// Returns `true` if the note execution conditions are known to validate for the given
+    /// `block_num`, `false` otherwise.
+    ///
+    /// This is a convenience wrapper around [`Self::can_be_consumed`] which treats unknown
+    /// execution conditions (i.e. [`NoteExecutionHint::None`]) as not executable.
+    pub fn is_executable_at(&self, block_num: BlockNumber) -> bool {
+        self.can_be_consumed(block_num).unwrap_or(false)
+    }
+
     /// Encodes the [`NoteExecutionHint`] into a 6-bit tag and a 32-bit payload.
     ///
     /// # Guarantees
@@ -307,7 +319,8 @@ mod tests {
 
         let after_block = NoteExecutionHint::after_block(12345.into()).unwrap();
         assert!(!after_block.can_be_consumed(12344.into()).unwrap());
-        assert!(after_block.can_be_consumed(12345.into()).unwrap());
+        assert!(!after_block.can_be_consumed(12345.into()).unwrap());
+        assert!(after_block.can_be_consumed(12346.into()).unwrap());
 
         let on_block_slot = NoteExecutionHint::on_block_slot(10, 7, 1);
         assert!(!on_block_slot.can_be_consumed(127.into()).unwrap()); // Block 127 is not in the slot 128..255
@@ -321,6 +334,20 @@ mod tests {
         // 2176..2303
     }
 
+    #[test]
+    fn test_is_executable_at() {
+        assert!(!NoteExecutionHint::none().is_executable_at(100.into()));
+        assert!(NoteExecutionHint::always().is_executable_at(100.into()));
+
+        let after_block = NoteExecutionHint::after_block(12345.into()).unwrap();
+        assert!(!after_block.is_executable_at(12345.into()));
+        assert!(after_block.is_executable_at(12346.into()));
+
+        let on_block_slot = NoteExecutionHint::on_block_slot(10, 7, 1);
+        assert!(!on_block_slot.is_executable_at(127.into()));
+        assert!(on_block_slot.is_executable_at(128.into()));
+    }
+
     #[test]
     fn test_parts_validity() {
         NoteExecutionHint::from_parts(NoteExecutionHint::NONE_TAG, 1).unwrap_err();
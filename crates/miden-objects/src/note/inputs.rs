@@ -145,6 +145,11 @@ impl Serializable for NoteInputs {
         target.write_u8(values.len().try_into().expect("inputs len is not a u8 value"));
         target.write_many(values);
     }
+
+    fn get_size_hint(&self) -> usize {
+        let u8_size = 0u8.get_size_hint();
+        u8_size + self.values.iter().map(Felt::get_size_hint).sum::<usize>()
+    }
 }
 
 impl Deserializable for NoteInputs {
@@ -9,6 +9,7 @@ use super::{
     DeserializationError,
     Felt,
     NoteError,
+    NoteExecutionMode,
     NoteTag,
     NoteType,
     Serializable,
@@ -115,6 +116,17 @@ impl NoteMetadata {
     pub fn is_private(&self) -> bool {
         self.note_type == NoteType::Private
     }
+
+    /// Returns `true` if the note can be executed by the network.
+    ///
+    /// This is the case if the note is public, so that the network has access to its details, its
+    /// tag requests [`NoteExecutionMode::Network`] and its execution hint is known, i.e. not
+    /// [`NoteExecutionHint::None`], so that the network can determine when to consume it.
+    pub fn is_network_executable(&self) -> bool {
+        self.note_type == NoteType::Public
+            && self.tag.execution_mode() == NoteExecutionMode::Network
+            && self.execution_hint != NoteExecutionHint::None
+    }
 }
 
 impl From<NoteMetadata> for Word {
@@ -173,6 +185,10 @@ impl Serializable for NoteMetadata {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         Word::from(self).write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        Word::empty().get_size_hint()
+    }
 }
 
 impl Deserializable for NoteMetadata {
@@ -301,7 +317,37 @@ mod tests {
     use anyhow::Context;
 
     use super::*;
-    use crate::testing::account_id::ACCOUNT_ID_MAX_ONES;
+    use crate::testing::account_id::{ACCOUNT_ID_MAX_ONES, ACCOUNT_ID_NETWORK_NON_FUNGIBLE_FAUCET};
+
+    #[test]
+    fn note_metadata_is_network_executable() {
+        let network_account = AccountId::try_from(ACCOUNT_ID_NETWORK_NON_FUNGIBLE_FAUCET).unwrap();
+        let local_account = AccountId::try_from(ACCOUNT_ID_MAX_ONES).unwrap();
+        let network_tag = NoteTag::from_account_id(network_account);
+        let local_tag = NoteTag::from_account_id(local_account);
+
+        let metadata = |note_type, tag, execution_hint| {
+            NoteMetadata::new(local_account, note_type, tag, execution_hint, Felt::default())
+                .unwrap()
+        };
+
+        assert!(
+            metadata(NoteType::Public, network_tag, NoteExecutionHint::always())
+                .is_network_executable()
+        );
+        assert!(
+            !metadata(NoteType::Public, network_tag, NoteExecutionHint::none())
+                .is_network_executable()
+        );
+        assert!(
+            !metadata(NoteType::Public, local_tag, NoteExecutionHint::always())
+                .is_network_executable()
+        );
+        assert!(
+            !metadata(NoteType::Private, local_tag, NoteExecutionHint::always())
+                .is_network_executable()
+        );
+    }
 
     #[test]
     fn note_metadata_serde() -> anyhow::Result<()> {
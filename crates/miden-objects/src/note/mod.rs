@@ -27,7 +27,7 @@ mod note_id;
 pub use note_id::NoteId;
 
 mod note_tag;
-pub use note_tag::{NoteExecutionMode, NoteTag};
+pub use note_tag::{NoteExecutionMode, NoteTag, NoteTagAccountMatch, NoteTagPattern};
 
 mod note_type;
 pub use note_type::NoteType;
@@ -87,7 +87,15 @@ impl Note {
     // --------------------------------------------------------------------------------------------
 
     /// Returns a new [Note] created with the specified parameters.
+    ///
+    /// In debug builds, this validates the commitment of the recipient via
+    /// [`NoteRecipient::validate_commitment`] so that improperly constructed recipients are caught
+    /// early.
     pub fn new(assets: NoteAssets, metadata: NoteMetadata, recipient: NoteRecipient) -> Self {
+        debug_assert!(
+            recipient.validate_commitment().is_ok(),
+            "note recipient commitment should match its serial number, script and inputs"
+        );
         let details = NoteDetails::new(assets, recipient);
         let header = NoteHeader::new(details.id(), metadata);
         let nullifier = details.nullifier();
@@ -161,6 +169,19 @@ impl Note {
     pub fn is_network_note(&self) -> bool {
         self.metadata().tag().execution_mode() == NoteExecutionMode::Network
     }
+
+    /// Returns the ID of the account this note is addressed to, if the note follows the P2ID
+    /// input layout.
+    ///
+    /// The first two note inputs are interpreted as the `[suffix, prefix]` of the recipient
+    /// account ID, which is how P2ID-style notes encode their target. Returns [`None`] if the note
+    /// has fewer than two inputs or they do not form a valid account ID.
+    pub fn recipient_account_id(&self) -> Option<AccountId> {
+        match self.inputs().values() {
+            [suffix, prefix, ..] => AccountId::try_from([*prefix, *suffix]).ok(),
+            _ => None,
+        }
+    }
 }
 
 // AS REF
@@ -233,6 +254,10 @@ impl Serializable for Note {
         header.metadata().write_into(target);
         details.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.header.metadata().get_size_hint() + self.details.get_size_hint()
+    }
 }
 
 impl Deserializable for Note {
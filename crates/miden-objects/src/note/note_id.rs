@@ -120,6 +120,10 @@ impl Serializable for NoteId {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         target.write_bytes(&self.0.to_bytes());
     }
+
+    fn get_size_hint(&self) -> usize {
+        Word::empty().get_size_hint()
+    }
 }
 
 impl Deserializable for NoteId {
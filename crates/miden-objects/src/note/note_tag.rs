@@ -27,6 +27,8 @@ const NETWORK_PUBLIC_USECASE: u32 = 0x4000_0000;
 const LOCAL_PUBLIC_ANY: u32 = 0x8000_0000;
 // The 2 most significant bits are set to `0b11`.
 const LOCAL_ANY: u32 = 0xc000_0000;
+// Masks out the two most significant bits of the upper half of a tag.
+const USE_CASE_ID_MASK: u16 = (1 << NoteTag::MAX_USE_CASE_ID_EXPONENT) - 1;
 
 /// [super::Note]'s execution mode hints.
 ///
@@ -76,6 +78,16 @@ pub enum NoteExecutionMode {
 /// public note for local execution is intended to allow users to search for notes that can be
 /// consumed right away, without requiring an off-band communication channel.
 ///
+/// **Note on Tag Truncation**
+///
+/// Tags targeting an account encode only the most significant bits of its ID, so distinct
+/// accounts can have the same tag. Tags created with [`NoteTag::from_account_id`] encode
+/// [`NoteTag::DEFAULT_LOCAL_TAG_LENGTH`] (14) bits of a local account ID and
+/// [`NoteTag::DEFAULT_NETWORK_TAG_LENGTH`] (30) bits of a network account ID. Assuming uniformly
+/// distributed IDs, the tag of an account therefore also matches a different account of the same
+/// kind with probability 2^-14 or 2^-30, respectively. Filtering notes by tag, e.g. with
+/// [`NoteTag::account_match`], can thus yield false positives, but never false negatives.
+///
 /// **Note on Type Safety**
 ///
 /// Each enum variant contains the raw encoding of the note tag, where the first two bits
@@ -222,7 +234,7 @@ impl NoteTag {
         payload: u16,
         execution: NoteExecutionMode,
     ) -> Result<Self, NoteError> {
-        if (use_case_id >> 14) != 0 {
+        if (use_case_id >> NoteTag::MAX_USE_CASE_ID_EXPONENT) != 0 {
             return Err(NoteError::NoteTagUseCaseTooLarge(use_case_id));
         }
 
@@ -279,6 +291,31 @@ impl NoteTag {
         }
     }
 
+    /// Returns the use case ID encoded in this tag, or `None` if this tag is not a use case tag.
+    ///
+    /// The use case ID is stored in the 14 bits following the two most significant bits of the
+    /// tag. Note that [`NoteTag::LocalAny`] tags targeting an account (see
+    /// [`NoteTag::from_account_id`]) share their layout with local use case tags. For such tags,
+    /// the returned value consists of the most significant bits of the targeted account ID.
+    ///
+    /// Returns `None` for [`NoteTag::NetworkAccount`] tags.
+    pub fn use_case_id(&self) -> Option<u16> {
+        match self {
+            NoteTag::NetworkAccount(_) => None,
+            NoteTag::NetworkUseCase(..) | NoteTag::LocalPublicAny(_) | NoteTag::LocalAny(_) => {
+                Some(((self.as_u32() >> 16) as u16) & USE_CASE_ID_MASK)
+            },
+        }
+    }
+
+    /// Returns the payload encoded in the 16 least significant bits of this tag, or `None` if this
+    /// tag is not a use case tag.
+    ///
+    /// See [`NoteTag::use_case_id`] for which tags are considered use case tags.
+    pub fn payload(&self) -> Option<u16> {
+        self.use_case_id().map(|_| self.as_u32() as u16)
+    }
+
     /// Returns the inner u32 value of this tag.
     pub fn as_u32(&self) -> u32 {
         // Note that we always set the two most significant bits to the prefix corresponding to the
@@ -315,6 +352,50 @@ impl NoteTag {
         }
     }
 
+    /// Returns whether this tag targets the account with the provided ID.
+    ///
+    /// Only tags with the layout of the tags created by [`NoteTag::from_account_id`] are
+    /// interpreted as targeting an account:
+    /// - A [`NoteTag::NetworkAccount`] tag matches the account if it is equal to the tag of the
+    ///   account.
+    /// - A [`NoteTag::LocalAny`] tag whose 16 least significant bits are zero matches the account
+    ///   if it is equal to the tag of the account. Since local use case tags with a zero payload
+    ///   share this layout, such use case tags are treated as targeting an account as well.
+    /// - For all other tags, i.e. use case tags, whether a note could be consumed by the account
+    ///   cannot be determined from the tag, so [`NoteTagAccountMatch::Unknown`] is returned.
+    ///
+    /// Note that [`NoteTagAccountMatch::Matches`] can be a false positive, see the note on tag
+    /// truncation in the [`NoteTag`] docs.
+    pub fn account_match(&self, account_id: &AccountId) -> NoteTagAccountMatch {
+        match self {
+            NoteTag::NetworkAccount(_) => {},
+            NoteTag::LocalAny(_) if self.as_u32() as u16 == 0 => {},
+            NoteTag::LocalAny(_) | NoteTag::NetworkUseCase(..) | NoteTag::LocalPublicAny(_) => {
+                return NoteTagAccountMatch::Unknown;
+            },
+        }
+
+        if Self::from_account_id(*account_id).as_u32() == self.as_u32() {
+            NoteTagAccountMatch::Matches
+        } else {
+            NoteTagAccountMatch::DoesNotMatch
+        }
+    }
+
+    /// Returns an iterator over the accounts among the provided `candidates` which are targeted by
+    /// this tag, i.e. for which [`NoteTag::account_match`] returns
+    /// [`NoteTagAccountMatch::Matches`].
+    ///
+    /// Since use case tags do not target an account, no accounts are returned for them.
+    pub fn matching_accounts<'a>(
+        &self,
+        candidates: impl Iterator<Item = &'a AccountId>,
+    ) -> impl Iterator<Item = &'a AccountId> {
+        let tag = *self;
+        candidates
+            .filter(move |account_id| tag.account_match(account_id) == NoteTagAccountMatch::Matches)
+    }
+
     /// Returns `true` if the note tag requires a public note.
     fn requires_public_note(&self) -> bool {
         matches!(
@@ -330,6 +411,60 @@ impl fmt::Display for NoteTag {
     }
 }
 
+// NOTE TAG ACCOUNT MATCH
+// ================================================================================================
+
+/// The result of matching a [`NoteTag`] against an account, see [`NoteTag::account_match`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NoteTagAccountMatch {
+    /// The tag targets the account, or a different account with the same tag.
+    Matches,
+    /// The tag targets a different account.
+    DoesNotMatch,
+    /// The tag does not target an account, so it cannot be determined whether a note with this
+    /// tag is intended for the account.
+    Unknown,
+}
+
+// NOTE TAG PATTERN
+// ================================================================================================
+
+/// A pattern which [`NoteTag`]s can be matched against, e.g. to filter notes by their tags.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NoteTagPattern {
+    /// Matches any tag.
+    Any,
+    /// Matches only the specified tag.
+    Exact(NoteTag),
+    /// Matches all tags whose `len` most significant bits are equal to the ones of `tag`.
+    ///
+    /// For example, a `len` of 16 applied to a use case tag matches all tags of that use case
+    /// regardless of their payload. A `len` of 0 matches any tag and a `len` larger than 32 is
+    /// treated as 32.
+    Prefix { tag: NoteTag, len: u8 },
+}
+
+impl NoteTagPattern {
+    /// Returns a new [`NoteTagPattern::Prefix`] which matches all tags of the use case of the
+    /// provided tag, i.e. all tags with the same two most significant bits and the same use case
+    /// ID, regardless of their payload.
+    pub fn use_case(tag: NoteTag) -> Self {
+        Self::Prefix { tag, len: 16 }
+    }
+
+    /// Returns `true` if the provided tag matches this pattern.
+    pub fn matches(&self, tag: NoteTag) -> bool {
+        match self {
+            NoteTagPattern::Any => true,
+            NoteTagPattern::Exact(expected) => expected.as_u32() == tag.as_u32(),
+            NoteTagPattern::Prefix { tag: prefix, len } => {
+                let mask = u32::MAX.checked_shl(32 - u32::from((*len).min(32))).unwrap_or(0);
+                prefix.as_u32() & mask == tag.as_u32() & mask
+            },
+        }
+    }
+}
+
 // CONVERSIONS INTO NOTE TAG
 // ================================================================================================
 
@@ -386,12 +521,14 @@ impl Deserializable for NoteTag {
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec::Vec;
 
     use assert_matches::assert_matches;
+    use rand::SeedableRng;
 
-    use super::{NoteExecutionMode, NoteTag};
+    use super::{NoteExecutionMode, NoteTag, NoteTagAccountMatch, NoteTagPattern};
     use crate::NoteError;
-    use crate::account::AccountId;
+    use crate::account::{AccountId, AccountIdVersion, AccountStorageMode, AccountType};
     use crate::note::NoteType;
     use crate::note::note_tag::{
         LOCAL_ANY,
@@ -418,6 +555,7 @@ mod tests {
         ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
         ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2,
         ACCOUNT_ID_SENDER,
+        AccountIdBuilder,
     };
 
     #[test]
@@ -654,6 +792,183 @@ mod tests {
         );
     }
 
+    #[test]
+    fn use_case_id_and_payload() {
+        let max_use_case_id = (1 << NoteTag::MAX_USE_CASE_ID_EXPONENT) - 1;
+
+        for use_case_id in [0, 1, max_use_case_id] {
+            for payload in [0, 1, u16::MAX] {
+                let tags = [
+                    NoteTag::for_public_use_case(use_case_id, payload, NoteExecutionMode::Network)
+                        .unwrap(),
+                    NoteTag::for_public_use_case(use_case_id, payload, NoteExecutionMode::Local)
+                        .unwrap(),
+                    NoteTag::for_local_use_case(use_case_id, payload).unwrap(),
+                ];
+
+                for tag in tags {
+                    assert_eq!(tag.use_case_id(), Some(use_case_id));
+                    assert_eq!(tag.payload(), Some(payload));
+                }
+            }
+        }
+
+        assert_matches!(
+          NoteTag::for_public_use_case(max_use_case_id + 1, 0, NoteExecutionMode::Network)
+              .unwrap_err(),
+          NoteError::NoteTagUseCaseTooLarge(use_case) if use_case == max_use_case_id + 1
+        );
+        assert_matches!(
+            NoteTag::for_local_use_case(u16::MAX, 0).unwrap_err(),
+            NoteError::NoteTagUseCaseTooLarge(u16::MAX)
+        );
+
+        let network_account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_NETWORK_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let network_account_tag = NoteTag::from_account_id(network_account_id);
+        assert_eq!(network_account_tag.use_case_id(), None);
+        assert_eq!(network_account_tag.payload(), None);
+    }
+
+    #[test]
+    fn matching_accounts() {
+        let local_account = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let other_local_account = AccountId::dummy(
+            [0xab; 15],
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Public,
+        );
+        let network_account =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_NETWORK_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let other_network_account = AccountId::dummy(
+            [0xcd; 15],
+            AccountIdVersion::Version0,
+            AccountType::FungibleFaucet,
+            AccountStorageMode::Network,
+        );
+        let candidates =
+            [local_account, other_local_account, network_account, other_network_account];
+
+        let matching = |tag: NoteTag| -> Vec<AccountId> {
+            tag.matching_accounts(candidates.iter()).copied().collect()
+        };
+
+        assert_eq!(matching(NoteTag::from_account_id(local_account)), [local_account]);
+        assert_eq!(matching(NoteTag::from_account_id(network_account)), [network_account]);
+
+        // Shorter tags are not prefix-matched, since their trailing zero bits would otherwise
+        // match any account whose ID has the same leading bits.
+        let short_tag = NoteTag::from_local_account_id(other_local_account, 8).unwrap();
+        assert_eq!(
+            short_tag.account_match(&other_local_account),
+            NoteTagAccountMatch::DoesNotMatch
+        );
+        assert!(matching(short_tag).is_empty());
+
+        // Use case tags do not target an account.
+        let long_tag =
+            NoteTag::from_local_account_id(other_local_account, NoteTag::MAX_LOCAL_TAG_LENGTH)
+                .unwrap();
+        let local_use_case = NoteTag::for_local_use_case(2, 3).unwrap();
+        let public_local_use_case =
+            NoteTag::for_public_use_case(2, 3, NoteExecutionMode::Local).unwrap();
+        let network_use_case =
+            NoteTag::for_public_use_case(2, 3, NoteExecutionMode::Network).unwrap();
+        for tag in [long_tag, local_use_case, public_local_use_case, network_use_case] {
+            for account_id in candidates {
+                assert_eq!(tag.account_match(&account_id), NoteTagAccountMatch::Unknown);
+            }
+            assert!(matching(tag).is_empty());
+        }
+
+        // Tags of network accounts never match local accounts and vice versa.
+        assert_eq!(
+            NoteTag::from_account_id(network_account).account_match(&local_account),
+            NoteTagAccountMatch::DoesNotMatch
+        );
+        assert_eq!(
+            NoteTag::from_account_id(local_account).account_match(&network_account),
+            NoteTagAccountMatch::DoesNotMatch
+        );
+    }
+
+    #[test]
+    fn account_match_false_positives() {
+        let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(0x2351);
+
+        for (storage_mode, tag_len) in [
+            (AccountStorageMode::Private, NoteTag::DEFAULT_LOCAL_TAG_LENGTH),
+            (AccountStorageMode::Public, NoteTag::DEFAULT_LOCAL_TAG_LENGTH),
+            (AccountStorageMode::Network, NoteTag::DEFAULT_NETWORK_TAG_LENGTH),
+        ] {
+            // The most significant tag_len bits of the ID prefix, which are encoded in the tag.
+            let truncated_id =
+                |account_id: AccountId| u64::from(account_id.prefix()) >> (64 - tag_len);
+
+            for _ in 0..100 {
+                let account_id =
+                    AccountIdBuilder::new().storage_mode(storage_mode).build_with_rng(&mut rng);
+                let other_account_id =
+                    AccountIdBuilder::new().storage_mode(storage_mode).build_with_rng(&mut rng);
+                let tag = NoteTag::from_account_id(account_id);
+
+                assert_eq!(tag.account_match(&account_id), NoteTagAccountMatch::Matches);
+                let expected = if truncated_id(account_id) == truncated_id(other_account_id) {
+                    NoteTagAccountMatch::Matches
+                } else {
+                    NoteTagAccountMatch::DoesNotMatch
+                };
+                assert_eq!(tag.account_match(&other_account_id), expected);
+            }
+        }
+
+        // Accounts whose IDs share the most significant 14 bits collide on their local tags.
+        let local_account = AccountId::dummy(
+            [0xab; 15],
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountUpdatableCode,
+            AccountStorageMode::Public,
+        );
+        let mut colliding_bytes = [0xcd; 15];
+        colliding_bytes[..2].copy_from_slice(&[0xab; 2]);
+        let colliding_account = AccountId::dummy(
+            colliding_bytes,
+            AccountIdVersion::Version0,
+            AccountType::RegularAccountImmutableCode,
+            AccountStorageMode::Private,
+        );
+        assert_eq!(
+            NoteTag::from_account_id(local_account).account_match(&colliding_account),
+            NoteTagAccountMatch::Matches
+        );
+    }
+
+    #[test]
+    fn note_tag_pattern() {
+        let tag = NoteTag::for_public_use_case(42, 7, NoteExecutionMode::Local).unwrap();
+        let same_use_case = NoteTag::for_public_use_case(42, 8, NoteExecutionMode::Local).unwrap();
+        let other_use_case = NoteTag::for_public_use_case(43, 7, NoteExecutionMode::Local).unwrap();
+        let other_mode = NoteTag::for_public_use_case(42, 7, NoteExecutionMode::Network).unwrap();
+
+        assert!(NoteTagPattern::Any.matches(tag));
+        assert!(NoteTagPattern::Any.matches(other_mode));
+
+        assert!(NoteTagPattern::Exact(tag).matches(tag));
+        assert!(!NoteTagPattern::Exact(tag).matches(same_use_case));
+
+        let use_case_pattern = NoteTagPattern::use_case(tag);
+        assert!(use_case_pattern.matches(tag));
+        assert!(use_case_pattern.matches(same_use_case));
+        assert!(!use_case_pattern.matches(other_use_case));
+        assert!(!use_case_pattern.matches(other_mode));
+
+        assert!(NoteTagPattern::Prefix { tag, len: 0 }.matches(other_mode));
+        assert!(NoteTagPattern::Prefix { tag, len: 2 }.matches(other_use_case));
+        assert!(!NoteTagPattern::Prefix { tag, len: 32 }.matches(same_use_case));
+        assert!(NoteTagPattern::Prefix { tag, len: u8::MAX }.matches(tag));
+    }
+
     /// Tests that as_u32 returns the correct prefix independent of the inner value.
     #[test]
     fn note_tag_as_u32() {
@@ -6,6 +6,7 @@ use super::{
     Deserializable,
     DeserializationError,
     Hasher,
+    NoteError,
     NoteInputs,
     NoteScript,
     Serializable,
@@ -60,6 +61,27 @@ impl NoteRecipient {
     pub fn digest(&self) -> Word {
         self.digest
     }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Validates that the stored digest of this recipient matches the digest computed from its
+    /// serial number, script and inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored digest does not match the computed digest.
+    pub fn validate_commitment(&self) -> Result<(), NoteError> {
+        let expected = compute_recipient_digest(self.serial_num, &self.script, &self.inputs);
+        if self.digest != expected {
+            return Err(NoteError::NoteRecipientCommitmentMismatch {
+                expected,
+                actual: self.digest,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Word {
@@ -87,6 +109,10 @@ impl Serializable for NoteRecipient {
         inputs.write_into(target);
         serial_num.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.script.get_size_hint() + self.inputs.get_size_hint() + self.serial_num.get_size_hint()
+    }
 }
 
 impl Deserializable for NoteRecipient {
@@ -98,3 +124,31 @@ impl Deserializable for NoteRecipient {
         Ok(Self::new(serial_num, script, inputs))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn validate_recipient_commitment() {
+        let recipient = NoteRecipient::new(
+            Word::from([1, 2, 3, 4u32]),
+            NoteScript::mock(),
+            NoteInputs::new(vec![5u32.into()]).unwrap(),
+        );
+        recipient.validate_commitment().unwrap();
+
+        let actual = Word::from([9, 9, 9, 9u32]);
+        let corrupted_recipient = NoteRecipient { digest: actual, ..recipient.clone() };
+        assert_matches!(
+            corrupted_recipient.validate_commitment(),
+            Err(NoteError::NoteRecipientCommitmentMismatch { expected, actual: digest })
+                if expected == recipient.digest() && digest == actual
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::fmt::Display;
@@ -5,7 +6,7 @@ use core::fmt::Display;
 use miden_processor::MastNodeExt;
 
 use super::Felt;
-use crate::assembly::mast::{MastForest, MastNodeId};
+use crate::assembly::mast::{MastForest, MastNode, MastNodeId};
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -75,6 +76,65 @@ impl NoteScript {
     pub fn entrypoint(&self) -> MastNodeId {
         self.entrypoint
     }
+
+    /// Returns an iterator over the MAST roots of the procedures called by this script.
+    ///
+    /// The iterator yields the callee of every `call` node reachable from the entrypoint of the
+    /// script, once per distinct root and in ascending order. This is the union of the
+    /// [`Self::call_branches`] of the script, so the same exclusions apply.
+    pub fn procedure_roots(&self) -> impl Iterator<Item = Word> {
+        self.call_branches().into_iter().flatten().collect::<BTreeSet<_>>().into_iter()
+    }
+
+    /// Returns the MAST roots of the procedures called by this script, grouped by execution
+    /// branch.
+    ///
+    /// Each set contains the callees of the `call` nodes which are executed along one path through
+    /// the conditionals of the script. Procedures invoked via `syscall` are kernel procedures and
+    /// are not included, nor are the callees of `dyncall`s, which are not known statically. The
+    /// bodies of the called procedures and of procedures referenced as external nodes are not
+    /// traversed.
+    pub fn call_branches(&self) -> Vec<BTreeSet<Word>> {
+        let mut branches = vec![BTreeSet::new()];
+        collect_call_branches(self.entrypoint, &self.mast, &mut branches);
+        branches
+    }
+}
+
+/// Adds the callees of the `call` nodes of the code block with the provided ID to the execution
+/// branches, creating a new branch for every conditional which calls additional procedures.
+fn collect_call_branches(
+    mast_node_id: MastNodeId,
+    forest: &MastForest,
+    branches: &mut Vec<BTreeSet<Word>>,
+) {
+    match &forest[mast_node_id] {
+        MastNode::Join(join_node) => {
+            collect_call_branches(join_node.first(), forest, branches);
+            collect_call_branches(join_node.second(), forest, branches);
+        },
+        MastNode::Split(split_node) => {
+            let current_branch = branches.last().expect("at least one execution branch").clone();
+            collect_call_branches(split_node.on_false(), forest, branches);
+
+            // If the previous branch had additional calls we need to create a new branch
+            if branches.last().expect("at least one execution branch").len() > current_branch.len()
+            {
+                branches.push(current_branch);
+            }
+
+            collect_call_branches(split_node.on_true(), forest, branches);
+        },
+        MastNode::Loop(loop_node) => collect_call_branches(loop_node.body(), forest, branches),
+        MastNode::Call(call_node) if !call_node.is_syscall() => {
+            let callee_digest = forest[call_node.callee()].digest();
+            branches
+                .last_mut()
+                .expect("at least one execution branch")
+                .insert(callee_digest);
+        },
+        MastNode::Block(_) | MastNode::Call(_) | MastNode::Dyn(_) | MastNode::External(_) => {},
+    }
 }
 
 // CONVERSIONS INTO NOTE SCRIPT
@@ -166,6 +226,14 @@ impl Serializable for NoteScript {
         self.mast.write_into(target);
         target.write_u32(self.entrypoint.as_u32());
     }
+
+    fn get_size_hint(&self) -> usize {
+        // the MAST forest does not provide a size hint, so it is serialized to measure its size
+        let mut mast_forest_target = Vec::new();
+        self.mast.write_into(&mut mast_forest_target);
+
+        mast_forest_target.len() + 0u32.get_size_hint()
+    }
 }
 
 impl Deserializable for NoteScript {
@@ -200,9 +268,12 @@ impl Display for NoteScript {
 
 #[cfg(test)]
 mod tests {
-    use super::{Felt, NoteScript, Vec};
+    use alloc::format;
+
+    use super::{BTreeSet, Felt, NoteScript, Vec};
     use crate::assembly::Assembler;
     use crate::testing::note::DEFAULT_NOTE_CODE;
+    use crate::utils::serde::Serializable;
 
     #[test]
     fn test_note_script_to_from_felt() {
@@ -216,4 +287,81 @@ mod tests {
 
         assert_eq!(note_script, decoded);
     }
+
+    #[test]
+    fn test_note_script_size_hint() {
+        let program = Assembler::default().assemble_program(DEFAULT_NOTE_CODE).unwrap();
+        let note_script = NoteScript::new(program);
+
+        assert_eq!(note_script.get_size_hint(), note_script.to_bytes().len());
+    }
+
+    #[test]
+    fn test_note_script_procedure_roots() {
+        let program = Assembler::default()
+            .assemble_program(
+                "
+                proc.foo push.1 drop end
+                proc.bar push.2 drop end
+
+                begin
+                    call.foo
+                    push.1
+                    if.true call.bar else call.foo end
+                end
+                ",
+            )
+            .unwrap();
+        let note_script = NoteScript::new(program);
+
+        let mut roots = note_script.procedure_roots().collect::<Vec<_>>();
+        let mut expected = note_script
+            .mast()
+            .procedure_digests()
+            .filter(|digest| *digest != note_script.root())
+            .collect::<Vec<_>>();
+        roots.sort();
+        expected.sort();
+        assert_eq!(roots.len(), 2);
+        assert_eq!(roots, expected);
+
+        let program = Assembler::default().assemble_program("begin push.1 drop end").unwrap();
+        assert_eq!(NoteScript::new(program).procedure_roots().count(), 0);
+    }
+
+    #[test]
+    fn test_note_script_call_branches() {
+        let program = Assembler::default()
+            .assemble_program(
+                "
+                proc.foo push.1 drop end
+                proc.bar push.2 drop end
+                proc.baz push.3 drop end
+
+                begin
+                    call.foo
+                    push.1
+                    if.true call.bar else call.baz end
+                end
+                ",
+            )
+            .unwrap();
+        let note_script = NoteScript::new(program);
+
+        // A procedure's MAST root is the root of its body, so it matches that of a program
+        // consisting of the same body.
+        let root_of = |body: &str| {
+            Assembler::default()
+                .assemble_program(format!("begin {body} end"))
+                .unwrap()
+                .hash()
+        };
+        let [foo, bar, baz] = ["push.1 drop", "push.2 drop", "push.3 drop"].map(root_of);
+
+        assert_eq!(
+            note_script.call_branches(),
+            [BTreeSet::from([foo, baz]), BTreeSet::from([foo, bar])]
+        );
+        assert_eq!(note_script.procedure_roots().collect::<BTreeSet<_>>(), [foo, bar, baz].into());
+    }
 }
@@ -0,0 +1,134 @@
+use core::fmt;
+
+use crate::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+use crate::{Felt, Word};
+
+// CUSTOM EVENT ID
+// ================================================================================================
+
+/// The identifier of a custom event emitted by an account component or script via
+/// `miden::tx::emit_event`.
+///
+/// Custom event IDs are 32-bit values. They are carried as part of the payload of a single event
+/// reserved by the transaction kernel, so they occupy their own ID space which can never collide
+/// with the IDs of events handled by the transaction kernel or the VM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CustomEventId(u32);
+
+impl CustomEventId {
+    /// Returns a new [`CustomEventId`] from the provided value.
+    pub const fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Returns the underlying value of the ID.
+    pub const fn as_u32(&self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for CustomEventId {
+    fn from(id: u32) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<CustomEventId> for Felt {
+    fn from(id: CustomEventId) -> Self {
+        Felt::from(id.0)
+    }
+}
+
+impl TryFrom<Felt> for CustomEventId {
+    type Error = Felt;
+
+    /// Returns a [`CustomEventId`] from the provided field element, or the element itself if it
+    /// lies outside of the range of custom event IDs.
+    fn try_from(id: Felt) -> Result<Self, Self::Error> {
+        u32::try_from(id.as_int()).map(Self::new).map_err(|_| id)
+    }
+}
+
+impl fmt::Display for CustomEventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serializable for CustomEventId {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.0);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.0.get_size_hint()
+    }
+}
+
+impl Deserializable for CustomEventId {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        source.read_u32().map(Self::new)
+    }
+}
+
+// CUSTOM EVENT
+// ================================================================================================
+
+/// A custom event emitted during the execution of a transaction, consisting of its ID and the
+/// payload words captured from the stack at the time the event was emitted.
+///
+/// Custom events allow account components and scripts to signal structured occurrences, e.g. that
+/// an order was filled, to off-chain systems indexing executed transactions. They are recorded by
+/// the transaction executor only and have no effect on the state transition or the proof of the
+/// transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomEvent {
+    id: CustomEventId,
+    payload: [Word; Self::PAYLOAD_NUM_WORDS],
+}
+
+impl CustomEvent {
+    /// The number of words captured from the stack as the payload of a custom event.
+    pub const PAYLOAD_NUM_WORDS: usize = 2;
+
+    /// Returns a new [`CustomEvent`] with the provided ID and payload.
+    pub fn new(id: CustomEventId, payload: [Word; Self::PAYLOAD_NUM_WORDS]) -> Self {
+        Self { id, payload }
+    }
+
+    /// Returns the ID of the event.
+    pub fn id(&self) -> CustomEventId {
+        self.id
+    }
+
+    /// Returns the payload of the event.
+    pub fn payload(&self) -> &[Word; Self::PAYLOAD_NUM_WORDS] {
+        &self.payload
+    }
+}
+
+impl Serializable for CustomEvent {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.id.write_into(target);
+        target.write_many(self.payload);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        self.id.get_size_hint() + self.payload.iter().map(Word::get_size_hint).sum::<usize>()
+    }
+}
+
+impl Deserializable for CustomEvent {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = CustomEventId::read_from(source)?;
+        let payload = source.read()?;
+
+        Ok(Self::new(id, payload))
+    }
+}
@@ -6,10 +6,12 @@ use super::{
     AccountId,
     AdviceInputs,
     BlockHeader,
+    CustomEvent,
     InputNote,
     InputNotes,
     NoteId,
     OutputNotes,
+    StorageAccessLog,
     TransactionArgs,
     TransactionId,
     TransactionOutputs,
@@ -46,6 +48,8 @@ pub struct ExecutedTransaction {
     tx_outputs: TransactionOutputs,
     account_delta: AccountDelta,
     tx_measurements: TransactionMeasurements,
+    storage_access_log: Option<StorageAccessLog>,
+    custom_events: Vec<CustomEvent>,
 }
 
 impl ExecutedTransaction {
@@ -80,9 +84,25 @@ impl ExecutedTransaction {
             tx_outputs,
             account_delta,
             tx_measurements,
+            storage_access_log: None,
+            custom_events: Vec::new(),
         }
     }
 
+    /// Returns a new [ExecutedTransaction] with the provided log of the storage accesses made
+    /// during execution attached.
+    pub fn with_storage_access_log(mut self, storage_access_log: StorageAccessLog) -> Self {
+        self.storage_access_log = Some(storage_access_log);
+        self
+    }
+
+    /// Returns a new [ExecutedTransaction] with the provided custom events emitted during
+    /// execution attached.
+    pub fn with_custom_events(mut self, custom_events: Vec<CustomEvent>) -> Self {
+        self.custom_events = custom_events;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -122,6 +142,9 @@ impl ExecutedTransaction {
     }
 
     /// Returns the block number at which the transaction will expire.
+    ///
+    /// If no expiration delta was set during execution, this is [`u32::MAX`], i.e. the maximum
+    /// expiration window.
     pub fn expiration_block_num(&self) -> BlockNumber {
         self.tx_outputs.expiration_block_num
     }
@@ -146,6 +169,11 @@ impl ExecutedTransaction {
         &self.tx_inputs
     }
 
+    /// Returns a reference to the outputs of this transaction.
+    pub fn tx_outputs(&self) -> &TransactionOutputs {
+        &self.tx_outputs
+    }
+
     /// Returns all the data requested by the VM from the advice provider while executing the
     /// transaction program.
     pub fn advice_witness(&self) -> &AdviceInputs {
@@ -158,6 +186,39 @@ impl ExecutedTransaction {
         &self.tx_measurements
     }
 
+    /// Returns the log of the account storage slots and storage map items accessed during the
+    /// execution of the transaction, or `None` if storage access tracking was not enabled on the
+    /// executor.
+    ///
+    /// The log is a debugging aid and is not part of the serialized transaction.
+    pub fn storage_access_log(&self) -> Option<&StorageAccessLog> {
+        self.storage_access_log.as_ref()
+    }
+
+    /// Returns the custom events emitted via `miden::tx::emit_event` during the execution of the
+    /// transaction, in the order in which they were emitted.
+    pub fn custom_events(&self) -> &[CustomEvent] {
+        &self.custom_events
+    }
+
+    /// Returns an estimate of the size in bytes of the serialized account update details of the
+    /// [`ProvenTransaction`](super::ProvenTransaction) resulting from proving this transaction.
+    ///
+    /// For accounts with public state, the update details contain the account delta, which is a
+    /// full state delta for new accounts. For private accounts, they contain no data besides the
+    /// type of the details. Proving fails if this size exceeds
+    /// [`ACCOUNT_UPDATE_MAX_SIZE`](crate::ACCOUNT_UPDATE_MAX_SIZE).
+    pub fn account_update_size_estimate(&self) -> usize {
+        // Size of the serialized enum tag of the update details.
+        let tag_size = 0u8.get_size_hint();
+
+        if self.initial_account().has_public_state() {
+            tag_size + self.account_delta.get_size_hint()
+        } else {
+            tag_size
+        }
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -188,6 +249,7 @@ impl Serializable for ExecutedTransaction {
         self.tx_outputs.write_into(target);
         self.account_delta.write_into(target);
         self.tx_measurements.write_into(target);
+        self.custom_events.write_into(target);
     }
 }
 
@@ -197,8 +259,12 @@ impl Deserializable for ExecutedTransaction {
         let tx_outputs = TransactionOutputs::read_from(source)?;
         let account_delta = AccountDelta::read_from(source)?;
         let tx_measurements = TransactionMeasurements::read_from(source)?;
+        let custom_events = Vec::<CustomEvent>::read_from(source)?;
+
+        let mut executed_tx = Self::new(tx_inputs, tx_outputs, account_delta, tx_measurements);
+        executed_tx.custom_events = custom_events;
 
-        Ok(Self::new(tx_inputs, tx_outputs, account_delta, tx_measurements))
+        Ok(executed_tx)
     }
 }
 
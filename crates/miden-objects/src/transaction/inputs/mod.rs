@@ -1,14 +1,16 @@
+use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
 use miden_core::utils::{Deserializable, Serializable};
 
 use super::PartialBlockchain;
-use crate::TransactionInputError;
 use crate::account::{AccountCode, PartialAccount};
+use crate::asset::AssetVault;
 use crate::block::{BlockHeader, BlockNumber};
 use crate::note::{Note, NoteInclusionProof};
 use crate::transaction::{TransactionArgs, TransactionScript};
+use crate::{AssetVaultError, TransactionInputError, Word};
 
 mod account;
 pub use account::AccountInputs;
@@ -49,35 +51,7 @@ impl TransactionInputs {
         blockchain: PartialBlockchain,
         input_notes: InputNotes<InputNote>,
     ) -> Result<Self, TransactionInputError> {
-        // Check that the partial blockchain and block header are consistent.
-        if blockchain.chain_length() != block_header.block_num() {
-            return Err(TransactionInputError::InconsistentChainLength {
-                expected: block_header.block_num(),
-                actual: blockchain.chain_length(),
-            });
-        }
-        if blockchain.peaks().hash_peaks() != block_header.chain_commitment() {
-            return Err(TransactionInputError::InconsistentChainCommitment {
-                expected: block_header.chain_commitment(),
-                actual: blockchain.peaks().hash_peaks(),
-            });
-        }
-        // Validate the authentication paths of the input notes.
-        for note in input_notes.iter() {
-            if let InputNote::Authenticated { note, proof } = note {
-                let note_block_num = proof.location().block_num();
-                let block_header = if note_block_num == block_header.block_num() {
-                    &block_header
-                } else {
-                    blockchain.get_block(note_block_num).ok_or(
-                        TransactionInputError::InputNoteBlockNotInPartialBlockchain(note.id()),
-                    )?
-                };
-                validate_is_in_block(note, proof, block_header)?;
-            }
-        }
-
-        Ok(Self {
+        let tx_inputs = Self {
             account,
             block_header,
             blockchain,
@@ -85,7 +59,10 @@ impl TransactionInputs {
             tx_args: TransactionArgs::default(),
             advice_inputs: AdviceInputs::default(),
             foreign_account_code: Vec::new(),
-        })
+        };
+        tx_inputs.validate()?;
+
+        Ok(tx_inputs)
     }
 
     /// Replaces the transaction inputs and assigns the given foreign account code.
@@ -119,6 +96,37 @@ impl TransactionInputs {
         self.advice_inputs = new_advice_inputs;
     }
 
+    /// Removes all advice map entries and Merkle store nodes whose keys are not contained in
+    /// `used_keys` from the advice inputs of the transaction.
+    ///
+    /// When re-executing a transaction, the advice data derived from the transaction inputs (e.g.
+    /// the native account, input notes and partial blockchain) as well as from the transaction
+    /// arguments is rebuilt, so only data that was provided during execution, such as lazily loaded
+    /// foreign accounts, witnesses and signatures, has to be retained. Stripping everything else
+    /// minimizes the size of the witness passed to the prover.
+    ///
+    /// Note that all entries not contained in `used_keys` are removed, including any advice that
+    /// was set up front through [`Self::with_advice_inputs`] or [`Self::set_advice_inputs`]. The
+    /// caller is responsible for including the keys of such advice in `used_keys` if it is still
+    /// needed.
+    pub fn strip_unused_advice(&mut self, used_keys: &BTreeSet<Word>) {
+        let advice_inputs = core::mem::take(&mut self.advice_inputs);
+
+        self.advice_inputs = AdviceInputs {
+            stack: advice_inputs.stack,
+            map: advice_inputs
+                .map
+                .into_iter()
+                .filter(|(key, _)| used_keys.contains(key))
+                .collect(),
+            store: advice_inputs
+                .store
+                .inner_nodes()
+                .filter(|node| used_keys.contains(&node.value))
+                .collect(),
+        };
+    }
+
     /// Updates the transaction arguments of the inputs.
     #[cfg(feature = "testing")]
     pub fn set_tx_args(&mut self, tx_args: TransactionArgs) {
@@ -174,6 +182,102 @@ impl TransactionInputs {
         &self.tx_args
     }
 
+    /// Returns a vault containing all assets of the input notes of the transaction.
+    ///
+    /// Fungible assets issued by the same faucet are merged into a single asset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the total amount of the fungible assets of a faucet exceeds the maximum fungible asset
+    ///   amount.
+    /// - the same non-fungible asset is contained in more than one input note.
+    pub fn total_input_assets(&self) -> Result<AssetVault, AssetVaultError> {
+        let mut vault = AssetVault::default();
+        for input_note in self.input_notes.iter() {
+            for asset in input_note.note().assets().iter() {
+                vault.add_asset(*asset)?;
+            }
+        }
+
+        Ok(vault)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Validates that the partial blockchain is consistent with the reference block header and
+    /// that all authenticated input notes are included in the blocks of the partial blockchain.
+    ///
+    /// These checks are performed by [`Self::new`], but not when deserializing the inputs, so this
+    /// method can be used to validate inputs received from an untrusted source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The partial blockchain does not track the block headers required to prove inclusion of any
+    ///   authenticated input note.
+    /// - The chain length or chain commitment of the partial blockchain does not match the
+    ///   reference block header.
+    /// - The inclusion proof of any authenticated input note is invalid.
+    pub fn validate(&self) -> Result<(), TransactionInputError> {
+        // Check that the partial blockchain and block header are consistent.
+        if self.blockchain.chain_length() != self.block_header.block_num() {
+            return Err(TransactionInputError::InconsistentChainLength {
+                expected: self.block_header.block_num(),
+                actual: self.blockchain.chain_length(),
+            });
+        }
+        if self.blockchain.peaks().hash_peaks() != self.block_header.chain_commitment() {
+            return Err(TransactionInputError::InconsistentChainCommitment {
+                expected: self.block_header.chain_commitment(),
+                actual: self.blockchain.peaks().hash_peaks(),
+            });
+        }
+        // Validate the authentication paths of the input notes.
+        for note in self.input_notes.iter() {
+            if let InputNote::Authenticated { note, proof, .. } = note {
+                let note_block_num = proof.location().block_num();
+                let block_header = if note_block_num == self.block_header.block_num() {
+                    &self.block_header
+                } else {
+                    self.blockchain.get_block(note_block_num).ok_or(
+                        TransactionInputError::InputNoteBlockNotInPartialBlockchain(note.id()),
+                    )?
+                };
+                validate_is_in_block(note, proof, block_header)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates that all input notes are executable at the reference block of the transaction
+    /// according to their [`NoteExecutionHint`](crate::note::NoteExecutionHint).
+    ///
+    /// Notes whose execution hint does not allow determining whether they can be consumed (i.e.
+    /// [`NoteExecutionHint::None`](crate::note::NoteExecutionHint::None)) are considered valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the execution hint of any input note indicates that the note cannot be
+    /// consumed at the reference block.
+    pub fn validate_expiry(&self) -> Result<(), TransactionInputError> {
+        let ref_block = self.ref_block();
+
+        for input_note in self.input_notes.iter() {
+            let note = input_note.note();
+            if note.metadata().execution_hint().can_be_consumed(ref_block) == Some(false) {
+                return Err(TransactionInputError::InputNoteNotExecutable {
+                    note_id: note.id(),
+                    ref_block,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -236,6 +340,13 @@ fn validate_is_in_block(
     proof: &NoteInclusionProof,
     block_header: &BlockHeader,
 ) -> Result<(), TransactionInputError> {
+    if proof.location().block_num() != block_header.block_num() {
+        return Err(TransactionInputError::InputNoteNotInBlock(
+            note.id(),
+            block_header.block_num(),
+        ));
+    }
+
     let note_index = proof.location().node_index_in_block().into();
     let note_commitment = note.commitment();
     proof
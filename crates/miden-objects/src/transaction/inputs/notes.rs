@@ -1,8 +1,9 @@
-use alloc::collections::BTreeSet;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec::Vec;
 
-use super::TransactionInputError;
-use crate::note::{Note, NoteId, NoteInclusionProof, NoteLocation, Nullifier};
+use super::{TransactionInputError, validate_is_in_block};
+use crate::block::{BlockHeader, BlockNumber};
+use crate::note::{Note, NoteId, NoteInclusionProof, NoteLocation, NoteTagPattern, Nullifier};
 use crate::utils::serde::{
     ByteReader,
     ByteWriter,
@@ -140,11 +141,77 @@ impl InputNotes<InputNote> {
     /// - The total number of notes is greater than [`MAX_INPUT_NOTES_PER_TX`].
     /// - The vector of notes contains duplicates.
     pub fn from_unauthenticated_notes(notes: Vec<Note>) -> Result<Self, TransactionInputError> {
-        let input_note_vec =
-            notes.into_iter().map(|note| InputNote::Unauthenticated { note }).collect();
+        let input_note_vec = notes.into_iter().map(InputNote::unauthenticated).collect();
 
         Self::new(input_note_vec)
     }
+
+    /// Returns the IDs of the unauthenticated notes in this [`InputNotes`], in the order of the
+    /// notes.
+    pub fn unauthenticated_note_ids(&self) -> Vec<NoteId> {
+        self.notes
+            .iter()
+            .filter(|note| matches!(note, InputNote::Unauthenticated { .. }))
+            .map(InputNote::id)
+            .collect()
+    }
+
+    /// Authenticates the unauthenticated notes for which `proofs` contains an inclusion proof and
+    /// returns the IDs of the notes which remain unauthenticated.
+    ///
+    /// Each proof is verified against the note root of the header of the block referenced by the
+    /// proof, which must be contained in `block_headers`. Proofs for notes which are already
+    /// authenticated or not part of this [`InputNotes`] are ignored.
+    ///
+    /// The notes are only updated if all proofs are valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the header of the block referenced by a proof is not contained in `block_headers`.
+    /// - a proof does not prove the inclusion of its note in the referenced block.
+    pub fn authenticate_all(
+        &mut self,
+        mut proofs: BTreeMap<NoteId, NoteInclusionProof>,
+        block_headers: &BTreeMap<BlockNumber, BlockHeader>,
+    ) -> Result<Vec<NoteId>, TransactionInputError> {
+        for input_note in self.notes.iter() {
+            let InputNote::Unauthenticated { note, .. } = input_note else {
+                continue;
+            };
+            let Some(proof) = proofs.get(&note.id()) else {
+                continue;
+            };
+
+            let block_num = proof.location().block_num();
+            let block_header = block_headers
+                .get(&block_num)
+                .ok_or(TransactionInputError::InputNoteBlockHeaderMissing(note.id(), block_num))?;
+            validate_is_in_block(note, proof, block_header)?;
+        }
+
+        self.notes = core::mem::take(&mut self.notes)
+            .into_iter()
+            .map(|input_note| match input_note {
+                InputNote::Unauthenticated { note, args } => match proofs.remove(&note.id()) {
+                    Some(proof) => InputNote::Authenticated { note, proof, args },
+                    None => InputNote::Unauthenticated { note, args },
+                },
+                authenticated_note => authenticated_note,
+            })
+            .collect();
+        self.commitment = build_input_note_commitment(&self.notes);
+
+        Ok(self.unauthenticated_note_ids())
+    }
+
+    /// Returns an iterator over the notes whose tags match the provided pattern.
+    pub fn iter_matching<'a>(
+        &'a self,
+        pattern: &'a NoteTagPattern,
+    ) -> impl Iterator<Item = &'a InputNote> + 'a {
+        self.notes.iter().filter(|note| pattern.matches(note.note().metadata().tag()))
+    }
 }
 
 impl<T> IntoIterator for InputNotes<T> {
@@ -192,6 +259,10 @@ impl<T: Serializable> Serializable for InputNotes<T> {
         target.write_u16(self.notes.len() as u16);
         target.write_many(&self.notes);
     }
+
+    fn get_size_hint(&self) -> usize {
+        0u16.get_size_hint() + self.notes.iter().map(T::get_size_hint).sum::<usize>()
+    }
 }
 
 impl<T: Deserializable + ToInputNoteCommitments> Deserializable for InputNotes<T> {
@@ -230,14 +301,21 @@ const AUTHENTICATED: u8 = 0;
 const UNAUTHENTICATED: u8 = 1;
 
 /// An input note for a transaction.
+///
+/// An input note can optionally carry the note arguments with which it should be consumed, see
+/// [`InputNote::with_args`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InputNote {
     /// Input notes whose existences in the chain is verified by the transaction kernel.
-    Authenticated { note: Note, proof: NoteInclusionProof },
+    Authenticated {
+        note: Note,
+        proof: NoteInclusionProof,
+        args: Option<Word>,
+    },
 
     /// Input notes whose existence in the chain is not verified by the transaction kernel, but
     /// instead is delegated to the protocol kernels.
-    Unauthenticated { note: Note },
+    Unauthenticated { note: Note, args: Option<Word> },
 }
 
 impl InputNote {
@@ -246,12 +324,51 @@ impl InputNote {
 
     /// Returns an authenticated [InputNote].
     pub fn authenticated(note: Note, proof: NoteInclusionProof) -> Self {
-        Self::Authenticated { note, proof }
+        Self::Authenticated { note, proof, args: None }
     }
 
     /// Returns an unauthenticated [InputNote].
     pub fn unauthenticated(note: Note) -> Self {
-        Self::Unauthenticated { note }
+        Self::Unauthenticated { note, args: None }
+    }
+
+    /// Returns the authenticated [InputNote] for this note with the provided inclusion proof,
+    /// preserving its note arguments.
+    ///
+    /// The proof is verified against the note root of the provided block header. If the note is
+    /// already authenticated, its inclusion proof is replaced by the provided one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the proof does not reference the block of the provided header.
+    /// - the proof does not prove the inclusion of the note in the block.
+    pub fn authenticate(
+        self,
+        proof: NoteInclusionProof,
+        block_header: &BlockHeader,
+    ) -> Result<Self, TransactionInputError> {
+        let (Self::Authenticated { note, args, .. } | Self::Unauthenticated { note, args }) = self;
+        validate_is_in_block(&note, &proof, block_header)?;
+
+        Ok(Self::Authenticated { note, proof, args })
+    }
+
+    /// Returns the [InputNote] with the provided note arguments attached.
+    ///
+    /// When the note is executed by the transaction executor, the arguments are added to the note
+    /// arguments of the [`TransactionArgs`](crate::transaction::TransactionArgs), unless these
+    /// already specify arguments for this note.
+    ///
+    /// If the note arguments were already set, they will be overwritten with the newly provided
+    /// ones.
+    #[must_use]
+    pub fn with_args(mut self, args: Word) -> Self {
+        match &mut self {
+            Self::Authenticated { args: note_args, .. }
+            | Self::Unauthenticated { args: note_args, .. } => *note_args = Some(args),
+        }
+        self
     }
 
     // ACCESSORS
@@ -266,7 +383,7 @@ impl InputNote {
     pub fn note(&self) -> &Note {
         match self {
             Self::Authenticated { note, .. } => note,
-            Self::Unauthenticated { note } => note,
+            Self::Unauthenticated { note, .. } => note,
         }
     }
 
@@ -274,7 +391,14 @@ impl InputNote {
     pub fn into_note(self) -> Note {
         match self {
             Self::Authenticated { note, .. } => note,
-            Self::Unauthenticated { note } => note,
+            Self::Unauthenticated { note, .. } => note,
+        }
+    }
+
+    /// Returns the note arguments attached to this note, if any.
+    pub fn args(&self) -> Option<Word> {
+        match self {
+            Self::Authenticated { args, .. } | Self::Unauthenticated { args, .. } => *args,
         }
     }
 
@@ -306,7 +430,7 @@ impl ToInputNoteCommitments for InputNote {
     fn note_commitment(&self) -> Option<Word> {
         match self {
             InputNote::Authenticated { .. } => None,
-            InputNote::Unauthenticated { note } => Some(note.commitment()),
+            InputNote::Unauthenticated { note, .. } => Some(note.commitment()),
         }
     }
 }
@@ -327,14 +451,16 @@ impl ToInputNoteCommitments for &InputNote {
 impl Serializable for InputNote {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         match self {
-            Self::Authenticated { note, proof } => {
+            Self::Authenticated { note, proof, args } => {
                 target.write(AUTHENTICATED);
                 target.write(note);
                 target.write(proof);
+                target.write(args);
             },
-            Self::Unauthenticated { note } => {
+            Self::Unauthenticated { note, args } => {
                 target.write(UNAUTHENTICATED);
                 target.write(note);
+                target.write(args);
             },
         }
     }
@@ -346,11 +472,13 @@ impl Deserializable for InputNote {
             AUTHENTICATED => {
                 let note = Note::read_from(source)?;
                 let proof = NoteInclusionProof::read_from(source)?;
-                Ok(Self::Authenticated { note, proof })
+                let args = Option::<Word>::read_from(source)?;
+                Ok(Self::Authenticated { note, proof, args })
             },
             UNAUTHENTICATED => {
                 let note = Note::read_from(source)?;
-                Ok(Self::Unauthenticated { note })
+                let args = Option::<Word>::read_from(source)?;
+                Ok(Self::Unauthenticated { note, args })
             },
             v => Err(DeserializationError::InvalidValue(format!("invalid input note type: {v}"))),
         }
@@ -369,6 +497,7 @@ mod input_notes_tests {
     use crate::TransactionInputError;
     use crate::note::Note;
     use crate::transaction::InputNote;
+    use crate::utils::serde::{Deserializable, Serializable};
 
     #[test]
     fn test_duplicate_input_notes() -> anyhow::Result<()> {
@@ -377,8 +506,8 @@ mod input_notes_tests {
         let mock_note_clone = mock_note.clone();
 
         let error = InputNotes::new(vec![
-            InputNote::Unauthenticated { note: mock_note },
-            InputNote::Unauthenticated { note: mock_note_clone },
+            InputNote::unauthenticated(mock_note),
+            InputNote::unauthenticated(mock_note_clone),
         ])
         .expect_err("input notes creation should fail");
 
@@ -386,4 +515,17 @@ mod input_notes_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_input_note_with_args_serialization() {
+        let args = Word::from([1, 2, 3, 4u32]);
+        let input_note = InputNote::unauthenticated(Note::mock_noop(Word::empty()));
+        assert_eq!(input_note.args(), None);
+
+        let input_note = input_note.with_args(args);
+        assert_eq!(input_note.args(), Some(args));
+
+        let decoded = InputNote::read_from_bytes(&input_note.to_bytes()).unwrap();
+        assert_eq!(decoded, input_note);
+    }
 }
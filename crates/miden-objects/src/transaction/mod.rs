@@ -4,17 +4,20 @@ use super::note::{NoteId, Nullifier};
 use super::vm::AdviceInputs;
 use super::{Felt, Hasher, WORD_SIZE, Word, ZERO};
 
+mod custom_event;
 mod executed_tx;
 mod inputs;
 mod ordered_transactions;
 mod outputs;
 mod partial_blockchain;
 mod proven_tx;
+mod storage_access_log;
 mod transaction_id;
 mod tx_args;
 mod tx_header;
 mod tx_summary;
 
+pub use custom_event::{CustomEvent, CustomEventId};
 pub use executed_tx::{ExecutedTransaction, TransactionMeasurements};
 pub use inputs::{AccountInputs, InputNote, InputNotes, ToInputNoteCommitments, TransactionInputs};
 pub use ordered_transactions::OrderedTransactionHeaders;
@@ -24,9 +27,16 @@ pub use proven_tx::{
     InputNoteCommitment,
     ProvenTransaction,
     ProvenTransactionBuilder,
+    ProvenTransactionSizeBreakdown,
     TxAccountUpdate,
 };
+pub use storage_access_log::{
+    AccountStorageAccesses,
+    StorageAccess,
+    StorageAccessKind,
+    StorageAccessLog,
+};
 pub use transaction_id::TransactionId;
 pub use tx_args::{TransactionArgs, TransactionScript};
 pub use tx_header::TransactionHeader;
-pub use tx_summary::TransactionSummary;
+pub use tx_summary::{TransactionSummary, TransactionSummaryField};
@@ -1,6 +1,8 @@
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec::Vec;
 
 use crate::account::AccountId;
+use crate::errors::ProposedBlockError;
 use crate::transaction::{TransactionHeader, TransactionId};
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use crate::{Felt, Hasher, Word, ZERO};
@@ -18,6 +20,8 @@ use crate::{Felt, Hasher, Word, ZERO};
 /// This type cannot be constructed directly, but can be retrieved through:
 /// - [`ProposedBatch::transaction_headers`](crate::batch::ProposedBatch::transaction_headers),
 /// - [`OrderedBatches::into_transactions`](crate::batch::OrderedBatches::into_transactions).
+/// - [`OrderedTransactionHeaders::try_from_batches`], which validates the transactions of the
+///   batches of a block.
 ///
 /// The rationale for this requirement is that it allows a client to cheaply validate the
 /// correctness of the transactions in a proven block returned by a remote prover.
@@ -34,11 +38,58 @@ impl OrderedTransactionHeaders {
         Self(transactions)
     }
 
+    /// Creates the ordered transaction headers of a block from the transaction headers of each of
+    /// its batches, e.g. obtained via
+    /// [`ProvenBatch::transactions`](crate::batch::ProvenBatch::transactions).
+    ///
+    /// The headers are flattened in the order of the batches, preserving the order of the
+    /// transactions within each batch.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - a transaction appears more than once across all batches.
+    /// - a transaction against an account does not start from the final state of the previous
+    ///   transaction against the same account.
+    pub fn try_from_batches<'batch>(
+        batches: impl IntoIterator<Item = &'batch OrderedTransactionHeaders>,
+    ) -> Result<Self, ProposedBlockError> {
+        let mut transaction_ids = BTreeSet::new();
+        let mut account_states = BTreeMap::<AccountId, Word>::new();
+        let mut transactions = Vec::new();
+
+        for tx in batches.into_iter().flat_map(|batch| batch.as_slice().iter()) {
+            if !transaction_ids.insert(tx.id()) {
+                return Err(ProposedBlockError::DuplicateTransaction { transaction_id: tx.id() });
+            }
+
+            if let Some(state_commitment) =
+                account_states.insert(tx.account_id(), tx.final_state_commitment())
+                && state_commitment != tx.initial_state_commitment()
+            {
+                return Err(ProposedBlockError::InconsistentAccountStateTransition {
+                    account_id: tx.account_id(),
+                    state_commitment,
+                    remaining_state_commitments: vec![tx.initial_state_commitment()],
+                });
+            }
+
+            transactions.push(tx.clone());
+        }
+
+        Ok(Self(transactions))
+    }
+
     /// Computes a commitment to the list of transactions.
     ///
     /// This is a sequential hash over each transaction's ID and its account ID.
     pub fn commitment(&self) -> Word {
-        Self::compute_commitment(self.0.as_slice().iter().map(|tx| (tx.id(), tx.account_id())))
+        Self::compute_commitment(self.iter_ids().map(|(account_id, tx_id)| (tx_id, account_id)))
+    }
+
+    /// Returns an iterator over the account ID and transaction ID of each transaction, in order.
+    pub fn iter_ids(&self) -> impl Iterator<Item = (AccountId, TransactionId)> + '_ {
+        self.0.iter().map(|tx| (tx.account_id(), tx.id()))
     }
 
     /// Returns a reference to the underlying transaction headers.
@@ -86,3 +137,61 @@ impl Deserializable for OrderedTransactionHeaders {
         source.read().map(OrderedTransactionHeaders::new_unchecked)
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+    use crate::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
+    use crate::transaction::InputNotes;
+
+    fn mock_tx(account_id: AccountId, initial_state: u32, final_state: u32) -> TransactionHeader {
+        TransactionHeader::new(
+            account_id,
+            Word::from([initial_state, 0, 0, 0]),
+            Word::from([final_state, 0, 0, 0]),
+            InputNotes::default(),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn try_from_batches_validates_account_state_transitions() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let tx0 = mock_tx(account_id, 0, 1);
+        let tx1 = mock_tx(account_id, 1, 2);
+        let tx2 = mock_tx(account_id, 2, 3);
+
+        let batch0 = OrderedTransactionHeaders::new_unchecked(vec![tx0.clone(), tx1.clone()]);
+        let batch1 = OrderedTransactionHeaders::new_unchecked(vec![tx2.clone()]);
+
+        let transactions = OrderedTransactionHeaders::try_from_batches([&batch0, &batch1]).unwrap();
+        assert_eq!(transactions.as_slice(), [tx0.clone(), tx1.clone(), tx2.clone()]);
+        assert_eq!(
+            transactions.iter_ids().collect::<Vec<_>>(),
+            [(account_id, tx0.id()), (account_id, tx1.id()), (account_id, tx2.id())]
+        );
+
+        // The batches applied in the wrong order do not form a valid chain of account states.
+        assert_matches!(
+            OrderedTransactionHeaders::try_from_batches([&batch1, &batch0]),
+            Err(ProposedBlockError::InconsistentAccountStateTransition {
+                account_id: id,
+                state_commitment,
+                remaining_state_commitments,
+            }) if id == account_id
+                && state_commitment == tx2.final_state_commitment()
+                && remaining_state_commitments == [tx0.initial_state_commitment()]
+        );
+
+        assert_matches!(
+            OrderedTransactionHeaders::try_from_batches([&batch0, &batch0]),
+            Err(ProposedBlockError::DuplicateTransaction { transaction_id }) if transaction_id == tx0.id()
+        );
+    }
+}
@@ -3,7 +3,7 @@ use alloc::string::ToString;
 use alloc::vec::Vec;
 use core::fmt::Debug;
 
-use crate::account::AccountHeader;
+use crate::account::{AccountHeader, AccountId};
 use crate::asset::FungibleAsset;
 use crate::block::BlockNumber;
 use crate::note::{
@@ -13,6 +13,7 @@ use crate::note::{
     NoteId,
     NoteMetadata,
     NoteRecipient,
+    NoteType,
     PartialNote,
     compute_note_commitment,
 };
@@ -141,6 +142,13 @@ impl OutputNotes {
         self.notes.iter()
     }
 
+    /// Returns an iterator over the notes of the provided [`NoteType`] in this [OutputNotes].
+    ///
+    /// The notes are returned in the order in which they are stored in this [OutputNotes].
+    pub fn filter_by_type(&self, note_type: NoteType) -> impl Iterator<Item = &OutputNote> {
+        self.notes.iter().filter(move |note| note.metadata().note_type() == note_type)
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -173,6 +181,10 @@ impl Serializable for OutputNotes {
         target.write_u16(self.notes.len() as u16);
         target.write_many(&self.notes);
     }
+
+    fn get_size_hint(&self) -> usize {
+        0u16.get_size_hint() + self.notes.iter().map(OutputNote::get_size_hint).sum::<usize>()
+    }
 }
 
 impl Deserializable for OutputNotes {
@@ -244,6 +256,19 @@ impl OutputNote {
         }
     }
 
+    /// Returns the ID of the account the processed [`Full`](OutputNote::Full) output note is
+    /// addressed to, if the note follows the P2ID input layout. Returns [`None`] otherwise or if
+    /// the note type is not [`Full`](OutputNote::Full).
+    ///
+    /// See [`Note::recipient_account_id`] for more details.
+    pub fn recipient_account_id(&self) -> Option<AccountId> {
+        match self {
+            OutputNote::Full(note) => note.recipient_account_id(),
+            OutputNote::Partial(_) => None,
+            OutputNote::Header(_) => None,
+        }
+    }
+
     /// Note's metadata.
     pub fn metadata(&self) -> &NoteMetadata {
         match self {
@@ -315,6 +340,15 @@ impl Serializable for OutputNote {
             },
         }
     }
+
+    fn get_size_hint(&self) -> usize {
+        let tag_size = FULL.get_size_hint();
+        match self {
+            OutputNote::Full(note) => tag_size + note.get_size_hint(),
+            OutputNote::Partial(note) => tag_size + note.get_size_hint(),
+            OutputNote::Header(note) => tag_size + note.get_size_hint(),
+        }
+    }
 }
 
 impl Deserializable for OutputNote {
@@ -333,10 +367,12 @@ impl Deserializable for OutputNote {
 
 #[cfg(test)]
 mod output_notes_tests {
+    use alloc::vec::Vec;
+
     use assert_matches::assert_matches;
 
     use super::OutputNotes;
-    use crate::note::Note;
+    use crate::note::{Note, NoteMetadata, NoteType};
     use crate::transaction::OutputNote;
     use crate::{TransactionOutputError, Word};
 
@@ -354,4 +390,43 @@ mod output_notes_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_filter_output_notes_by_type() -> anyhow::Result<()> {
+        let mock_note = |serial_num: u32, note_type: NoteType| -> anyhow::Result<OutputNote> {
+            let note = Note::mock_noop(Word::from([serial_num, 0, 0, 0]));
+            let metadata = note.metadata();
+            let metadata = NoteMetadata::new(
+                metadata.sender(),
+                note_type,
+                metadata.tag(),
+                metadata.execution_hint(),
+                metadata.aux(),
+            )?;
+            Ok(OutputNote::Full(Note::new(
+                note.assets().clone(),
+                metadata,
+                note.recipient().clone(),
+            )))
+        };
+
+        let public_note = mock_note(0, NoteType::Public)?;
+        let private_note = mock_note(1, NoteType::Private)?;
+        let encrypted_note = mock_note(2, NoteType::Encrypted)?;
+        let other_public_note = mock_note(3, NoteType::Public)?.shrink();
+        let output_notes = OutputNotes::new(vec![
+            public_note.clone(),
+            private_note.clone(),
+            encrypted_note.clone(),
+            other_public_note.clone(),
+        ])?;
+
+        let filter =
+            |note_type| output_notes.filter_by_type(note_type).cloned().collect::<Vec<_>>();
+        assert_eq!(filter(NoteType::Public), [public_note, other_public_note]);
+        assert_eq!(filter(NoteType::Private), [private_note]);
+        assert_eq!(filter(NoteType::Encrypted), [encrypted_note]);
+
+        Ok(())
+    }
 }
@@ -132,6 +132,22 @@ impl ProvenTransaction {
         self.input_notes.iter().map(InputNoteCommitment::nullifier)
     }
 
+    /// Returns the sizes in bytes of the sections of this transaction when serialized.
+    pub fn serialized_size_breakdown(&self) -> ProvenTransactionSizeBreakdown {
+        let other = self.ref_block_num.to_bytes().len()
+            + self.ref_block_commitment.to_bytes().len()
+            + self.fee.to_bytes().len()
+            + self.expiration_block_num.to_bytes().len();
+
+        ProvenTransactionSizeBreakdown {
+            account_update: self.account_update.to_bytes().len(),
+            input_notes: self.input_notes.to_bytes().len(),
+            output_notes: self.output_notes.to_bytes().len(),
+            proof: self.proof.to_bytes().len(),
+            other,
+        }
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -237,6 +253,56 @@ impl Deserializable for ProvenTransaction {
     }
 }
 
+// PROVEN TRANSACTION SIZE BREAKDOWN
+// ================================================================================================
+
+/// The sizes in bytes of the sections of a serialized [`ProvenTransaction`], see
+/// [`ProvenTransaction::serialized_size_breakdown`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProvenTransactionSizeBreakdown {
+    account_update: usize,
+    input_notes: usize,
+    output_notes: usize,
+    proof: usize,
+    other: usize,
+}
+
+impl ProvenTransactionSizeBreakdown {
+    /// Returns the size of the serialized [`TxAccountUpdate`].
+    ///
+    /// Note that only the size of its [`AccountUpdateDetails`] is limited by
+    /// [`ACCOUNT_UPDATE_MAX_SIZE`].
+    pub fn account_update(&self) -> usize {
+        self.account_update
+    }
+
+    /// Returns the size of the serialized input note commitments.
+    pub fn input_notes(&self) -> usize {
+        self.input_notes
+    }
+
+    /// Returns the size of the serialized output notes.
+    pub fn output_notes(&self) -> usize {
+        self.output_notes
+    }
+
+    /// Returns the size of the serialized proof.
+    pub fn proof(&self) -> usize {
+        self.proof
+    }
+
+    /// Returns the size of the remaining serialized fields, i.e. of the reference block, the fee
+    /// and the expiration block number.
+    pub fn other(&self) -> usize {
+        self.other
+    }
+
+    /// Returns the size of the entire serialized transaction.
+    pub fn total(&self) -> usize {
+        self.account_update + self.input_notes + self.output_notes + self.proof + self.other
+    }
+}
+
 // PROVEN TRANSACTION BUILDER
 // ================================================================================================
 
@@ -493,7 +559,7 @@ impl TxAccountUpdate {
                 if is_new_account {
                     // Validate that for new accounts, the full account state can be constructed
                     // from the delta. This will fail if it is not such a full state delta.
-                    let account = Account::try_from(delta).map_err(|err| {
+                    let account = Account::try_from(delta.as_ref()).map_err(|err| {
                         ProvenTransactionError::NewPublicStateAccountRequiresFullStateDelta {
                             id: delta.id(),
                             source: err,
@@ -562,6 +628,14 @@ impl Serializable for TxAccountUpdate {
         self.account_delta_commitment.write_into(target);
         self.details.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.account_id.get_size_hint()
+            + self.init_state_commitment.get_size_hint()
+            + self.final_state_commitment.get_size_hint()
+            + self.account_delta_commitment.get_size_hint()
+            + self.details.get_size_hint()
+    }
 }
 
 impl Deserializable for TxAccountUpdate {
@@ -634,7 +708,7 @@ impl From<&InputNote> for InputNoteCommitment {
                 nullifier: note.nullifier(),
                 header: None,
             },
-            InputNote::Unauthenticated { note } => Self {
+            InputNote::Unauthenticated { note, .. } => Self {
                 nullifier: note.nullifier(),
                 header: Some(*note.header()),
             },
@@ -666,6 +740,10 @@ impl Serializable for InputNoteCommitment {
         self.nullifier.write_into(target);
         self.header.write_into(target);
     }
+
+    fn get_size_hint(&self) -> usize {
+        self.nullifier.get_size_hint() + self.header.get_size_hint()
+    }
 }
 
 impl Deserializable for InputNoteCommitment {
@@ -682,6 +760,7 @@ impl Deserializable for InputNoteCommitment {
 
 #[cfg(test)]
 mod tests {
+    use alloc::boxed::Box;
     use alloc::collections::BTreeMap;
 
     use anyhow::Context;
@@ -749,7 +828,7 @@ mod tests {
             .build_existing()?;
         let delta = AccountDelta::try_from(account.clone())?;
 
-        let details = AccountUpdateDetails::Delta(delta);
+        let details = AccountUpdateDetails::Delta(Box::new(delta));
 
         TxAccountUpdate::new(
             account.id(),
@@ -779,7 +858,7 @@ mod tests {
         let storage_delta = AccountStorageDelta::from_iters([], [], [(4, storage_delta)]);
         let delta = AccountDelta::new(account_id, storage_delta, AccountVaultDelta::default(), ONE)
             .unwrap();
-        let details = AccountUpdateDetails::Delta(delta);
+        let details = AccountUpdateDetails::Delta(Box::new(delta));
         let details_size = details.get_size_hint();
 
         let err = TxAccountUpdate::new(
@@ -0,0 +1,277 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+
+use crate::Word;
+use crate::account::AccountId;
+use crate::utils::serde::{
+    ByteReader,
+    ByteWriter,
+    Deserializable,
+    DeserializationError,
+    Serializable,
+};
+
+// STORAGE ACCESS KIND
+// ================================================================================================
+
+/// The kind of an access to an account storage slot or storage map item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageAccessKind {
+    Read,
+    Write,
+}
+
+// STORAGE ACCESS
+// ================================================================================================
+
+/// Describes whether an account storage slot or storage map item was read and/or written during
+/// the execution of a transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StorageAccess {
+    read: bool,
+    written: bool,
+}
+
+impl StorageAccess {
+    const READ_FLAG: u8 = 0b01;
+    const WRITE_FLAG: u8 = 0b10;
+
+    /// Returns `true` if the slot or map item was read.
+    pub fn is_read(&self) -> bool {
+        self.read
+    }
+
+    /// Returns `true` if the slot or map item was written.
+    pub fn is_written(&self) -> bool {
+        self.written
+    }
+
+    /// Records an access of the provided kind.
+    fn record(&mut self, kind: StorageAccessKind) {
+        match kind {
+            StorageAccessKind::Read => self.read = true,
+            StorageAccessKind::Write => self.written = true,
+        }
+    }
+}
+
+impl Serializable for StorageAccess {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let mut flags = 0u8;
+        if self.read {
+            flags |= Self::READ_FLAG;
+        }
+        if self.written {
+            flags |= Self::WRITE_FLAG;
+        }
+        target.write_u8(flags);
+    }
+
+    fn get_size_hint(&self) -> usize {
+        1
+    }
+}
+
+impl Deserializable for StorageAccess {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let flags = source.read_u8()?;
+        if flags == 0 || flags > (Self::READ_FLAG | Self::WRITE_FLAG) {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid storage access flags: {flags}"
+            )));
+        }
+
+        Ok(Self {
+            read: flags & Self::READ_FLAG != 0,
+            written: flags & Self::WRITE_FLAG != 0,
+        })
+    }
+}
+
+// ACCOUNT STORAGE ACCESSES
+// ================================================================================================
+
+/// The storage slots and storage map items of a single account which were accessed during the
+/// execution of a transaction.
+///
+/// Slot indexes are absolute, i.e. the storage offset of the accessing account component has
+/// already been applied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountStorageAccesses {
+    slots: BTreeMap<u8, StorageAccess>,
+    map_items: BTreeMap<(u8, Word), StorageAccess>,
+}
+
+impl AccountStorageAccesses {
+    /// Returns the accessed storage slots, in ascending order of their index.
+    ///
+    /// Slots accessed only via their map items are not included here.
+    pub fn slots(&self) -> &BTreeMap<u8, StorageAccess> {
+        &self.slots
+    }
+
+    /// Returns the accessed storage map items keyed by the index of their slot and their raw,
+    /// i.e. unhashed, map key.
+    pub fn map_items(&self) -> &BTreeMap<(u8, Word), StorageAccess> {
+        &self.map_items
+    }
+}
+
+impl Serializable for AccountStorageAccesses {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.slots.len());
+        for (slot_index, access) in self.slots.iter() {
+            target.write_u8(*slot_index);
+            access.write_into(target);
+        }
+
+        target.write_usize(self.map_items.len());
+        for ((slot_index, key), access) in self.map_items.iter() {
+            target.write_u8(*slot_index);
+            key.write_into(target);
+            access.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for AccountStorageAccesses {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_slots = source.read_usize()?;
+        let mut slots = BTreeMap::new();
+        for _ in 0..num_slots {
+            let slot_index = source.read_u8()?;
+            let access = StorageAccess::read_from(source)?;
+            slots.insert(slot_index, access);
+        }
+
+        let num_map_items = source.read_usize()?;
+        let mut map_items = BTreeMap::new();
+        for _ in 0..num_map_items {
+            let slot_index = source.read_u8()?;
+            let key = Word::read_from(source)?;
+            let access = StorageAccess::read_from(source)?;
+            map_items.insert((slot_index, key), access);
+        }
+
+        Ok(Self { slots, map_items })
+    }
+}
+
+// STORAGE ACCESS LOG
+// ================================================================================================
+
+/// A log of the account storage slots and storage map items accessed during the execution of a
+/// transaction, for the native account as well as for foreign accounts.
+///
+/// The log is only recorded if storage access tracking was enabled on the transaction executor,
+/// e.g. to audit which parts of an account's storage a transaction depends on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StorageAccessLog {
+    accounts: BTreeMap<AccountId, AccountStorageAccesses>,
+}
+
+impl StorageAccessLog {
+    /// Returns the storage accesses of the account with the provided ID, if any of its storage was
+    /// accessed.
+    pub fn get(&self, account_id: &AccountId) -> Option<&AccountStorageAccesses> {
+        self.accounts.get(account_id)
+    }
+
+    /// Returns an iterator over the storage accesses of all accounts, in ascending order of their
+    /// IDs.
+    pub fn iter(&self) -> impl Iterator<Item = (&AccountId, &AccountStorageAccesses)> {
+        self.accounts.iter()
+    }
+
+    /// Returns `true` if no storage accesses were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// Records an access of the provided kind to the storage slot of the provided account.
+    pub fn record_slot_access(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        kind: StorageAccessKind,
+    ) {
+        self.accounts
+            .entry(account_id)
+            .or_default()
+            .slots
+            .entry(slot_index)
+            .or_default()
+            .record(kind);
+    }
+
+    /// Records an access of the provided kind to the storage map item under the provided raw key
+    /// in the storage slot of the provided account.
+    pub fn record_map_item_access(
+        &mut self,
+        account_id: AccountId,
+        slot_index: u8,
+        key: Word,
+        kind: StorageAccessKind,
+    ) {
+        self.accounts
+            .entry(account_id)
+            .or_default()
+            .map_items
+            .entry((slot_index, key))
+            .or_default()
+            .record(kind);
+    }
+}
+
+impl Serializable for StorageAccessLog {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.accounts.write_into(target);
+    }
+}
+
+impl Deserializable for StorageAccessLog {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let accounts = BTreeMap::<AccountId, AccountStorageAccesses>::read_from(source)?;
+        Ok(Self { accounts })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::account_id::{
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    };
+
+    #[test]
+    fn storage_access_log_serde() {
+        let native_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let foreign_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let key = Word::from([1, 2, 3, 4u32]);
+
+        let mut log = StorageAccessLog::default();
+        log.record_slot_access(native_id, 0, StorageAccessKind::Read);
+        log.record_slot_access(native_id, 0, StorageAccessKind::Write);
+        log.record_map_item_access(native_id, 1, key, StorageAccessKind::Write);
+        log.record_slot_access(foreign_id, 2, StorageAccessKind::Read);
+
+        let native_accesses = log.get(&native_id).unwrap();
+        let slot_access = native_accesses.slots()[&0];
+        assert!(slot_access.is_read() && slot_access.is_written());
+        let map_item_access = native_accesses.map_items()[&(1, key)];
+        assert!(!map_item_access.is_read() && map_item_access.is_written());
+        assert_eq!(log.iter().count(), 2);
+
+        let bytes = log.to_bytes();
+        assert_eq!(StorageAccessLog::read_from_bytes(&bytes).unwrap(), log);
+
+        // an access which is neither a read nor a write is invalid
+        assert!(StorageAccess::read_from_bytes(&[0]).is_err());
+        assert!(StorageAccess::read_from_bytes(&[4]).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
@@ -16,7 +16,7 @@ use crate::utils::serde::{
     Serializable,
 };
 use crate::vm::{AdviceInputs, AdviceMap, Program};
-use crate::{EMPTY_WORD, MastForest, MastNodeId};
+use crate::{EMPTY_WORD, MAX_INPUT_NOTES_PER_TX, MastForest, MastNodeId, TransactionInputError};
 
 // TRANSACTION ARGUMENTS
 // ================================================================================================
@@ -39,6 +39,9 @@ use crate::{EMPTY_WORD, MastForest, MastNodeId};
 ///   this argument is not specified, the [`EMPTY_WORD`] would be used as a default value. If the
 ///   [AdviceInputs] are propagated with some user defined map entries, this argument could be used
 ///   as a key to access the corresponding value.
+/// - Maximum fee: the maximum amount of the native asset the account is willing to pay as the
+///   transaction fee. If the fee of the executed transaction exceeds it, the execution fails. If
+///   this argument is not specified, any fee is accepted.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
@@ -46,6 +49,7 @@ pub struct TransactionArgs {
     note_args: BTreeMap<NoteId, Word>,
     advice_inputs: AdviceInputs,
     auth_args: Word,
+    max_fee: Option<u64>,
 }
 
 impl TransactionArgs {
@@ -63,6 +67,7 @@ impl TransactionArgs {
             note_args: Default::default(),
             advice_inputs,
             auth_args: EMPTY_WORD,
+            max_fee: None,
         }
     }
 
@@ -109,6 +114,16 @@ impl TransactionArgs {
         self
     }
 
+    /// Returns new [TransactionArgs] instantiated with the provided maximum fee, i.e. the maximum
+    /// amount of the native asset the account is willing to pay as the transaction fee.
+    ///
+    /// If the maximum fee was already set, it will be overwritten with the newly provided one.
+    #[must_use]
+    pub fn with_max_fee(mut self, max_fee: u64) -> Self {
+        self.max_fee = Some(max_fee);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -133,6 +148,23 @@ impl TransactionArgs {
         self.note_args.get(&note_id)
     }
 
+    /// Returns the number of notes for which note arguments were provided.
+    ///
+    /// Each note argument is expected to be consumed by a distinct input note, so this is a lower
+    /// bound on the number of input notes of the transaction.
+    pub fn note_count(&self) -> usize {
+        self.note_args.len()
+    }
+
+    /// Returns the size of the serialized transaction script in bytes, or `0` if no transaction
+    /// script was specified.
+    ///
+    /// The script is serialized to measure its size, so this should not be called repeatedly for
+    /// large scripts.
+    pub fn script_size_hint(&self) -> usize {
+        self.tx_script.as_ref().map(|tx_script| tx_script.to_bytes().len()).unwrap_or(0)
+    }
+
     /// Returns a reference to the internal [AdviceInputs].
     pub fn advice_inputs(&self) -> &AdviceInputs {
         &self.advice_inputs
@@ -149,6 +181,31 @@ impl TransactionArgs {
         self.auth_args
     }
 
+    /// Returns the maximum amount of the native asset the account is willing to pay as the
+    /// transaction fee, or `None` if any fee is accepted.
+    pub fn max_fee(&self) -> Option<u64> {
+        self.max_fee
+    }
+
+    /// Validates that these arguments do not exceed the limits of a single transaction.
+    ///
+    /// This allows detecting violations before the transaction is executed, without constructing
+    /// the [`TransactionInputs`](super::TransactionInputs) of the transaction.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The number of notes for which note arguments were provided is greater than
+    ///   [`MAX_INPUT_NOTES_PER_TX`].
+    pub fn validate_limits(&self) -> Result<(), TransactionInputError> {
+        let note_count = self.note_count();
+        if note_count > MAX_INPUT_NOTES_PER_TX {
+            return Err(TransactionInputError::TooManyInputNotes(note_count));
+        }
+
+        Ok(())
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -215,6 +272,14 @@ impl TransactionArgs {
         }
     }
 
+    /// Extends the note arguments with the provided note ID and argument pairs.
+    ///
+    /// Arguments for notes which already have arguments will be overwritten with the newly
+    /// provided ones.
+    pub fn extend_note_args<T: IntoIterator<Item = (NoteId, Word)>>(&mut self, iter: T) {
+        self.note_args.extend(iter);
+    }
+
     /// Extends the internal advice inputs' map with the provided key-value pairs.
     pub fn extend_advice_map<T: IntoIterator<Item = (Word, Vec<Felt>)>>(&mut self, iter: T) {
         self.advice_inputs.map.extend(iter);
@@ -229,6 +294,22 @@ impl TransactionArgs {
     pub fn extend_advice_inputs(&mut self, advice_inputs: AdviceInputs) {
         self.advice_inputs.extend(advice_inputs);
     }
+
+    /// Removes the advice map entries and Merkle store nodes with the provided keys from the
+    /// internal advice inputs.
+    pub fn remove_advice(&mut self, keys: &BTreeSet<Word>) {
+        let advice_inputs = core::mem::take(&mut self.advice_inputs);
+
+        self.advice_inputs = AdviceInputs {
+            stack: advice_inputs.stack,
+            map: advice_inputs.map.into_iter().filter(|(key, _)| !keys.contains(key)).collect(),
+            store: advice_inputs
+                .store
+                .inner_nodes()
+                .filter(|node| !keys.contains(&node.value))
+                .collect(),
+        };
+    }
 }
 
 /// Concatenates two [`Word`]s into a [`Vec<Felt>`] containing 8 elements.
@@ -252,6 +333,7 @@ impl Serializable for TransactionArgs {
         self.note_args.write_into(target);
         self.advice_inputs.write_into(target);
         self.auth_args.write_into(target);
+        self.max_fee.write_into(target);
     }
 }
 
@@ -262,6 +344,7 @@ impl Deserializable for TransactionArgs {
         let note_args = BTreeMap::<NoteId, Word>::read_from(source)?;
         let advice_inputs = AdviceInputs::read_from(source)?;
         let auth_args = Word::read_from(source)?;
+        let max_fee = Option::<u64>::read_from(source)?;
 
         Ok(Self {
             tx_script,
@@ -269,6 +352,7 @@ impl Deserializable for TransactionArgs {
             note_args,
             advice_inputs,
             auth_args,
+            max_fee,
         })
     }
 }
@@ -320,6 +404,14 @@ impl TransactionScript {
     pub fn root(&self) -> Word {
         self.mast[self.entrypoint].digest()
     }
+
+    /// Returns a reference to the inputs embedded into this transaction script at compile time,
+    /// i.e. the advice map of the script's [MastForest].
+    ///
+    /// These entries are added to the advice inputs of a transaction executing this script.
+    pub fn inputs(&self) -> &AdviceMap {
+        self.mast.advice_map()
+    }
 }
 
 // SERIALIZATION
@@ -343,10 +435,14 @@ impl Deserializable for TransactionScript {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use miden_core::AdviceMap;
     use miden_core::utils::{Deserializable, Serializable};
 
-    use crate::transaction::TransactionArgs;
+    use crate::assembly::Assembler;
+    use crate::note::NoteId;
+    use crate::transaction::{TransactionArgs, TransactionScript};
+    use crate::{Felt, MAX_INPUT_NOTES_PER_TX, TransactionInputError, Word};
 
     #[test]
     fn test_tx_args_serialization() {
@@ -356,4 +452,69 @@ mod tests {
 
         assert_eq!(tx_args, decoded);
     }
+
+    #[test]
+    fn test_tx_args_serialization_with_max_fee() {
+        let tx_args = TransactionArgs::new(AdviceMap::default()).with_max_fee(1_000);
+        let bytes: std::vec::Vec<u8> = tx_args.to_bytes();
+        let decoded = TransactionArgs::read_from_bytes(&bytes).unwrap();
+
+        assert_eq!(decoded.max_fee(), Some(1_000));
+        assert_eq!(tx_args, decoded);
+    }
+
+    #[test]
+    fn test_tx_args_validate_limits() {
+        let tx_args = TransactionArgs::new(AdviceMap::default());
+        assert_eq!(tx_args.note_count(), 0);
+        assert_eq!(tx_args.script_size_hint(), 0);
+        tx_args.validate_limits().unwrap();
+
+        let note_args = (0..=MAX_INPUT_NOTES_PER_TX as u64)
+            .map(|i| {
+                let word = Word::from([i, 0, 0, 0].map(Felt::new));
+                (NoteId::new(word, word), word)
+            })
+            .collect();
+        let tx_args = tx_args.with_note_args(note_args);
+        assert_eq!(tx_args.note_count(), MAX_INPUT_NOTES_PER_TX + 1);
+        assert_matches!(
+            tx_args.validate_limits(),
+            Err(TransactionInputError::TooManyInputNotes(count)) if count == MAX_INPUT_NOTES_PER_TX + 1
+        );
+    }
+
+    #[test]
+    fn test_tx_args_script_size_hint() {
+        let program = Assembler::default().assemble_program("begin push.1 drop end").unwrap();
+        let tx_script = TransactionScript::new(program);
+        let tx_args = TransactionArgs::new(AdviceMap::default()).with_tx_script(tx_script.clone());
+
+        assert!(tx_args.script_size_hint() > 0);
+        assert_eq!(tx_args.script_size_hint(), tx_script.to_bytes().len());
+        tx_args.validate_limits().unwrap();
+    }
+
+    #[test]
+    fn test_tx_script_inputs() {
+        let program = Assembler::default()
+            .assemble_program(
+                "
+                adv_map.A([1,2,3,4])=[5,6,7,8]
+
+                begin
+                    push.1 drop
+                end
+                ",
+            )
+            .unwrap();
+        let tx_script = TransactionScript::new(program);
+
+        let inputs = tx_script.inputs();
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(
+            inputs.get(&Word::from([1, 2, 3, 4u32])).map(|values| values.to_vec()),
+            Some([5, 6, 7, 8u32].map(Felt::from).to_vec())
+        );
+    }
 }
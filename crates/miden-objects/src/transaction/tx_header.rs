@@ -6,6 +6,7 @@ use crate::Word;
 use crate::note::NoteHeader;
 use crate::transaction::{
     AccountId,
+    ExecutedTransaction,
     InputNoteCommitment,
     InputNotes,
     OutputNotes,
@@ -15,7 +16,8 @@ use crate::transaction::{
 use crate::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
 
 /// A transaction header derived from a
-/// [`ProvenTransaction`](crate::transaction::ProvenTransaction).
+/// [`ProvenTransaction`](crate::transaction::ProvenTransaction) or an
+/// [`ExecutedTransaction`](crate::transaction::ExecutedTransaction).
 ///
 /// The header is essentially a direct copy of the transaction's commitments, in particular the
 /// initial and final account state commitment as well as all nullifiers of consumed notes and all
@@ -95,6 +97,14 @@ impl TransactionHeader {
         }
     }
 
+    /// Constructs a new [`TransactionHeader`] from an [`ExecutedTransaction`].
+    ///
+    /// This is equivalent to `TransactionHeader::from(executed_tx)`, see the [`From`]
+    /// implementation for details.
+    pub fn from_executed(executed_tx: &ExecutedTransaction) -> Self {
+        Self::from(executed_tx)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -159,6 +169,28 @@ impl From<&ProvenTransaction> for TransactionHeader {
     }
 }
 
+impl From<&ExecutedTransaction> for TransactionHeader {
+    /// Constructs a [`TransactionHeader`] from an [`ExecutedTransaction`].
+    ///
+    /// The resulting header is equal to the header derived from the [`ProvenTransaction`] that
+    /// results from proving the executed transaction, so this can be used to inspect a transaction
+    /// before it is proven.
+    fn from(tx: &ExecutedTransaction) -> Self {
+        // SAFETY: The data in an executed transaction is guaranteed to be internally consistent and
+        // so we can skip the consistency checks by the `new` constructor.
+        TransactionHeader::new_unchecked(
+            tx.id(),
+            tx.account_id(),
+            tx.initial_account().initial_commitment(),
+            tx.final_account().commitment(),
+            InputNotes::new_unchecked(
+                tx.input_notes().iter().map(InputNoteCommitment::from).collect(),
+            ),
+            tx.output_notes().iter().map(NoteHeader::from).collect(),
+        )
+    }
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -1,10 +1,11 @@
 use alloc::vec::Vec;
+use core::fmt;
 
 use crate::account::AccountDelta;
 use crate::crypto::SequentialCommit;
-use crate::transaction::{InputNote, InputNotes, OutputNotes};
+use crate::transaction::{ExecutedTransaction, InputNote, InputNotes, OutputNotes};
 use crate::utils::{Deserializable, Serializable};
-use crate::{Felt, Word};
+use crate::{Felt, Hasher, Word};
 
 /// The summary of the changes that result from executing a transaction.
 ///
@@ -62,25 +63,135 @@ impl TransactionSummary {
 
     /// Computes the commitment to the [`TransactionSummary`].
     ///
-    /// This can be used to sign the transaction.
+    /// This is the message signed by auth components and is the same as the one computed by
+    /// `miden::auth::hash_tx_summary` in the transaction kernel.
     pub fn to_commitment(&self) -> Word {
         <Self as SequentialCommit>::to_commitment(self)
     }
+
+    /// Computes the commitment to a [`TransactionSummary`] from the commitments to its parts.
+    ///
+    /// This allows computing the message to sign without the full account delta and notes, e.g.
+    /// on a hardware wallet that only receives the commitments and the salt.
+    pub fn commitment_from_parts(
+        account_delta_commitment: Word,
+        input_notes_commitment: Word,
+        output_notes_commitment: Word,
+        salt: Word,
+    ) -> Word {
+        Hasher::hash_elements(&elements_from_parts(
+            account_delta_commitment,
+            input_notes_commitment,
+            output_notes_commitment,
+            salt,
+        ))
+    }
+
+    /// Returns the fields which the commitment of this summary commits to, in the order in which
+    /// they are committed to.
+    ///
+    /// This is intended for displaying the message a user is asked to sign.
+    pub fn display_fields(&self) -> [TransactionSummaryField; 4] {
+        [
+            TransactionSummaryField::AccountDeltaCommitment(self.account_delta.to_commitment()),
+            TransactionSummaryField::InputNotesCommitment(self.input_notes.commitment()),
+            TransactionSummaryField::OutputNotesCommitment(self.output_notes.commitment()),
+            TransactionSummaryField::Salt(self.salt),
+        ]
+    }
+
+    /// Returns `true` if this summary describes the provided [`ExecutedTransaction`].
+    ///
+    /// This is the case if the account delta of this summary is the one committed to by the
+    /// transaction outputs (i.e. the delta before the fee is removed) and the input and output
+    /// notes match those of the transaction. The salt is chosen by the auth component and is not
+    /// checked.
+    pub fn verify_against(&self, executed_transaction: &ExecutedTransaction) -> bool {
+        self.account_delta.id() == executed_transaction.account_id()
+            && self.account_delta.to_commitment()
+                == executed_transaction.tx_outputs().account_delta_commitment
+            && self.input_notes.commitment() == executed_transaction.input_notes().commitment()
+            && self.output_notes.commitment() == executed_transaction.output_notes().commitment()
+    }
 }
 
 impl SequentialCommit for TransactionSummary {
     type Commitment = Word;
 
     fn to_elements(&self) -> Vec<Felt> {
-        let mut elements = Vec::with_capacity(16);
-        elements.extend_from_slice(self.account_delta.to_commitment().as_elements());
-        elements.extend_from_slice(self.input_notes.commitment().as_elements());
-        elements.extend_from_slice(self.output_notes.commitment().as_elements());
-        elements.extend_from_slice(self.salt.as_elements());
-        elements
+        elements_from_parts(
+            self.account_delta.to_commitment(),
+            self.input_notes.commitment(),
+            self.output_notes.commitment(),
+            self.salt,
+        )
+    }
+}
+
+// TRANSACTION SUMMARY FIELD
+// ================================================================================================
+
+/// A field of a [`TransactionSummary`] as committed to by
+/// [`TransactionSummary::to_commitment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionSummaryField {
+    /// The commitment to the account delta of the transaction.
+    AccountDeltaCommitment(Word),
+    /// The commitment to the notes consumed by the transaction.
+    InputNotesCommitment(Word),
+    /// The commitment to the notes created by the transaction.
+    OutputNotesCommitment(Word),
+    /// The salt chosen by the auth component, e.g. containing the reference block number and the
+    /// final nonce of the account.
+    Salt(Word),
+}
+
+impl TransactionSummaryField {
+    /// Returns a human-readable name of this field.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransactionSummaryField::AccountDeltaCommitment(_) => "account delta commitment",
+            TransactionSummaryField::InputNotesCommitment(_) => "input notes commitment",
+            TransactionSummaryField::OutputNotesCommitment(_) => "output notes commitment",
+            TransactionSummaryField::Salt(_) => "salt",
+        }
+    }
+
+    /// Returns the value of this field.
+    pub fn value(&self) -> Word {
+        match self {
+            TransactionSummaryField::AccountDeltaCommitment(value)
+            | TransactionSummaryField::InputNotesCommitment(value)
+            | TransactionSummaryField::OutputNotesCommitment(value)
+            | TransactionSummaryField::Salt(value) => *value,
+        }
     }
 }
 
+impl fmt::Display for TransactionSummaryField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name(), self.value())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the elements committed to by a [`TransactionSummary`] with the provided parts.
+fn elements_from_parts(
+    account_delta_commitment: Word,
+    input_notes_commitment: Word,
+    output_notes_commitment: Word,
+    salt: Word,
+) -> Vec<Felt> {
+    let mut elements = Vec::with_capacity(16);
+    elements.extend_from_slice(account_delta_commitment.as_elements());
+    elements.extend_from_slice(input_notes_commitment.as_elements());
+    elements.extend_from_slice(output_notes_commitment.as_elements());
+    elements.extend_from_slice(salt.as_elements());
+    elements
+}
+
 impl Serializable for TransactionSummary {
     fn write_into<W: miden_core::utils::ByteWriter>(&self, target: &mut W) {
         self.account_delta.write_into(target);
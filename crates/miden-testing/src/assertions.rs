@@ -0,0 +1,333 @@
+use miden_objects::account::{Account, AccountId, StorageMap};
+use miden_objects::asset::{AssetVaultKey, FungibleAsset};
+use miden_objects::crypto::merkle::{LeafIndex, NodeIndex, SMT_DEPTH, SmtLeaf};
+use miden_objects::transaction::ExecutedTransaction;
+use miden_objects::{LexicographicWord, Word};
+use miden_processor::AdviceInputs;
+
+// ACCOUNT STATE ASSERTIONS
+// ================================================================================================
+
+/// Assertions on the state of the native account after an [`ExecutedTransaction`].
+///
+/// The final state is derived from the account delta of the transaction. Where the delta alone is
+/// not sufficient, e.g. for the balance of an asset in an existing account, the initial state is
+/// looked up in the initial account of the transaction and in the advice witness, which contains
+/// the data of all vault and storage map entries accessed during execution.
+///
+/// All assertions panic if the final state does not match the expectation or cannot be
+/// determined from the transaction.
+pub trait AccountStateAssertions {
+    /// Asserts that the value storage slot at `slot_idx` holds `expected` after the transaction.
+    fn assert_storage_value(&self, slot_idx: u8, expected: Word);
+
+    /// Asserts that the map storage slot at `slot_idx` maps `key` to `expected` after the
+    /// transaction.
+    fn assert_map_entry(&self, slot_idx: u8, key: Word, expected: Word);
+
+    /// Asserts that the vault holds `expected` units of the fungible asset issued by `faucet_id`
+    /// after the transaction.
+    fn assert_balance(&self, faucet_id: AccountId, expected: u64);
+
+    /// Asserts that the transaction incremented the nonce of the account by `expected`.
+    fn assert_nonce_delta(&self, expected: u64);
+
+    /// Returns the final state of the account by applying the account delta of the transaction to
+    /// the provided `initial` account.
+    ///
+    /// For new accounts, the account delta represents the full state of the account and the final
+    /// account is built from it directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - `initial` is not the account against which the transaction was executed.
+    /// - The account delta cannot be applied.
+    /// - The commitment of the resulting account does not match the final account commitment in the
+    ///   transaction outputs.
+    fn expect_final_account(&self, initial: &Account) -> Account;
+}
+
+impl AccountStateAssertions for ExecutedTransaction {
+    fn assert_storage_value(&self, slot_idx: u8, expected: Word) {
+        let value = match self.account_delta().storage().values().get(&slot_idx) {
+            Some(value) => *value,
+            None if self.account_delta().is_full_state() => Word::empty(),
+            None => {
+                let (_, value) = self
+                    .initial_account()
+                    .storage()
+                    .header()
+                    .slot(slot_idx as usize)
+                    .unwrap_or_else(|err| panic!("failed to get storage slot {slot_idx}: {err}"));
+                *value
+            },
+        };
+
+        assert_eq!(value, expected, "unexpected value in storage slot {slot_idx}");
+    }
+
+    fn assert_map_entry(&self, slot_idx: u8, key: Word, expected: Word) {
+        let delta_value =
+            self.account_delta().storage().maps().get(&slot_idx).and_then(|map_delta| {
+                map_delta.entries().get(&LexicographicWord::new(key)).copied()
+            });
+
+        let value = match delta_value {
+            Some(value) => value,
+            None if self.account_delta().is_full_state() => Word::empty(),
+            None => {
+                let (_, map_root) = self
+                    .initial_account()
+                    .storage()
+                    .header()
+                    .slot(slot_idx as usize)
+                    .unwrap_or_else(|err| panic!("failed to get storage slot {slot_idx}: {err}"));
+
+                self.initial_account()
+                    .storage()
+                    .maps()
+                    .find(|map| map.root() == *map_root)
+                    .and_then(|map| map.get(&key))
+                    .or_else(|| {
+                        smt_value(self.advice_witness(), *map_root, StorageMap::hash_key(key))
+                    })
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "value of key {key} in storage map {slot_idx} is not tracked by the transaction"
+                        )
+                    })
+            },
+        };
+
+        assert_eq!(value, expected, "unexpected value of key {key} in storage map {slot_idx}");
+    }
+
+    fn assert_balance(&self, faucet_id: AccountId, expected: u64) {
+        let amount_delta = self.account_delta().vault().fungible().amount(&faucet_id).unwrap_or(0);
+
+        let initial_balance = if self.account_delta().is_full_state() {
+            0
+        } else {
+            let vault_key = AssetVaultKey::from_account_id(faucet_id)
+                .unwrap_or_else(|| panic!("{faucet_id} is not a fungible faucet ID"));
+            let vault = self.initial_account().vault();
+
+            let asset = vault
+                .get(vault_key)
+                .ok()
+                .map(|asset| asset.map(Word::from).unwrap_or_default())
+                .or_else(|| smt_value(self.advice_witness(), vault.root(), vault_key.into()))
+                .unwrap_or_else(|| {
+                    panic!("balance of faucet {faucet_id} is not tracked by the transaction")
+                });
+
+            if asset.is_empty() {
+                0
+            } else {
+                FungibleAsset::try_from(asset)
+                    .expect("vault should only contain valid fungible assets")
+                    .amount()
+            }
+        };
+
+        let balance = initial_balance
+            .checked_add_signed(amount_delta)
+            .expect("account delta should not remove more than the initial balance");
+
+        assert_eq!(balance, expected, "unexpected balance of faucet {faucet_id}");
+    }
+
+    fn assert_nonce_delta(&self, expected: u64) {
+        assert_eq!(
+            self.account_delta().nonce_delta().as_int(),
+            expected,
+            "unexpected nonce delta of account {}",
+            self.account_id()
+        );
+    }
+
+    fn expect_final_account(&self, initial: &Account) -> Account {
+        assert_eq!(
+            initial.id(),
+            self.account_id(),
+            "initial account is not the account against which the transaction was executed"
+        );
+
+        let final_account = if self.account_delta().is_full_state() {
+            Account::try_from(self.account_delta())
+                .expect("full state account delta should convert into an account")
+        } else {
+            let mut final_account = initial.clone();
+            final_account
+                .apply_delta(self.account_delta())
+                .expect("account delta should apply to the initial account");
+            final_account
+        };
+
+        assert_eq!(
+            final_account.commitment(),
+            self.final_account().commitment(),
+            "commitment of the final account does not match the transaction outputs"
+        );
+
+        final_account
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value of `key` in the sparse Merkle tree with the provided `root`, as opened by the
+/// provided advice inputs, or `None` if the leaf of the key is not part of the advice inputs.
+fn smt_value(advice: &AdviceInputs, root: Word, key: Word) -> Option<Word> {
+    let leaf_index = LeafIndex::<SMT_DEPTH>::from(key);
+    let leaf_hash = advice.store.get_node(root, NodeIndex::from(leaf_index)).ok()?;
+
+    if leaf_hash == SmtLeaf::new_empty(leaf_index).hash() {
+        return Some(Word::empty());
+    }
+
+    let leaf_elements = advice.map.get(&leaf_hash)?;
+    let value = leaf_elements
+        .chunks_exact(8)
+        .find(|entry| &entry[..4] == key.as_elements())
+        .map(|entry| Word::try_from(&entry[4..]).expect("entry value should be a word"))
+        .unwrap_or_default();
+
+    Some(value)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use miden_lib::testing::account_component::MockAccountComponent;
+    use miden_lib::utils::ScriptBuilder;
+    use miden_objects::Felt;
+    use miden_objects::account::{AccountBuilder, AccountStorageMode, StorageSlot};
+    use miden_objects::note::NoteType;
+    use miden_objects::transaction::TransactionScript;
+
+    use super::*;
+    use crate::{Auth, MockChain, TransactionContextBuilder};
+
+    const VALUE: Word = Word::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    const MAP_KEY: Word = Word::new([Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]);
+    const OTHER_MAP_KEY: Word = Word::new([Felt::new(9), Felt::new(9), Felt::new(9), Felt::new(9)]);
+
+    /// Returns a script which sets the value slot 0 and the key [`MAP_KEY`] in the map slot 1 to
+    /// [`VALUE`].
+    fn set_storage_script() -> anyhow::Result<TransactionScript> {
+        let code = format!(
+            "
+            use.mock::account
+
+            begin
+                push.{VALUE} push.0
+                call.account::set_item
+                dropw
+
+                push.{VALUE} push.{MAP_KEY} push.1
+                call.account::set_map_item
+
+                exec.::std::sys::truncate_stack
+            end
+            "
+        );
+
+        Ok(ScriptBuilder::with_mock_libraries()?.compile_tx_script(code)?)
+    }
+
+    #[tokio::test]
+    async fn account_state_assertions_for_existing_account() -> anyhow::Result<()> {
+        let map = StorageMap::with_entries([(MAP_KEY, Word::from([1u32; 4]))])?;
+        let mut builder = MockChain::builder();
+        let account = builder.add_existing_mock_account_with_storage_and_assets(
+            Auth::IncrNonce,
+            [StorageSlot::Value(Word::empty()), StorageSlot::Map(map)],
+            [FungibleAsset::mock(100)],
+        )?;
+        let note = builder.add_p2id_note(
+            account.id(),
+            account.id(),
+            &[FungibleAsset::mock(50)],
+            NoteType::Public,
+        )?;
+        let executed_tx = builder
+            .build()?
+            .build_tx_context(account.id(), &[note.id()], &[])?
+            .tx_script(set_storage_script()?)
+            .build()?
+            .execute()
+            .await?;
+
+        executed_tx.assert_storage_value(0, VALUE);
+        executed_tx.assert_map_entry(1, MAP_KEY, VALUE);
+        executed_tx.assert_balance(FungibleAsset::mock_issuer(), 150);
+        executed_tx.assert_nonce_delta(1);
+
+        let final_account = executed_tx.expect_final_account(&account);
+        assert_eq!(final_account.storage().get_item(0)?, VALUE);
+        assert_eq!(final_account.vault().get_balance(FungibleAsset::mock_issuer())?, 150);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn account_state_assertions_for_new_account() -> anyhow::Result<()> {
+        let map = StorageMap::with_entries([
+            (MAP_KEY, Word::from([1u32; 4])),
+            (OTHER_MAP_KEY, Word::from([2u32; 4])),
+        ])?;
+        let account = AccountBuilder::new([3; 32])
+            .storage_mode(AccountStorageMode::Public)
+            .with_auth_component(Auth::IncrNonce)
+            .with_component(MockAccountComponent::with_slots(vec![
+                StorageSlot::Value(Word::empty()),
+                StorageSlot::Map(map),
+            ]))
+            .build()?;
+        assert!(account.is_new());
+
+        let executed_tx = TransactionContextBuilder::new(account.clone())
+            .tx_script(set_storage_script()?)
+            .build()?
+            .execute()
+            .await?;
+
+        executed_tx.assert_storage_value(0, VALUE);
+        executed_tx.assert_map_entry(1, MAP_KEY, VALUE);
+        executed_tx.assert_map_entry(1, OTHER_MAP_KEY, Word::from([2u32; 4]));
+        executed_tx.assert_balance(FungibleAsset::mock_issuer(), 0);
+        executed_tx.assert_nonce_delta(1);
+
+        let final_account = executed_tx.expect_final_account(&account);
+        assert!(!final_account.is_new());
+        assert_eq!(final_account.storage().get_item(0)?, VALUE);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "unexpected nonce delta")]
+    async fn assert_nonce_delta_fails_on_mismatch() {
+        let mut builder = MockChain::builder();
+        let account = builder
+            .add_existing_wallet_with_assets(Auth::IncrNonce, [FungibleAsset::mock(100)])
+            .unwrap();
+        let executed_tx = builder
+            .build()
+            .unwrap()
+            .build_tx_context(account.id(), &[], &[])
+            .unwrap()
+            .build()
+            .unwrap()
+            .execute()
+            .await
+            .unwrap();
+
+        executed_tx.assert_nonce_delta(2);
+    }
+}
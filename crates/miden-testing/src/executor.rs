@@ -70,7 +70,7 @@ impl<H: AsyncHost> CodeExecutor<H> {
         // fast processor expects the reverse order.
         //
         // Once we use the FastProcessor for execution and proving, we can change the way these
-        // inputs are constructed in TransactionKernel::prepare_inputs.
+        // inputs are constructed in TransactionKernel::build_inputs.
         let stack_inputs =
             StackInputs::new(self.stack_inputs.unwrap_or_default().iter().copied().collect())
                 .unwrap();
@@ -12,6 +12,7 @@ use miden_objects::crypto::merkle::MerkleError;
 use miden_objects::note::{Note, NoteType};
 use miden_objects::testing::account_id::AccountIdBuilder;
 use miden_objects::transaction::{InputNote, InputNoteCommitment, OutputNote, PartialBlockchain};
+use miden_objects::utils::Serializable;
 use miden_objects::{BatchAccountUpdateError, ProposedBatchError, Word};
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -799,3 +800,49 @@ fn noop_tx_after_state_updating_tx_against_same_account() -> anyhow::Result<()>
 
     Ok(())
 }
+
+/// Tests that the estimated size of a batch matches the serialized size of the account updates,
+/// input notes and output notes of its transactions.
+#[test]
+fn proposed_batch_estimate_size_bytes() -> anyhow::Result<()> {
+    let TestSetup { mut chain, account1, account2, .. } = setup_chain();
+    let block1 = chain.block_header(1);
+    let block2 = chain.prove_next_block()?;
+
+    let note0 = mock_note(70);
+    let note1 = mock_note(71);
+    let tx1 =
+        MockProvenTxBuilder::with_account(account1.id(), Word::empty(), account1.commitment())
+            .ref_block_commitment(block1.commitment())
+            .unauthenticated_notes(vec![note0.clone()])
+            .output_notes(vec![
+                OutputNote::Full(note1.clone()),
+                OutputNote::Header(*mock_note(72).header()),
+            ])
+            .build()?;
+    let tx2 =
+        MockProvenTxBuilder::with_account(account2.id(), Word::empty(), account2.commitment())
+            .ref_block_commitment(block1.commitment())
+            .output_notes(vec![mock_output_note(73)])
+            .build()?;
+
+    let expected_size = [&tx1, &tx2]
+        .into_iter()
+        .map(|tx| {
+            tx.account_update().to_bytes().len()
+                + tx.input_notes().to_bytes().len()
+                + tx.output_notes().to_bytes().len()
+        })
+        .sum::<usize>();
+
+    let batch = ProposedBatch::new(
+        [tx1, tx2].into_iter().map(Arc::new).collect(),
+        block2.header().clone(),
+        chain.latest_partial_blockchain(),
+        BTreeMap::default(),
+    )?;
+
+    assert_eq!(batch.estimate_size_bytes(), expected_size);
+
+    Ok(())
+}
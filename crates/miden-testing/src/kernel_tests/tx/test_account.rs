@@ -4,6 +4,7 @@ use std::collections::BTreeMap;
 
 use anyhow::Context;
 use miden_lib::errors::tx_kernel_errors::{
+    self,
     ERR_ACCOUNT_ID_SUFFIX_LEAST_SIGNIFICANT_BYTE_MUST_BE_ZERO,
     ERR_ACCOUNT_ID_SUFFIX_MOST_SIGNIFICANT_BIT_MUST_BE_ZERO,
     ERR_ACCOUNT_ID_UNKNOWN_STORAGE_MODE,
@@ -15,6 +16,7 @@ use miden_lib::errors::tx_kernel_errors::{
 };
 use miden_lib::testing::account_component::MockAccountComponent;
 use miden_lib::testing::mock_account::MockAccountExt;
+use miden_lib::testing::note::NoteBuilder;
 use miden_lib::transaction::TransactionKernel;
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::delta::AccountUpdateDetails;
@@ -45,10 +47,17 @@ use miden_objects::testing::account_id::{
     ACCOUNT_ID_SENDER,
 };
 use miden_objects::testing::storage::STORAGE_LEAVES_2;
-use miden_objects::transaction::{ExecutedTransaction, OutputNote, TransactionScript};
+use miden_objects::transaction::{
+    ExecutedTransaction,
+    OutputNote,
+    TransactionArgs,
+    TransactionScript,
+};
+use miden_objects::utils::{Deserializable, Serializable};
 use miden_objects::{LexicographicWord, StarkField};
 use miden_processor::{EMPTY_WORD, ExecutionError, MastNodeExt, Word};
-use miden_tx::{LocalTransactionProver, TransactionExecutorError};
+use miden_tx::auth::UnreachableAuth;
+use miden_tx::{LocalTransactionProver, TransactionExecutor, TransactionExecutorError};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use winter_rand_utils::rand_value;
@@ -760,6 +769,86 @@ async fn test_account_component_storage_offset() -> miette::Result<()> {
     Ok(())
 }
 
+/// Tests that a component which calls into another component is placed after it, even if it is
+/// added to the builder first, and that both components access their own storage when the
+/// dependent component calls into its dependency.
+#[tokio::test]
+async fn test_component_dependency_storage_offsets() -> anyhow::Result<()> {
+    let source_code_dependency = "
+        use.miden::native_account
+
+        export.set_dependency_value
+            push.1.2.3.4.0
+            exec.native_account::set_item
+            dropw
+        end
+    ";
+    let dependency = AccountComponent::compile(
+        NamedSource::new("test::dependency", source_code_dependency),
+        TransactionKernel::assembler(),
+        vec![StorageSlot::Value(Word::empty())],
+    )?
+    .with_supports_all_types();
+    let set_dependency_value = dependency
+        .get_procedure_root_by_name("test::dependency::set_dependency_value")
+        .context("failed to find procedure root")?;
+
+    let source_code_dependent = "
+        use.miden::native_account
+        use.test::dependency
+
+        export.set_values
+            call.dependency::set_dependency_value
+
+            push.5.6.7.8.0
+            exec.native_account::set_item
+            dropw
+        end
+    ";
+    let assembler = TransactionKernel::assembler()
+        .with_dynamic_library(dependency.library())
+        .expect("dependency library should be linkable");
+    let dependent = AccountComponent::compile(
+        source_code_dependent,
+        assembler,
+        vec![StorageSlot::Value(Word::empty())],
+    )?
+    .with_supports_all_types()
+    .with_dependency("test::dependency");
+    let set_values = dependent
+        .get_procedure_root_by_name("$anon::set_values")
+        .context("failed to find procedure root")?;
+
+    let mut account = AccountBuilder::new([7; 32])
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(dependent)
+        .with_component(dependency)
+        .build_existing()?;
+
+    // The dependency is placed before the dependent component.
+    assert_eq!(account.code().component_count(), Some(3));
+    assert_eq!(account.code().storage_offset_of_component(1), Some(0));
+    assert_eq!(account.code().storage_offset_of_component(2), Some(1));
+    assert_eq!(
+        account.code().procedures_of_component(1),
+        Some([set_dependency_value].as_slice())
+    );
+
+    let tx_script =
+        ScriptBuilder::default().compile_tx_script(format!("begin call.{set_values} end"))?;
+    let tx = TransactionContextBuilder::new(account.clone())
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await?;
+    account.apply_delta(tx.account_delta())?;
+
+    assert_eq!(account.storage().get_item(0)?, Word::from([1, 2, 3, 4u32]));
+    assert_eq!(account.storage().get_item(1)?, Word::from([5, 6, 7, 8u32]));
+
+    Ok(())
+}
+
 /// Tests that we can successfully create regular and faucet accounts with empty storage.
 #[tokio::test]
 async fn create_account_with_empty_storage_slots() -> anyhow::Result<()> {
@@ -1040,7 +1129,7 @@ async fn prove_account_creation_with_non_empty_storage() -> anyhow::Result<()> {
     let AccountUpdateDetails::Delta(delta) = proven_tx.account_update().details() else {
         panic!("expected delta");
     };
-    assert_eq!(delta, tx.account_delta());
+    assert_eq!(delta.as_ref(), tx.account_delta());
 
     Ok(())
 }
@@ -1606,6 +1695,46 @@ async fn incrementing_nonce_twice_fails() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the error code of a deliberately triggered kernel failure maps to the message of the
+/// kernel error, and that unknown codes do not map to a message.
+#[tokio::test]
+async fn kernel_error_code_maps_to_message() -> anyhow::Result<()> {
+    let source_code = "
+        use.miden::native_account
+
+        export.auth_incr_nonce_twice
+            exec.native_account::incr_nonce drop
+            exec.native_account::incr_nonce drop
+        end
+    ";
+
+    let faulty_auth_component =
+        AccountComponent::compile(source_code, TransactionKernel::assembler(), vec![])?
+            .with_supports_all_types();
+    let account = AccountBuilder::new([5; 32])
+        .with_auth_component(faulty_auth_component)
+        .with_component(MockAccountComponent::with_empty_slots())
+        .build()
+        .context("failed to build account")?;
+
+    let err_code = TransactionContextBuilder::new(account)
+        .build()?
+        .execute()
+        .await
+        .expect_err("transaction should fail")
+        .kernel_error_code()
+        .context("failure should be caused by a kernel error")?;
+    assert_eq!(
+        tx_kernel_errors::message_for_code(err_code),
+        Some(ERR_ACCOUNT_NONCE_CAN_ONLY_BE_INCREMENTED_ONCE.message())
+    );
+
+    // Codes which don't belong to any kernel error should not map to a message.
+    assert_eq!(tx_kernel_errors::message_for_code(Felt::new(u32::MAX as u64)), None);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_has_procedure() -> miette::Result<()> {
     // Create a standard account using the mock component
@@ -1780,6 +1909,85 @@ async fn test_get_initial_map_item() -> miette::Result<()> {
     Ok(())
 }
 
+/// Tests that the executor records the storage slots and map items accessed by a consumed note
+/// when storage access tracking is enabled, and that no log is recorded otherwise.
+#[tokio::test]
+async fn test_storage_access_tracking() -> anyhow::Result<()> {
+    let account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_slots(AccountStorage::mock_storage_slots()))
+        .build_existing()?;
+
+    let (map_key, _) = STORAGE_LEAVES_2[0];
+    let new_value = Word::from([9, 8, 7, 6u32]);
+    let note = NoteBuilder::new(ACCOUNT_ID_SENDER.try_into()?, ChaCha20Rng::from_os_rng())
+        .code(format!(
+            "
+            use.mock::account->mock_account
+
+            begin
+                push.0
+                call.mock_account::get_item dropw
+
+                push.{map_key}
+                push.2
+                call.mock_account::get_map_item dropw dropw
+
+                push.{new_value}
+                push.1
+                call.mock_account::set_item dropw dropw
+            end
+            "
+        ))
+        .dynamically_linked_libraries(TransactionKernel::mock_libraries())
+        .build()?;
+
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .extend_input_notes(vec![note.clone()])
+        .build()?;
+    let block_num = tx_context.tx_inputs().block_header().block_num();
+
+    let executed_tx = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .execute_transaction(
+            account.id(),
+            block_num,
+            tx_context.input_notes().clone(),
+            TransactionArgs::default(),
+        )
+        .await?;
+    assert!(executed_tx.storage_access_log().is_none());
+
+    let executed_tx = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_storage_access_tracking()
+        .execute_transaction(
+            account.id(),
+            block_num,
+            tx_context.input_notes().clone(),
+            TransactionArgs::default(),
+        )
+        .await?;
+    let storage_access_log = executed_tx
+        .storage_access_log()
+        .context("storage access log should be recorded")?;
+    assert_eq!(storage_access_log.iter().count(), 1);
+
+    let accesses = storage_access_log.get(&account.id()).context("account should be logged")?;
+    assert_eq!(accesses.slots().len(), 2);
+    assert!(accesses.slots()[&0].is_read());
+    assert!(!accesses.slots()[&0].is_written());
+    assert!(!accesses.slots()[&1].is_read());
+    assert!(accesses.slots()[&1].is_written());
+    assert_eq!(accesses.map_items().len(), 1);
+    assert!(accesses.map_items()[&(2, map_key)].is_read());
+    assert!(!accesses.map_items()[&(2, map_key)].is_written());
+
+    // the log is not part of the serialized executed transaction
+    let deserialized_tx = ExecutedTransaction::read_from_bytes(&executed_tx.to_bytes())?;
+    assert!(deserialized_tx.storage_access_log().is_none());
+
+    Ok(())
+}
+
 /// Tests that incrementing the account nonce fails if it would overflow the field.
 #[tokio::test]
 async fn incrementing_nonce_overflow_fails() -> anyhow::Result<()> {
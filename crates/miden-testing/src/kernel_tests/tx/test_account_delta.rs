@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 use std::string::String;
 
 use anyhow::Context;
+use assert_matches::assert_matches;
 use miden_lib::testing::account_component::MockAccountComponent;
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::delta::AccountUpdateDetails;
@@ -37,8 +38,9 @@ use miden_objects::testing::constants::{
 };
 use miden_objects::testing::storage::{STORAGE_INDEX_0, STORAGE_INDEX_2};
 use miden_objects::transaction::TransactionScript;
+use miden_objects::utils::Serializable;
 use miden_objects::{EMPTY_WORD, Felt, LexicographicWord, Word, ZERO};
-use miden_tx::LocalTransactionProver;
+use miden_tx::{LocalTransactionProver, TransactionProverError};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use winter_rand_utils::rand_value;
@@ -846,7 +848,7 @@ async fn proven_tx_storage_maps_matches_executed_tx_for_new_account() -> anyhow:
         panic!("expected delta");
     };
 
-    let proven_tx_account = Account::try_from(proven_tx_delta)?;
+    let proven_tx_account = Account::try_from(proven_tx_delta.as_ref())?;
     let exec_tx_account = Account::try_from(tx.account_delta())?;
 
     assert_eq!(proven_tx_account.storage(), exec_tx_account.storage());
@@ -857,7 +859,7 @@ async fn proven_tx_storage_maps_matches_executed_tx_for_new_account() -> anyhow:
 
     // Check that the deltas from proven and executed tx, which were converted from accounts are
     // identical. This is essentially a roundtrip test.
-    assert_eq!(&proven_tx_delta_converted, proven_tx_delta);
+    assert_eq!(&proven_tx_delta_converted, proven_tx_delta.as_ref());
     assert_eq!(&exec_tx_delta_converted, tx.account_delta());
     assert_eq!(&proven_tx_delta_converted, tx.account_delta());
 
@@ -869,6 +871,62 @@ async fn proven_tx_storage_maps_matches_executed_tx_for_new_account() -> anyhow:
     Ok(())
 }
 
+/// Tests that the estimated account update size of an executed transaction matches the size of
+/// the account update of the proven transaction, and that the prover rejects transactions whose
+/// account update exceeds the maximum size before proving them.
+#[tokio::test]
+async fn prover_checks_account_update_size_before_proving() -> anyhow::Result<()> {
+    let TestSetup { mock_chain, account_id, .. } =
+        setup_test([StorageSlot::Map(StorageMap::new())], [], [])?;
+
+    let set_map_items: String = (1..=20u32)
+        .map(|i| {
+            let key = Word::from([i, 0, 0, 0u32]);
+            let value = Word::from([i, i, i, i]);
+            format!("push.{value} push.{key} push.0 exec.set_map_item\n")
+        })
+        .collect();
+    let tx_script = compile_tx_script(format!("begin {set_map_items} end"))?;
+
+    let tx = mock_chain
+        .build_tx_context(account_id, &[], &[])?
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await?;
+    assert_eq!(tx.account_delta().storage().maps().get(&0).unwrap().num_entries(), 20);
+    let estimated_size = tx.account_update_size_estimate();
+
+    // The estimate is exact, so a limit equal to the estimate is not exceeded.
+    let proven_tx = LocalTransactionProver::default()
+        .with_account_update_max_size(estimated_size)
+        .prove_dummy(tx.clone())?;
+    assert_eq!(proven_tx.account_update().details().to_bytes().len(), estimated_size);
+
+    let breakdown = proven_tx.serialized_size_breakdown();
+    assert_eq!(breakdown.account_update(), proven_tx.account_update().to_bytes().len());
+    assert_eq!(breakdown.total(), proven_tx.to_bytes().len());
+
+    // A limit just below the estimate is exceeded and the transaction is rejected before it is
+    // proven.
+    let error = LocalTransactionProver::default()
+        .with_account_update_max_size(estimated_size - 1)
+        .prove(tx)
+        .unwrap_err();
+    assert_matches!(
+        error,
+        TransactionProverError::AccountUpdateSizeLimitExceeded {
+            account_id: error_account_id,
+            estimated_size: error_estimated_size,
+            max_size,
+        } if error_account_id == account_id
+            && error_estimated_size == estimated_size
+            && max_size == estimated_size - 1
+    );
+
+    Ok(())
+}
+
 /// Tests that adding a fungible asset with amount zero to the account vault works and does not
 /// result in an account delta entry.
 #[tokio::test]
@@ -1,8 +1,15 @@
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
 use assert_matches::assert_matches;
-use miden_lib::note::{NoteConsumptionStatus, WellKnownNote, create_p2id_note, create_p2ide_note};
+use miden_lib::note::{
+    NoteConsumptionStatus,
+    WellKnownNote,
+    create_p2id_note,
+    create_p2ide_cancel_note,
+    create_p2ide_note,
+};
 use miden_lib::testing::mock_account::MockAccountExt;
 use miden_lib::testing::note::NoteBuilder;
 use miden_lib::transaction::TransactionKernel;
@@ -20,6 +27,7 @@ use miden_objects::note::{
     NoteType,
 };
 use miden_objects::testing::account_id::{
+    ACCOUNT_ID_PRIVATE_SENDER,
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE_2,
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
@@ -32,6 +40,7 @@ use miden_processor::crypto::RpoRandomCoin;
 use miden_tx::auth::UnreachableAuth;
 use miden_tx::{
     FailedNote,
+    NoteCheckerConfig,
     NoteConsumptionChecker,
     NoteConsumptionInfo,
     TransactionExecutor,
@@ -41,7 +50,7 @@ use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 
 use crate::utils::create_public_p2any_note;
-use crate::{Auth, MockChain, TransactionContextBuilder, TxContextInput};
+use crate::{Auth, MockAuthenticator, MockChain, TransactionContextBuilder, TxContextInput};
 
 #[tokio::test]
 async fn check_note_consumability_well_known_notes_success() -> anyhow::Result<()> {
@@ -79,7 +88,13 @@ async fn check_note_consumability_well_known_notes_success() -> anyhow::Result<(
     let notes_checker = NoteConsumptionChecker::new(&executor);
 
     let consumption_info = notes_checker
-        .check_notes_consumability(target_account_id, block_ref, notes.clone(), tx_args)
+        .check_notes_consumability(
+            target_account_id,
+            block_ref,
+            notes.clone(),
+            tx_args,
+            NoteCheckerConfig::default(),
+        )
         .await?;
 
     assert_matches!(consumption_info, NoteConsumptionInfo { successful, failed, .. } => {
@@ -105,6 +120,7 @@ async fn check_note_consumability_custom_notes_success(
         let account =
             Account::mock(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, Auth::IncrNonce);
         let (_, authenticator) = Auth::BasicAuth.build_component();
+        let authenticator = authenticator.map(MockAuthenticator::from);
         TransactionContextBuilder::new(account)
             .extend_input_notes(notes.clone())
             .authenticator(authenticator)
@@ -121,7 +137,13 @@ async fn check_note_consumability_custom_notes_success(
     let notes_checker = NoteConsumptionChecker::new(&executor);
 
     let consumption_info = notes_checker
-        .check_notes_consumability(account_id, block_ref, notes.clone(), tx_args)
+        .check_notes_consumability(
+            account_id,
+            block_ref,
+            notes.clone(),
+            tx_args,
+            NoteCheckerConfig::default(),
+        )
         .await?;
 
     assert_matches!(consumption_info, NoteConsumptionInfo { successful, failed, .. }=> {
@@ -200,14 +222,21 @@ async fn check_note_consumability_partial_success() -> anyhow::Result<()> {
     let notes_checker = NoteConsumptionChecker::new(&executor);
 
     let consumption_info = notes_checker
-        .check_notes_consumability(account_id, block_ref, notes, tx_args)
+        .check_notes_consumability(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            NoteCheckerConfig::default(),
+        )
         .await?;
 
     assert_matches!(
         consumption_info,
         NoteConsumptionInfo {
             successful,
-            failed
+            failed,
+            ..
         } => {
                 assert_eq!(failed.len(), 2);
                 assert_eq!(successful.len(), 3);
@@ -250,6 +279,220 @@ async fn check_note_consumability_partial_success() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that checking chunks of notes in parallel yields the same result as checking all notes
+/// sequentially.
+#[tokio::test]
+async fn check_notes_parallel_matches_sequential() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let mut rng = ChaCha20Rng::from_seed([0_u8; 32]);
+
+    let mut notes = Vec::new();
+    for i in 0..20u64 {
+        // Every fourth note fails.
+        let note = if i % 4 == 0 {
+            NoteBuilder::new(sender, ChaCha20Rng::from_seed(rng.random()))
+                .code(format!("begin push.{i} drop push.0 div end"))
+                .dynamically_linked_libraries([TransactionKernel::library()])
+                .build()?
+        } else {
+            builder.add_p2id_note(
+                ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+                account.id(),
+                &[FungibleAsset::mock(10 + i)],
+                NoteType::Public,
+            )?
+        };
+        notes.push(note);
+    }
+
+    let mock_chain = builder.build()?;
+    let tx_context = mock_chain
+        .build_tx_context(TxContextInput::Account(account), &[], &notes)?
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context);
+    let notes_checker = NoteConsumptionChecker::new(&executor);
+
+    let sequential_info = notes_checker
+        .check_notes_consumability(
+            account_id,
+            block_ref,
+            notes.clone(),
+            tx_args.clone(),
+            NoteCheckerConfig::default(),
+        )
+        .await?;
+    let parallel_info = notes_checker
+        .check_notes_parallel(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            NoteCheckerConfig::default(),
+            4,
+        )
+        .await?;
+
+    let sorted_ids = |notes: &mut dyn Iterator<Item = &Note>| {
+        let mut ids = notes.map(Note::id).collect::<Vec<_>>();
+        ids.sort();
+        ids
+    };
+    let successful = sorted_ids(&mut sequential_info.successful.iter());
+    let failed = sorted_ids(&mut sequential_info.failed.iter().map(|failed| &failed.note));
+    assert_eq!(successful.len(), 15);
+    assert_eq!(failed.len(), 5);
+    assert!(sequential_info.unchecked.is_empty());
+
+    assert_eq!(sorted_ids(&mut parallel_info.successful.iter()), successful);
+    assert_eq!(sorted_ids(&mut parallel_info.failed.iter().map(|failed| &failed.note)), failed);
+    assert!(parallel_info.unchecked.is_empty());
+    for failed_note in parallel_info.failed {
+        assert_matches!(failed_note.cause(), Some(ExecutionError::DivideByZero { .. }));
+    }
+
+    Ok(())
+}
+
+/// Tests that the execution of a note exceeding the per-note cycle budget is aborted and the note
+/// is reported as failed while the other notes are still checked, and that checking can be stopped
+/// on the first failure.
+#[tokio::test]
+async fn check_note_consumability_per_note_cycle_budget() -> anyhow::Result<()> {
+    const PER_NOTE_MAX_CYCLES: u32 = 50_000;
+
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    // A note which never terminates, so checking it only completes if its execution is aborted
+    // once it exceeds the per-note budget.
+    let long_running_note = NoteBuilder::new(
+        sender,
+        ChaCha20Rng::from_seed(ChaCha20Rng::from_seed([0_u8; 32]).random()),
+    )
+    .code(
+        "
+        use.miden::tx
+
+        begin
+            push.1
+            while.true
+                padw padw push.1 exec.tx::emit_event
+                push.1
+            end
+        end
+        ",
+    )
+    .build()?;
+
+    let failing_note = NoteBuilder::new(
+        sender,
+        ChaCha20Rng::from_seed(ChaCha20Rng::from_seed([1_u8; 32]).random()),
+    )
+    .code("begin push.1 drop push.0 div end")
+    .build()?;
+
+    let successful_note = builder.add_p2id_note(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+        account.id(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+
+    let mock_chain = builder.build()?;
+    let notes = vec![long_running_note.clone(), failing_note.clone(), successful_note.clone()];
+    let tx_context = mock_chain
+        .build_tx_context(TxContextInput::Account(account), &[], &notes)?
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args().clone();
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context);
+    let notes_checker = NoteConsumptionChecker::new(&executor);
+    let config = NoteCheckerConfig {
+        per_note_max_cycles: PER_NOTE_MAX_CYCLES,
+        ..Default::default()
+    };
+
+    let consumption_info = notes_checker
+        .check_notes_consumability(account_id, block_ref, notes.clone(), tx_args.clone(), config)
+        .await?;
+
+    assert_eq!(consumption_info.successful, vec![successful_note.clone()]);
+    assert!(consumption_info.unchecked.is_empty());
+    assert_eq!(consumption_info.failed.len(), 2);
+    // The long running note is executed before the failing note, so it is eliminated first.
+    assert_matches!(
+        &consumption_info.failed[0],
+        FailedNote {
+            note,
+            error: TransactionExecutorError::NoteCycleBudgetExceeded {
+                num_cycles,
+                max_cycles: PER_NOTE_MAX_CYCLES,
+            }
+        } => {
+            assert_eq!(note.id(), long_running_note.id());
+            assert!(*num_cycles > PER_NOTE_MAX_CYCLES);
+        }
+    );
+    assert_matches!(
+        &consumption_info.failed[1],
+        FailedNote {
+            note,
+            error: TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::DivideByZero { .. }
+            )
+        } => assert_eq!(note.id(), failing_note.id())
+    );
+
+    // The failing note aborted on an invalid operation in its script, while exceeding the cycle
+    // budget is not treated as a script error.
+    assert!(consumption_info.failed[0].cause().is_none());
+    assert!(!consumption_info.failed[0].is_script_error());
+    assert_matches!(consumption_info.failed[1].cause(), Some(ExecutionError::DivideByZero { .. }));
+    assert!(consumption_info.failed[1].is_script_error());
+
+    // The long running note on its own is reported as exceeding its budget.
+    let consumption_status = notes_checker
+        .can_consume(
+            account_id,
+            block_ref,
+            InputNote::unauthenticated(long_running_note.clone()),
+            tx_args.clone(),
+            config,
+        )
+        .await?;
+    assert_matches!(
+        consumption_status,
+        NoteConsumptionStatus::ExceededCycleBudget { num_cycles, max_cycles: PER_NOTE_MAX_CYCLES }
+            if num_cycles > PER_NOTE_MAX_CYCLES
+    );
+
+    // When stopping on the first failure, the notes which were not determined yet are unchecked.
+    let config = NoteCheckerConfig { stop_on_first_failure: true, ..config };
+    let consumption_info = notes_checker
+        .check_notes_consumability(account_id, block_ref, notes, tx_args, config)
+        .await?;
+
+    assert!(consumption_info.successful.is_empty());
+    assert_eq!(consumption_info.failed.len(), 1);
+    assert_eq!(consumption_info.failed[0].note.id(), long_running_note.id());
+    assert_eq!(consumption_info.unchecked, vec![successful_note, failing_note]);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn check_note_consumability_epilogue_failure() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
@@ -280,14 +523,21 @@ async fn check_note_consumability_epilogue_failure() -> anyhow::Result<()> {
     let notes_checker = NoteConsumptionChecker::new(&executor);
 
     let consumption_info = notes_checker
-        .check_notes_consumability(account_id, block_ref, notes, tx_args)
+        .check_notes_consumability(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            NoteCheckerConfig::default(),
+        )
         .await?;
 
     assert_matches!(
        consumption_info,
        NoteConsumptionInfo {
            successful,
-           failed
+           failed,
+            ..
        } => {
            assert!(successful.is_empty());
            assert_eq!(failed.len(), 1);
@@ -362,14 +612,21 @@ async fn check_note_consumability_epilogue_failure_with_new_combination() -> any
     let notes_checker = NoteConsumptionChecker::new(&executor);
 
     let consumption_info = notes_checker
-        .check_notes_consumability(account_id, block_ref, notes, tx_args)
+        .check_notes_consumability(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            NoteCheckerConfig::default(),
+        )
         .await?;
 
     assert_matches!(
         consumption_info,
         NoteConsumptionInfo {
             successful,
-            failed
+            failed,
+            ..
         } => {
                 assert_eq!(failed.len(), 2);
                 assert_eq!(successful.len(), 3);
@@ -412,6 +669,38 @@ async fn check_note_consumability_epilogue_failure_with_new_combination() -> any
     Ok(())
 }
 
+/// Tests that a note whose execution hint indicates that it is not meant to be consumed at the
+/// reference block is reported as not yet consumable.
+#[tokio::test]
+async fn test_check_note_consumability_after_block_hint() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let mock_chain = builder.build()?;
+
+    let tx_context = mock_chain.build_tx_context(account.id(), &[], &[])?.build()?;
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+
+    let after_block_note = NoteBuilder::new(account.id(), ChaCha20Rng::from_os_rng())
+        .note_execution_hint(NoteExecutionHint::after_block(block_ref + 10)?)
+        .code("begin push.1 drop end")
+        .build()?;
+
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context);
+    let consumability_info = NoteConsumptionChecker::new(&executor)
+        .can_consume(
+            account.id(),
+            block_ref,
+            InputNote::unauthenticated(after_block_note),
+            tx_context.tx_args().clone(),
+            NoteCheckerConfig::default(),
+        )
+        .await?;
+
+    assert_matches!(consumability_info, NoteConsumptionStatus::NotYetConsumable);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_check_note_consumability_without_signatures() -> anyhow::Result<()> {
     let mut builder = MockChain::builder();
@@ -445,8 +734,9 @@ async fn test_check_note_consumability_without_signatures() -> anyhow::Result<()
         .can_consume(
             account_id,
             block_ref,
-            InputNote::Unauthenticated { note: successful_note },
+            InputNote::unauthenticated(successful_note),
             tx_args,
+            NoteCheckerConfig::default(),
         )
         .await?;
 
@@ -526,8 +816,9 @@ async fn test_check_note_consumability_static_analysis_invalid_inputs() -> anyho
         .can_consume(
             target_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide_wrong_inputs_number.clone() },
+            InputNote::unauthenticated(p2ide_wrong_inputs_number.clone()),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await?;
     assert_matches!(consumability_info, NoteConsumptionStatus::NeverConsumable(reason) => {
@@ -544,8 +835,9 @@ async fn test_check_note_consumability_static_analysis_invalid_inputs() -> anyho
         .can_consume(
             target_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide_invalid_target_id.clone() },
+            InputNote::unauthenticated(p2ide_invalid_target_id.clone()),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await?;
     assert_matches!(consumability_info, NoteConsumptionStatus::NeverConsumable(reason) => {
@@ -558,8 +850,9 @@ async fn test_check_note_consumability_static_analysis_invalid_inputs() -> anyho
         .can_consume(
             target_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide_wrong_target.clone() },
+            InputNote::unauthenticated(p2ide_wrong_target.clone()),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await?;
     assert_matches!(consumability_info, NoteConsumptionStatus::NeverConsumable(reason) => {
@@ -572,8 +865,9 @@ async fn test_check_note_consumability_static_analysis_invalid_inputs() -> anyho
         .can_consume(
             target_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide_invalid_reclaim.clone() },
+            InputNote::unauthenticated(p2ide_invalid_reclaim.clone()),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await?;
     assert_matches!(consumability_info, NoteConsumptionStatus::NeverConsumable(reason) => {
@@ -586,8 +880,9 @@ async fn test_check_note_consumability_static_analysis_invalid_inputs() -> anyho
         .can_consume(
             target_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide_invalid_timelock.clone() },
+            InputNote::unauthenticated(p2ide_invalid_timelock.clone()),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await?;
     assert_matches!(consumability_info, NoteConsumptionStatus::NeverConsumable(reason) => {
@@ -676,8 +971,9 @@ async fn test_check_note_consumability_static_analysis_receiver(
         .can_consume(
             target_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide },
+            InputNote::unauthenticated(p2ide),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await;
 
@@ -766,8 +1062,9 @@ async fn test_check_note_consumability_static_analysis_sender(
         .can_consume(
             sender_account_id,
             block_ref,
-            InputNote::Unauthenticated { note: p2ide },
+            InputNote::unauthenticated(p2ide),
             tx_args.clone(),
+            NoteCheckerConfig::default(),
         )
         .await;
 
@@ -776,6 +1073,98 @@ async fn test_check_note_consumability_static_analysis_sender(
     Ok(())
 }
 
+/// Tests that [`NoteConsumptionChecker::can_consume_with_cancellations()`] treats cancelled P2IDE
+/// notes as never consumable by their target, while their sender can still reclaim them.
+#[tokio::test]
+async fn test_check_note_consumability_cancelled_p2ide() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+
+    let account = builder.add_existing_wallet(Auth::Noop)?;
+    let target_account_id = account.id();
+    let sender_account_id: AccountId =
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap();
+
+    let p2ide_inputs = |reclaim_height| {
+        [
+            target_account_id.suffix().as_int(),
+            target_account_id.prefix().as_u64(),
+            reclaim_height,
+            1,
+        ]
+    };
+    let cancelled_p2ide = create_p2ide_note_with_inputs(p2ide_inputs(2), sender_account_id);
+    let other_p2ide = create_p2ide_note_with_inputs(p2ide_inputs(3), sender_account_id);
+    builder.add_output_note(OutputNote::Full(cancelled_p2ide.clone()));
+    builder.add_output_note(OutputNote::Full(other_p2ide.clone()));
+
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_until_block(3)?;
+
+    let tx_context = mock_chain
+        .build_tx_context(
+            TxContextInput::Account(account),
+            &[cancelled_p2ide.id(), other_p2ide.id()],
+            &[],
+        )?
+        .build()?;
+
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let tx_args = tx_context.tx_args();
+
+    let executor =
+        TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context).with_tracing();
+    let notes_checker = NoteConsumptionChecker::new(&executor);
+
+    let cancel_note =
+        create_p2ide_cancel_note(cancelled_p2ide.id(), sender_account_id, target_account_id)?;
+    let seen_cancel_note_ids = BTreeSet::from([cancel_note.id()]);
+
+    let check = |account_id, note: &Note| {
+        notes_checker.can_consume_with_cancellations(
+            account_id,
+            block_ref,
+            InputNote::unauthenticated(note.clone()),
+            tx_args.clone(),
+            NoteCheckerConfig::default(),
+            &seen_cancel_note_ids,
+        )
+    };
+
+    // the target should not consume the cancelled note anymore
+    assert_matches!(
+        check(target_account_id, &cancelled_p2ide).await?,
+        NoteConsumptionStatus::NeverConsumable(reason) => {
+            assert_eq!(reason.to_string(), "the P2IDE note was cancelled by its sender");
+        }
+    );
+
+    // notes which were not cancelled are unaffected
+    assert_matches!(
+        check(target_account_id, &other_p2ide).await?,
+        NoteConsumptionStatus::ConsumableWithAuthorization
+    );
+
+    // the sender can still reclaim the cancelled note once the reclaim height is reached
+    assert_matches!(
+        check(sender_account_id, &cancelled_p2ide).await?,
+        NoteConsumptionStatus::ConsumableWithAuthorization
+    );
+
+    // the cancellation is irrelevant for accounts which are not the target of the note
+    let third_account_id: AccountId = ACCOUNT_ID_PRIVATE_SENDER.try_into().unwrap();
+    let third_account_status = check(third_account_id, &cancelled_p2ide).await?;
+    assert!(
+        !matches!(
+            &third_account_status,
+            NoteConsumptionStatus::NeverConsumable(reason)
+                if reason.to_string() == "the P2IDE note was cancelled by its sender"
+        ),
+        "unexpected status {third_account_status:?}"
+    );
+
+    Ok(())
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -10,15 +10,22 @@ use miden_lib::errors::tx_kernel_errors::{
 };
 use miden_lib::testing::mock_account::MockAccountExt;
 use miden_lib::testing::note::NoteBuilder;
-use miden_lib::transaction::EXPIRATION_BLOCK_ELEMENT_IDX;
 use miden_lib::transaction::memory::{
     NOTE_MEM_SIZE,
     OUTPUT_NOTE_ASSET_COMMITMENT_OFFSET,
     OUTPUT_NOTE_SECTION_OFFSET,
 };
+use miden_lib::transaction::{AccountHeaderExt, EXPIRATION_BLOCK_ELEMENT_IDX};
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::Word;
-use miden_objects::account::{Account, AccountDelta, AccountStorageDelta, AccountVaultDelta};
+use miden_objects::account::{
+    Account,
+    AccountDelta,
+    AccountHeader,
+    AccountHeaderDiff,
+    AccountStorageDelta,
+    AccountVaultDelta,
+};
 use miden_objects::asset::{Asset, FungibleAsset};
 use miden_objects::note::{NoteTag, NoteType};
 use miden_objects::testing::account_id::{
@@ -28,7 +35,7 @@ use miden_objects::testing::account_id::{
     ACCOUNT_ID_SENDER,
 };
 use miden_objects::transaction::{OutputNote, OutputNotes};
-use miden_processor::{Felt, ONE};
+use miden_processor::{AdviceInputs, Felt, ONE};
 
 use super::{ZERO, create_mock_notes_procedure};
 use crate::kernel_tests::tx::ExecutionOutputExt;
@@ -144,6 +151,61 @@ async fn test_epilogue() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the final account header parsed from the outputs of the transaction kernel matches
+/// the header of the initial account with the transaction's delta applied.
+#[tokio::test]
+async fn final_account_header_from_stack_outputs() -> anyhow::Result<()> {
+    let account = Account::mock(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, Auth::IncrNonce);
+    let input_note = create_public_p2any_note(ACCOUNT_ID_SENDER.try_into().unwrap(), []);
+    let tx_context = TransactionContextBuilder::new(account.clone())
+        .extend_input_notes(vec![input_note])
+        .build()?;
+
+    let code = "
+        use.$kernel::prologue
+        use.$kernel::epilogue
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.epilogue::finalize_transaction
+
+            # truncate the stack
+            repeat.13 movup.13 drop end
+        end
+        ";
+
+    let exec_output = tx_context.execute_code(code).await?;
+    let (_, advice_map, merkle_store) = exec_output.advice.into_parts();
+    let advice_inputs = AdviceInputs {
+        map: advice_map,
+        store: merkle_store,
+        ..Default::default()
+    };
+
+    let final_header = AccountHeader::try_from_stack_outputs(&exec_output.stack, &advice_inputs)?;
+
+    // The transaction only increments the nonce of the account.
+    let mut final_account = account.clone();
+    final_account.apply_delta(&AccountDelta::new(
+        account.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )?)?;
+
+    assert_eq!(final_header, AccountHeader::from(&final_account));
+    assert!(final_header.matches_account(&final_account));
+    assert_eq!(
+        AccountHeader::from(&account).diff(&final_header),
+        AccountHeaderDiff {
+            nonce_changed: true,
+            ..Default::default()
+        }
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_compute_output_note_id() -> anyhow::Result<()> {
     let tx_context = {
@@ -1,11 +1,13 @@
 use anyhow::Context;
 use assert_matches::assert_matches;
+use miden_lib::transaction::TransactionAdviceInputs;
 use miden_objects::account::{AccountId, StorageMap, StorageSlot};
 use miden_objects::asset::{Asset, FungibleAsset, NonFungibleAsset};
+use miden_objects::block::FeeParameters;
 use miden_objects::note::NoteType;
 use miden_objects::testing::account_id::ACCOUNT_ID_NATIVE_ASSET_FAUCET;
 use miden_objects::transaction::{ExecutedTransaction, OutputNote};
-use miden_objects::{self, Felt, Word};
+use miden_objects::{self, Felt, Word, ZERO};
 use miden_tx::TransactionExecutorError;
 use winter_rand_utils::rand_value;
 
@@ -58,17 +60,18 @@ async fn tx_host_aborts_if_account_balance_does_not_cover_fee() -> anyhow::Resul
     let account_amount = 100;
     let note_amount = 100;
     let native_asset_id = AccountId::try_from(ACCOUNT_ID_NATIVE_ASSET_FAUCET)?;
+    let fee_parameters = FeeParameters::new(native_asset_id, 50)?;
 
-    let mut builder =
-        MockChain::builder().native_asset_id(native_asset_id).verification_base_fee(50);
+    let mut builder = MockChain::builder().fee_parameters(fee_parameters.clone());
     let native_asset = FungibleAsset::new(native_asset_id, account_amount)?;
     let account =
         builder.add_existing_wallet_with_assets(Auth::IncrNonce, [native_asset.into()])?;
     let fee_note = builder.add_p2id_note_with_fee(account.id(), note_amount)?;
     let chain = builder.build()?;
+    assert_eq!(chain.latest_block_header().fee_parameters(), &fee_parameters);
 
     let err = chain
-        .build_tx_context(account, &[fee_note.id()], &[])?
+        .build_tx_context(account.clone(), &[fee_note.id()], &[])?
         .build()?
         .execute()
         .await
@@ -76,14 +79,71 @@ async fn tx_host_aborts_if_account_balance_does_not_cover_fee() -> anyhow::Resul
 
     assert_matches!(
         err,
-        TransactionExecutorError::InsufficientFee { account_balance, tx_fee: _ } => {
+        TransactionExecutorError::InsufficientFee { account_id, account_balance, tx_fee } => {
+            assert_eq!(account_id, account.id());
             assert_eq!(account_balance, account_amount + note_amount);
+            assert_eq!(tx_fee.faucet_id(), native_asset_id);
+            assert!(tx_fee.amount() > account_balance);
         }
     );
 
     Ok(())
 }
 
+/// Tests that the transaction executor fails if the fee exceeds the maximum fee specified in the
+/// transaction arguments and that a sufficient maximum fee does not change the transaction.
+#[tokio::test]
+async fn tx_executor_enforces_max_fee() -> anyhow::Result<()> {
+    let native_asset_id = AccountId::try_from(ACCOUNT_ID_NATIVE_ASSET_FAUCET)?;
+
+    let mut builder =
+        MockChain::builder().native_asset_id(native_asset_id).verification_base_fee(50);
+    let native_asset = FungibleAsset::new(native_asset_id, 100_000)?;
+    let account =
+        builder.add_existing_wallet_with_assets(Auth::IncrNonce, [native_asset.into()])?;
+    let chain = builder.build()?;
+
+    let execute_with_max_fee = async |max_fee: Option<u64>| {
+        let mut tx_context = chain.build_tx_context(account.id(), &[], &[])?.build()?;
+        if let Some(max_fee) = max_fee {
+            tx_context.set_tx_args(tx_context.tx_args().clone().with_max_fee(max_fee));
+
+            let advice_inputs = TransactionAdviceInputs::new(tx_context.tx_inputs())?;
+            assert_eq!(
+                advice_inputs
+                    .as_advice_inputs()
+                    .map
+                    .get(&TransactionAdviceInputs::MAX_FEE_ADVICE_KEY)
+                    .map(|values| values.to_vec()),
+                Some(vec![Felt::new(max_fee), ZERO, ZERO, ZERO])
+            );
+        }
+
+        anyhow::Ok(tx_context.execute().await)
+    };
+
+    let tx = execute_with_max_fee(None).await??;
+    let fee = tx.fee().amount();
+    assert!(fee > 0);
+
+    let err = execute_with_max_fee(Some(fee - 1)).await?.unwrap_err();
+    assert_matches!(
+        err,
+        TransactionExecutorError::FeeExceedsLimit { fee: actual_fee, limit } => {
+            assert_eq!(actual_fee, fee);
+            assert_eq!(limit, fee - 1);
+        }
+    );
+
+    let capped_tx = execute_with_max_fee(Some(fee)).await??;
+    assert_eq!(capped_tx.fee(), tx.fee());
+    assert_eq!(capped_tx.final_account().commitment(), tx.final_account().commitment());
+    assert_eq!(capped_tx.output_notes(), tx.output_notes());
+    assert_eq!(capped_tx.account_delta(), tx.account_delta());
+
+    Ok(())
+}
+
 /// Tests that the _actual_ number of cycles after compute_fee is called are less than the
 /// _predicted_ number of cycles (based on the constants) across a diverse set of transactions.
 ///
@@ -1,6 +1,8 @@
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use miden_lib::errors::tx_kernel_errors::{
     ERR_FOREIGN_ACCOUNT_CONTEXT_AGAINST_NATIVE_ACCOUNT,
@@ -8,7 +10,6 @@ use miden_lib::errors::tx_kernel_errors::{
     ERR_FOREIGN_ACCOUNT_MAX_NUMBER_EXCEEDED,
 };
 use miden_lib::testing::account_component::MockAccountComponent;
-use miden_lib::transaction::TransactionKernel;
 use miden_lib::transaction::memory::{
     ACCOUNT_DATA_LENGTH,
     ACCT_CODE_COMMITMENT_OFFSET,
@@ -21,6 +22,7 @@ use miden_lib::transaction::memory::{
     NUM_ACCT_PROCEDURES_OFFSET,
     NUM_ACCT_STORAGE_SLOTS_OFFSET,
 };
+use miden_lib::transaction::{TransactionAdviceInputs, TransactionKernel};
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::{
     Account,
@@ -30,25 +32,52 @@ use miden_objects::account::{
     AccountProcedureInfo,
     AccountStorage,
     AccountStorageMode,
+    PartialAccount,
+    StorageMapWitness,
     StorageSlot,
 };
 use miden_objects::assembly::DefaultSourceManager;
 use miden_objects::assembly::diagnostics::NamedSource;
-use miden_objects::asset::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+use miden_objects::assembly::mast::MastForest;
+use miden_objects::asset::{
+    Asset,
+    AssetVaultKey,
+    AssetWitness,
+    FungibleAsset,
+    NonFungibleAsset,
+    NonFungibleAssetDetails,
+};
+use miden_objects::block::{BlockHeader, BlockNumber};
+use miden_objects::note::NoteScript;
 use miden_objects::testing::account_id::{
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
     ACCOUNT_ID_PUBLIC_NON_FUNGIBLE_FAUCET,
 };
 use miden_objects::testing::storage::STORAGE_LEAVES_2;
+use miden_objects::transaction::{AccountInputs, PartialBlockchain};
+use miden_objects::utils::Serializable;
 use miden_objects::{FieldElement, Word, ZERO};
 use miden_processor::fast::ExecutionOutput;
-use miden_processor::{AdviceInputs, Felt};
-use miden_tx::LocalTransactionProver;
+use miden_processor::{AdviceInputs, Felt, MastForestStore};
+use miden_tx::auth::UnreachableAuth;
+use miden_tx::{
+    CachingDataStore,
+    DataStore,
+    DataStoreError,
+    LocalTransactionProver,
+    TransactionExecutor,
+};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 
 use crate::kernel_tests::tx::ExecutionOutputExt;
-use crate::{Auth, MockChainBuilder, assert_execution_error, assert_transaction_executor_error};
+use crate::{
+    Auth,
+    MockChainBuilder,
+    TransactionContext,
+    assert_execution_error,
+    assert_transaction_executor_error,
+};
 
 // SIMPLE FPI TESTS
 // ================================================================================================
@@ -645,6 +674,190 @@ async fn test_fpi_execute_foreign_procedure() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the [`CachingDataStore`] serves the foreign account inputs from its cache when
+/// executing a transaction repeatedly, while the native account is re-fetched after it was
+/// changed by the first transaction and the foreign account is re-fetched after it was explicitly
+/// invalidated.
+#[tokio::test]
+async fn test_fpi_caching_data_store() -> anyhow::Result<()> {
+    let foreign_account_code_source = "
+        use.miden::active_account
+
+        export.get_item_foreign
+            push.1 drop
+            exec.active_account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 movup.6 drop drop drop
+        end
+    ";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let foreign_account_component = AccountComponent::compile(
+        NamedSource::new("foreign_account", foreign_account_code_source),
+        TransactionKernel::with_kernel_library(source_manager.clone()),
+        vec![AccountStorage::mock_item_0().slot],
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component.clone())
+        .build_existing()?;
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_empty_slots())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    let mut mock_chain =
+        MockChainBuilder::with_accounts([native_account.clone(), foreign_account.clone()])?
+            .build()?;
+    mock_chain.prove_next_block()?;
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            padw padw padw push.0.0
+            # => [pad(14)]
+
+            push.0
+            procref.::foreign_account::get_item_foreign
+            push.{foreign_suffix} push.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+            # => [STORAGE_VALUE]
+
+            push.1.2.3.4 assert_eqw
+            # => []
+
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+    );
+
+    let tx_script = ScriptBuilder::with_source_manager(source_manager.clone())
+        .with_dynamically_linked_library(foreign_account_component.library())?
+        .compile_tx_script(code)?;
+
+    let foreign_account_inputs = mock_chain
+        .get_foreign_account_inputs(foreign_account.id())
+        .expect("failed to get foreign account inputs");
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])?
+        .foreign_accounts([foreign_account_inputs])
+        .tx_script(tx_script)
+        .with_source_manager(source_manager.clone())
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let notes = tx_context.input_notes().clone();
+    let tx_args = tx_context.tx_args().clone();
+
+    let data_store = CachingDataStore::new(CountingDataStore::new(tx_context));
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&data_store)
+        .with_source_manager(source_manager)
+        .with_debug_mode();
+
+    executor
+        .execute_transaction(account_id, block_ref, notes.clone(), tx_args.clone())
+        .await?;
+    assert_eq!(data_store.inner().transaction_inputs_requests.load(Ordering::Relaxed), 1);
+    assert_eq!(data_store.inner().foreign_account_inputs_requests.load(Ordering::Relaxed), 1);
+
+    // The first transaction incremented the nonce of the native account, so its cached state is
+    // stale and must be fetched again, while the foreign account inputs are served from the cache.
+    executor
+        .execute_transaction(account_id, block_ref, notes.clone(), tx_args.clone())
+        .await?;
+    assert_eq!(data_store.inner().transaction_inputs_requests.load(Ordering::Relaxed), 2);
+    assert_eq!(data_store.inner().foreign_account_inputs_requests.load(Ordering::Relaxed), 1);
+
+    // Explicitly invalidating the foreign account forces its inputs to be fetched again.
+    data_store.invalidate_account(foreign_account.id());
+    executor.execute_transaction(account_id, block_ref, notes, tx_args).await?;
+    assert_eq!(data_store.inner().transaction_inputs_requests.load(Ordering::Relaxed), 3);
+    assert_eq!(data_store.inner().foreign_account_inputs_requests.load(Ordering::Relaxed), 2);
+
+    Ok(())
+}
+
+/// A [`DataStore`] which counts the requests for transaction and foreign account inputs it
+/// forwards to the wrapped [`TransactionContext`].
+struct CountingDataStore {
+    tx_context: TransactionContext,
+    transaction_inputs_requests: AtomicUsize,
+    foreign_account_inputs_requests: AtomicUsize,
+}
+
+impl CountingDataStore {
+    fn new(tx_context: TransactionContext) -> Self {
+        Self {
+            tx_context,
+            transaction_inputs_requests: AtomicUsize::new(0),
+            foreign_account_inputs_requests: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl DataStore for CountingDataStore {
+    async fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError> {
+        self.transaction_inputs_requests.fetch_add(1, Ordering::Relaxed);
+        self.tx_context.get_transaction_inputs(account_id, ref_blocks).await
+    }
+
+    async fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        ref_block: BlockNumber,
+    ) -> Result<AccountInputs, DataStoreError> {
+        self.foreign_account_inputs_requests.fetch_add(1, Ordering::Relaxed);
+        self.tx_context.get_foreign_account_inputs(foreign_account_id, ref_block).await
+    }
+
+    async fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_key: AssetVaultKey,
+    ) -> Result<AssetWitness, DataStoreError> {
+        self.tx_context.get_vault_asset_witness(account_id, vault_root, vault_key).await
+    }
+
+    async fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> Result<StorageMapWitness, DataStoreError> {
+        self.tx_context.get_storage_map_witness(account_id, map_root, map_key).await
+    }
+
+    async fn get_note_script(&self, script_root: Word) -> Result<NoteScript, DataStoreError> {
+        self.tx_context.get_note_script(script_root).await
+    }
+}
+
+impl MastForestStore for CountingDataStore {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        self.tx_context.get(procedure_hash)
+    }
+}
+
 /// Test that a foreign account can get the balance of a fungible asset and check the presence of a
 /// non-fungible asset.
 #[tokio::test]
@@ -870,6 +1083,177 @@ async fn foreign_account_get_initial_balance() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that advice data which is not required to re-execute a transaction is stripped from its
+/// inputs and that the stripped transaction can still be proven.
+#[tokio::test]
+async fn stripped_advice_inputs_can_be_proven() -> anyhow::Result<()> {
+    let foreign_account_code_source = "
+        use.miden::active_account
+
+        export.get_item_foreign
+            # make this foreign procedure unique to make sure that we invoke the procedure of the
+            # foreign account, not the native one
+            push.1 drop
+            exec.active_account::get_item
+
+            # truncate the stack
+            movup.6 movup.6 movup.6 drop drop drop
+        end
+    ";
+
+    let source_manager = Arc::new(DefaultSourceManager::default());
+    let foreign_account_component = AccountComponent::compile(
+        NamedSource::new("foreign_account", foreign_account_code_source),
+        TransactionKernel::with_kernel_library(source_manager.clone()),
+        vec![AccountStorage::mock_item_0().slot],
+    )?
+    .with_supports_all_types();
+
+    let foreign_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(foreign_account_component.clone())
+        .build_existing()?;
+
+    let native_account = AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_empty_slots())
+        .storage_mode(AccountStorageMode::Public)
+        .build_existing()?;
+
+    // Create a number of accounts which are never accessed by the transaction.
+    let unrelated_accounts = (0..5)
+        .map(|_| {
+            AccountBuilder::new(ChaCha20Rng::from_os_rng().random())
+                .with_auth_component(Auth::IncrNonce)
+                .with_component(MockAccountComponent::with_slots(
+                    AccountStorage::mock_storage_slots(),
+                ))
+                .build_existing()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut mock_chain = MockChainBuilder::with_accounts(
+        [native_account.clone(), foreign_account.clone()]
+            .into_iter()
+            .chain(unrelated_accounts.iter().cloned()),
+    )?
+    .build()?;
+    mock_chain.prove_next_block()?;
+
+    let code = format!(
+        "
+        use.std::sys
+
+        use.miden::tx
+
+        begin
+            # pad the stack for the `execute_foreign_procedure` execution
+            padw padw padw push.0.0
+            # => [pad(14)]
+
+            # push the index of desired storage item
+            push.0
+
+            # get the hash of the `get_item_foreign` account procedure
+            procref.::foreign_account::get_item_foreign
+
+            # push the foreign account ID
+            push.{foreign_suffix} push.{foreign_prefix}
+            # => [foreign_account_id_prefix, foreign_account_id_suffix, FOREIGN_PROC_ROOT, storage_item_index, pad(14)]
+
+            exec.tx::execute_foreign_procedure
+            # => [STORAGE_VALUE]
+
+            # assert the correctness of the obtained value
+            push.1.2.3.4 assert_eqw
+            # => []
+
+            # truncate the stack
+            exec.sys::truncate_stack
+        end
+        ",
+        foreign_prefix = foreign_account.id().prefix().as_felt(),
+        foreign_suffix = foreign_account.id().suffix(),
+    );
+
+    let tx_script = ScriptBuilder::with_source_manager(source_manager.clone())
+        .with_dynamically_linked_library(foreign_account_component.library())?
+        .compile_tx_script(code)?;
+
+    let foreign_account_inputs = mock_chain.get_foreign_account_inputs(foreign_account.id())?;
+    let unrelated_account_inputs = unrelated_accounts
+        .iter()
+        .map(|account| mock_chain.get_foreign_account_inputs(account.id()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Provide the advice data of the unrelated accounts up front, as a client that pre-fetches
+    // foreign account data would do.
+    let mut unrelated_advice = TransactionAdviceInputs::default();
+    unrelated_advice.add_foreign_accounts(
+        &unrelated_account_inputs
+            .iter()
+            .map(|(account, witness)| AccountInputs::new(account.into(), witness.clone()))
+            .collect::<Vec<_>>(),
+    )?;
+
+    let tx_context = mock_chain
+        .build_tx_context(native_account.id(), &[], &[])?
+        .foreign_accounts(
+            [foreign_account_inputs]
+                .into_iter()
+                .chain(unrelated_account_inputs.iter().cloned()),
+        )
+        .extend_advice_inputs(unrelated_advice.into_advice_inputs())
+        .tx_script(tx_script)
+        .with_source_manager(source_manager)
+        .build()?;
+
+    // The advice provider the transaction is executed with is initialized from the original
+    // transaction inputs and is only extended during execution, so the advice built from these
+    // inputs is part of the unstripped witness.
+    let (_, initial_advice) = TransactionKernel::build_inputs(tx_context.tx_inputs())?;
+    let initial_advice = initial_advice.into_advice_inputs();
+
+    let unstripped_tx_inputs = tx_context.tx_inputs().clone();
+    let executed_transaction = tx_context.execute().await?;
+    let advice_witness = executed_transaction.advice_witness();
+    let tx_args_advice = executed_transaction.tx_inputs().tx_args().advice_inputs();
+
+    // The headers of the unrelated accounts are part of the unstripped witness and transaction
+    // arguments, but must have been stripped from both in the executed transaction.
+    for account in unrelated_accounts.iter() {
+        let account_id_key =
+            Word::from([account.id().suffix(), account.id().prefix().as_felt(), ZERO, ZERO]);
+        assert!(initial_advice.map.get(&account_id_key).is_some());
+        assert!(unstripped_tx_inputs.tx_args().advice_inputs().map.contains_key(&account_id_key));
+        assert!(!advice_witness.map.contains_key(&account_id_key));
+        assert!(!tx_args_advice.map.contains_key(&account_id_key));
+    }
+
+    // Every entry of the stripped witness is also part of the unstripped witness, so the latter
+    // is strictly larger.
+    let mut unstripped_advice = initial_advice;
+    unstripped_advice.extend(advice_witness.clone());
+    assert!(advice_witness.map.len() < unstripped_advice.map.len());
+    assert!(advice_witness.to_bytes().len() < unstripped_advice.to_bytes().len());
+
+    // The serialized inputs of the executed transaction must be smaller than the same inputs
+    // carrying the unstripped witness and transaction arguments.
+    let unstripped_tx_inputs = unstripped_tx_inputs
+        .with_foreign_account_code(executed_transaction.tx_inputs().foreign_account_code().to_vec())
+        .with_advice_inputs(unstripped_advice);
+    assert!(
+        executed_transaction.tx_inputs().to_bytes().len() < unstripped_tx_inputs.to_bytes().len()
+    );
+    assert!(tx_args_advice.map.len() < unstripped_tx_inputs.tx_args().advice_inputs().map.len());
+
+    let executed_transaction_id = executed_transaction.id();
+    let proven_tx = LocalTransactionProver::default().prove(executed_transaction)?;
+    assert_eq!(proven_tx.id(), executed_transaction_id);
+
+    Ok(())
+}
+
 // NESTED FPI TESTS
 // ================================================================================================
 
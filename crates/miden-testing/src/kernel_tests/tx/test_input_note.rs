@@ -1,11 +1,15 @@
 use alloc::string::String;
 
+use assert_matches::assert_matches;
 use miden_lib::utils::ScriptBuilder;
-use miden_objects::Word;
+use miden_objects::asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset};
 use miden_objects::note::Note;
+use miden_objects::testing::account_id::ACCOUNT_ID_SENDER;
+use miden_objects::{AssetVaultError, Word};
 
 use super::{TestSetup, setup_test};
-use crate::TxContextInput;
+use crate::utils::create_public_p2any_note;
+use crate::{TransactionContextBuilder, TxContextInput};
 
 /// Check that the assets number and assets commitment obtained from the
 /// `input_note::get_assets_info` procedure is correct for each note with zero, one and two
@@ -423,3 +427,39 @@ async fn test_get_serial_number() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Check that
+/// [`TransactionInputs::total_input_assets`](miden_objects::transaction::TransactionInputs::total_input_assets)
+/// merges the fungible assets of all input notes and contains all non-fungible assets.
+#[test]
+fn test_total_input_assets() -> anyhow::Result<()> {
+    let sender = ACCOUNT_ID_SENDER.try_into()?;
+    let non_fungible_asset = NonFungibleAsset::mock(&[1, 2, 3]);
+    let note_0 = create_public_p2any_note(sender, [FungibleAsset::mock(100)]);
+    let note_1 = create_public_p2any_note(sender, [FungibleAsset::mock(50), non_fungible_asset]);
+
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .extend_input_notes(vec![note_0, note_1])
+        .build()?;
+    let total_input_assets = tx_context.tx_inputs().total_input_assets()?;
+
+    let expected_vault = AssetVault::new(&[FungibleAsset::mock(150), non_fungible_asset])?;
+    assert_eq!(total_input_assets, expected_vault);
+
+    // the same non-fungible asset cannot be received twice
+    let note_2 = create_public_p2any_note(sender, [non_fungible_asset]);
+    let note_3 = create_public_p2any_note(sender, [FungibleAsset::mock(10), non_fungible_asset]);
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .extend_input_notes(vec![note_2, note_3])
+        .build()?;
+    assert_matches!(
+        tx_context.tx_inputs().total_input_assets(),
+        Err(AssetVaultError::DuplicateNonFungibleAsset(asset)) if Asset::from(asset) == non_fungible_asset
+    );
+
+    // transactions without input notes receive no assets
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+    assert_eq!(tx_context.tx_inputs().total_input_assets()?, AssetVault::default());
+
+    Ok(())
+}
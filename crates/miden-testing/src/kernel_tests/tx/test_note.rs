@@ -29,15 +29,18 @@ use miden_objects::testing::account_id::{
     ACCOUNT_ID_REGULAR_PRIVATE_ACCOUNT_UPDATABLE_CODE,
     ACCOUNT_ID_SENDER,
 };
-use miden_objects::transaction::{OutputNote, TransactionArgs};
+use miden_objects::transaction::{InputNotes, OutputNote, TransactionArgs};
 use miden_objects::{Felt, Word, ZERO};
 use miden_processor::fast::ExecutionOutput;
+use miden_tx::TransactionExecutor;
+use miden_tx::auth::UnreachableAuth;
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 
 use crate::kernel_tests::tx::{ExecutionOutputExt, input_note_data_ptr};
 use crate::{
     Auth,
+    MockAuthenticator,
     MockChain,
     TransactionContext,
     TransactionContextBuilder,
@@ -166,6 +169,61 @@ async fn test_note_script_and_note_args() -> miette::Result<()> {
     Ok(())
 }
 
+/// Tests that note arguments attached to input notes are passed to the note scripts and that note
+/// arguments specified in the transaction arguments take precedence over them.
+#[tokio::test]
+async fn test_note_args_attached_to_input_notes() -> anyhow::Result<()> {
+    let attached_args = Word::from([1, 2, 3, 4u32]);
+    let tx_args_note_args = Word::from([5, 6, 7, 8u32]);
+
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+    let mut rng = ChaCha20Rng::from_seed([3; 32]);
+    let attached_args_note = NoteBuilder::new(account.id(), &mut rng)
+        .code(format!("begin push.{attached_args} assert_eqw end"))
+        .build()?;
+    let overridden_args_note = NoteBuilder::new(account.id(), &mut rng)
+        .code(format!("begin push.{tx_args_note_args} assert_eqw end"))
+        .build()?;
+    builder.add_output_note(OutputNote::Full(attached_args_note.clone()));
+    builder.add_output_note(OutputNote::Full(overridden_args_note.clone()));
+
+    let tx_context = builder
+        .build()?
+        .build_tx_context(account.id(), &[attached_args_note.id(), overridden_args_note.id()], &[])?
+        .build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let notes = InputNotes::new(
+        tx_context
+            .input_notes()
+            .iter()
+            .map(|note| note.clone().with_args(attached_args))
+            .collect(),
+    )?;
+    let tx_args = tx_context
+        .tx_args()
+        .clone()
+        .with_note_args(BTreeMap::from([(overridden_args_note.id(), tx_args_note_args)]));
+
+    let executed_transaction = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .execute_transaction(account_id, block_ref, notes, tx_args)
+        .await?;
+
+    assert_eq!(
+        executed_transaction.tx_args().get_note_args(attached_args_note.id()),
+        Some(&attached_args)
+    );
+    assert_eq!(
+        executed_transaction.tx_args().get_note_args(overridden_args_note.id()),
+        Some(&tx_args_note_args)
+    );
+
+    Ok(())
+}
+
 fn note_setup_stack_assertions(exec_output: &ExecutionOutput, inputs: &TransactionContext) {
     let mut expected_stack = [ZERO; 16];
 
@@ -516,6 +574,7 @@ async fn test_public_key_as_note_input() -> anyhow::Result<()> {
     let public_key_value = Word::from(public_key);
 
     let (rpo_component, authenticator) = Auth::BasicAuth.build_component();
+    let authenticator = authenticator.map(MockAuthenticator::from);
 
     let mock_seed_1 = Word::from([1, 2, 3, 4u32]).as_bytes();
     let target_account = AccountBuilder::new(mock_seed_1)
@@ -10,7 +10,6 @@ use miden_lib::errors::tx_kernel_errors::{
 };
 use miden_lib::testing::account_component::MockAccountComponent;
 use miden_lib::testing::mock_account::MockAccountExt;
-use miden_lib::transaction::TransactionKernel;
 use miden_lib::transaction::memory::{
     ACCT_DB_ROOT_PTR,
     BLOCK_COMMITMENT_PTR,
@@ -62,6 +61,12 @@ use miden_lib::transaction::memory::{
     TX_SCRIPT_ROOT_PTR,
     VERIFICATION_BASE_FEE_IDX,
 };
+use miden_lib::transaction::{
+    TransactionAdviceInputs,
+    TransactionAdviceMapEntry,
+    TransactionAdviceValidationError,
+    TransactionKernel,
+};
 use miden_objects::account::{
     Account,
     AccountBuilder,
@@ -80,7 +85,12 @@ use miden_objects::testing::account_id::{
     ACCOUNT_ID_SENDER,
 };
 use miden_objects::testing::noop_auth_component::NoopAuthComponent;
-use miden_objects::transaction::{ExecutedTransaction, TransactionArgs, TransactionScript};
+use miden_objects::transaction::{
+    ExecutedTransaction,
+    TransactionArgs,
+    TransactionInputs,
+    TransactionScript,
+};
 use miden_objects::{EMPTY_WORD, ONE, WORD_SIZE};
 use miden_processor::fast::ExecutionOutput;
 use miden_processor::{AdviceInputs, Word};
@@ -803,3 +813,127 @@ async fn test_get_blk_timestamp() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_validate_advice() -> anyhow::Result<()> {
+    let account = Account::mock(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, Auth::IncrNonce);
+    let input_note =
+        create_public_p2any_note(ACCOUNT_ID_SENDER.try_into().unwrap(), [FungibleAsset::mock(100)]);
+    let tx_context = TransactionContextBuilder::new(account)
+        .extend_input_notes(vec![input_note.clone()])
+        .build()?;
+    let tx_inputs = tx_context.tx_inputs();
+
+    let (_, advice) = TransactionKernel::build_inputs(tx_inputs)?;
+    let required_entries = advice.required_map_entries().to_vec();
+    let advice = advice.into_advice_inputs();
+    TransactionKernel::validate_advice(tx_inputs, &advice)?;
+
+    let partial_account = tx_inputs.account();
+    for expected_entry in [
+        (TransactionAdviceMapEntry::KernelProcedures, TransactionKernel.to_commitment()),
+        (
+            TransactionAdviceMapEntry::BlockchainPeaks,
+            tx_inputs.blockchain().peaks().hash_peaks(),
+        ),
+        (TransactionAdviceMapEntry::AccountCode, partial_account.code().commitment()),
+        (
+            TransactionAdviceMapEntry::AccountStorageHeader,
+            partial_account.storage().header().compute_commitment(),
+        ),
+        (TransactionAdviceMapEntry::InputNotes, tx_inputs.input_notes().commitment()),
+        (
+            TransactionAdviceMapEntry::NoteInputs(input_note.id()),
+            input_note.inputs().commitment(),
+        ),
+        (
+            TransactionAdviceMapEntry::NoteAssets(input_note.id()),
+            input_note.assets().commitment(),
+        ),
+    ] {
+        assert!(required_entries.contains(&expected_entry), "{expected_entry:?} is not required");
+    }
+    assert_missing_map_entries_detected(tx_inputs, &advice, &required_entries)?;
+
+    // modifying a required entry is detected
+    let mut malformed_advice = advice.clone();
+    malformed_advice.map.insert(partial_account.code().commitment(), vec![ONE]);
+    assert_matches::assert_matches!(
+        TransactionKernel::validate_advice(tx_inputs, &malformed_advice),
+        Err(TransactionAdviceValidationError::MalformedMapEntry {
+            entry: TransactionAdviceMapEntry::AccountCode,
+            ..
+        })
+    );
+
+    // truncating the advice stack is detected
+    let mut truncated_advice = advice.clone();
+    let stack_len = truncated_advice.stack.len();
+    truncated_advice.stack.truncate(stack_len - 1);
+    assert_matches::assert_matches!(
+        TransactionKernel::validate_advice(tx_inputs, &truncated_advice),
+        Err(TransactionAdviceValidationError::AdviceStackMismatch { index }) if index == stack_len - 1
+    );
+
+    // new accounts and maximum fees require additional entries
+    let storage_map =
+        StorageMap::with_entries([(Word::from([1, 2, 3, 4u32]), Word::from([5, 6, 7, 8u32]))])?;
+    let account = AccountBuilder::new([7; 32])
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(Auth::IncrNonce)
+        .with_component(MockAccountComponent::with_slots(vec![StorageSlot::Map(
+            storage_map.clone(),
+        )]))
+        .build()?;
+    let mut tx_context = TransactionContextBuilder::new(account).build()?;
+    tx_context.set_tx_args(tx_context.tx_args().clone().with_max_fee(1_000));
+    let tx_inputs = tx_context.tx_inputs();
+
+    let (_, advice) = TransactionKernel::build_inputs(tx_inputs)?;
+    let required_entries = advice.required_map_entries().to_vec();
+    let advice = advice.into_advice_inputs();
+    TransactionKernel::validate_advice(tx_inputs, &advice)?;
+
+    assert!(
+        required_entries
+            .iter()
+            .any(|(entry, _)| *entry == TransactionAdviceMapEntry::AccountSeed)
+    );
+    assert!(
+        required_entries
+            .contains(&(TransactionAdviceMapEntry::AccountStorageMapEntries, storage_map.root()))
+    );
+    assert!(required_entries.contains(&(
+        TransactionAdviceMapEntry::MaxFee,
+        TransactionAdviceInputs::MAX_FEE_ADVICE_KEY
+    )));
+    assert_missing_map_entries_detected(tx_inputs, &advice, &required_entries)?;
+
+    Ok(())
+}
+
+/// Asserts that removing any of the `required_entries` from `advice` is detected by
+/// [`TransactionKernel::validate_advice`].
+fn assert_missing_map_entries_detected(
+    tx_inputs: &TransactionInputs,
+    advice: &AdviceInputs,
+    required_entries: &[(TransactionAdviceMapEntry, Word)],
+) -> anyhow::Result<()> {
+    for &(expected_entry, expected_key) in required_entries {
+        let mut incomplete_advice = advice.clone();
+        incomplete_advice
+            .map
+            .remove(&expected_key)
+            .with_context(|| format!("advice map should contain {expected_entry:?}"))?;
+
+        assert_matches::assert_matches!(
+            TransactionKernel::validate_advice(tx_inputs, &incomplete_advice),
+            Err(TransactionAdviceValidationError::MissingMapEntry { entry, key }) => {
+                assert_eq!(entry, expected_entry);
+                assert_eq!(key, expected_key);
+            }
+        );
+    }
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::time::Duration;
 
 use anyhow::Context;
 use assert_matches::assert_matches;
@@ -8,6 +11,7 @@ use miden_lib::account::wallets::BasicWallet;
 use miden_lib::note::create_p2id_note;
 use miden_lib::testing::account_component::IncrNonceAuthComponent;
 use miden_lib::testing::mock_account::MockAccountExt;
+use miden_lib::testing::note::NoteBuilder;
 use miden_lib::transaction::TransactionKernel;
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::{
@@ -15,15 +19,28 @@ use miden_objects::account::{
     AccountBuilder,
     AccountCode,
     AccountComponent,
+    AccountDelta,
+    AccountHeader,
+    AccountId,
     AccountStorage,
     AccountStorageMode,
     AccountType,
+    PartialAccount,
+    StorageMapWitness,
     StorageSlot,
 };
 use miden_objects::assembly::DefaultSourceManager;
 use miden_objects::assembly::diagnostics::NamedSource;
-use miden_objects::asset::{Asset, AssetVault, FungibleAsset, NonFungibleAsset};
-use miden_objects::block::BlockNumber;
+use miden_objects::assembly::mast::MastForest;
+use miden_objects::asset::{
+    Asset,
+    AssetVault,
+    AssetVaultKey,
+    AssetWitness,
+    FungibleAsset,
+    NonFungibleAsset,
+};
+use miden_objects::block::{BlockHeader, BlockNumber};
 use miden_objects::note::{
     Note,
     NoteAssets,
@@ -34,10 +51,12 @@ use miden_objects::note::{
     NoteInputs,
     NoteMetadata,
     NoteRecipient,
+    NoteScript,
     NoteTag,
     NoteType,
 };
 use miden_objects::testing::account_id::{
+    ACCOUNT_ID_NATIVE_ASSET_FAUCET,
     ACCOUNT_ID_PRIVATE_SENDER,
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
@@ -47,20 +66,51 @@ use miden_objects::testing::account_id::{
 use miden_objects::testing::constants::{FUNGIBLE_ASSET_AMOUNT, NON_FUNGIBLE_ASSET_DATA};
 use miden_objects::testing::note::DEFAULT_NOTE_CODE;
 use miden_objects::transaction::{
+    AccountInputs,
+    CustomEvent,
+    CustomEventId,
+    ExecutedTransaction,
+    InputNote,
     InputNotes,
     OutputNote,
     OutputNotes,
+    PartialBlockchain,
     TransactionArgs,
+    TransactionHeader,
+    TransactionInputs,
+    TransactionOutputs,
     TransactionSummary,
 };
-use miden_objects::{Felt, FieldElement, Hasher, ONE, Word};
+use miden_objects::utils::{Deserializable, Serializable};
+use miden_objects::{
+    Felt,
+    FieldElement,
+    Hasher,
+    MIN_TX_EXECUTION_CYCLES,
+    ONE,
+    TransactionInputError,
+    Word,
+};
 use miden_processor::crypto::RpoRandomCoin;
+use miden_processor::{ExecutionError, ExecutionOptions, MastForestStore};
 use miden_tx::auth::UnreachableAuth;
-use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use miden_tx::{
+    DataStore,
+    DataStoreError,
+    DelegatedProver,
+    ExecutionProfile,
+    LocalTransactionProver,
+    NoopDataStore,
+    TransactionExecutor,
+    TransactionExecutorError,
+    TransactionProver,
+    TransactionProverError,
+    validate_executed_transaction,
+};
 
 use crate::kernel_tests::tx::ExecutionOutputExt;
 use crate::utils::{create_public_p2any_note, create_spawn_note};
-use crate::{Auth, MockChain, TransactionContextBuilder};
+use crate::{Auth, MockChain, TransactionContext, TransactionContextBuilder};
 
 /// Tests that consuming a note created in a block that is newer than the reference block of the
 /// transaction fails.
@@ -123,6 +173,221 @@ async fn consuming_note_created_in_future_block_fails() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that re-executing the inputs of an executed transaction without a data store results in
+/// the same transaction.
+#[tokio::test]
+async fn execute_with_inputs_reproduces_executed_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account =
+        builder.add_existing_wallet_with_assets(Auth::BasicAuth, [FungibleAsset::mock(100)])?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(50)],
+        NoteType::Public,
+    )?;
+    let mock_chain = builder.build()?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    // The signature generated during the first execution is part of the advice inputs, so no
+    // authenticator is required.
+    let reexecuted_transaction =
+        TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&NoopDataStore)
+            .execute_with_inputs(executed_transaction.tx_inputs().clone())
+            .await?;
+
+    assert_eq!(reexecuted_transaction.id(), executed_transaction.id());
+    assert_eq!(
+        reexecuted_transaction.final_account().commitment(),
+        executed_transaction.final_account().commitment()
+    );
+
+    Ok(())
+}
+
+/// Tests that a transaction which does not complete within the provided timeout is aborted, while
+/// the same transaction completes if the timeout is sufficiently large.
+#[tokio::test]
+async fn execute_transaction_with_timeout() -> anyhow::Result<()> {
+    let tx_context = TransactionContextBuilder::with_existing_mock_account().build()?;
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let notes = tx_context.input_notes().clone();
+    let tx_args = tx_context.tx_args().clone();
+    let source_manager = tx_context.source_manager();
+
+    let data_store = SlowDataStore {
+        tx_context,
+        delay: Duration::from_millis(100),
+    };
+    let executor = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&data_store)
+        .with_source_manager(source_manager);
+
+    let timeout = Duration::from_millis(10);
+    let result = executor
+        .execute_transaction_with_timeout(
+            account_id,
+            block_ref,
+            notes.clone(),
+            tx_args.clone(),
+            timeout,
+        )
+        .await;
+    assert_matches!(result, Err(TransactionExecutorError::ExecutionTimeout(t)) if t == timeout);
+
+    executor
+        .execute_transaction_with_timeout(
+            account_id,
+            block_ref,
+            notes,
+            tx_args,
+            Duration::from_secs(600),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// A [`DataStore`] which delays the transaction inputs it fetches from the wrapped
+/// [`TransactionContext`].
+struct SlowDataStore {
+    tx_context: TransactionContext,
+    delay: Duration,
+}
+
+impl DataStore for SlowDataStore {
+    async fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError> {
+        tokio::time::sleep(self.delay).await;
+        self.tx_context.get_transaction_inputs(account_id, ref_blocks).await
+    }
+
+    async fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        ref_block: BlockNumber,
+    ) -> Result<AccountInputs, DataStoreError> {
+        self.tx_context.get_foreign_account_inputs(foreign_account_id, ref_block).await
+    }
+
+    async fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_key: AssetVaultKey,
+    ) -> Result<AssetWitness, DataStoreError> {
+        self.tx_context.get_vault_asset_witness(account_id, vault_root, vault_key).await
+    }
+
+    async fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> Result<StorageMapWitness, DataStoreError> {
+        self.tx_context.get_storage_map_witness(account_id, map_root, map_key).await
+    }
+
+    async fn get_note_script(&self, script_root: Word) -> Result<NoteScript, DataStoreError> {
+        self.tx_context.get_note_script(script_root).await
+    }
+}
+
+impl MastForestStore for SlowDataStore {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        self.tx_context.get(procedure_hash)
+    }
+}
+
+/// Tests that unauthenticated input notes can be authenticated with the inclusion proofs provided
+/// by the chain, so that the transaction consuming them does not require them to be authenticated
+/// in the batch.
+#[tokio::test]
+async fn authenticate_unauthenticated_input_notes() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let other_note = builder.add_p2id_note(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(50)],
+        NoteType::Public,
+    )?;
+    let mut mock_chain = builder.build()?;
+
+    let proof = mock_chain
+        .committed_notes()
+        .get(&note.id())
+        .context("note should be committed")?
+        .inclusion_proof()
+        .clone();
+    let genesis_header = mock_chain.block_header(0);
+
+    // Authenticate only one of the notes.
+    let mut input_notes = InputNotes::new(vec![
+        InputNote::unauthenticated(note.clone()),
+        InputNote::unauthenticated(other_note.clone()),
+    ])?;
+    assert_eq!(input_notes.unauthenticated_note_ids(), vec![note.id(), other_note.id()]);
+
+    let unauthenticated_commitment = input_notes.commitment();
+    let remaining_note_ids = input_notes.authenticate_all(
+        BTreeMap::from([(note.id(), proof.clone())]),
+        &BTreeMap::from([(BlockNumber::GENESIS, genesis_header.clone())]),
+    )?;
+    assert_eq!(remaining_note_ids, vec![other_note.id()]);
+    assert_eq!(input_notes.get_note(0).proof(), Some(&proof));
+    assert_ne!(input_notes.commitment(), unauthenticated_commitment);
+
+    // Execute a transaction consuming the authenticated note.
+    let input_note =
+        InputNote::unauthenticated(note.clone()).authenticate(proof.clone(), &genesis_header)?;
+    let tx_context = mock_chain.build_tx_context(account.id(), &[], &[])?.build()?;
+    let executed_transaction = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .execute_transaction(
+            account.id(),
+            BlockNumber::GENESIS,
+            InputNotes::new(vec![input_note])?,
+            TransactionArgs::default(),
+        )
+        .await?;
+    let proven_transaction = LocalTransactionProver::default().prove_dummy(executed_transaction)?;
+    assert!(proven_transaction.input_notes().get_note(0).header().is_none());
+
+    // Verification failures identify the note.
+    mock_chain.prove_next_block()?;
+    assert_matches!(
+        InputNote::unauthenticated(note.clone()).authenticate(proof.clone(), &mock_chain.block_header(1)),
+        Err(TransactionInputError::InputNoteNotInBlock(note_id, block_num))
+            if note_id == note.id() && block_num == 1u32.into()
+    );
+    assert_matches!(
+        InputNote::unauthenticated(other_note.clone()).authenticate(proof.clone(), &genesis_header),
+        Err(TransactionInputError::InputNoteNotInBlock(note_id, _)) if note_id == other_note.id()
+    );
+    let mut input_notes = InputNotes::new(vec![InputNote::unauthenticated(note.clone())])?;
+    assert_matches!(
+        input_notes.authenticate_all(BTreeMap::from([(note.id(), proof)]), &BTreeMap::new()),
+        Err(TransactionInputError::InputNoteBlockHeaderMissing(note_id, _)) if note_id == note.id()
+    );
+    assert_eq!(input_notes.unauthenticated_note_ids(), vec![note.id()]);
+
+    Ok(())
+}
+
 // BLOCK TESTS
 // ================================================================================================
 
@@ -466,6 +731,7 @@ async fn user_code_can_abort_transaction_with_summary() -> anyhow::Result<()> {
     builder.add_output_note(OutputNote::Full(input_note.clone()));
     let mock_chain = builder.build()?;
 
+    let account_id = account.id();
     let tx_context = mock_chain.build_tx_context(account, &[input_note.id()], &[])?.build()?;
     let ref_block_num = tx_context.tx_inputs().block_header().block_num().as_u32();
     let final_nonce = tx_context.account().nonce().as_int() as u32 + 1;
@@ -474,7 +740,8 @@ async fn user_code_can_abort_transaction_with_summary() -> anyhow::Result<()> {
 
     let error = tx_context.execute().await.unwrap_err();
 
-    assert_matches!(error, TransactionExecutorError::Unauthorized(tx_summary) => {
+    assert_matches!(error, TransactionExecutorError::Unauthorized { account_id: unauthorized_account_id, summary: tx_summary } => {
+        assert_eq!(unauthorized_account_id, account_id);
         assert!(tx_summary.account_delta().vault().is_empty());
         assert!(tx_summary.account_delta().storage().is_empty());
         assert_eq!(tx_summary.account_delta().nonce_delta().as_int(), 1);
@@ -483,6 +750,20 @@ async fn user_code_can_abort_transaction_with_summary() -> anyhow::Result<()> {
         assert_eq!(tx_summary.salt(), Word::from(
           [0, 0, ref_block_num, final_nonce]
         ));
+
+        // The host only returns the summary if its commitment matches the message emitted by the
+        // kernel, so the commitment from its parts must match as well.
+        let [delta_commitment, input_notes_commitment, output_notes_commitment, salt] =
+            tx_summary.display_fields().map(|field| field.value());
+        assert_eq!(
+            TransactionSummary::commitment_from_parts(
+                delta_commitment,
+                input_notes_commitment,
+                output_notes_commitment,
+                salt,
+            ),
+            tx_summary.to_commitment()
+        );
     });
 
     Ok(())
@@ -524,6 +805,15 @@ async fn tx_summary_commitment_is_signed_by_falcon_auth() -> anyhow::Result<()>
         ]),
     );
     let summary_commitment = summary.to_commitment();
+    assert!(summary.verify_against(&tx));
+
+    let other_summary = TransactionSummary::new(
+        summary.account_delta().clone(),
+        InputNotes::default(),
+        summary.output_notes().clone(),
+        summary.salt(),
+    );
+    assert!(!other_summary.verify_against(&tx));
 
     let account_interface = AccountInterface::from(&account);
     let pub_key = match account_interface.auth().first().unwrap() {
@@ -597,6 +887,287 @@ async fn execute_tx_view_script() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that a procedure whose MAST forest is neither part of the transaction script nor
+/// provided by the data store can be executed if it is served by the executor's MAST forest
+/// resolver.
+#[tokio::test]
+async fn mast_forest_resolver_serves_dyncall_target() -> anyhow::Result<()> {
+    let test_module_source = "
+        export.foo
+            push.3.4
+            add
+            swapw dropw
+        end
+    ";
+
+    let source = NamedSource::new("test::module_1", test_module_source);
+    let library = TransactionKernel::assembler().assemble_library([source]).unwrap();
+    let foo_root = library
+        .get_procedure_root_by_name("test::module_1::foo")
+        .context("library should export foo")?;
+
+    // The library is not linked against the script, so the forest of `foo` is unknown to the
+    // transaction host.
+    let source = format!(
+        "
+        use.std::sys
+
+        begin
+            push.{foo_root} mem_storew.4000 dropw
+            push.4000 dyncall
+            exec.sys::truncate_stack
+        end
+        "
+    );
+    let tx_script = ScriptBuilder::default().compile_tx_script(source)?;
+    let tx_context = TransactionContextBuilder::with_existing_mock_account()
+        .tx_script(tx_script.clone())
+        .build()?;
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let advice_inputs = tx_context.tx_args().advice_inputs().clone();
+
+    let err = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .execute_tx_view_script(account_id, block_ref, tx_script.clone(), advice_inputs.clone())
+        .await
+        .unwrap_err();
+    assert_matches!(
+        err,
+        TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::DynamicNodeNotFound { digest, .. }
+        ) if digest == foo_root
+    );
+
+    let forest = library.mast_forest().clone();
+    let resolver =
+        move |procedure_root: &Word| (*procedure_root == foo_root).then(|| forest.clone());
+    let stack_outputs = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_mast_forest_resolver(Arc::new(resolver))
+        .execute_tx_view_script(account_id, block_ref, tx_script, advice_inputs)
+        .await?;
+
+    assert_eq!(stack_outputs[0], Felt::new(7));
+
+    Ok(())
+}
+
+/// Tests that the number of cycles estimated by the executor matches the measurements of the
+/// executed transaction.
+#[tokio::test]
+async fn estimate_cycles_matches_executed_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let tx_context = builder.build()?.build_tx_context(account, &[note.id()], &[])?.build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let notes = tx_context.input_notes().clone();
+    let tx_args = tx_context.tx_args().clone();
+
+    let num_cycles = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .estimate_cycles(account_id, block_ref, notes, tx_args)
+        .await?;
+
+    let executed_transaction = tx_context.execute().await?;
+    assert_eq!(num_cycles as usize, executed_transaction.measurements().total_cycles());
+
+    Ok(())
+}
+
+/// Executes the transaction of the provided context with an executor using the provided profile.
+async fn execute_with_profile(
+    tx_context: &TransactionContext,
+    profile: ExecutionProfile,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let notes = tx_context.input_notes().clone();
+    let tx_args = tx_context.tx_args().clone();
+
+    let mut executor = TransactionExecutor::new(tx_context).with_profile(profile);
+    if let Some(authenticator) = tx_context.authenticator() {
+        executor = executor.with_authenticator(authenticator);
+    }
+
+    executor.execute_transaction(account_id, block_ref, notes, tx_args).await
+}
+
+/// Tests that all execution profiles can execute a simple wallet transaction consuming a P2ID
+/// note.
+#[tokio::test]
+async fn execution_profiles_execute_wallet_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let tx_context = builder.build()?.build_tx_context(account, &[note.id()], &[])?.build()?;
+
+    for profile in [
+        ExecutionProfile::wallet(),
+        ExecutionProfile::faucet(),
+        ExecutionProfile::batch_checker(),
+        ExecutionProfile::max(),
+    ] {
+        let executed_transaction = execute_with_profile(&tx_context, profile).await?;
+        assert!(executed_transaction.measurements().total_cycles() < profile.max_cycles() as usize);
+    }
+
+    Ok(())
+}
+
+/// Tests that all execution profiles can execute a faucet transaction minting assets into a note.
+#[tokio::test]
+async fn execution_profiles_execute_mint_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "TST", 1000, None)?;
+    let mock_chain = builder.build()?;
+
+    let recipient = Word::from([1, 2, 3, 4u32]);
+    let tag = NoteTag::from_account_id(ACCOUNT_ID_PRIVATE_SENDER.try_into()?);
+    let tx_script_code = format!(
+        "
+        begin
+            # pad the stack before call
+            push.0.0.0 padw
+
+            push.{recipient}
+            push.{note_execution_hint}
+            push.{note_type}
+            push.0
+            push.{tag}
+            push.100
+            # => [amount, tag, aux, note_type, execution_hint, RECIPIENT, pad(7)]
+
+            call.::miden::contracts::faucets::basic_fungible::distribute
+            # => [note_idx, pad(15)]
+
+            # truncate the stack
+            dropw dropw dropw dropw
+        end
+        ",
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        note_type = NoteType::Private as u8,
+        tag = u32::from(tag),
+    );
+    let tx_script = ScriptBuilder::default().compile_tx_script(tx_script_code)?;
+    let tx_context = mock_chain.build_tx_context(faucet, &[], &[])?.tx_script(tx_script).build()?;
+
+    for profile in [
+        ExecutionProfile::wallet(),
+        ExecutionProfile::faucet(),
+        ExecutionProfile::batch_checker(),
+        ExecutionProfile::max(),
+    ] {
+        let executed_transaction = execute_with_profile(&tx_context, profile).await?;
+        assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+        assert!(executed_transaction.measurements().total_cycles() < profile.max_cycles() as usize);
+    }
+
+    Ok(())
+}
+
+/// Tests that the batch checker and max execution profiles can execute a transaction consuming 10
+/// notes.
+#[tokio::test]
+async fn execution_profiles_execute_ten_note_consumption() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let assets_per_note = vec![vec![FungibleAsset::mock(10)]; 10];
+    let notes = builder.add_p2id_notes_bulk(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &assets_per_note,
+        NoteType::Public,
+    )?;
+    let note_ids: Vec<_> = notes.iter().map(Note::id).collect();
+    let tx_context = builder.build()?.build_tx_context(account, &note_ids, &[])?.build()?;
+
+    for profile in [ExecutionProfile::batch_checker(), ExecutionProfile::max()] {
+        let executed_transaction = execute_with_profile(&tx_context, profile).await?;
+        assert_eq!(executed_transaction.input_notes().num_notes(), 10);
+        assert!(executed_transaction.measurements().total_cycles() < profile.max_cycles() as usize);
+    }
+
+    Ok(())
+}
+
+/// Tests that executing a transaction which takes more cycles than the maximum of the executor's
+/// execution options fails.
+#[tokio::test]
+async fn execution_exceeding_max_cycles_fails() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_PRIVATE_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(100)],
+        NoteType::Public,
+    )?;
+    let tx_context = builder.build()?.build_tx_context(account, &[note.id()], &[])?.build()?;
+
+    let account_id = tx_context.account().id();
+    let block_ref = tx_context.tx_inputs().block_header().block_num();
+    let exec_options = ExecutionOptions::new(
+        Some(MIN_TX_EXECUTION_CYCLES),
+        MIN_TX_EXECUTION_CYCLES,
+        false,
+        false,
+    )?;
+
+    let result = TransactionExecutor::<'_, '_, _, UnreachableAuth>::new(&tx_context)
+        .with_options(exec_options)?
+        .execute_transaction(
+            account_id,
+            block_ref,
+            tx_context.input_notes().clone(),
+            tx_context.tx_args().clone(),
+        )
+        .await;
+
+    assert_matches!(
+        result,
+        Err(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::CycleLimitExceeded(max_cycles)
+        )) if max_cycles == MIN_TX_EXECUTION_CYCLES
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn tx_context_retains_output_notes_of_last_execution() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let asset = FungibleAsset::mock(10);
+    let account = builder.add_existing_wallet_with_assets(Auth::IncrNonce, [asset])?;
+    let output_note = create_public_p2any_note(account.id(), [asset]);
+    let spawn_note = builder.add_spawn_note([&output_note])?;
+    let mut tx_context = builder
+        .build()?
+        .build_tx_context(account.id(), &[spawn_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(output_note.clone())])
+        .build()?;
+
+    assert!(tx_context.output_notes().is_none());
+
+    let executed_transaction = tx_context.execute_mut().await?;
+    let output_notes = tx_context.output_notes().expect("output notes should be retained");
+    assert_eq!(output_notes, executed_transaction.output_notes());
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(output_notes.get_note(0).id(), output_note.id());
+
+    Ok(())
+}
+
 // TEST TRANSACTION SCRIPT
 // ================================================================================================
 
@@ -751,3 +1322,379 @@ async fn inputs_created_correctly() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests that [`TransactionInputs::validate_expiry`] rejects input notes whose execution hint does
+/// not allow consuming them at the reference block.
+///
+/// [`TransactionInputs::validate_expiry`]: miden_objects::transaction::TransactionInputs::validate_expiry
+#[test]
+fn validate_expiry_rejects_notes_not_executable_at_reference_block() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_until_block(10u32)?;
+
+    let executable_note = NoteBuilder::new(account.id(), RpoRandomCoin::new(Word::from([1u32; 4])))
+        .note_execution_hint(NoteExecutionHint::after_block(5.into())?)
+        .build()?;
+    let future_note = NoteBuilder::new(account.id(), RpoRandomCoin::new(Word::from([2u32; 4])))
+        .note_execution_hint(NoteExecutionHint::after_block(500.into())?)
+        .build()?;
+
+    let tx_inputs = mock_chain.get_transaction_inputs(&account, &[], &[executable_note])?;
+    tx_inputs.validate_expiry()?;
+
+    let future_note_id = future_note.id();
+    let tx_inputs = mock_chain.get_transaction_inputs(&account, &[], &[future_note])?;
+    assert_matches!(
+        tx_inputs.validate_expiry(),
+        Err(TransactionInputError::InputNoteNotExecutable { note_id, ref_block }) => {
+            assert_eq!(note_id, future_note_id);
+            assert_eq!(ref_block, 10u32.into());
+        }
+    );
+
+    Ok(())
+}
+
+/// Tests that the transaction header derived from an executed transaction is equal to the header
+/// derived from the corresponding proven transaction.
+#[tokio::test]
+async fn transaction_header_from_executed_transaction_matches_proven_transaction()
+-> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let asset = FungibleAsset::mock(100);
+    let account = builder.add_existing_wallet_with_assets(Auth::BasicAuth, [asset])?;
+    let output_note = create_public_p2any_note(account.id(), [asset]);
+    let spawn_note = builder.add_spawn_note([&output_note])?;
+    let unauthenticated_note = create_public_p2any_note(account.id(), [asset]);
+    let mock_chain = builder.build()?;
+
+    let executed_tx = mock_chain
+        .build_tx_context(account.id(), &[spawn_note.id()], &[unauthenticated_note])?
+        .extend_expected_output_notes(vec![OutputNote::Full(output_note)])
+        .build()?
+        .execute()
+        .await?;
+
+    // The transaction does not set an expiration delta, so it should have the maximum expiration
+    // window.
+    assert_eq!(executed_tx.expiration_block_num(), BlockNumber::from(u32::MAX));
+
+    let executed_tx_header = TransactionHeader::from_executed(&executed_tx);
+    let proven_tx = LocalTransactionProver::default().prove_dummy(executed_tx)?;
+
+    assert_eq!(executed_tx_header, TransactionHeader::from(&proven_tx));
+    assert_eq!(proven_tx.expiration_block_num(), BlockNumber::from(u32::MAX));
+
+    Ok(())
+}
+
+// EXECUTED TRANSACTION VALIDATION
+// ================================================================================================
+
+/// Tests that tampering with any part of a valid executed transaction is detected by
+/// [`validate_executed_transaction`] and makes the prover reject the transaction.
+#[tokio::test]
+async fn validate_executed_transaction_rejects_tampered_transactions() -> anyhow::Result<()> {
+    let native_asset_id = AccountId::try_from(ACCOUNT_ID_NATIVE_ASSET_FAUCET)?;
+    let mut builder =
+        MockChain::builder().native_asset_id(native_asset_id).verification_base_fee(50);
+    let native_asset = FungibleAsset::new(native_asset_id, 100_000)?;
+    let account =
+        builder.add_existing_wallet_with_assets(Auth::IncrNonce, [native_asset.into()])?;
+    let output_note = create_public_p2any_note(account.id(), []);
+    let spawn_note = builder.add_spawn_note([&output_note])?;
+    let mut mock_chain = builder.build()?;
+    mock_chain.prove_next_block()?;
+
+    let tx = mock_chain
+        .build_tx_context(account.id(), &[spawn_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(output_note)])
+        .build()?
+        .execute()
+        .await?;
+    validate_executed_transaction(&tx)?;
+    assert!(tx.fee().amount() > 0);
+
+    let tamper =
+        |modify: &dyn Fn(&mut TransactionInputs, &mut TransactionOutputs, &mut AccountDelta)| {
+            let (mut tx_inputs, mut tx_outputs, mut account_delta, measurements) =
+                tx.clone().into_parts();
+            modify(&mut tx_inputs, &mut tx_outputs, &mut account_delta);
+            ExecutedTransaction::new(tx_inputs, tx_outputs, account_delta, measurements)
+        };
+    let other_account_id = AccountId::try_from(ACCOUNT_ID_PRIVATE_SENDER)?;
+
+    // Replace the reference block header with the genesis block header. The inputs have to be
+    // assembled through deserialization since the constructor validates them.
+    let genesis_header = mock_chain.block_header(0);
+    let tampered_tx = tamper(&|tx_inputs, _, _| {
+        let mut bytes = tx_inputs.account().to_bytes();
+        bytes.extend(genesis_header.to_bytes());
+        bytes.extend(tx_inputs.blockchain().to_bytes());
+        bytes.extend(tx_inputs.input_notes().to_bytes());
+        bytes.extend(tx_inputs.tx_args().to_bytes());
+        bytes.extend(tx_inputs.advice_inputs().to_bytes());
+        bytes.extend(tx_inputs.foreign_account_code().to_vec().to_bytes());
+        *tx_inputs = TransactionInputs::read_from_bytes(&bytes).unwrap();
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::InvalidTransactionInputs(
+            TransactionInputError::InconsistentChainLength { .. }
+        )
+    );
+    // The prover should reject the transaction before proving it.
+    assert_matches!(
+        LocalTransactionProver::default().prove(tampered_tx).unwrap_err(),
+        TransactionProverError::InvalidTransactionInputs(_)
+    );
+
+    let tampered_tx = tamper(&|_, _, account_delta| {
+        *account_delta = AccountDelta::new(
+            other_account_id,
+            account_delta.storage().clone(),
+            account_delta.vault().clone(),
+            account_delta.nonce_delta(),
+        )
+        .unwrap();
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::InconsistentAccountDeltaId { expected, actual }
+            if expected == account.id() && actual == other_account_id
+    );
+
+    let tampered_tx = tamper(&|_, tx_outputs, _| {
+        tx_outputs.account_delta_commitment = Word::from([1, 2, 3, 4u32]);
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::InconsistentAccountDeltaCommitment { output_commitment, .. }
+            if output_commitment == Word::from([1, 2, 3, 4u32])
+    );
+
+    let non_native_asset_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2)?;
+    let tampered_tx = tamper(&|_, tx_outputs, _| {
+        tx_outputs.fee = FungibleAsset::new(non_native_asset_id, tx_outputs.fee.amount()).unwrap();
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::FeeAssetNotNativeAsset { expected, actual }
+            if expected == native_asset_id && actual == non_native_asset_id
+    );
+
+    let fee = tx.fee().amount();
+    let tampered_tx = tamper(&|tx_inputs, _, _| {
+        tx_inputs.set_tx_args(tx_inputs.tx_args().clone().with_max_fee(fee - 1));
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::FeeExceedsLimit { fee: actual_fee, limit }
+            if actual_fee == fee && limit == fee - 1
+    );
+
+    let tampered_tx = tamper(&|_, tx_outputs, _| {
+        let final_account = &tx_outputs.account;
+        tx_outputs.account = AccountHeader::new(
+            final_account.id(),
+            final_account.nonce() + ONE,
+            final_account.vault_root(),
+            final_account.storage_commitment(),
+            final_account.code_commitment(),
+        );
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::InconsistentAccountNonceDelta { expected, actual }
+            if expected == actual + ONE
+    );
+
+    let tampered_tx = tamper(&|_, tx_outputs, _| {
+        tx_outputs.output_notes =
+            OutputNotes::new(vec![OutputNote::Full(spawn_note.clone())]).unwrap();
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::OutputNoteConsumedAsInputNote(note_id)
+            if note_id == spawn_note.id()
+    );
+    assert_matches!(
+        LocalTransactionProver::default().prove(tampered_tx).unwrap_err(),
+        TransactionProverError::OutputNoteConsumedAsInputNote(_)
+    );
+
+    let foreign_note = create_public_p2any_note(other_account_id, []);
+    let tampered_tx = tamper(&|_, tx_outputs, _| {
+        tx_outputs.output_notes =
+            OutputNotes::new(vec![OutputNote::Full(foreign_note.clone())]).unwrap();
+    });
+    assert_matches!(
+        validate_executed_transaction(&tampered_tx).unwrap_err(),
+        TransactionProverError::OutputNoteSenderMismatch { note_id, sender, account_id }
+            if note_id == foreign_note.id()
+                && sender == other_account_id
+                && account_id == account.id()
+    );
+
+    for expiration_block_num in [tx.block_header().block_num(), BlockNumber::from(u32::MAX - 1)] {
+        let tampered_tx = tamper(&|_, tx_outputs, _| {
+            tx_outputs.expiration_block_num = expiration_block_num;
+        });
+        assert_matches!(
+            validate_executed_transaction(&tampered_tx).unwrap_err(),
+            TransactionProverError::InvalidExpirationBlockNum { expiration_block_num: actual, .. }
+                if actual == expiration_block_num
+        );
+    }
+
+    Ok(())
+}
+
+/// Tests that the [`DelegatedProver`] proves transactions through its transport, using an
+/// in-process transport which routes to a [`LocalTransactionProver`].
+#[tokio::test]
+async fn delegated_prover_proves_through_transport() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let executed_transaction = builder
+        .build()?
+        .build_tx_context(account.id(), &[], &[])?
+        .build()?
+        .execute()
+        .await?;
+    let executed_transaction_id = executed_transaction.id();
+
+    let prover = DelegatedProver::new(|request: Vec<u8>| async move {
+        let executed_transaction = ExecutedTransaction::read_from_bytes(&request)
+            .map_err(TransactionProverError::ProvenTransactionDeserializationFailed)?;
+        let proven_transaction = LocalTransactionProver::default().prove(executed_transaction)?;
+        Ok::<_, TransactionProverError>(proven_transaction.to_bytes())
+    });
+
+    let proven_transaction = TransactionProver::prove(&prover, executed_transaction).await?;
+    assert_eq!(proven_transaction.id(), executed_transaction_id);
+
+    Ok(())
+}
+
+/// Tests that the [`DelegatedProver`] rejects a proven transaction returned by its transport if
+/// it does not belong to the submitted executed transaction.
+#[tokio::test]
+async fn delegated_prover_rejects_mismatched_proven_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let other_account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let mock_chain = builder.build()?;
+
+    let executed_transaction =
+        mock_chain.build_tx_context(account.id(), &[], &[])?.build()?.execute().await?;
+    let other_transaction = mock_chain
+        .build_tx_context(other_account.id(), &[], &[])?
+        .build()?
+        .execute()
+        .await?;
+    let expected_id = executed_transaction.id();
+    let actual_id = other_transaction.id();
+
+    // The transport ignores the request and responds with a proof of another transaction.
+    let response = LocalTransactionProver::default().prove_dummy(other_transaction)?.to_bytes();
+    let prover = DelegatedProver::new(|_request: Vec<u8>| {
+        let response = response.clone();
+        async move { Ok::<_, TransactionProverError>(response) }
+    });
+
+    let err = TransactionProver::prove(&prover, executed_transaction).await.unwrap_err();
+    assert_matches!(
+        err,
+        TransactionProverError::ProvenTransactionIdMismatch { expected, actual }
+            if expected == expected_id && actual == actual_id
+    );
+
+    Ok(())
+}
+
+/// Tests that the [`DelegatedProver`] rejects a proven transaction returned by its transport if
+/// its proof cannot be verified, even if it belongs to the submitted executed transaction.
+#[tokio::test]
+async fn delegated_prover_rejects_unverifiable_proven_transaction() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+    let executed_transaction = builder
+        .build()?
+        .build_tx_context(account.id(), &[], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    // The transport responds with a dummy proof of the submitted transaction.
+    let response = LocalTransactionProver::default()
+        .prove_dummy(executed_transaction.clone())?
+        .to_bytes();
+    let prover = DelegatedProver::new(|_request: Vec<u8>| {
+        let response = response.clone();
+        async move { Ok::<_, TransactionProverError>(response) }
+    });
+
+    let err = TransactionProver::prove(&prover, executed_transaction).await.unwrap_err();
+    assert_matches!(err, TransactionProverError::ProvenTransactionVerificationFailed(_));
+
+    Ok(())
+}
+
+/// Tests that custom events emitted by an account component via `miden::tx::emit_event` are
+/// recorded in the executed transaction in the order of emission, and that they do not prevent
+/// proving the transaction.
+#[tokio::test]
+async fn executed_transaction_records_custom_events() -> anyhow::Result<()> {
+    let component_code = r#"
+        use.miden::tx
+
+        export.fill_orders
+            push.[5,6,7,8] push.[1,2,3,4] push.100
+            exec.tx::emit_event
+
+            push.[13,14,15,16] push.[9,10,11,12] push.200
+            exec.tx::emit_event
+        end
+    "#;
+    let component =
+        AccountComponent::compile(component_code, TransactionKernel::assembler(), vec![])?
+            .with_supports_all_types();
+
+    let account = AccountBuilder::new([7; 32])
+        .with_auth_component(IncrNonceAuthComponent)
+        .with_component(component.clone())
+        .build_existing()?;
+
+    let tx_script = ScriptBuilder::default().compile_tx_script(format!(
+        "begin call.{} end",
+        component.library().get_procedure_root_by_name("$anon::fill_orders").unwrap()
+    ))?;
+
+    let executed_tx = crate::TransactionContextBuilder::new(account)
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await?;
+
+    let expected_events = [
+        CustomEvent::new(
+            CustomEventId::new(100),
+            [Word::from([1, 2, 3, 4u32]), Word::from([5, 6, 7, 8u32])],
+        ),
+        CustomEvent::new(
+            CustomEventId::new(200),
+            [Word::from([9, 10, 11, 12u32]), Word::from([13, 14, 15, 16u32])],
+        ),
+    ];
+    assert_eq!(executed_tx.custom_events(), expected_events);
+
+    let deserialized_tx = ExecutedTransaction::read_from_bytes(&executed_tx.to_bytes())?;
+    assert_eq!(deserialized_tx.custom_events(), expected_events);
+
+    LocalTransactionProver::default().prove(executed_tx)?;
+
+    Ok(())
+}
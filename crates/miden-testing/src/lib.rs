@@ -6,18 +6,23 @@ extern crate alloc;
 #[cfg(any(feature = "std", test))]
 extern crate std;
 
+mod assertions;
+pub use assertions::AccountStateAssertions;
+
 mod mock_chain;
 pub use mock_chain::{
     AccountState,
     Auth,
+    BlockMutations,
     MockChain,
     MockChainBuilder,
     MockChainNote,
+    NetworkTransactionReport,
     TxContextInput,
 };
 
 mod tx_context;
-pub use tx_context::{TransactionContext, TransactionContextBuilder};
+pub use tx_context::{MockAuthenticator, TransactionContext, TransactionContextBuilder};
 
 pub mod executor;
 
@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+
+use miden_objects::block::account_tree::AccountMutationSet;
+use miden_objects::block::{BlockHeader, NullifierMutationSet};
+use miden_processor::DeserializationError;
+use miden_tx::utils::{ByteReader, Deserializable, Serializable};
+use winterfell::ByteWriter;
+
+use super::note::MockChainNote;
+
+// BLOCK MUTATIONS
+// ================================================================================================
+
+/// The changes the [`MockChain`](crate::MockChain) applied to its state when it proved a block.
+///
+/// This allows consumers that maintain their own copy of the chain state to mirror the effects of
+/// a block: applying the account and nullifier tree mutations to the trees of the previous block
+/// results in the account and nullifier roots committed to by the new block header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockMutations {
+    header: BlockHeader,
+    account_tree_mutations: AccountMutationSet,
+    nullifier_tree_mutations: NullifierMutationSet,
+    created_notes: Vec<MockChainNote>,
+}
+
+impl BlockMutations {
+    /// Creates a new [`BlockMutations`] from the provided parts.
+    pub(super) fn new(
+        header: BlockHeader,
+        account_tree_mutations: AccountMutationSet,
+        nullifier_tree_mutations: NullifierMutationSet,
+        created_notes: Vec<MockChainNote>,
+    ) -> Self {
+        Self {
+            header,
+            account_tree_mutations,
+            nullifier_tree_mutations,
+            created_notes,
+        }
+    }
+
+    /// Returns the header of the proven block.
+    pub fn header(&self) -> &BlockHeader {
+        &self.header
+    }
+
+    /// Returns the mutations applied to the account tree.
+    pub fn account_tree_mutations(&self) -> &AccountMutationSet {
+        &self.account_tree_mutations
+    }
+
+    /// Returns the mutations applied to the nullifier tree.
+    pub fn nullifier_tree_mutations(&self) -> &NullifierMutationSet {
+        &self.nullifier_tree_mutations
+    }
+
+    /// Returns the notes created in the block together with their inclusion proofs, in the order
+    /// of their index in the block.
+    pub fn created_notes(&self) -> &[MockChainNote] {
+        &self.created_notes
+    }
+
+    /// Consumes self and returns the block header, the account tree mutations, the nullifier tree
+    /// mutations and the created notes.
+    pub fn into_parts(
+        self,
+    ) -> (BlockHeader, AccountMutationSet, NullifierMutationSet, Vec<MockChainNote>) {
+        (
+            self.header,
+            self.account_tree_mutations,
+            self.nullifier_tree_mutations,
+            self.created_notes,
+        )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BlockMutations {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        self.account_tree_mutations.write_into(target);
+        self.nullifier_tree_mutations.write_into(target);
+        self.created_notes.write_into(target);
+    }
+}
+
+impl Deserializable for BlockMutations {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = BlockHeader::read_from(source)?;
+        let account_tree_mutations = AccountMutationSet::read_from(source)?;
+        let nullifier_tree_mutations = NullifierMutationSet::read_from(source)?;
+        let created_notes = Vec::<MockChainNote>::read_from(source)?;
+
+        Ok(Self::new(
+            header,
+            account_tree_mutations,
+            nullifier_tree_mutations,
+            created_notes,
+        ))
+    }
+}
@@ -3,8 +3,15 @@ use alloc::vec::Vec;
 
 use anyhow::Context;
 use miden_block_prover::{LocalBlockProver, ProvenBlockError};
+use miden_lib::note::WellKnownNote;
 use miden_objects::account::delta::AccountUpdateDetails;
-use miden_objects::account::{Account, AccountId, AuthSecretKey, PartialAccount};
+use miden_objects::account::{
+    Account,
+    AccountId,
+    AccountStorageMode,
+    AuthSecretKey,
+    PartialAccount,
+};
 use miden_objects::batch::{ProposedBatch, ProvenBatch};
 use miden_objects::block::account_tree::AccountTree;
 use miden_objects::block::{
@@ -18,7 +25,15 @@ use miden_objects::block::{
     ProposedBlock,
     ProvenBlock,
 };
-use miden_objects::note::{Note, NoteHeader, NoteId, NoteInclusionProof, Nullifier};
+use miden_objects::note::{
+    Note,
+    NoteHeader,
+    NoteId,
+    NoteInclusionProof,
+    NoteTag,
+    NoteTagPattern,
+    Nullifier,
+};
 use miden_objects::transaction::{
     ExecutedTransaction,
     InputNote,
@@ -26,6 +41,7 @@ use miden_objects::transaction::{
     OutputNote,
     PartialBlockchain,
     ProvenTransaction,
+    TransactionId,
     TransactionInputs,
 };
 use miden_processor::{DeserializationError, Word};
@@ -37,8 +53,10 @@ use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use winterfell::ByteWriter;
 
+use super::block_mutations::BlockMutations;
+use super::network_report::NetworkTransactionReport;
 use super::note::MockChainNote;
-use crate::{MockChainBuilder, TransactionContextBuilder};
+use crate::{MockAuthenticator, MockChainBuilder, TransactionContextBuilder};
 
 // MOCK CHAIN
 // ================================================================================================
@@ -401,7 +419,8 @@ impl MockChain {
 
     /// Returns the map of note IDs to committed notes.
     ///
-    /// These notes are committed for authenticated consumption.
+    /// These notes are committed for authenticated consumption. Notes are not removed from this map
+    /// once consumed, use the [`Self::nullifier_tree`] to check whether a note has been consumed.
     pub fn committed_notes(&self) -> &BTreeMap<NoteId, MockChainNote> {
         &self.committed_notes
     }
@@ -413,6 +432,43 @@ impl MockChain {
         note.clone().try_into().ok()
     }
 
+    /// Returns all committed and unconsumed public notes that target the given account, as
+    /// [`InputNote`]s ready for authenticated consumption.
+    ///
+    /// Only [`WellKnownNote::P2ID`] and [`WellKnownNote::P2IDE`] notes are considered, whose target
+    /// account is read from the note inputs.
+    pub fn consumable_notes_for(&self, account_id: AccountId) -> Vec<InputNote> {
+        self.committed_notes
+            .values()
+            .filter_map(|note| match note {
+                MockChainNote::Public(note, proof) => Some((note, proof)),
+                MockChainNote::Private(..) => None,
+            })
+            .filter(|(note, _)| {
+                matches!(
+                    WellKnownNote::from_note(note),
+                    Some(WellKnownNote::P2ID | WellKnownNote::P2IDE)
+                )
+            })
+            .filter(|(note, _)| {
+                let inputs = note.inputs().values();
+                inputs.len() >= 2
+                    && AccountId::try_from([inputs[1], inputs[0]])
+                        .is_ok_and(|target_id| target_id == account_id)
+            })
+            .filter(|(note, _)| self.nullifier_tree.get_block_num(&note.nullifier()).is_none())
+            .map(|(note, proof)| InputNote::authenticated(note.clone(), proof.clone()))
+            .collect()
+    }
+
+    /// Returns all committed notes whose tags match the provided pattern.
+    pub fn notes_matching(&self, pattern: &NoteTagPattern) -> Vec<&MockChainNote> {
+        self.committed_notes
+            .values()
+            .filter(|note| pattern.matches(note.metadata().tag()))
+            .collect()
+    }
+
     /// Returns a reference to the account identified by the given account ID.
     ///
     /// The account is retrieved with the latest state known to the [`MockChain`].
@@ -427,6 +483,80 @@ impl MockChain {
         &self.account_tree
     }
 
+    /// Returns the history of the account identified by the given account ID as pairs of the
+    /// number of each block which updated the account and the account's state commitment after
+    /// that block, in ascending order of block numbers.
+    ///
+    /// The history is derived from the account updates of the proven blocks, so it does not
+    /// require storing additional account states. The returned vector is empty if the account
+    /// was never updated in any block.
+    pub fn account_history(&self, account_id: AccountId) -> Vec<(BlockNumber, Word)> {
+        self.blocks
+            .iter()
+            .filter_map(|block| {
+                block
+                    .updated_accounts()
+                    .iter()
+                    .find(|update| update.account_id() == account_id)
+                    .map(|update| (block.header().block_num(), update.final_state_commitment()))
+            })
+            .collect()
+    }
+
+    /// Returns the state commitment the account identified by the given account ID had after
+    /// the block with the given number was applied, or `None` if the account did not exist at
+    /// that block or the block does not exist yet.
+    pub fn account_commitment_at(
+        &self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Option<Word> {
+        if block_num.as_usize() >= self.blocks.len() {
+            return None;
+        }
+
+        self.account_history(account_id)
+            .into_iter()
+            .take_while(|(update_block_num, _)| *update_block_num <= block_num)
+            .last()
+            .map(|(_, commitment)| commitment)
+    }
+
+    /// Returns the state the public account identified by the given account ID had after the
+    /// block with the given number was applied.
+    ///
+    /// The state is reconstructed by applying the account deltas of the proven blocks up to and
+    /// including the given block, starting from the full state delta which created the account.
+    ///
+    /// Returns `None` if the account did not exist at that block, the block does not exist yet
+    /// or the account is private, i.e. the blocks do not contain its state.
+    pub fn account_state_at(
+        &self,
+        account_id: AccountId,
+        block_num: BlockNumber,
+    ) -> Option<Account> {
+        let mut account: Option<Account> = None;
+        for block in self.blocks.get(..=block_num.as_usize())? {
+            let Some(update) =
+                block.updated_accounts().iter().find(|update| update.account_id() == account_id)
+            else {
+                continue;
+            };
+
+            let AccountUpdateDetails::Delta(account_delta) = update.details() else {
+                return None;
+            };
+
+            if account_delta.is_full_state() {
+                account = Some(Account::try_from(account_delta.as_ref()).ok()?);
+            } else {
+                account.as_mut()?.apply_delta(account_delta).ok()?;
+            }
+        }
+
+        account
+    }
+
     // BATCH APIS
     // ----------------------------------------------------------------------------------------
 
@@ -552,6 +682,7 @@ impl MockChain {
         let authenticator = self.account_authenticators.get(&input.id());
         let authenticator =
             authenticator.and_then(|authenticator| authenticator.authenticator().cloned());
+        let authenticator = authenticator.map(MockAuthenticator::from);
 
         anyhow::ensure!(
             reference_block.as_usize() < self.blocks.len(),
@@ -655,7 +786,7 @@ impl MockChain {
         }
 
         for note in unauthenticated_notes {
-            input_notes.push(InputNote::Unauthenticated { note: note.clone() })
+            input_notes.push(InputNote::unauthenticated(note.clone()))
         }
 
         let block_headers = block_headers_map.values();
@@ -770,7 +901,17 @@ impl MockChain {
     ///
     /// This will commit all the currently pending transactions into the chain state.
     pub fn prove_next_block(&mut self) -> anyhow::Result<ProvenBlock> {
-        self.prove_and_apply_block(None)
+        self.prove_and_apply_block(None).map(|(proven_block, _)| proven_block)
+    }
+
+    /// Proves the next block in the mock chain and returns the [`BlockMutations`] applied to the
+    /// chain state.
+    ///
+    /// This will commit all the currently pending transactions into the chain state, like
+    /// [`MockChain::prove_next_block`]. The proven block itself can be retrieved via
+    /// [`MockChain::proven_blocks`].
+    pub fn prove_next_block_detailed(&mut self) -> anyhow::Result<BlockMutations> {
+        self.prove_and_apply_block(None).map(|(_, block_mutations)| block_mutations)
     }
 
     /// Proves the next block in the mock chain at the given timestamp.
@@ -778,6 +919,41 @@ impl MockChain {
     /// This will commit all the currently pending transactions into the chain state.
     pub fn prove_next_block_at(&mut self, timestamp: u32) -> anyhow::Result<ProvenBlock> {
         self.prove_and_apply_block(Some(timestamp))
+            .map(|(proven_block, _)| proven_block)
+    }
+
+    /// Proves the next block in the mock chain containing only the pending transactions with the
+    /// given IDs.
+    ///
+    /// All other pending transactions remain pending in their original order, so they can be
+    /// included in later blocks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - any of the given transaction IDs does not identify a pending transaction.
+    /// - proving or applying the block fails.
+    pub fn prove_block_with_transactions(
+        &mut self,
+        tx_ids: &[TransactionId],
+    ) -> anyhow::Result<ProvenBlock> {
+        if let Some(tx_id) = tx_ids
+            .iter()
+            .find(|tx_id| !self.pending_transactions.iter().any(|tx| tx.id() == **tx_id))
+        {
+            anyhow::bail!("transaction {tx_id} is not pending");
+        }
+
+        let (included_transactions, excluded_transactions) =
+            core::mem::take(&mut self.pending_transactions)
+                .into_iter()
+                .partition(|tx| tx_ids.contains(&tx.id()));
+        self.pending_transactions = included_transactions;
+
+        let result = self.prove_and_apply_block(None);
+        self.pending_transactions = excluded_transactions;
+
+        result.map(|(proven_block, _)| proven_block)
     }
 
     /// Proves new blocks until the block with the given target block number has been created.
@@ -837,6 +1013,96 @@ impl MockChain {
         self.pending_transactions.push(transaction);
     }
 
+    /// Simulates the network executing the notes targeting network accounts, and adds the
+    /// resulting transactions to the list of pending transactions.
+    ///
+    /// The notes picked up for execution are the committed public notes that are not yet consumed,
+    /// neither on-chain nor by a pending transaction, and whose tag is the
+    /// [`NoteTag::NetworkAccount`](miden_objects::note::NoteTag::NetworkAccount) tag of an account
+    /// with [`AccountStorageMode::Network`](miden_objects::account::AccountStorageMode::Network)
+    /// tracked by the chain.
+    ///
+    /// Each note is consumed by a separate transaction which builds on top of the state of the
+    /// account resulting from the previous successful transaction. Like the network, the
+    /// transactions are executed without an authenticator, so they only succeed if the auth
+    /// component of the account does not require signatures, e.g. [`Auth::IncrNonce`]. A
+    /// failing note does not prevent the other notes from being executed.
+    ///
+    /// A block has to be created to apply the transaction effects to the chain state, e.g. using
+    /// [`MockChain::prove_next_block`].
+    ///
+    /// [`Auth::IncrNonce`]: crate::Auth::IncrNonce
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an executed transaction cannot be added to the pending transactions.
+    /// Failures to execute a note are recorded in the returned [`NetworkTransactionReport`]
+    /// instead.
+    pub async fn execute_network_transactions(
+        &mut self,
+    ) -> anyhow::Result<NetworkTransactionReport> {
+        let pending_nullifiers: BTreeSet<Nullifier> = self
+            .pending_transactions
+            .iter()
+            .flat_map(ProvenTransaction::nullifiers)
+            .collect();
+
+        let network_accounts: Vec<Account> = self
+            .committed_accounts
+            .values()
+            .filter(|account| account.id().storage_mode() == AccountStorageMode::Network)
+            .cloned()
+            .collect();
+
+        let mut report = NetworkTransactionReport::default();
+        for mut account in network_accounts {
+            let account_tag = NoteTag::from_account_id(account.id());
+            let note_ids: Vec<NoteId> = self
+                .committed_notes
+                .values()
+                .filter_map(|note| match note {
+                    MockChainNote::Public(note, _) => Some(note),
+                    MockChainNote::Private(..) => None,
+                })
+                .filter(|note| note.metadata().tag() == account_tag)
+                .filter(|note| {
+                    let nullifier = note.nullifier();
+                    self.nullifier_tree.get_block_num(&nullifier).is_none()
+                        && !pending_nullifiers.contains(&nullifier)
+                })
+                .map(Note::id)
+                .collect();
+
+            for note_id in note_ids {
+                let execution_result = async {
+                    let tx_context = self
+                        .build_tx_context(
+                            TxContextInput::Account(account.clone()),
+                            &[note_id],
+                            &[],
+                        )?
+                        .authenticator(None)
+                        .build()?;
+                    anyhow::Ok(tx_context.execute().await?)
+                }
+                .await;
+
+                match execution_result {
+                    Ok(executed_transaction) => {
+                        account
+                            .apply_delta(executed_transaction.account_delta())
+                            .context("failed to apply delta of network transaction")?;
+                        self.add_pending_executed_transaction(&executed_transaction)?;
+                        report.record_executed(note_id, executed_transaction.id());
+                    },
+                    Err(err) => report.record_failed(note_id, err),
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     // PRIVATE HELPERS
     // ----------------------------------------------------------------------------------------
 
@@ -845,30 +1111,41 @@ impl MockChain {
     /// - Insert account and nullifiers into the respective trees.
     /// - Updated accounts from the block are updated in the committed accounts.
     /// - Created notes are inserted into the committed notes.
-    /// - Consumed notes are removed from the committed notes.
     /// - The block is appended to the [`BlockChain`] and the list of proven blocks.
-    fn apply_block(&mut self, proven_block: ProvenBlock) -> anyhow::Result<()> {
-        for account_update in proven_block.updated_accounts() {
-            self.account_tree
-                .insert(account_update.account_id(), account_update.final_state_commitment())
-                .context("failed to insert account update into account tree")?;
-        }
-
-        for nullifier in proven_block.created_nullifiers() {
-            self.nullifier_tree
-                .mark_spent(*nullifier, proven_block.header().block_num())
-                .context("failed to mark block nullifier as spent")?;
-
-            // TODO: Remove from self.committed_notes. This is not critical to have for now. It is
-            // not straightforward, because committed_notes are indexed by note IDs rather than
-            // nullifiers, so we'll have to create a second index to do this.
-        }
+    ///
+    /// Returns the [`BlockMutations`] that were applied to the chain state.
+    fn apply_block(&mut self, proven_block: ProvenBlock) -> anyhow::Result<BlockMutations> {
+        let account_tree_mutations = self
+            .account_tree
+            .compute_mutations(proven_block.updated_accounts().iter().map(|account_update| {
+                (account_update.account_id(), account_update.final_state_commitment())
+            }))
+            .context("failed to compute account tree mutations")?;
+        self.account_tree
+            .apply_mutations(account_tree_mutations.clone())
+            .context("failed to apply account tree mutations")?;
+
+        // consumed notes are kept in the committed notes, since private notes cannot be matched to
+        // their nullifiers; the nullifier tree is the source of truth for note consumption
+        let block_num = proven_block.header().block_num();
+        let nullifier_tree_mutations = self
+            .nullifier_tree
+            .compute_mutations(
+                proven_block
+                    .created_nullifiers()
+                    .iter()
+                    .map(|nullifier| (*nullifier, block_num)),
+            )
+            .context("failed to compute nullifier tree mutations")?;
+        self.nullifier_tree
+            .apply_mutations(nullifier_tree_mutations.clone())
+            .context("failed to apply nullifier tree mutations")?;
 
         for account_update in proven_block.updated_accounts() {
             match account_update.details() {
                 AccountUpdateDetails::Delta(account_delta) => {
                     if account_delta.is_full_state() {
-                        let account = Account::try_from(account_delta)
+                        let account = Account::try_from(account_delta.as_ref())
                             .context("failed to convert full state delta into full account")?;
                         self.committed_accounts.insert(account.id(), account.clone());
                     } else {
@@ -890,6 +1167,7 @@ impl MockChain {
         }
 
         let notes_tree = proven_block.build_output_note_tree();
+        let mut created_notes = Vec::new();
         for (block_note_index, created_note) in proven_block.output_notes() {
             let note_path = notes_tree.open(block_note_index);
             let note_inclusion_proof = NoteInclusionProof::new(
@@ -899,19 +1177,17 @@ impl MockChain {
             )
             .context("failed to create inclusion proof for output note")?;
 
-            if let OutputNote::Full(note) = created_note {
-                self.committed_notes
-                    .insert(note.id(), MockChainNote::Public(note.clone(), note_inclusion_proof));
+            let mock_chain_note = if let OutputNote::Full(note) = created_note {
+                MockChainNote::Public(note.clone(), note_inclusion_proof)
             } else {
-                self.committed_notes.insert(
+                MockChainNote::Private(
                     created_note.id(),
-                    MockChainNote::Private(
-                        created_note.id(),
-                        *created_note.metadata(),
-                        note_inclusion_proof,
-                    ),
-                );
-            }
+                    *created_note.metadata(),
+                    note_inclusion_proof,
+                )
+            };
+            self.committed_notes.insert(mock_chain_note.id(), mock_chain_note.clone());
+            created_notes.push(mock_chain_note);
         }
 
         debug_assert_eq!(
@@ -925,10 +1201,17 @@ impl MockChain {
             "current mock chain length and new block's number should match"
         );
 
+        let block_mutations = BlockMutations::new(
+            proven_block.header().clone(),
+            account_tree_mutations,
+            nullifier_tree_mutations,
+            created_notes,
+        );
+
         self.chain.push(proven_block.header().commitment());
         self.blocks.push(proven_block);
 
-        Ok(())
+        Ok(block_mutations)
     }
 
     fn pending_transactions_to_batches(&mut self) -> anyhow::Result<Vec<ProvenBatch>> {
@@ -957,7 +1240,10 @@ impl MockChain {
     /// 2. Insert all the account updates, nullifiers and notes from the block into the chain state.
     ///
     /// If a `timestamp` is provided, it will be set on the block.
-    fn prove_and_apply_block(&mut self, timestamp: Option<u32>) -> anyhow::Result<ProvenBlock> {
+    fn prove_and_apply_block(
+        &mut self,
+        timestamp: Option<u32>,
+    ) -> anyhow::Result<(ProvenBlock, BlockMutations)> {
         // Create batches from pending transactions.
         // ----------------------------------------------------------------------------------------
 
@@ -977,9 +1263,10 @@ impl MockChain {
         // Apply block.
         // ----------------------------------------------------------------------------------------
 
-        self.apply_block(proven_block.clone()).context("failed to apply block")?;
+        let block_mutations =
+            self.apply_block(proven_block.clone()).context("failed to apply block")?;
 
-        Ok(proven_block)
+        Ok((proven_block, block_mutations))
     }
 }
 
@@ -1134,14 +1421,17 @@ impl From<Account> for TxContextInput {
 #[cfg(test)]
 mod tests {
     use miden_lib::account::wallets::BasicWallet;
-    use miden_objects::account::{AccountBuilder, AccountStorageMode};
-    use miden_objects::asset::{Asset, FungibleAsset};
-    use miden_objects::note::NoteType;
+    use miden_lib::testing::note::NoteBuilder;
+    use miden_lib::transaction::TransactionKernel;
+    use miden_objects::account::{AccountBuilder, AccountComponent, StorageSlot};
+    use miden_objects::asset::{Asset, FungibleAsset, NonFungibleAsset};
+    use miden_objects::note::{NoteExecutionMode, NoteType};
     use miden_objects::testing::account_id::{
         ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET,
         ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
         ACCOUNT_ID_SENDER,
     };
+    use rand::rngs::SmallRng;
 
     use super::*;
     use crate::Auth;
@@ -1198,6 +1488,397 @@ mod tests {
             mock_chain.account_tree.open(account_id).state_commitment()
         );
 
+        // The blocks only contain the commitment of private accounts but not their state.
+        let block_num = mock_chain.latest_block_header().block_num();
+        assert_eq!(
+            mock_chain.account_commitment_at(account_id, block_num),
+            Some(tx.final_account().commitment())
+        );
+        assert!(mock_chain.account_state_at(account_id, block_num).is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn account_history_across_blocks() -> anyhow::Result<()> {
+        let mut builder = MockChain::builder();
+        let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+        let assets_per_note: Vec<Vec<Asset>> =
+            (1..=3u64).map(|amount| vec![FungibleAsset::mock(amount)]).collect();
+        let notes = builder.add_p2id_notes_bulk(
+            ACCOUNT_ID_SENDER.try_into()?,
+            account.id(),
+            &assets_per_note,
+            NoteType::Public,
+        )?;
+        let mut mock_chain = builder.build()?;
+
+        let genesis_commitment = account.commitment();
+        assert_eq!(
+            mock_chain.account_history(account.id()),
+            vec![(BlockNumber::GENESIS, genesis_commitment)]
+        );
+
+        // Consume one note per block and apply the deltas to a local copy of the account.
+        let mut expected_account = account.clone();
+        let mut expected_history = vec![(BlockNumber::GENESIS, genesis_commitment)];
+        for note in &notes {
+            let tx = mock_chain
+                .build_tx_context(account.id(), &[note.id()], &[])?
+                .build()?
+                .execute()
+                .await?;
+            mock_chain.add_pending_executed_transaction(&tx)?;
+            let block = mock_chain.prove_next_block()?;
+
+            expected_account.apply_delta(tx.account_delta())?;
+            expected_history.push((block.header().block_num(), expected_account.commitment()));
+        }
+
+        assert_eq!(mock_chain.account_history(account.id()), expected_history);
+        for (block_num, commitment) in &expected_history {
+            assert_eq!(
+                mock_chain.account_commitment_at(account.id(), *block_num),
+                Some(*commitment)
+            );
+            assert_eq!(
+                mock_chain
+                    .account_state_at(account.id(), *block_num)
+                    .map(|account| account.commitment()),
+                Some(*commitment)
+            );
+        }
+        assert_eq!(
+            mock_chain.account_state_at(account.id(), 3u32.into()).as_ref(),
+            Some(&expected_account)
+        );
+
+        // Blocks without an update of the account keep the previous commitment.
+        mock_chain.prove_next_block()?;
+        assert_eq!(
+            mock_chain.account_commitment_at(account.id(), 4u32.into()),
+            Some(expected_account.commitment())
+        );
+        assert_eq!(mock_chain.account_commitment_at(account.id(), 5u32.into()), None);
+        assert_eq!(mock_chain.account_state_at(account.id(), 5u32.into()), None);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prove_block_with_transactions() -> anyhow::Result<()> {
+        let mut builder = MockChain::builder();
+        let account1 = builder.add_existing_wallet(Auth::IncrNonce)?;
+        let account2 = builder.add_existing_wallet(Auth::IncrNonce)?;
+        let mut mock_chain = builder.build()?;
+
+        let tx1 = mock_chain.build_tx_context(account1.id(), &[], &[])?.build()?.execute().await?;
+        let tx2 = mock_chain.build_tx_context(account2.id(), &[], &[])?.build()?.execute().await?;
+        mock_chain.add_pending_executed_transaction(&tx1)?;
+        mock_chain.add_pending_executed_transaction(&tx2)?;
+
+        // Only the second transaction is included in the block.
+        let block = mock_chain.prove_block_with_transactions(&[tx2.id()])?;
+        assert_eq!(block.transactions().as_slice().len(), 1);
+        assert_eq!(block.transactions().as_slice()[0].id(), tx2.id());
+        assert_eq!(
+            mock_chain.account_tree().open(account2.id()).state_commitment(),
+            tx2.final_account().commitment()
+        );
+        assert_eq!(
+            mock_chain.account_tree().open(account1.id()).state_commitment(),
+            account1.commitment()
+        );
+
+        // A transaction that is not pending cannot be included.
+        assert!(mock_chain.prove_block_with_transactions(&[tx2.id()]).is_err());
+
+        // The excluded transaction remains pending and is included in the next block.
+        let block = mock_chain.prove_next_block()?;
+        assert_eq!(block.transactions().as_slice().len(), 1);
+        assert_eq!(block.transactions().as_slice()[0].id(), tx1.id());
+        assert_eq!(
+            mock_chain.account_tree().open(account1.id()).state_commitment(),
+            tx1.final_account().commitment()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn prove_next_block_detailed() -> anyhow::Result<()> {
+        let offered_asset = FungibleAsset::mock(100);
+        let requested_asset = NonFungibleAsset::mock(&[1, 2, 3, 4]);
+
+        let mut builder = MockChain::builder();
+        let sender = builder.add_existing_wallet_with_assets(Auth::IncrNonce, [offered_asset])?;
+        let target = builder.add_existing_wallet_with_assets(Auth::IncrNonce, [requested_asset])?;
+        let (swap_note, payback_note) = builder.add_swap_note(
+            sender.id(),
+            offered_asset,
+            requested_asset,
+            NoteType::Private,
+        )?;
+        let mut mock_chain = builder.build()?;
+        mock_chain.prove_next_block()?;
+
+        let tx = mock_chain
+            .build_tx_context(target.id(), &[swap_note.id()], &[])?
+            .build()?
+            .execute()
+            .await?;
+        mock_chain.add_pending_executed_transaction(&tx)?;
+
+        let mut account_tree = mock_chain.account_tree().clone();
+        let mut nullifier_tree = mock_chain.nullifier_tree().clone();
+
+        let block_mutations = mock_chain.prove_next_block_detailed()?;
+        let block_mutations = BlockMutations::read_from_bytes(&block_mutations.to_bytes())?;
+
+        assert_eq!(block_mutations.header(), &mock_chain.latest_block_header());
+        assert_eq!(block_mutations.created_notes().len(), 1);
+        assert_eq!(block_mutations.created_notes()[0].id(), payback_note.id());
+
+        // Applying the mutations to the previous trees results in the roots of the new block.
+        let (header, account_tree_mutations, nullifier_tree_mutations, _) =
+            block_mutations.into_parts();
+        account_tree.apply_mutations(account_tree_mutations)?;
+        nullifier_tree.apply_mutations(nullifier_tree_mutations)?;
+
+        assert_eq!(account_tree.root(), header.account_root());
+        assert_eq!(nullifier_tree.root(), header.nullifier_root());
+        assert_eq!(account_tree.get(target.id()), tx.final_account().commitment());
+        assert_eq!(nullifier_tree.get_block_num(&swap_note.nullifier()), Some(header.block_num()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn execute_network_transactions() -> anyhow::Result<()> {
+        let counter_code = "
+            use.miden::active_account
+            use.miden::native_account
+
+            export.increment
+                push.0 exec.active_account::get_item
+                # => [COUNTER]
+
+                add.1 push.0 exec.native_account::set_item
+                # => [OLD_COUNTER]
+
+                dropw
+            end
+        ";
+        let counter_component = AccountComponent::compile(
+            counter_code,
+            TransactionKernel::assembler(),
+            vec![StorageSlot::Value(Word::empty())],
+        )?
+        .with_supports_all_types();
+        let increment_root = counter_component
+            .library()
+            .get_procedure_root_by_name("$anon::increment")
+            .context("counter component should export increment")?;
+
+        let mut builder = MockChain::builder();
+        let counter_account = builder.add_account_from_builder(
+            Auth::IncrNonce,
+            AccountBuilder::new([7; 32])
+                .storage_mode(AccountStorageMode::Network)
+                .with_component(counter_component),
+            AccountState::Exists,
+        )?;
+        let counter_tag = NoteTag::from_account_id(counter_account.id());
+        let sender = ACCOUNT_ID_SENDER.try_into()?;
+
+        let increment_notes = (0..2)
+            .map(|seed| {
+                let note = NoteBuilder::new(sender, SmallRng::seed_from_u64(seed))
+                    .note_type(NoteType::Public)
+                    .tag(counter_tag.as_u32())
+                    .code(format!("begin call.{increment_root} end"))
+                    .build()?;
+                builder.add_output_note(OutputNote::Full(note.clone()));
+                anyhow::Ok(note)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let failing_note = NoteBuilder::new(sender, SmallRng::seed_from_u64(2))
+            .note_type(NoteType::Public)
+            .tag(counter_tag.as_u32())
+            .code("begin push.0 assert end")
+            .build()?;
+        builder.add_output_note(OutputNote::Full(failing_note.clone()));
+        let mut mock_chain = builder.build()?;
+
+        let report = mock_chain.execute_network_transactions().await?;
+        assert_eq!(
+            report.executed_notes().keys().copied().collect::<BTreeSet<_>>(),
+            increment_notes.iter().map(Note::id).collect()
+        );
+        assert_eq!(
+            report.failed_notes().keys().copied().collect::<Vec<_>>(),
+            vec![failing_note.id()]
+        );
+
+        // notes consumed by pending transactions are not picked up again
+        let report = mock_chain.execute_network_transactions().await?;
+        assert!(report.executed_notes().is_empty());
+
+        mock_chain.prove_next_block()?;
+        let counter_account = mock_chain.committed_account(counter_account.id())?;
+        assert_eq!(counter_account.nonce().as_int(), 3);
+        assert_eq!(counter_account.storage().get_item(0)?, Word::from([0, 0, 0, 2u32]));
+        for note in &increment_notes {
+            assert!(mock_chain.nullifier_tree.get_block_num(&note.nullifier()).is_some());
+        }
+        assert!(mock_chain.nullifier_tree.get_block_num(&failing_note.nullifier()).is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn add_p2id_notes_bulk() -> anyhow::Result<()> {
+        let faucet_id = ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into()?;
+        let mut builder = MockChain::builder();
+        let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+        let assets_per_note: Vec<Vec<Asset>> = (1..=300u64)
+            .map(|amount| vec![FungibleAsset::new(faucet_id, amount).unwrap().into()])
+            .collect();
+        let notes = builder.add_p2id_notes_bulk(
+            ACCOUNT_ID_SENDER.try_into()?,
+            account.id(),
+            &assets_per_note,
+            NoteType::Public,
+        )?;
+        assert_eq!(notes.len(), 300);
+
+        let mut mock_chain = builder.build()?;
+
+        // All notes should be part of the genesis block.
+        assert_eq!(mock_chain.proven_blocks()[0].output_notes().count(), 300);
+        for note in &notes {
+            let committed_note = mock_chain.committed_notes().get(&note.id()).unwrap();
+            assert_eq!(committed_note.inclusion_proof().location().block_num(), 0u32.into());
+        }
+        assert_eq!(mock_chain.consumable_notes_for(account.id()).len(), 300);
+
+        let consumed_note_ids: Vec<NoteId> = notes.iter().take(10).map(Note::id).collect();
+        let tx = mock_chain
+            .build_tx_context(account.id(), &consumed_note_ids, &[])?
+            .build()?
+            .execute()
+            .await?;
+        mock_chain.add_pending_executed_transaction(&tx)?;
+        mock_chain.prove_next_block()?;
+
+        let consumable_notes = mock_chain.consumable_notes_for(account.id());
+        assert_eq!(consumable_notes.len(), 290);
+        assert!(
+            consumable_notes
+                .iter()
+                .all(|input_note| !consumed_note_ids.contains(&input_note.id()))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn mock_chain_note_into_input_note() -> anyhow::Result<()> {
+        let sender = ACCOUNT_ID_SENDER.try_into()?;
+        let mut builder = MockChain::builder();
+        let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+        let public_note = builder.add_p2id_note(sender, account.id(), &[], NoteType::Public)?;
+        let mut mock_chain = builder.build()?;
+
+        let chain_note = mock_chain.committed_notes().get(&public_note.id()).unwrap().clone();
+
+        // The note was created in the reference block, which is not part of the partial
+        // blockchain.
+        assert!(chain_note.into_input_note(&mock_chain.latest_partial_blockchain()).is_err());
+
+        mock_chain.prove_next_block()?;
+        let partial_blockchain = mock_chain.latest_partial_blockchain();
+
+        let input_note = chain_note.into_input_note(&partial_blockchain)?;
+        assert_eq!(input_note.id(), public_note.id());
+        assert_eq!(input_note.location(), Some(chain_note.inclusion_proof().location()));
+
+        // Private notes lack the details needed to consume them.
+        let private_chain_note = MockChainNote::Private(
+            public_note.id(),
+            *public_note.metadata(),
+            chain_note.inclusion_proof().clone(),
+        );
+        assert!(private_chain_note.into_input_note(&partial_blockchain).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn notes_matching() -> anyhow::Result<()> {
+        let sender = ACCOUNT_ID_SENDER.try_into()?;
+        let mut builder = MockChain::builder();
+        let account = builder.add_existing_wallet(Auth::IncrNonce)?;
+
+        let p2id_notes = [
+            builder.add_p2id_note(sender, account.id(), &[], NoteType::Public)?,
+            builder.add_p2id_note(sender, account.id(), &[], NoteType::Private)?,
+        ];
+
+        let use_case_tag = NoteTag::for_public_use_case(42, 1, NoteExecutionMode::Local)?;
+        let use_case_tags = [
+            use_case_tag,
+            NoteTag::for_public_use_case(42, 2, NoteExecutionMode::Local)?,
+            NoteTag::for_public_use_case(43, 1, NoteExecutionMode::Local)?,
+        ];
+        let mut use_case_notes = Vec::new();
+        for (seed, tag) in use_case_tags.into_iter().enumerate() {
+            let note = NoteBuilder::new(sender, SmallRng::seed_from_u64(seed as u64))
+                .tag(tag.as_u32())
+                .build()?;
+            builder.add_output_note(OutputNote::Full(note.clone()));
+            use_case_notes.push(note);
+        }
+
+        let mock_chain = builder.build()?;
+
+        let matching_ids = |pattern: NoteTagPattern| {
+            let mut note_ids: Vec<NoteId> =
+                mock_chain.notes_matching(&pattern).into_iter().map(MockChainNote::id).collect();
+            note_ids.sort();
+            note_ids
+        };
+        let sorted_ids = |notes: &[&Note]| {
+            let mut note_ids: Vec<NoteId> = notes.iter().map(|note| note.id()).collect();
+            note_ids.sort();
+            note_ids
+        };
+
+        assert_eq!(mock_chain.notes_matching(&NoteTagPattern::Any).len(), 5);
+        assert_eq!(
+            matching_ids(NoteTagPattern::Exact(NoteTag::from_account_id(account.id()))),
+            sorted_ids(&[&p2id_notes[0], &p2id_notes[1]])
+        );
+        assert_eq!(
+            matching_ids(NoteTagPattern::use_case(use_case_tag)),
+            sorted_ids(&[&use_case_notes[0], &use_case_notes[1]])
+        );
+        assert_eq!(
+            matching_ids(NoteTagPattern::Exact(use_case_tag)),
+            sorted_ids(&[&use_case_notes[0]])
+        );
+
+        let input_notes = InputNotes::new(
+            use_case_notes
+                .iter()
+                .chain(p2id_notes.iter())
+                .map(|note| InputNote::unauthenticated(note.clone()))
+                .collect(),
+        )?;
+        let pattern = NoteTagPattern::use_case(use_case_tag);
+        assert_eq!(input_notes.iter_matching(&pattern).count(), 2);
+
         Ok(())
     }
 
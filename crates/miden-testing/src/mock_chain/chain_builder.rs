@@ -1,3 +1,4 @@
+use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
@@ -13,7 +14,11 @@ const DEFAULT_FAUCET_DECIMALS: u8 = 10;
 // ================================================================================================
 
 use itertools::Itertools;
-use miden_lib::account::faucets::{BasicFungibleFaucet, NetworkFungibleFaucet};
+use miden_lib::account::faucets::{
+    BasicFungibleFaucet,
+    BasicNonFungibleFaucet,
+    NetworkFungibleFaucet,
+};
 use miden_lib::account::wallets::BasicWallet;
 use miden_lib::note::{create_p2id_note, create_p2ide_note, create_swap_note};
 use miden_lib::testing::account_component::MockAccountComponent;
@@ -168,6 +173,18 @@ impl MockChainBuilder {
         self
     }
 
+    /// Sets the native asset ID and the `verification_base_fee` of the chain from the provided
+    /// [`FeeParameters`].
+    ///
+    /// This is equivalent to calling [`Self::native_asset_id`] and
+    /// [`Self::verification_base_fee`] and can be used to test transactions whose fees are not
+    /// covered by the account, see
+    /// [`TransactionExecutorError::InsufficientFee`](miden_tx::TransactionExecutorError::InsufficientFee).
+    pub fn fee_parameters(self, fee_parameters: FeeParameters) -> Self {
+        self.native_asset_id(fee_parameters.native_asset_id())
+            .verification_base_fee(fee_parameters.verification_base_fee())
+    }
+
     /// Consumes the builder, creates the genesis block of the chain and returns the [`MockChain`].
     pub fn build(self) -> anyhow::Result<MockChain> {
         // Create the genesis block, consisting of the provided accounts and notes.
@@ -179,7 +196,7 @@ impl MockChainBuilder {
                 let account_commitment = account.commitment();
                 let account_delta = AccountDelta::try_from(account)
                     .expect("chain builder should only store existing accounts without seeds");
-                let update_details = AccountUpdateDetails::Delta(account_delta);
+                let update_details = AccountUpdateDetails::Delta(Box::new(account_delta));
 
                 BlockAccountUpdate::new(account_id, account_commitment, update_details)
             })
@@ -349,6 +366,29 @@ impl MockChainBuilder {
         Ok(account)
     }
 
+    /// Adds an existing [`BasicNonFungibleFaucet`] account to the initial chain state and
+    /// registers the authenticator.
+    ///
+    /// Basic non-fungible faucets always use `AccountStorageMode::Public` and require
+    /// authentication.
+    pub fn add_existing_basic_non_fungible_faucet(
+        &mut self,
+        auth_method: Auth,
+        token_symbol: &str,
+        max_supply: u64,
+    ) -> anyhow::Result<Account> {
+        let token_symbol = TokenSymbol::new(token_symbol).context("invalid argument")?;
+        let basic_faucet = BasicNonFungibleFaucet::new(token_symbol, Felt::new(max_supply))
+            .context("invalid argument")?;
+
+        let account_builder = AccountBuilder::new(self.rng.random())
+            .storage_mode(AccountStorageMode::Public)
+            .with_component(basic_faucet)
+            .account_type(AccountType::NonFungibleFaucet);
+
+        self.add_account_from_builder(auth_method, account_builder, AccountState::Exists)
+    }
+
     /// Adds an existing [`NetworkFungibleFaucet`] account to the initial chain state.
     ///
     /// Network fungible faucets always use `AccountStorageMode::Network` and `Auth::NoAuth`.
@@ -547,6 +587,41 @@ impl MockChainBuilder {
         Ok(note)
     }
 
+    /// Creates one P2ID note for each entry of `assets_per_note` from the provided parameters and
+    /// adds all of them to the list of genesis notes.
+    ///
+    /// This is equivalent to calling [`Self::add_p2id_note`] for each entry. The serial numbers of
+    /// the notes are drawn from the builder's RNG, so the created notes are deterministic for a
+    /// given builder seed.
+    ///
+    /// In the created [`MockChain`], all notes will be part of the genesis block and immediately
+    /// spendable by `target_account_id`.
+    pub fn add_p2id_notes_bulk(
+        &mut self,
+        sender_account_id: AccountId,
+        target_account_id: AccountId,
+        assets_per_note: &[Vec<Asset>],
+        note_type: NoteType,
+    ) -> Result<Vec<Note>, NoteError> {
+        let notes = assets_per_note
+            .iter()
+            .map(|assets| {
+                create_p2id_note(
+                    sender_account_id,
+                    target_account_id,
+                    assets.clone(),
+                    note_type,
+                    Felt::ZERO,
+                    &mut self.rng,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.notes.extend(notes.iter().cloned().map(OutputNote::Full));
+
+        Ok(notes)
+    }
+
     /// Adds a P2IDE [`OutputNote`] (pay‑to‑ID‑extended) to the list of genesis notes.
     ///
     /// A P2IDE note can include an optional `timelock_height` and/or an optional
@@ -1,9 +1,13 @@
 mod auth;
+mod block_mutations;
 mod chain;
 mod chain_builder;
+mod network_report;
 mod note;
 
 pub use auth::Auth;
+pub use block_mutations::BlockMutations;
 pub use chain::{AccountState, MockChain, TxContextInput};
 pub use chain_builder::MockChainBuilder;
+pub use network_report::NetworkTransactionReport;
 pub use note::MockChainNote;
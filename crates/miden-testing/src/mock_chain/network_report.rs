@@ -0,0 +1,42 @@
+use alloc::collections::BTreeMap;
+
+use miden_objects::note::NoteId;
+use miden_objects::transaction::TransactionId;
+
+// NETWORK TRANSACTION REPORT
+// ================================================================================================
+
+/// The outcome of
+/// [`MockChain::execute_network_transactions`](crate::MockChain::execute_network_transactions).
+///
+/// Every note that was picked up for network execution is either contained in the executed notes,
+/// mapped to the ID of the transaction that consumed it, or in the failed notes, mapped to the
+/// error that prevented its execution.
+#[derive(Debug, Default)]
+pub struct NetworkTransactionReport {
+    executed_notes: BTreeMap<NoteId, TransactionId>,
+    failed_notes: BTreeMap<NoteId, anyhow::Error>,
+}
+
+impl NetworkTransactionReport {
+    /// Returns the notes that were successfully consumed, mapped to the IDs of the transactions
+    /// that consumed them.
+    pub fn executed_notes(&self) -> &BTreeMap<NoteId, TransactionId> {
+        &self.executed_notes
+    }
+
+    /// Returns the notes whose execution failed, mapped to the errors that caused the failure.
+    pub fn failed_notes(&self) -> &BTreeMap<NoteId, anyhow::Error> {
+        &self.failed_notes
+    }
+
+    /// Records the successful consumption of the note by the transaction with the provided ID.
+    pub(super) fn record_executed(&mut self, note_id: NoteId, transaction_id: TransactionId) {
+        self.executed_notes.insert(note_id, transaction_id);
+    }
+
+    /// Records the failed execution of the note.
+    pub(super) fn record_failed(&mut self, note_id: NoteId, error: anyhow::Error) {
+        self.failed_notes.insert(note_id, error);
+    }
+}
@@ -1,5 +1,6 @@
+use miden_objects::NoteError;
 use miden_objects::note::{Note, NoteId, NoteInclusionProof, NoteMetadata};
-use miden_objects::transaction::InputNote;
+use miden_objects::transaction::{InputNote, PartialBlockchain};
 use miden_processor::DeserializationError;
 use miden_tx::utils::{ByteReader, Deserializable, Serializable};
 use winterfell::ByteWriter;
@@ -50,6 +51,54 @@ impl MockChainNote {
             MockChainNote::Public(note, _) => Some(note),
         }
     }
+
+    /// Returns an authenticated [`InputNote`] for this note after verifying its inclusion proof
+    /// against the header of the block in which the note was created.
+    ///
+    /// The block header is taken from the provided partial blockchain, so notes created in the
+    /// reference block of the partial blockchain cannot be converted with this method.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the note is private, since the details needed to consume it are not stored in the chain.
+    /// - the block in which the note was created is not tracked by the partial blockchain.
+    /// - the note's inclusion proof is not valid against the note root of that block.
+    pub fn into_input_note(
+        &self,
+        partial_blockchain: &PartialBlockchain,
+    ) -> Result<InputNote, NoteError> {
+        let MockChainNote::Public(note, inclusion_proof) = self else {
+            return Err(NoteError::other(format!(
+                "private note {} cannot be converted into an input note due to missing details",
+                self.id()
+            )));
+        };
+
+        let block_num = inclusion_proof.location().block_num();
+        let block_header = partial_blockchain.get_block(block_num).ok_or_else(|| {
+            NoteError::other(format!(
+                "block {block_num} of note {} is not tracked by the partial blockchain",
+                note.id()
+            ))
+        })?;
+
+        inclusion_proof
+            .note_path()
+            .verify(
+                inclusion_proof.location().node_index_in_block().into(),
+                note.commitment(),
+                &block_header.note_root(),
+            )
+            .map_err(|source| {
+                NoteError::other_with_source(
+                    format!("note {} is not included in block {block_num}", note.id()),
+                    source,
+                )
+            })?;
+
+        Ok(InputNote::authenticated(note.clone(), inclusion_proof.clone()))
+    }
 }
 
 impl TryFrom<MockChainNote> for InputNote {
@@ -60,7 +109,7 @@ impl TryFrom<MockChainNote> for InputNote {
             MockChainNote::Private(..) => Err(anyhow::anyhow!(
                 "private notes in the mock chain cannot be converted into input notes due to missing details"
             )),
-            MockChainNote::Public(note, proof) => Ok(InputNote::Authenticated { note, proof }),
+            MockChainNote::Public(note, proof) => Ok(InputNote::authenticated(note, proof)),
         }
     }
 }
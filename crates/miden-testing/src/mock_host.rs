@@ -10,6 +10,7 @@ use miden_processor::{
     AsyncHost,
     BaseHost,
     EventError,
+    ExecutionError,
     FutureMaybeSend,
     MastForest,
     ProcessState,
@@ -100,6 +101,14 @@ impl<'store> BaseHost for MockHost<'store> {
     ) {
         self.exec_host.get_label_and_source_file(location)
     }
+
+    fn on_trace(
+        &mut self,
+        process: &mut ProcessState,
+        trace_id: u32,
+    ) -> Result<(), ExecutionError> {
+        self.exec_host.on_trace(process, trace_id)
+    }
 }
 
 impl<'store> AsyncHost for MockHost<'store> {
@@ -0,0 +1,178 @@
+use alloc::collections::BTreeMap;
+use alloc::string::ToString;
+
+use miden_objects::Word;
+use miden_objects::account::{AuthSecretKey, PublicKeyCommitment, Signature};
+use miden_objects::crypto::dsa::rpo_falcon512::SecretKey;
+use miden_processor::FutureMaybeSend;
+use miden_tx::AuthenticationError;
+use miden_tx::auth::{BasicAuthenticator, SigningInputs, TransactionAuthenticator};
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+// MOCK AUTHENTICATOR
+// ================================================================================================
+
+/// A [`TransactionAuthenticator`] for tests which produces deterministic signatures.
+///
+/// A mock authenticator either signs with the keys registered for it, using an RNG with a fixed
+/// seed, or denies all signature requests, see [`MockAuthenticator::deny_all`]. Additionally,
+/// precomputed signatures can be injected via [`MockAuthenticator::with_signature`], which are
+/// returned instead of generating a signature in both modes.
+#[derive(Debug, Clone)]
+pub struct MockAuthenticator {
+    /// The authenticator used to sign messages, or `None` if all requests should be denied.
+    signer: Option<BasicAuthenticator<ChaCha20Rng>>,
+    /// (public key commitment, message) |-> signature mapping of the injected signatures.
+    signatures: BTreeMap<(Word, Word), Signature>,
+}
+
+impl MockAuthenticator {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [`MockAuthenticator`] which signs with the provided keys.
+    ///
+    /// The RNG used for signing is seeded with a fixed seed, so the generated signatures are the
+    /// same across runs.
+    pub fn new(keys: &[(Word, AuthSecretKey)]) -> Self {
+        BasicAuthenticator::new_with_rng(keys, ChaCha20Rng::from_seed(Default::default())).into()
+    }
+
+    /// Returns a new [`MockAuthenticator`] for a single RPO Falcon 512 key deterministically
+    /// derived from the provided seed, together with the commitment to its public key.
+    ///
+    /// The commitment can be used to build the auth component of an account, e.g.
+    /// `AuthRpoFalcon512::new(pub_key_commitment)`.
+    pub fn new_rpo_falcon512(seed: [u8; 32]) -> (Self, PublicKeyCommitment) {
+        let secret_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed(seed));
+        let pub_key_commitment = PublicKeyCommitment::from(secret_key.public_key());
+        let authenticator =
+            Self::new(&[(pub_key_commitment.into(), AuthSecretKey::RpoFalcon512(secret_key))]);
+
+        (authenticator, pub_key_commitment)
+    }
+
+    /// Returns a new [`MockAuthenticator`] which rejects any signature request for which no
+    /// signature was injected with [`AuthenticationError::RejectedSignature`].
+    ///
+    /// Note that a transaction executor reports a rejected signature request as a
+    /// [`TransactionKernelError::SignatureGenerationFailed`] event error, which
+    /// [`TransactionExecutorError::is_auth_error`] classifies as an authentication error, rather
+    /// than as [`TransactionExecutorError::Unauthorized`]. The latter is only returned when an auth
+    /// component aborts the transaction because it is missing signatures, in which case no
+    /// signature is requested from the authenticator.
+    ///
+    /// [`TransactionKernelError::SignatureGenerationFailed`]: miden_tx::TransactionKernelError::SignatureGenerationFailed
+    /// [`TransactionExecutorError::is_auth_error`]: miden_tx::TransactionExecutorError::is_auth_error
+    /// [`TransactionExecutorError::Unauthorized`]: miden_tx::TransactionExecutorError::Unauthorized
+    pub fn deny_all() -> Self {
+        Self {
+            signer: None,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    /// Injects a precomputed signature which is returned when a signature of `message` is
+    /// requested for the key with the provided commitment.
+    ///
+    /// The signature is returned as-is, so this can also be used to inject invalid signatures.
+    #[must_use]
+    pub fn with_signature(
+        mut self,
+        pub_key_commitment: PublicKeyCommitment,
+        message: Word,
+        signature: Signature,
+    ) -> Self {
+        self.signatures.insert((pub_key_commitment.into(), message), signature);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the keys this authenticator signs with, or `None` if it denies all requests.
+    pub fn keys(&self) -> Option<&BTreeMap<Word, AuthSecretKey>> {
+        self.signer.as_ref().map(BasicAuthenticator::keys)
+    }
+}
+
+impl From<BasicAuthenticator<ChaCha20Rng>> for MockAuthenticator {
+    fn from(authenticator: BasicAuthenticator<ChaCha20Rng>) -> Self {
+        Self {
+            signer: Some(authenticator),
+            signatures: BTreeMap::new(),
+        }
+    }
+}
+
+impl TransactionAuthenticator for MockAuthenticator {
+    fn get_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &SigningInputs,
+    ) -> impl FutureMaybeSend<Result<Signature, AuthenticationError>> {
+        let injected_signature = self
+            .signatures
+            .get(&(pub_key_commitment.into(), signing_inputs.to_commitment()))
+            .cloned();
+
+        async move {
+            if let Some(signature) = injected_signature {
+                return Ok(signature);
+            }
+
+            match &self.signer {
+                Some(signer) => signer.get_signature(pub_key_commitment, signing_inputs).await,
+                None => Err(AuthenticationError::RejectedSignature(
+                    "mock authenticator denies all signature requests".to_string(),
+                )),
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn mock_authenticator_signatures() -> anyhow::Result<()> {
+        let message = Word::from([1, 2, 3, 4u32]);
+        let signing_inputs = SigningInputs::Blind(message);
+
+        // Keys and signatures are derived deterministically.
+        let (authenticator, pub_key_commitment) = MockAuthenticator::new_rpo_falcon512([1; 32]);
+        let (other_authenticator, other_pub_key_commitment) =
+            MockAuthenticator::new_rpo_falcon512([1; 32]);
+        assert_eq!(pub_key_commitment, other_pub_key_commitment);
+
+        let signature = authenticator.get_signature(pub_key_commitment, &signing_inputs).await?;
+        let other_signature =
+            other_authenticator.get_signature(pub_key_commitment, &signing_inputs).await?;
+        assert_eq!(signature.to_prepared_signature(), other_signature.to_prepared_signature());
+
+        // Injected signatures are returned even if all other requests are denied.
+        let denying_authenticator = MockAuthenticator::deny_all().with_signature(
+            pub_key_commitment,
+            message,
+            signature.clone(),
+        );
+        let injected_signature =
+            denying_authenticator.get_signature(pub_key_commitment, &signing_inputs).await?;
+        assert_eq!(injected_signature.to_prepared_signature(), signature.to_prepared_signature());
+        assert_matches!(
+            denying_authenticator
+                .get_signature(pub_key_commitment, &SigningInputs::Blind(Word::empty()))
+                .await,
+            Err(AuthenticationError::RejectedSignature(_))
+        );
+
+        Ok(())
+    }
+}
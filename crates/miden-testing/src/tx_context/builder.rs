@@ -24,14 +24,10 @@ use miden_objects::transaction::{
 };
 use miden_processor::{AdviceInputs, Felt, Word};
 use miden_tx::TransactionMastStore;
-use miden_tx::auth::BasicAuthenticator;
-use rand_chacha::ChaCha20Rng;
 
-use super::TransactionContext;
+use super::{MockAuthenticator, TransactionContext};
 use crate::{MockChain, MockChainNote};
 
-pub type MockAuthenticator = BasicAuthenticator<ChaCha20Rng>;
-
 // TRANSACTION CONTEXT BUILDER
 // ================================================================================================
 
@@ -164,6 +160,15 @@ impl TransactionContextBuilder {
         self
     }
 
+    /// Set the provided [`MockAuthenticator`] as the authenticator for the transaction.
+    ///
+    /// This allows executing transactions against accounts requiring signatures, e.g. for keys
+    /// created with [`MockAuthenticator::new_rpo_falcon512`], without wiring up the account via
+    /// [`Auth::BasicAuth`](crate::Auth::BasicAuth).
+    pub fn with_mock_authenticator(self, authenticator: MockAuthenticator) -> Self {
+        self.authenticator(Some(authenticator))
+    }
+
     /// Set foreign account codes that are used by the transaction
     pub fn foreign_accounts(
         mut self,
@@ -330,6 +335,7 @@ impl TransactionContextBuilder {
             source_manager: self.source_manager,
             is_lazy_loading_enabled: self.is_lazy_loading_enabled,
             note_scripts: self.note_scripts,
+            output_notes: None,
         })
     }
 }
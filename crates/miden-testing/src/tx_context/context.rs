@@ -15,13 +15,14 @@ use miden_objects::transaction::{
     ExecutedTransaction,
     InputNote,
     InputNotes,
+    OutputNotes,
     PartialBlockchain,
     TransactionArgs,
     TransactionInputs,
 };
 use miden_processor::fast::ExecutionOutput;
 use miden_processor::{ExecutionError, FutureMaybeSend, MastForest, MastForestStore, Word};
-use miden_tx::auth::{BasicAuthenticator, UnreachableAuth};
+use miden_tx::auth::UnreachableAuth;
 use miden_tx::{
     AccountProcedureIndexMap,
     DataStore,
@@ -32,11 +33,10 @@ use miden_tx::{
     TransactionExecutorHost,
     TransactionMastStore,
 };
-use rand_chacha::ChaCha20Rng;
 
 use crate::executor::CodeExecutor;
 use crate::mock_host::MockHost;
-use crate::tx_context::builder::MockAuthenticator;
+use crate::tx_context::MockAuthenticator;
 
 // TRANSACTION CONTEXT
 // ================================================================================================
@@ -55,6 +55,7 @@ pub struct TransactionContext {
     pub(super) source_manager: Arc<dyn SourceManagerSync>,
     pub(super) is_lazy_loading_enabled: bool,
     pub(super) note_scripts: BTreeMap<Word, NoteScript>,
+    pub(super) output_notes: Option<OutputNotes>,
 }
 
 impl TransactionContext {
@@ -79,7 +80,7 @@ impl TransactionContext {
     ///
     /// - If the provided `code` is not a valid program.
     pub async fn execute_code(&self, code: &str) -> Result<ExecutionOutput, ExecutionError> {
-        let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(&self.tx_inputs)
+        let (stack_inputs, advice_inputs) = TransactionKernel::build_inputs(&self.tx_inputs)
             .expect("error initializing transaction inputs");
 
         // Virtual file name should be unique.
@@ -139,12 +140,33 @@ impl TransactionContext {
 
     /// Executes the transaction through a [TransactionExecutor]
     pub async fn execute(self) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        self.execute_transaction().await
+    }
+
+    /// Executes the transaction through a [TransactionExecutor] without consuming the context.
+    ///
+    /// On success, the output notes of the executed transaction are retained and can be accessed
+    /// through [`TransactionContext::output_notes`].
+    pub async fn execute_mut(&mut self) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let executed_transaction = self.execute_transaction().await?;
+        self.output_notes = Some(executed_transaction.output_notes().clone());
+
+        Ok(executed_transaction)
+    }
+
+    /// Returns the output notes of the last transaction executed with
+    /// [`TransactionContext::execute_mut`], or `None` if no transaction has been executed yet.
+    pub fn output_notes(&self) -> Option<&OutputNotes> {
+        self.output_notes.as_ref()
+    }
+
+    async fn execute_transaction(&self) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let account_id = self.account().id();
         let block_num = self.tx_inputs().block_header().block_num();
         let notes = self.tx_inputs().input_notes().clone();
         let tx_args = self.tx_args().clone();
 
-        let mut tx_executor = TransactionExecutor::new(&self)
+        let mut tx_executor = TransactionExecutor::new(self)
             .with_source_manager(self.source_manager.clone())
             .with_debug_mode();
         if let Some(authenticator) = self.authenticator() {
@@ -178,7 +200,7 @@ impl TransactionContext {
         &self.tx_inputs
     }
 
-    pub fn authenticator(&self) -> Option<&BasicAuthenticator<ChaCha20Rng>> {
+    pub fn authenticator(&self) -> Option<&MockAuthenticator> {
         self.authenticator.as_ref()
     }
 
@@ -1,5 +1,7 @@
+mod authenticator;
 mod builder;
 mod context;
 
+pub use authenticator::MockAuthenticator;
 pub use builder::TransactionContextBuilder;
 pub use context::TransactionContext;
@@ -0,0 +1,116 @@
+use assert_matches::assert_matches;
+use miden_lib::account::auth::AuthRpoFalcon512;
+use miden_lib::account::wallets::BasicWallet;
+use miden_objects::account::{Account, AccountBuilder, AccountStorageMode, PublicKeyCommitment};
+use miden_objects::asset::FungibleAsset;
+use miden_objects::note::{NoteId, NoteType};
+use miden_objects::testing::account_id::ACCOUNT_ID_SENDER;
+use miden_processor::ExecutionError;
+use miden_testing::{MockAuthenticator, MockChain};
+use miden_tx::{AuthenticationError, TransactionExecutorError, TransactionKernelError};
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Creates a chain with a wallet authenticated by [`AuthRpoFalcon512`] with the provided key and a
+/// P2ID note the wallet can consume.
+fn setup_wallet_with_note(
+    pub_key_commitment: PublicKeyCommitment,
+) -> anyhow::Result<(Account, MockChain, NoteId)> {
+    let account = AccountBuilder::new([3; 32])
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(AuthRpoFalcon512::new(pub_key_commitment))
+        .with_component(BasicWallet)
+        .build_existing()?;
+
+    let mut builder = MockChain::builder();
+    builder.add_account(account.clone())?;
+    let note = builder.add_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        account.id(),
+        &[FungibleAsset::mock(10)],
+        NoteType::Public,
+    )?;
+
+    Ok((account, builder.build()?, note.id()))
+}
+
+// TESTS
+// ================================================================================================
+
+/// Tests that a transaction against an account requiring a signature succeeds with a mock
+/// authenticator holding the account's key.
+#[tokio::test]
+async fn mock_authenticator_signs_transaction() -> anyhow::Result<()> {
+    let (authenticator, pub_key_commitment) = MockAuthenticator::new_rpo_falcon512([7; 32]);
+    let (account, mock_chain, note_id) = setup_wallet_with_note(pub_key_commitment)?;
+
+    let executed_transaction = mock_chain
+        .build_tx_context(account.id(), &[note_id], &[])?
+        .with_mock_authenticator(authenticator)
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_transaction.final_account().nonce(), account.nonce() + 1u32.into());
+
+    Ok(())
+}
+
+/// Tests that a transaction against an account requiring a signature fails if the authenticator
+/// denies the signature request.
+///
+/// The denial surfaces as a signature generation error rather than as
+/// [`TransactionExecutorError::Unauthorized`], which is only returned by auth components that are
+/// missing signatures.
+#[tokio::test]
+async fn mock_authenticator_deny_all_rejects_signature() -> anyhow::Result<()> {
+    let (_, pub_key_commitment) = MockAuthenticator::new_rpo_falcon512([7; 32]);
+    let (account, mock_chain, note_id) = setup_wallet_with_note(pub_key_commitment)?;
+
+    let error = mock_chain
+        .build_tx_context(account.id(), &[note_id], &[])?
+        .with_mock_authenticator(MockAuthenticator::deny_all())
+        .build()?
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert!(error.is_auth_error());
+    assert_matches!(
+        error,
+        TransactionExecutorError::TransactionProgramExecutionFailed(ExecutionError::EventError {
+            error,
+            ..
+        }) => {
+            assert_matches!(
+                error.downcast_ref::<TransactionKernelError>(),
+                Some(TransactionKernelError::SignatureGenerationFailed(
+                    AuthenticationError::RejectedSignature(_)
+                ))
+            );
+        }
+    );
+
+    Ok(())
+}
+
+/// Tests that a transaction against an account requiring a signature fails if no authenticator is
+/// provided.
+#[tokio::test]
+async fn transaction_without_authenticator_fails() -> anyhow::Result<()> {
+    let (_, pub_key_commitment) = MockAuthenticator::new_rpo_falcon512([7; 32]);
+    let (account, mock_chain, note_id) = setup_wallet_with_note(pub_key_commitment)?;
+
+    let error = mock_chain
+        .build_tx_context(account.id(), &[note_id], &[])?
+        .authenticator(None)
+        .build()?
+        .execute()
+        .await
+        .unwrap_err();
+
+    assert_matches!(error, TransactionExecutorError::MissingAuthenticator);
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+mod mock_authenticator;
 mod rpo_falcon_acl;
 
 mod multisig;
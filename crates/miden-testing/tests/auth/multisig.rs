@@ -1,6 +1,19 @@
+use std::sync::{Arc, Mutex};
+
+use assert_matches::assert_matches;
+use miden_lib::account::auth::{
+    AuthRpoFalcon512,
+    AuthRpoFalcon512Multisig,
+    AuthRpoFalcon512MultisigConfig,
+};
 use miden_lib::account::components::rpo_falcon_512_multisig_library;
 use miden_lib::account::interface::AccountInterface;
 use miden_lib::account::wallets::BasicWallet;
+use miden_lib::errors::note_script_errors::{
+    ERR_MULTISIG_APPROVER_ALREADY_EXISTS,
+    ERR_MULTISIG_APPROVER_NOT_FOUND,
+    ERR_MULTISIG_THRESHOLD_EXCEEDS_TOTAL_WEIGHT,
+};
 use miden_lib::errors::tx_kernel_errors::ERR_TX_ALREADY_EXECUTED;
 use miden_lib::note::create_p2id_note;
 use miden_lib::testing::account_interface::get_public_keys_from_account;
@@ -12,6 +25,8 @@ use miden_objects::account::{
     AccountStorageMode,
     AccountType,
     AuthSecretKey,
+    PublicKeyCommitment,
+    Signature,
 };
 use miden_objects::asset::FungibleAsset;
 use miden_objects::crypto::dsa::rpo_falcon512::{PublicKey, SecretKey};
@@ -20,15 +35,31 @@ use miden_objects::testing::account_id::{
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
     ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
 };
-use miden_objects::transaction::OutputNote;
+use miden_objects::transaction::{ExecutedTransaction, OutputNote, TransactionScript};
 use miden_objects::vm::AdviceMap;
 use miden_objects::{Felt, Hasher, Word};
-use miden_processor::AdviceInputs;
 use miden_processor::crypto::RpoRandomCoin;
+use miden_processor::{AdviceInputs, ExecutionError, FutureMaybeSend};
 use miden_testing::utils::create_spawn_note;
-use miden_testing::{Auth, MockChainBuilder, assert_transaction_executor_error};
-use miden_tx::TransactionExecutorError;
-use miden_tx::auth::{BasicAuthenticator, SigningInputs, TransactionAuthenticator};
+use miden_testing::{
+    Auth,
+    MockAuthenticator,
+    MockChain,
+    MockChainBuilder,
+    assert_transaction_executor_error,
+};
+use miden_tx::auth::{
+    AuthenticatorSet,
+    BasicAuthenticator,
+    SigningInputs,
+    TransactionAuthenticator,
+};
+use miden_tx::{
+    AuthenticationError,
+    TransactionExecutor,
+    TransactionExecutorError,
+    TransactionKernelError,
+};
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 
@@ -73,6 +104,38 @@ fn setup_keys_and_authenticators(
     Ok((secret_keys, public_keys, authenticators))
 }
 
+/// A [`TransactionAuthenticator`] which records the public key commitments of the signature
+/// requests it serves before forwarding them to the wrapped authenticator.
+struct RecordingAuthenticator<A> {
+    inner: A,
+    requested_keys: Arc<Mutex<Vec<PublicKeyCommitment>>>,
+}
+
+impl<A> RecordingAuthenticator<A> {
+    /// Returns the wrapped authenticator and a handle to the list of requested keys.
+    fn new(inner: A) -> (Self, Arc<Mutex<Vec<PublicKeyCommitment>>>) {
+        let requested_keys = Arc::new(Mutex::new(Vec::new()));
+        (
+            Self {
+                inner,
+                requested_keys: requested_keys.clone(),
+            },
+            requested_keys,
+        )
+    }
+}
+
+impl<A: TransactionAuthenticator + Sync> TransactionAuthenticator for RecordingAuthenticator<A> {
+    fn get_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &SigningInputs,
+    ) -> impl FutureMaybeSend<Result<Signature, AuthenticationError>> {
+        self.requested_keys.lock().unwrap().push(pub_key_commitment);
+        self.inner.get_signature(pub_key_commitment, signing_inputs)
+    }
+}
+
 /// Creates a multisig account with the specified configuration
 fn create_multisig_account(
     threshold: u32,
@@ -93,6 +156,48 @@ fn create_multisig_account(
     Ok(multisig_account)
 }
 
+/// Executes a transaction against the multisig account which is signed by the provided approvers.
+///
+/// The transaction is first executed without signatures to obtain the transaction summary the
+/// approvers sign.
+async fn execute_multisig_tx(
+    mock_chain: &MockChain,
+    account_id: AccountId,
+    tx_script: Option<TransactionScript>,
+    salt: Word,
+    signers: &[(&PublicKey, &BasicAuthenticator<ChaCha20Rng>)],
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let tx_context_builder = || {
+        let tx_context_builder = mock_chain
+            .build_tx_context(account_id, &[], &[])
+            .expect("failed to build tx context")
+            .auth_args(salt);
+        match tx_script.clone() {
+            Some(tx_script) => tx_context_builder.tx_script(tx_script),
+            None => tx_context_builder,
+        }
+    };
+
+    let tx_summary = match tx_context_builder().build().unwrap().execute().await {
+        Err(TransactionExecutorError::Unauthorized { summary: tx_summary, .. }) => tx_summary,
+        result => return result,
+    };
+
+    let msg = tx_summary.as_ref().to_commitment();
+    let tx_summary = SigningInputs::TransactionSummary(tx_summary);
+    let mut signed_tx_context_builder = tx_context_builder();
+    for (public_key, authenticator) in signers {
+        let signature = authenticator
+            .get_signature(public_key.to_commitment().into(), &tx_summary)
+            .await
+            .expect("failed to sign the transaction summary");
+        signed_tx_context_builder =
+            signed_tx_context_builder.add_signature((*public_key).clone().into(), msg, signature);
+    }
+
+    signed_tx_context_builder.build().unwrap().execute().await
+}
+
 // ================================================================================================
 // TESTS
 // ================================================================================================
@@ -144,7 +249,7 @@ async fn test_multisig_2_of_2_with_note_creation() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary = match tx_context_init.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
 
@@ -185,6 +290,164 @@ async fn test_multisig_2_of_2_with_note_creation() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests 2-of-3 multisig where the signatures of the approvers are provided by different
+/// authenticators combined in an [`AuthenticatorSet`].
+///
+/// This test verifies that the signature requests are routed to the authenticator registered for
+/// the requested public key, that requests for unregistered public keys are rejected when no
+/// fallback authenticator is set and that the signatures obtained from the set verify.
+///
+/// **Roles:**
+/// - 3 Approvers (2 signers required, each served by a separate authenticator)
+/// - 1 Multisig Contract
+#[tokio::test]
+async fn test_multisig_2_of_3_with_authenticator_set() -> anyhow::Result<()> {
+    // Setup keys and authenticators (3 approvers, only the first 2 can sign)
+    let (_secret_keys, public_keys, authenticators) = setup_keys_and_authenticators(3, 2)?;
+
+    let multisig_account = create_multisig_account(2, &public_keys, 10, vec![])?;
+
+    let mut mock_chain = MockChainBuilder::with_accounts([multisig_account.clone()])
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let mut authenticator_set = AuthenticatorSet::new();
+    let mut requests = Vec::new();
+    for (public_key, authenticator) in public_keys.iter().zip(authenticators) {
+        let (authenticator, requested_keys) = RecordingAuthenticator::new(authenticator);
+        authenticator_set.register(public_key.to_commitment().into(), authenticator);
+        requests.push(requested_keys);
+    }
+
+    let salt = Word::from([Felt::new(1); 4]);
+
+    // Execute transaction without signatures first to get tx summary
+    let tx_context_init = mock_chain
+        .build_tx_context(multisig_account.id(), &[], &[])?
+        .auth_args(salt)
+        .build()?;
+
+    let tx_summary = match tx_context_init.execute().await.unwrap_err() {
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
+        error => panic!("expected abort with tx effects: {error:?}"),
+    };
+
+    let msg = tx_summary.as_ref().to_commitment();
+    let tx_summary = SigningInputs::TransactionSummary(tx_summary);
+
+    // Get signatures for the first two approvers, each served by its own authenticator
+    let sig_1 = authenticator_set
+        .get_signature(public_keys[0].to_commitment().into(), &tx_summary)
+        .await?;
+    let sig_2 = authenticator_set
+        .get_signature(public_keys[1].to_commitment().into(), &tx_summary)
+        .await?;
+
+    // Each request was served by the authenticator registered for the requested key
+    for (public_key, requested_keys) in public_keys.iter().zip(&requests) {
+        let expected: PublicKeyCommitment = public_key.to_commitment().into();
+        assert_eq!(*requested_keys.lock().unwrap(), [expected]);
+    }
+
+    // The third approver is not registered and there is no fallback
+    let err = authenticator_set
+        .get_signature(public_keys[2].to_commitment().into(), &tx_summary)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, AuthenticationError::UnknownPublicKey(_)));
+
+    // Execute transaction with signatures - should succeed
+    let executed_tx = mock_chain
+        .build_tx_context(multisig_account.id(), &[], &[])?
+        .auth_args(salt)
+        .add_signature(public_keys[0].clone().into(), msg, sig_1)
+        .add_signature(public_keys[1].clone().into(), msg, sig_2)
+        .build()?
+        .execute()
+        .await?;
+
+    mock_chain.add_pending_executed_transaction(&executed_tx)?;
+    mock_chain.prove_next_block()?;
+
+    Ok(())
+}
+
+/// Tests that an [`AuthenticatorSet`] installed as the authenticator of a
+/// [`TransactionExecutor`] dispatches the signature requests of the executed transactions to the
+/// authenticator registered for the requested public key.
+///
+/// The multisig component only verifies signatures which were provided up front, so the requests
+/// are issued by accounts using single-signature authentication, each with its own key.
+#[tokio::test]
+async fn test_authenticator_set_dispatches_executor_signature_requests() -> anyhow::Result<()> {
+    let keys = [[1; 32], [2; 32], [3; 32]].map(MockAuthenticator::new_rpo_falcon512);
+
+    let mut builder = MockChain::builder();
+    let mut accounts = Vec::new();
+    for (seed, (_, pub_key_commitment)) in keys.iter().enumerate() {
+        let account = AccountBuilder::new([seed as u8; 32])
+            .storage_mode(AccountStorageMode::Public)
+            .with_auth_component(AuthRpoFalcon512::new(*pub_key_commitment))
+            .with_component(BasicWallet)
+            .build_existing()?;
+        builder.add_account(account.clone())?;
+        accounts.push(account);
+    }
+    let mock_chain = builder.build()?;
+
+    // Register authenticators for the keys of the first two accounts only
+    let mut authenticator_set = AuthenticatorSet::new();
+    let mut requests = Vec::new();
+    for (authenticator, pub_key_commitment) in keys.iter().take(2).cloned() {
+        let (authenticator, requested_keys) = RecordingAuthenticator::new(authenticator);
+        authenticator_set.register(pub_key_commitment, authenticator);
+        requests.push(requested_keys);
+    }
+
+    let execute = async |account: &Account| {
+        let tx_context = mock_chain.build_tx_context(account.id(), &[], &[])?.build()?;
+        TransactionExecutor::new(&tx_context)
+            .with_authenticator(&authenticator_set)
+            .execute_transaction(
+                account.id(),
+                tx_context.tx_inputs().block_header().block_num(),
+                tx_context.input_notes().clone(),
+                tx_context.tx_args().clone(),
+            )
+            .await
+            .map_err(anyhow::Error::from)
+    };
+
+    // Each transaction is signed by the authenticator registered for the key of its account
+    for (account, ((_, pub_key_commitment), requested_keys)) in
+        accounts.iter().zip(keys.iter().zip(&requests))
+    {
+        let executed_tx = execute(account).await?;
+        assert_eq!(executed_tx.final_account().nonce(), account.nonce() + 1u32.into());
+        assert_eq!(*requested_keys.lock().unwrap(), [*pub_key_commitment]);
+    }
+
+    // The key of the third account is not registered and there is no fallback
+    let error = execute(&accounts[2]).await.unwrap_err();
+    assert_matches!(
+        error.downcast_ref::<TransactionExecutorError>(),
+        Some(TransactionExecutorError::TransactionProgramExecutionFailed(
+            ExecutionError::EventError { error, .. }
+        )) => {
+            assert_matches!(
+                error.downcast_ref::<TransactionKernelError>(),
+                Some(TransactionKernelError::SignatureGenerationFailed(
+                    AuthenticationError::UnknownPublicKey(_)
+                ))
+            );
+        }
+    );
+    assert!(requests.iter().all(|requested_keys| requested_keys.lock().unwrap().len() == 1));
+
+    Ok(())
+}
+
 /// Tests 2-of-4 multisig with all possible signer combinations.
 ///
 /// This test verifies that a multisig account with 4 approvers and threshold 2
@@ -226,7 +489,7 @@ async fn test_multisig_2_of_4_all_signer_combinations() -> anyhow::Result<()> {
             .build()?;
 
         let tx_summary = match tx_context_init.execute().await.unwrap_err() {
-            TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+            TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
             error => panic!("expected abort with tx effects: {error:?}"),
         };
 
@@ -293,7 +556,7 @@ async fn test_multisig_replay_protection() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary = match tx_context_init.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
 
@@ -433,7 +696,7 @@ async fn test_multisig_update_signers() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary = match tx_context_init.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
 
@@ -568,7 +831,7 @@ async fn test_multisig_update_signers() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary_new = match tx_context_init_new.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
 
@@ -668,7 +931,7 @@ async fn test_multisig_update_signers_remove_owner() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary = match tx_context_init.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
 
@@ -866,7 +1129,7 @@ async fn test_multisig_new_approvers_cannot_sign_before_update() -> anyhow::Resu
         .build()?;
 
     let tx_summary = match tx_context_init.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
 
@@ -952,7 +1215,7 @@ async fn test_multisig_proc_threshold_overrides() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary = match tx_context.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_summary) => tx_summary,
+        TransactionExecutorError::Unauthorized { summary: tx_summary, .. } => tx_summary,
         error => panic!("expected abort with tx summary: {error:?}"),
     };
 
@@ -1009,7 +1272,7 @@ async fn test_multisig_proc_threshold_overrides() -> anyhow::Result<()> {
         .build()?;
 
     let tx_summary2 = match tx_context_init.execute().await.unwrap_err() {
-        TransactionExecutorError::Unauthorized(tx_effects) => tx_effects,
+        TransactionExecutorError::Unauthorized { summary: tx_effects, .. } => tx_effects,
         error => panic!("expected abort with tx effects: {error:?}"),
     };
     // Get signature from only ONE approver
@@ -1031,7 +1294,7 @@ async fn test_multisig_proc_threshold_overrides() -> anyhow::Result<()> {
 
     let result = tx_context_one_sig.execute().await;
     match result {
-        Err(TransactionExecutorError::Unauthorized(_)) => {
+        Err(TransactionExecutorError::Unauthorized { .. }) => {
             // Expected: transaction should fail with insufficient signatures
         },
         _ => panic!(
@@ -1070,3 +1333,238 @@ async fn test_multisig_proc_threshold_overrides() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Tests rotating an approver out of the multisig configuration.
+///
+/// **Test Flow:**
+/// 1. Create a 2-of-3 multisig account.
+/// 2. Remove the second approver and add a new approver in a transaction signed by the current
+///    approvers.
+/// 3. Verify that a transaction signed by the removed approver is rejected.
+/// 4. Verify that a transaction signed by the new set of approvers succeeds.
+#[tokio::test]
+async fn test_multisig_rotate_approver() -> anyhow::Result<()> {
+    let (_secret_keys, public_keys, authenticators) = setup_keys_and_authenticators(4, 4)?;
+    let multisig_account = create_multisig_account(2, &public_keys[0..3], 10, vec![])?;
+
+    let mut mock_chain = MockChainBuilder::with_accounts([multisig_account.clone()])?.build()?;
+
+    let removed_key = public_keys[1].to_commitment();
+    let new_key = public_keys[3].to_commitment();
+    let tx_script = ScriptBuilder::new(true)
+        .with_dynamically_linked_library(&rpo_falcon_512_multisig_library())?
+        .compile_tx_script(format!(
+            "
+            begin
+                push.{removed_key}
+                call.::remove_approver
+                dropw
+
+                push.{new_key}
+                call.::add_approver
+                dropw
+            end
+            "
+        ))?;
+
+    let rotate_tx = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        Some(tx_script),
+        Word::from([Felt::new(1); 4]),
+        &[(&public_keys[0], &authenticators[0]), (&public_keys[1], &authenticators[1])],
+    )
+    .await?;
+    mock_chain.add_pending_executed_transaction(&rotate_tx)?;
+    mock_chain.prove_next_block()?;
+
+    // The last approver took the index of the removed approver.
+    let rotated_account = mock_chain.committed_account(multisig_account.id())?.clone();
+    let expected_keys =
+        vec![public_keys[0].to_commitment(), public_keys[2].to_commitment(), new_key];
+    assert_eq!(get_public_keys_from_account(&rotated_account), expected_keys);
+    assert_eq!(
+        AuthRpoFalcon512MultisigConfig::read_from_storage(rotated_account.storage(), 0)?,
+        AuthRpoFalcon512MultisigConfig::new(
+            expected_keys.into_iter().map(Into::into).collect(),
+            2
+        )?
+    );
+    assert_eq!(
+        rotated_account.storage().get_map_item(1, Word::from([3u32, 0, 0, 0]))?,
+        Word::empty()
+    );
+
+    // A transaction signed by the removed approver does not reach the threshold.
+    let result = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        None,
+        Word::from([Felt::new(2); 4]),
+        &[(&public_keys[0], &authenticators[0]), (&public_keys[1], &authenticators[1])],
+    )
+    .await;
+    assert!(matches!(result, Err(TransactionExecutorError::Unauthorized { .. })));
+
+    // A transaction signed by the new set of approvers succeeds.
+    let tx = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        None,
+        Word::from([Felt::new(2); 4]),
+        &[(&public_keys[0], &authenticators[0]), (&public_keys[3], &authenticators[3])],
+    )
+    .await?;
+    assert_eq!(tx.account_delta().nonce_delta(), Felt::new(1));
+
+    Ok(())
+}
+
+/// Tests updating the threshold of the multisig configuration and that invalid approver and
+/// threshold updates are rejected.
+#[tokio::test]
+async fn test_multisig_update_threshold_and_invalid_updates() -> anyhow::Result<()> {
+    let (_secret_keys, public_keys, authenticators) = setup_keys_and_authenticators(4, 4)?;
+    let multisig_account = create_multisig_account(2, &public_keys[0..3], 10, vec![])?;
+
+    let mut mock_chain = MockChainBuilder::with_accounts([multisig_account.clone()])?.build()?;
+    let salt = Word::from([Felt::new(1); 4]);
+    let two_signers =
+        [(&public_keys[0], &authenticators[0]), (&public_keys[1], &authenticators[1])];
+    let compile_tx_script = |code: String| {
+        ScriptBuilder::new(true)
+            .with_dynamically_linked_library(&rpo_falcon_512_multisig_library())?
+            .compile_tx_script(code)
+    };
+
+    let existing_key = public_keys[0].to_commitment();
+    let tx_script =
+        compile_tx_script(format!("begin push.{existing_key} call.::add_approver dropw end"))?;
+    let result = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        Some(tx_script),
+        salt,
+        &two_signers,
+    )
+    .await;
+    assert_transaction_executor_error!(result, ERR_MULTISIG_APPROVER_ALREADY_EXISTS);
+
+    let unknown_key = public_keys[3].to_commitment();
+    let tx_script =
+        compile_tx_script(format!("begin push.{unknown_key} call.::remove_approver dropw end"))?;
+    let result = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        Some(tx_script),
+        salt,
+        &two_signers,
+    )
+    .await;
+    assert_transaction_executor_error!(result, ERR_MULTISIG_APPROVER_NOT_FOUND);
+
+    let tx_script = compile_tx_script("begin push.4 call.::update_threshold drop end".into())?;
+    let result = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        Some(tx_script),
+        salt,
+        &two_signers,
+    )
+    .await;
+    assert_transaction_executor_error!(result, ERR_MULTISIG_THRESHOLD_EXCEEDS_TOTAL_WEIGHT);
+
+    // Raise the threshold to 3, which still only requires the signatures of the current threshold.
+    let tx_script = compile_tx_script("begin push.3 call.::update_threshold drop end".into())?;
+    let update_tx = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        Some(tx_script),
+        salt,
+        &two_signers,
+    )
+    .await?;
+    mock_chain.add_pending_executed_transaction(&update_tx)?;
+    mock_chain.prove_next_block()?;
+
+    let updated_account = mock_chain.committed_account(multisig_account.id())?;
+    assert_eq!(updated_account.storage().get_item(0)?, Word::from([3u32, 3, 0, 0]));
+
+    // Two signatures no longer reach the threshold.
+    let salt = Word::from([Felt::new(2); 4]);
+    let result =
+        execute_multisig_tx(&mock_chain, multisig_account.id(), None, salt, &two_signers).await;
+    assert!(matches!(result, Err(TransactionExecutorError::Unauthorized { .. })));
+
+    Ok(())
+}
+
+/// Tests that a weighted multisig authorizes transactions based on the sum of the weights of the
+/// signers rather than the number of signatures.
+///
+/// **Roles:**
+/// - 2 Approvers with weight 1 (ops keys)
+/// - 1 Approver with weight 3 (recovery key)
+/// - Threshold 3
+#[tokio::test]
+async fn test_multisig_weighted_approvers() -> anyhow::Result<()> {
+    let (_secret_keys, public_keys, authenticators) = setup_keys_and_authenticators(3, 3)?;
+    let weights = [1, 1, 3];
+
+    let config = AuthRpoFalcon512MultisigConfig::new_weighted(
+        public_keys
+            .iter()
+            .map(PublicKey::to_commitment)
+            .map(Into::into)
+            .zip(weights)
+            .collect(),
+        3,
+    )?;
+    let multisig_account = AccountBuilder::new([0; 32])
+        .with_auth_component(AuthRpoFalcon512Multisig::new(config.clone())?)
+        .with_component(BasicWallet)
+        .account_type(AccountType::RegularAccountUpdatableCode)
+        .storage_mode(AccountStorageMode::Public)
+        .with_assets(vec![FungibleAsset::mock(10)])
+        .build_existing()?;
+
+    // Extracting the public keys keeps working for weighted multisig accounts.
+    let extracted_pub_keys = get_public_keys_from_account(&multisig_account);
+    assert_eq!(extracted_pub_keys.len(), 3);
+    assert_eq!(
+        AuthRpoFalcon512MultisigConfig::read_from_storage(multisig_account.storage(), 0)?,
+        config
+    );
+
+    let mock_chain = MockChainBuilder::with_accounts([multisig_account.clone()])?.build()?;
+    let salt = Word::from([Felt::new(1); 4]);
+
+    // The two ops keys only have a combined weight of 2.
+    let ops_signers =
+        [(&public_keys[0], &authenticators[0]), (&public_keys[1], &authenticators[1])];
+    let result =
+        execute_multisig_tx(&mock_chain, multisig_account.id(), None, salt, &ops_signers).await;
+    assert!(matches!(result, Err(TransactionExecutorError::Unauthorized { .. })));
+
+    // The recovery key alone meets the threshold.
+    let recovery_signer = [(&public_keys[2], &authenticators[2])];
+    execute_multisig_tx(&mock_chain, multisig_account.id(), None, salt, &recovery_signer).await?;
+
+    // Removing the recovery key would leave a total weight of 2 which does not reach the
+    // threshold.
+    let recovery_key = public_keys[2].to_commitment();
+    let tx_script = ScriptBuilder::new(true)
+        .with_dynamically_linked_library(&rpo_falcon_512_multisig_library())?
+        .compile_tx_script(format!("begin push.{recovery_key} call.::remove_approver dropw end"))?;
+    let result = execute_multisig_tx(
+        &mock_chain,
+        multisig_account.id(),
+        Some(tx_script),
+        salt,
+        &recovery_signer,
+    )
+    .await;
+    assert_transaction_executor_error!(result, ERR_MULTISIG_THRESHOLD_EXCEEDS_TOTAL_WEIGHT);
+
+    Ok(())
+}
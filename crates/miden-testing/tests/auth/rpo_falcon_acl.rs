@@ -15,7 +15,7 @@ use miden_objects::account::{
 use miden_objects::note::Note;
 use miden_objects::transaction::OutputNote;
 use miden_objects::{Felt, FieldElement, Word};
-use miden_testing::{Auth, MockChain};
+use miden_testing::{Auth, MockAuthenticator, MockChain};
 use miden_tx::TransactionExecutorError;
 
 // CONSTANTS
@@ -97,6 +97,7 @@ async fn test_rpo_falcon_acl() -> anyhow::Result<()> {
         allow_unauthorized_input_notes: true,
     }
     .build_component();
+    let authenticator = authenticator.map(MockAuthenticator::from);
 
     let tx_script_with_trigger_1 = r#"
         use.mock::account
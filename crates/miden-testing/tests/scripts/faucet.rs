@@ -3,10 +3,20 @@ extern crate alloc;
 use core::slice;
 use std::sync::Arc;
 
-use miden_lib::account::faucets::{BasicFungibleFaucet, FungibleFaucetExt, NetworkFungibleFaucet};
-use miden_lib::errors::tx_kernel_errors::ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED;
+use miden_lib::account::faucets::{
+    BasicFungibleFaucet,
+    BasicNonFungibleFaucet,
+    FungibleFaucetExt,
+    NetworkFungibleFaucet,
+};
+use miden_lib::errors::note_script_errors::ERR_BASIC_NON_FUNGIBLE_MINT_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED;
+use miden_lib::errors::tx_kernel_errors::{
+    ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND,
+    ERR_FUNGIBLE_ASSET_DISTRIBUTE_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED,
+};
 use miden_lib::note::WellKnownNote;
 use miden_lib::testing::note::NoteBuilder;
+use miden_lib::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::{
     Account,
@@ -16,7 +26,7 @@ use miden_objects::account::{
     AccountType,
 };
 use miden_objects::assembly::DefaultSourceManager;
-use miden_objects::asset::{Asset, FungibleAsset};
+use miden_objects::asset::{Asset, AssetVaultKey, FungibleAsset};
 use miden_objects::note::{
     Note,
     NoteAssets,
@@ -31,7 +41,7 @@ use miden_objects::note::{
 };
 use miden_objects::testing::account_id::ACCOUNT_ID_PRIVATE_SENDER;
 use miden_objects::transaction::{ExecutedTransaction, OutputNote};
-use miden_objects::{Felt, Word};
+use miden_objects::{Felt, Word, ZERO};
 use miden_processor::crypto::RpoRandomCoin;
 use miden_testing::{Auth, MockChain, assert_transaction_executor_error};
 
@@ -681,3 +691,264 @@ async fn network_faucet_burn() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+// TESTS BASIC NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates the transaction script which mints a non-fungible asset from the provided metadata
+/// into a note with the provided recipient.
+fn create_non_fungible_mint_script_code(
+    metadata: Word,
+    recipient: Word,
+    tag: NoteTag,
+    note_type: NoteType,
+) -> String {
+    format!(
+        "
+            begin
+                # pad the stack before call
+                padw
+
+                push.{recipient}
+                push.{note_execution_hint}
+                push.{note_type}
+                push.0
+                push.{tag}
+                push.{metadata}
+                # => [METADATA, tag, aux, note_type, execution_hint, RECIPIENT, pad(4)]
+
+                call.::miden::contracts::faucets::basic_non_fungible::mint
+                # => [pad(16)]
+
+                # truncate the stack
+                dropw dropw dropw dropw
+            end
+            ",
+        note_type = note_type as u8,
+        note_execution_hint = Felt::from(NoteExecutionHint::always()),
+        tag = u32::from(tag),
+    )
+}
+
+/// Creates a public note which burns the provided assets when consumed by the basic non-fungible
+/// faucet with the provided ID.
+fn create_non_fungible_burn_note(faucet_id: AccountId, assets: Vec<Asset>) -> anyhow::Result<Note> {
+    let burn_note_script = ScriptBuilder::default().compile_note_script(
+        "
+        begin
+            dropw
+            call.::miden::contracts::faucets::basic_non_fungible::burn
+            dropw
+        end
+        ",
+    )?;
+    let tag = NoteTag::for_local_use_case(0, 0)?;
+    let recipient =
+        NoteRecipient::new(Word::from([5, 6, 7, 8u32]), burn_note_script, NoteInputs::new(vec![])?);
+
+    Ok(Note::new(
+        NoteAssets::new(assets)?,
+        NoteMetadata::new(faucet_id, NoteType::Public, tag, NoteExecutionHint::always(), ZERO)?,
+        recipient,
+    ))
+}
+
+/// Tests that a non-fungible asset minted by a basic non-fungible faucet is tracked in the faucet's
+/// reserved slot and that burning it removes it again.
+#[tokio::test]
+async fn basic_non_fungible_faucet_mint_and_burn() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_non_fungible_faucet(Auth::BasicAuth, "NFT", 10)?;
+    let mut mock_chain = builder.build()?;
+
+    let metadata = Word::from([1, 2, 3, 4u32]);
+    let asset = BasicNonFungibleFaucet::asset_from_metadata(faucet.id(), metadata)?;
+
+    // Mint the asset directly into a note which burns it when consumed by the faucet.
+    let burn_note = create_non_fungible_burn_note(faucet.id(), vec![asset.into()])?;
+
+    // MINT
+    // --------------------------------------------------------------------------------------------
+    let tx_script =
+        ScriptBuilder::default().compile_tx_script(create_non_fungible_mint_script_code(
+            metadata,
+            burn_note.recipient().digest(),
+            burn_note.metadata().tag(),
+            NoteType::Public,
+        ))?;
+    let executed_mint = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .extend_expected_output_notes(vec![OutputNote::Full(burn_note.clone())])
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_mint.output_notes().get_note(0).id(), burn_note.id());
+
+    let mut faucet_after_mint = faucet.clone();
+    faucet_after_mint.apply_delta(executed_mint.account_delta())?;
+    assert_eq!(
+        faucet_after_mint
+            .storage()
+            .get_map_item(FAUCET_STORAGE_DATA_SLOT, AssetVaultKey::from(asset).into())?,
+        Word::from(asset)
+    );
+    // The faucet component is added after the auth component, so the number of minted assets is
+    // stored in slot 3.
+    assert_eq!(faucet_after_mint.storage().get_item(3)?[3], Felt::new(1));
+
+    mock_chain.add_pending_executed_transaction(&executed_mint)?;
+    mock_chain.prove_next_block()?;
+
+    // BURN
+    // --------------------------------------------------------------------------------------------
+    let executed_burn = mock_chain
+        .build_tx_context(faucet.id(), &[burn_note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    let mut faucet_after_burn = faucet_after_mint.clone();
+    faucet_after_burn.apply_delta(executed_burn.account_delta())?;
+    assert_eq!(
+        faucet_after_burn
+            .storage()
+            .get_map_item(FAUCET_STORAGE_DATA_SLOT, AssetVaultKey::from(asset).into())?,
+        Word::empty()
+    );
+    // Burning the asset decrements the number of minted assets again.
+    assert_eq!(faucet_after_burn.storage().get_item(3)?[3], ZERO);
+
+    Ok(())
+}
+
+/// Tests that a non-fungible asset minted by a basic non-fungible faucet into a P2ID note ends up
+/// in the vault of the wallet consuming the note.
+#[tokio::test]
+async fn basic_non_fungible_faucet_mint_to_wallet() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_non_fungible_faucet(Auth::BasicAuth, "NFT", 10)?;
+    let wallet = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let mut mock_chain = builder.build()?;
+
+    let metadata = Word::from([1, 2, 3, 4u32]);
+    let asset = BasicNonFungibleFaucet::asset_from_metadata(faucet.id(), metadata)?;
+    let p2id_note = create_p2id_note_exact(
+        faucet.id(),
+        wallet.id(),
+        vec![asset.into()],
+        NoteType::Public,
+        ZERO,
+        Word::from([5, 6, 7, 8u32]),
+    )?;
+
+    // MINT
+    // --------------------------------------------------------------------------------------------
+    let tx_script =
+        ScriptBuilder::default().compile_tx_script(create_non_fungible_mint_script_code(
+            metadata,
+            p2id_note.recipient().digest(),
+            p2id_note.metadata().tag(),
+            NoteType::Public,
+        ))?;
+    let executed_mint = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .extend_expected_output_notes(vec![OutputNote::Full(p2id_note.clone())])
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_mint.output_notes().get_note(0).id(), p2id_note.id());
+
+    mock_chain.add_pending_executed_transaction(&executed_mint)?;
+    mock_chain.prove_next_block()?;
+
+    // CONSUME
+    // --------------------------------------------------------------------------------------------
+    let executed_consume = mock_chain
+        .build_tx_context(wallet.id(), &[p2id_note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    let mut wallet_after_consume = wallet.clone();
+    wallet_after_consume.apply_delta(executed_consume.account_delta())?;
+    assert_eq!(wallet_after_consume.vault().num_assets(), 1);
+    assert!(wallet_after_consume.vault().has_non_fungible_asset(asset)?);
+
+    Ok(())
+}
+
+/// Tests that burning a non-fungible asset which was never issued by the basic non-fungible faucet
+/// fails.
+#[tokio::test]
+async fn basic_non_fungible_faucet_burn_fails_for_unissued_asset() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_non_fungible_faucet(Auth::BasicAuth, "NFT", 10)?;
+
+    let asset =
+        BasicNonFungibleFaucet::asset_from_metadata(faucet.id(), Word::from([1, 2, 3, 4u32]))?;
+    let burn_note = create_non_fungible_burn_note(faucet.id(), vec![asset.into()])?;
+    builder.add_output_note(OutputNote::Full(burn_note.clone()));
+    let mock_chain = builder.build()?;
+
+    let result = mock_chain
+        .build_tx_context(faucet.id(), &[burn_note.id()], &[])?
+        .build()?
+        .execute()
+        .await;
+
+    assert_transaction_executor_error!(result, ERR_FAUCET_NON_FUNGIBLE_ASSET_TO_BURN_NOT_FOUND);
+
+    Ok(())
+}
+
+/// Tests that minting more non-fungible assets than the max supply of the faucet fails.
+#[tokio::test]
+async fn basic_non_fungible_faucet_mint_fails_exceeds_max_supply() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_non_fungible_faucet(Auth::BasicAuth, "NFT", 1)?;
+    let mut mock_chain = builder.build()?;
+
+    let tag = NoteTag::for_local_use_case(0, 0)?;
+    let recipient = Word::from([0, 1, 2, 3u32]);
+
+    let tx_script =
+        ScriptBuilder::default().compile_tx_script(create_non_fungible_mint_script_code(
+            Word::from([1, 2, 3, 4u32]),
+            recipient,
+            tag,
+            NoteType::Private,
+        ))?;
+    let executed_mint = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await?;
+    mock_chain.add_pending_executed_transaction(&executed_mint)?;
+    mock_chain.prove_next_block()?;
+
+    let tx_script =
+        ScriptBuilder::default().compile_tx_script(create_non_fungible_mint_script_code(
+            Word::from([5, 6, 7, 8u32]),
+            recipient,
+            tag,
+            NoteType::Private,
+        ))?;
+    let result = mock_chain
+        .build_tx_context(faucet.id(), &[], &[])?
+        .tx_script(tx_script)
+        .build()?
+        .execute()
+        .await;
+
+    assert_transaction_executor_error!(
+        result,
+        ERR_BASIC_NON_FUNGIBLE_MINT_WOULD_CAUSE_MAX_SUPPLY_TO_BE_EXCEEDED
+    );
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use assert_matches::assert_matches;
+use miden_lib::transaction::{TransactionIntentBuilder, TransactionIntentError};
+use miden_objects::Word;
+use miden_objects::asset::{Asset, FungibleAsset};
+use miden_objects::crypto::rand::RpoRandomCoin;
+use miden_objects::note::NoteType;
+use miden_objects::transaction::{ExecutedTransaction, OutputNote, TransactionArgs};
+use miden_testing::{Auth, MockChain, TransactionContext};
+use miden_tx::TransactionExecutor;
+
+/// Executes a transaction with the provided arguments against the account of the provided context
+/// via [`TransactionExecutor::execute_transaction`].
+async fn execute_intent(
+    tx_context: &TransactionContext,
+    tx_args: TransactionArgs,
+) -> anyhow::Result<ExecutedTransaction> {
+    let executor = TransactionExecutor::new(tx_context)
+        .with_authenticator(tx_context.authenticator().unwrap());
+
+    Ok(executor
+        .execute_transaction(
+            tx_context.account().id(),
+            tx_context.tx_inputs().block_header().block_num(),
+            tx_context.tx_inputs().input_notes().clone(),
+            tx_args,
+        )
+        .await?)
+}
+
+/// Tests that a pay intent creates the expected P2ID note, and that the note can be consumed by
+/// the recipient via a consume intent.
+#[tokio::test]
+async fn pay_intent_creates_note_consumable_by_recipient() -> anyhow::Result<()> {
+    let sent_asset: Asset = FungibleAsset::mock(40);
+
+    let mut builder = MockChain::builder();
+    let mut sender =
+        builder.add_existing_wallet_with_assets(Auth::BasicAuth, [FungibleAsset::mock(100)])?;
+    let mut recipient = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let mut mock_chain = builder.build()?;
+
+    let intent =
+        TransactionIntentBuilder::new(&sender, RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])))
+            .pay(recipient.id(), vec![sent_asset], NoteType::Public)?
+            .with_expiration_delta(10)
+            .build()?;
+    let [OutputNote::Full(expected_note)] = intent.expected_output_notes() else {
+        panic!("pay intent should create exactly one full output note");
+    };
+    let expected_note = expected_note.clone();
+
+    let tx_context = mock_chain.build_tx_context(sender.id(), &[], &[])?.build()?;
+    let executed_transaction = execute_intent(&tx_context, intent.tx_args().clone()).await?;
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(
+        executed_transaction.output_notes().get_note(0),
+        &OutputNote::Full(expected_note.clone())
+    );
+    sender.apply_delta(executed_transaction.account_delta())?;
+    assert_eq!(sender.vault().get_balance(sent_asset.unwrap_fungible().faucet_id())?, 60);
+
+    mock_chain.add_pending_executed_transaction(&executed_transaction)?;
+    mock_chain.prove_next_block()?;
+
+    // the recipient consumes the created note
+    let intent =
+        TransactionIntentBuilder::new(&recipient, RpoRandomCoin::new(Word::from([5, 6, 7, 8u32])))
+            .consume(vec![expected_note.id()])
+            .build()?;
+    assert!(intent.tx_args().tx_script().is_none());
+
+    let tx_context = mock_chain
+        .build_tx_context(recipient.id(), intent.input_note_ids(), &[])?
+        .build()?;
+    let executed_transaction = execute_intent(&tx_context, intent.tx_args().clone()).await?;
+
+    recipient.apply_delta(executed_transaction.account_delta())?;
+    assert_eq!(recipient.vault().assets().collect::<Vec<_>>(), vec![sent_asset]);
+
+    Ok(())
+}
+
+/// Tests that a mint intent distributes the minted asset via the expected P2ID note.
+#[tokio::test]
+async fn mint_intent_creates_note_with_minted_asset() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let faucet = builder.add_existing_basic_faucet(Auth::BasicAuth, "POL", 200, None)?;
+    let recipient = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let mock_chain = builder.build()?;
+
+    // a wallet cannot mint assets and a faucet cannot pay assets
+    let rng = RpoRandomCoin::new(Word::from([1, 2, 3, 4u32]));
+    assert_matches!(
+        TransactionIntentBuilder::new(&recipient, rng)
+            .mint(faucet.id(), 10, NoteType::Private)
+            .err(),
+        Some(TransactionIntentError::MintFromNonFaucet(account_id)) if account_id == recipient.id()
+    );
+    assert_matches!(
+        TransactionIntentBuilder::new(&faucet, rng)
+            .pay(recipient.id(), vec![FungibleAsset::new(faucet.id(), 10)?.into()], NoteType::Private)
+            .err(),
+        Some(TransactionIntentError::PayFromFaucet(account_id)) if account_id == faucet.id()
+    );
+
+    let intent = TransactionIntentBuilder::new(&faucet, rng)
+        .mint(recipient.id(), 10, NoteType::Private)?
+        .build()?;
+    let [OutputNote::Full(expected_note)] = intent.expected_output_notes() else {
+        panic!("mint intent should create exactly one full output note");
+    };
+    assert_eq!(
+        expected_note.assets().iter().collect::<Vec<_>>(),
+        vec![&Asset::from(FungibleAsset::new(faucet.id(), 10)?)]
+    );
+
+    let tx_context = mock_chain.build_tx_context(faucet.id(), &[], &[])?.build()?;
+    let executed_transaction = execute_intent(&tx_context, intent.tx_args().clone()).await?;
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(
+        executed_transaction.output_notes().get_note(0),
+        &OutputNote::Full(expected_note.clone())
+    );
+
+    Ok(())
+}
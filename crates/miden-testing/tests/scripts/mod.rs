@@ -1,6 +1,8 @@
 mod faucet;
 mod fee;
+mod intent;
 mod p2id;
 mod p2ide;
+mod p2mid;
 mod send_note;
 mod swap;
@@ -1,10 +1,11 @@
+use miden_lib::account::interface::AccountInterface;
 use miden_lib::errors::note_script_errors::ERR_P2ID_TARGET_ACCT_MISMATCH;
-use miden_lib::note::create_p2id_note;
+use miden_lib::note::{create_asset_split_note, create_p2id_note};
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::Account;
 use miden_objects::asset::{Asset, AssetVault, FungibleAsset};
 use miden_objects::crypto::rand::RpoRandomCoin;
-use miden_objects::note::NoteType;
+use miden_objects::note::{NoteType, PartialNote};
 use miden_objects::testing::account_id::{
     ACCOUNT_ID_PRIVATE_FUNGIBLE_FAUCET,
     ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_2,
@@ -280,3 +281,82 @@ async fn test_create_consume_multiple_notes() -> anyhow::Result<()> {
     assert_eq!(account.vault().get_balance(FungibleAsset::mock_issuer())?, 5);
     Ok(())
 }
+
+/// Consumes a note, keeps part of its asset and returns the rest to the sender in a change note
+/// created by `create_asset_split_note`.
+#[tokio::test]
+async fn consume_note_and_return_change() -> anyhow::Result<()> {
+    let mut builder = MockChain::builder();
+    let mut account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let sender = ACCOUNT_ID_SENDER.try_into()?;
+    let received_asset = FungibleAsset::mock(100).unwrap_fungible();
+    let input_note =
+        builder.add_p2id_note(sender, account.id(), &[received_asset.into()], NoteType::Public)?;
+    let mock_chain = builder.build()?;
+
+    let (change_note, kept_asset) = create_asset_split_note(
+        &account,
+        received_asset,
+        30,
+        sender,
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )?;
+    assert_eq!(kept_asset, FungibleAsset::new(received_asset.faucet_id(), 30)?);
+    assert_eq!(change_note.recipient_account_id(), Some(sender));
+    assert_eq!(
+        change_note.assets().iter().collect::<Vec<_>>(),
+        [&Asset::from(FungibleAsset::new(received_asset.faucet_id(), 70)?)]
+    );
+
+    let tx_script = AccountInterface::from(&account).build_send_notes_script(
+        &[PartialNote::from(change_note.clone())],
+        None,
+        false,
+    )?;
+    let tx_context = mock_chain
+        .build_tx_context(account.id(), &[input_note.id()], &[])?
+        .extend_expected_output_notes(vec![OutputNote::Full(change_note)])
+        .tx_script(tx_script)
+        .build()?;
+    let executed_transaction = tx_context.execute().await?;
+
+    account.apply_delta(executed_transaction.account_delta())?;
+    assert_eq!(account.vault().get_balance(received_asset.faucet_id())?, kept_asset.amount());
+
+    // there is no change to return if the whole asset is kept
+    assert!(
+        create_asset_split_note(
+            &account,
+            received_asset,
+            received_asset.amount(),
+            sender,
+            NoteType::Public,
+            Felt::new(0),
+            &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+        )
+        .is_err()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn p2id_note_recipient_account_id() -> anyhow::Result<()> {
+    let target = ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into()?;
+    let note = create_p2id_note(
+        ACCOUNT_ID_SENDER.try_into()?,
+        target,
+        vec![FungibleAsset::mock(10)],
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )?;
+
+    assert_eq!(note.recipient_account_id(), Some(target));
+    assert_eq!(OutputNote::Full(note.clone()).recipient_account_id(), Some(target));
+    assert_eq!(OutputNote::Header(*note.header()).recipient_account_id(), None);
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use miden_lib::errors::note_script_errors::ERR_P2MID_TARGET_ACCT_MISMATCH;
+use miden_lib::note::{WellKnownNote, create_p2mid_note};
+use miden_objects::account::{AccountId, AccountIdVersion, AccountStorageMode, AccountType};
+use miden_objects::asset::{Asset, FungibleAsset};
+use miden_objects::crypto::rand::RpoRandomCoin;
+use miden_objects::note::NoteType;
+use miden_objects::testing::account_id::ACCOUNT_ID_SENDER;
+use miden_objects::transaction::OutputNote;
+use miden_objects::{Felt, NoteError, Word};
+use miden_testing::{Auth, MockChain, assert_transaction_executor_error};
+
+/// Tests that a P2MID note can be consumed by the second of its targets, but not by an account
+/// which is not one of the targets.
+#[tokio::test]
+async fn p2mid_script_consumable_by_any_target() -> anyhow::Result<()> {
+    let fungible_asset: Asset = FungibleAsset::mock(123);
+
+    let mut builder = MockChain::builder();
+
+    // Create accounts
+    let sender_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let target_account_1 = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let target_account_2 = builder.add_existing_wallet(Auth::BasicAuth)?;
+    let malicious_account = builder.add_existing_wallet(Auth::BasicAuth)?;
+
+    // Create the note
+    let note = create_p2mid_note(
+        sender_account.id(),
+        vec![target_account_1.id(), target_account_2.id()],
+        vec![fungible_asset],
+        NoteType::Public,
+        Felt::new(0),
+        &mut RpoRandomCoin::new(Word::from([1, 2, 3, 4u32])),
+    )?;
+    builder.add_output_note(OutputNote::Full(note.clone()));
+
+    let mock_chain = builder.build()?;
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+
+    let executed_transaction = mock_chain
+        .build_tx_context(target_account_2.id(), &[note.id()], &[])?
+        .build()?
+        .execute()
+        .await?;
+
+    assert_eq!(executed_transaction.account_delta().vault().fungible().num_assets(), 1);
+    assert_eq!(
+        executed_transaction
+            .account_delta()
+            .vault()
+            .fungible()
+            .amount(&FungibleAsset::mock_issuer()),
+        Some(123)
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+
+    let executed_transaction_2 = mock_chain
+        .build_tx_context(malicious_account.id(), &[], &[note])?
+        .build()?
+        .execute()
+        .await;
+
+    assert_transaction_executor_error!(executed_transaction_2, ERR_P2MID_TARGET_ACCT_MISMATCH);
+
+    Ok(())
+}
+
+/// Tests that a P2MID note cannot be created without targets or with more than the maximum number
+/// of targets.
+#[test]
+fn p2mid_note_rejects_invalid_number_of_targets() -> anyhow::Result<()> {
+    let sender = ACCOUNT_ID_SENDER.try_into()?;
+    let targets: Vec<AccountId> = (0..=WellKnownNote::P2MID_MAX_NUM_TARGETS as u8)
+        .map(|i| {
+            AccountId::dummy(
+                [i; 15],
+                AccountIdVersion::Version0,
+                AccountType::RegularAccountUpdatableCode,
+                AccountStorageMode::Private,
+            )
+        })
+        .collect();
+    let mut rng = RpoRandomCoin::new(Word::from([1, 2, 3, 4u32]));
+
+    let create_note = |targets: Vec<AccountId>, rng: &mut RpoRandomCoin| {
+        create_p2mid_note(
+            sender,
+            targets,
+            vec![FungibleAsset::mock(10)],
+            NoteType::Public,
+            Felt::new(0),
+            rng,
+        )
+    };
+
+    assert!(matches!(create_note(vec![], &mut rng), Err(NoteError::Other { .. })));
+    assert!(matches!(create_note(targets.clone(), &mut rng), Err(NoteError::Other { .. })));
+
+    let max_targets = targets[..WellKnownNote::P2MID_MAX_NUM_TARGETS].to_vec();
+    let note = create_note(max_targets.clone(), &mut rng)?;
+    assert_eq!(note.inputs().num_values() as usize, 1 + 2 * max_targets.len());
+
+    Ok(())
+}
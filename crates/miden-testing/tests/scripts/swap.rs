@@ -1,5 +1,6 @@
 use anyhow::Context;
 use miden_lib::note::utils;
+use miden_lib::note::utils::predict_output_note_id;
 use miden_lib::utils::ScriptBuilder;
 use miden_objects::account::{Account, AccountId, AccountStorageMode, AccountType};
 use miden_objects::asset::{Asset, FungibleAsset, NonFungibleAsset};
@@ -304,6 +305,50 @@ async fn settle_coincidence_of_wants() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Tests that the ID of the payback note of a SWAP note can be predicted from its details before
+/// the SWAP note is consumed.
+#[tokio::test]
+async fn predict_swap_payback_note_id() -> anyhow::Result<()> {
+    let SwapTestSetup {
+        mock_chain,
+        target_account,
+        requested_asset,
+        swap_note,
+        payback_note,
+        ..
+    } = setup_swap_test(NoteType::Private)?;
+
+    let predicted_payback_note_id = predict_output_note_id(
+        payback_note.serial_num(),
+        payback_note.script().clone(),
+        payback_note.inputs().clone(),
+        &NoteAssets::new(vec![requested_asset])?,
+    );
+
+    let consume_swap_note_tx = mock_chain
+        .build_tx_context(target_account.id(), &[swap_note.id()], &[])
+        .context("failed to build tx context")?
+        .build()?
+        .execute()
+        .await?;
+
+    // The SWAP note must have produced exactly the payback note whose ID was predicted.
+    assert_eq!(consume_swap_note_tx.output_notes().num_notes(), 1);
+    let output_payback_note = consume_swap_note_tx
+        .output_notes()
+        .iter()
+        .find(|note| note.id() == predicted_payback_note_id)
+        .context("payback note with the predicted ID was not produced")?;
+    assert_eq!(output_payback_note.id(), payback_note.id());
+    assert_eq!(output_payback_note.metadata().sender(), target_account.id());
+    assert_eq!(
+        output_payback_note.assets().context("payback note assets should be known")?,
+        &NoteAssets::new(vec![requested_asset])?
+    );
+
+    Ok(())
+}
+
 struct SwapTestSetup {
     mock_chain: MockChain,
     sender_account: Account,
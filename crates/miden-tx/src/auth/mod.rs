@@ -1,5 +1,6 @@
 mod tx_authenticator;
 pub use tx_authenticator::{
+    AuthenticatorSet,
     BasicAuthenticator,
     SigningInputs,
     TransactionAuthenticator,
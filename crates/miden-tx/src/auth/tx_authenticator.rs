@@ -3,6 +3,7 @@ use alloc::collections::BTreeMap;
 use alloc::string::ToString;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
+use core::pin::Pin;
 
 use miden_objects::account::{AuthSecretKey, PublicKeyCommitment, Signature};
 use miden_objects::crypto::SequentialCommit;
@@ -242,6 +243,108 @@ impl<R: Rng + Send + Sync> TransactionAuthenticator for BasicAuthenticator<R> {
     }
 }
 
+// AUTHENTICATOR SET
+// ================================================================================================
+
+/// An object-safe counterpart of [`TransactionAuthenticator`] which allows storing authenticators
+/// of different types in an [`AuthenticatorSet`].
+trait ErasedAuthenticator: Send + Sync {
+    fn get_signature_erased<'a>(
+        &'a self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &'a SigningInputs,
+    ) -> Pin<Box<dyn FutureMaybeSend<Result<Signature, AuthenticationError>> + 'a>>;
+}
+
+impl<T: TransactionAuthenticator + Send + Sync> ErasedAuthenticator for T {
+    fn get_signature_erased<'a>(
+        &'a self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &'a SigningInputs,
+    ) -> Pin<Box<dyn FutureMaybeSend<Result<Signature, AuthenticationError>> + 'a>> {
+        Box::pin(self.get_signature(pub_key_commitment, signing_inputs))
+    }
+}
+
+/// A [`TransactionAuthenticator`] that routes signature requests to one of several authenticators
+/// based on the requested public key commitment.
+///
+/// This allows combining authenticators backed by different key stores (e.g. a local key store
+/// and a hardware wallet) for a single transaction, such as one that requires signatures from
+/// multiple approvers of a multisig account.
+///
+/// Requests for public key commitments that have not been registered are routed to the fallback
+/// authenticator, if one is set.
+#[derive(Clone, Default)]
+pub struct AuthenticatorSet {
+    /// pub_key_commitment |-> authenticator mapping
+    routes: BTreeMap<Word, Arc<dyn ErasedAuthenticator>>,
+    fallback: Option<Arc<dyn ErasedAuthenticator>>,
+}
+
+impl AuthenticatorSet {
+    /// Returns a new, empty [`AuthenticatorSet`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the given authenticator to serve signature requests for the provided public key
+    /// commitment.
+    ///
+    /// If an authenticator was already registered for this public key commitment, it is replaced.
+    pub fn register(
+        &mut self,
+        pub_key_commitment: PublicKeyCommitment,
+        authenticator: impl TransactionAuthenticator + Send + Sync + 'static,
+    ) {
+        self.routes.insert(pub_key_commitment.into(), Arc::new(authenticator));
+    }
+
+    /// Sets the authenticator which serves signature requests for public key commitments that
+    /// have not been registered explicitly.
+    pub fn with_fallback(
+        mut self,
+        authenticator: impl TransactionAuthenticator + Send + Sync + 'static,
+    ) -> Self {
+        self.fallback = Some(Arc::new(authenticator));
+        self
+    }
+
+    /// Returns `true` if signature requests for the provided public key commitment are routed to
+    /// an explicitly registered authenticator.
+    pub fn contains(&self, pub_key_commitment: PublicKeyCommitment) -> bool {
+        self.routes.contains_key(&Word::from(pub_key_commitment))
+    }
+}
+
+impl TransactionAuthenticator for AuthenticatorSet {
+    /// Gets a signature over a message from the authenticator registered for the given public key
+    /// commitment, or from the fallback authenticator if none is registered.
+    ///
+    /// # Errors
+    /// If no authenticator is registered for the public key commitment and no fallback is set,
+    /// [`AuthenticationError::UnknownPublicKey`] is returned.
+    fn get_signature(
+        &self,
+        pub_key_commitment: PublicKeyCommitment,
+        signing_inputs: &SigningInputs,
+    ) -> impl FutureMaybeSend<Result<Signature, AuthenticationError>> {
+        let pub_key: Word = pub_key_commitment.into();
+        let authenticator = self.routes.get(&pub_key).or(self.fallback.as_ref());
+
+        async move {
+            match authenticator {
+                Some(authenticator) => {
+                    authenticator.get_signature_erased(pub_key_commitment, signing_inputs).await
+                },
+                None => Err(AuthenticationError::UnknownPublicKey(format!(
+                    "no authenticator is registered for public key {pub_key}",
+                ))),
+            }
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
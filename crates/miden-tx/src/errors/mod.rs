@@ -2,15 +2,16 @@ use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::error::Error;
+use core::time::Duration;
 
 use miden_lib::transaction::TransactionAdviceMapMismatch;
 use miden_objects::account::AccountId;
 use miden_objects::assembly::diagnostics::reporting::PrintDiagnostic;
-use miden_objects::asset::AssetVaultKey;
+use miden_objects::asset::{AssetVaultKey, FungibleAsset};
 use miden_objects::block::BlockNumber;
 use miden_objects::crypto::merkle::SmtProofError;
 use miden_objects::note::{NoteId, NoteMetadata};
-use miden_objects::transaction::TransactionSummary;
+use miden_objects::transaction::{TransactionId, TransactionSummary};
 use miden_objects::{
     AccountDeltaError,
     AccountError,
@@ -55,6 +56,14 @@ pub(crate) enum TransactionCheckerError {
         failed_note_index: usize,
         error: TransactionExecutorError,
     },
+    #[error(
+        "note at index {note_index} took {num_cycles} cycles which exceeds the per-note budget of {max_cycles} cycles"
+    )]
+    NoteCycleBudgetExceeded {
+        note_index: usize,
+        num_cycles: u32,
+        max_cycles: u32,
+    },
 }
 
 impl From<TransactionCheckerError> for TransactionExecutorError {
@@ -64,6 +73,9 @@ impl From<TransactionCheckerError> for TransactionExecutorError {
             TransactionCheckerError::PrologueExecution(error) => error,
             TransactionCheckerError::EpilogueExecution(error) => error,
             TransactionCheckerError::NoteExecution { error, .. } => error,
+            TransactionCheckerError::NoteCycleBudgetExceeded { num_cycles, max_cycles, .. } => {
+                TransactionExecutorError::NoteCycleBudgetExceeded { num_cycles, max_cycles }
+            },
         }
     }
 }
@@ -75,12 +87,15 @@ impl From<TransactionCheckerError> for TransactionExecutorError {
 pub enum TransactionExecutorError {
     #[error("the advice map contains conflicting map entries")]
     ConflictingAdviceMapEntry(#[source] TransactionAdviceMapMismatch),
+    #[error("transaction execution did not complete within {0:?}")]
+    ExecutionTimeout(Duration),
     #[error("failed to fetch transaction inputs from the data store")]
     FetchTransactionInputsFailed(#[source] DataStoreError),
     #[error("foreign account inputs for ID {0} are not anchored on reference block")]
     ForeignAccountNotAnchoredInReference(AccountId),
     #[error(
-        "execution options' cycles must be between {min_cycles} and {max_cycles}, but found {actual}"
+        "execution options' cycles must be between {min_cycles} and {max_cycles}, but found {actual} which {}",
+        describe_cycles_bound_violation(*.min_cycles, *.max_cycles, *.actual)
     )]
     InvalidExecutionOptionsCycles {
         min_cycles: u32,
@@ -108,9 +123,17 @@ pub enum TransactionExecutorError {
     #[error("expected account nonce delta to be {expected}, found {actual}")]
     InconsistentAccountNonceDelta { expected: Felt, actual: Felt },
     #[error(
-        "native asset amount {account_balance} in the account vault is not sufficient to cover the transaction fee of {tx_fee}"
+        "native asset amount {account_balance} in the vault of account {account_id} is not sufficient to cover the transaction fee of {} issued by faucet {}",
+        tx_fee.amount(),
+        tx_fee.faucet_id()
     )]
-    InsufficientFee { account_balance: u64, tx_fee: u64 },
+    InsufficientFee {
+        account_id: AccountId,
+        account_balance: u64,
+        tx_fee: FungibleAsset,
+    },
+    #[error("transaction fee of {fee} exceeds the maximum fee of {limit}")]
+    FeeExceedsLimit { fee: u64, limit: u64 },
     #[error("account witness provided for account ID {0} is invalid")]
     InvalidAccountWitness(AccountId, #[source] SmtProofError),
     #[error(
@@ -127,12 +150,90 @@ pub enum TransactionExecutorError {
     TransactionProgramExecutionFailed(ExecutionError),
     /// This variant can be matched on to get the summary of a transaction for signing purposes.
     // It is boxed to avoid triggering clippy::result_large_err for functions that return this type.
-    #[error("transaction is unauthorized with summary {0:?}")]
-    Unauthorized(Box<TransactionSummary>),
+    #[error("transaction against account {account_id} is unauthorized with summary {summary:?}")]
+    Unauthorized {
+        account_id: AccountId,
+        summary: Box<TransactionSummary>,
+    },
     #[error(
         "failed to respond to signature requested since no authenticator is assigned to the host"
     )]
     MissingAuthenticator,
+    /// This variant is only returned by the
+    /// [`NoteConsumptionChecker`](crate::NoteConsumptionChecker) for notes whose execution exceeds
+    /// the [`NoteCheckerConfig::per_note_max_cycles`](crate::NoteCheckerConfig::per_note_max_cycles)
+    /// budget.
+    #[error(
+        "note execution took at least {num_cycles} cycles which exceeds the per-note budget of {max_cycles} cycles"
+    )]
+    NoteCycleBudgetExceeded { num_cycles: u32, max_cycles: u32 },
+}
+
+impl TransactionExecutorError {
+    /// Returns the error code of the failed assertion which caused the execution of the transaction
+    /// program to fail, or `None` if the error was not caused by a failed assertion.
+    ///
+    /// The message of errors raised by the transaction kernel can be looked up using
+    /// [`message_for_code`](miden_lib::errors::tx_kernel_errors::message_for_code).
+    pub fn kernel_error_code(&self) -> Option<Felt> {
+        match self {
+            Self::TransactionProgramExecutionFailed(ExecutionError::FailedAssertion {
+                err_code,
+                ..
+            }) => Some(*err_code),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the error may be resolved by retrying the transaction with fresh data,
+    /// and `false` if retrying the same transaction is expected to fail again.
+    ///
+    /// Retryable errors are caused by stale or not yet available data, e.g. an input note which
+    /// was created after the reference block of the transaction, a foreign account whose inputs
+    /// are not anchored on the reference block or a data store which was not able to provide a
+    /// block. Errors caused by the transaction itself, e.g. [`Self::Unauthorized`] or
+    /// [`Self::InsufficientFee`], are not retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::NoteBlockPastReferenceBlock(..)
+            | Self::ForeignAccountNotAnchoredInReference(_)
+            | Self::InvalidAccountWitness(..) => true,
+            Self::FetchTransactionInputsFailed(err) => {
+                matches!(err, DataStoreError::BlockNotFound(_) | DataStoreError::Other { .. })
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the error was caused by the authentication of the transaction, i.e. if
+    /// the transaction is not sufficiently authorized, no authenticator is available or the
+    /// authenticator failed to generate a signature.
+    pub fn is_auth_error(&self) -> bool {
+        match self {
+            Self::Unauthorized { .. } | Self::MissingAuthenticator => true,
+            Self::TransactionProgramExecutionFailed(ExecutionError::EventError {
+                error, ..
+            }) => {
+                matches!(
+                    error.downcast_ref::<TransactionKernelError>(),
+                    Some(TransactionKernelError::SignatureGenerationFailed(_))
+                )
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Describes which bound of the allowed range of execution cycles the provided number of cycles
+/// violates and by how much.
+fn describe_cycles_bound_violation(min_cycles: u32, max_cycles: u32, actual: u32) -> String {
+    if actual < min_cycles {
+        format!("is {} below the minimum", min_cycles - actual)
+    } else if actual > max_cycles {
+        format!("is {} above the maximum", actual - max_cycles)
+    } else {
+        String::from("is within the bounds")
+    }
 }
 
 // TRANSACTION PROVER ERROR
@@ -142,12 +243,71 @@ pub enum TransactionExecutorError {
 pub enum TransactionProverError {
     #[error("failed to apply account delta")]
     AccountDeltaApplyFailed(#[source] AccountError),
+    #[error("transaction inputs of the executed transaction are invalid")]
+    InvalidTransactionInputs(#[source] TransactionInputError),
+    #[error(
+        "account delta is for account {actual} but the transaction was executed against account {expected}"
+    )]
+    InconsistentAccountDeltaId { expected: AccountId, actual: AccountId },
+    #[error(
+        "account delta commitment in the transaction outputs ({output_commitment}) does not match the commitment of the account delta ({delta_commitment})"
+    )]
+    InconsistentAccountDeltaCommitment {
+        output_commitment: Word,
+        delta_commitment: Word,
+    },
+    #[error("failed to add the fee asset to the post-fee account delta")]
+    AddFeeAssetToDelta(#[source] AccountDeltaError),
+    #[error("expected account nonce delta to be {expected}, found {actual}")]
+    InconsistentAccountNonceDelta { expected: Felt, actual: Felt },
+    #[error(
+        "fee asset is issued by faucet {actual} but the native asset of the reference block is {expected}"
+    )]
+    FeeAssetNotNativeAsset { expected: AccountId, actual: AccountId },
+    #[error("transaction fee of {fee} exceeds the maximum fee of {limit}")]
+    FeeExceedsLimit { fee: u64, limit: u64 },
+    #[error("output note {0} is also consumed as an input note of the transaction")]
+    OutputNoteConsumedAsInputNote(NoteId),
+    #[error("output note {note_id} has sender {sender} but was created by account {account_id}")]
+    OutputNoteSenderMismatch {
+        note_id: NoteId,
+        sender: AccountId,
+        account_id: AccountId,
+    },
+    #[error(
+        "expiration block number {expiration_block_num} is not within the allowed range after reference block {ref_block_num}"
+    )]
+    InvalidExpirationBlockNum {
+        ref_block_num: BlockNumber,
+        expiration_block_num: BlockNumber,
+    },
     #[error("failed to remove the fee asset from the pre-fee account delta")]
     RemoveFeeAssetFromDelta(#[source] AccountDeltaError),
+    #[error(
+        "estimated account update size of {estimated_size} bytes for account {account_id} exceeds the maximum update size of {max_size} bytes"
+    )]
+    AccountUpdateSizeLimitExceeded {
+        account_id: AccountId,
+        estimated_size: usize,
+        max_size: usize,
+    },
     #[error("failed to construct transaction outputs")]
     TransactionOutputConstructionFailed(#[source] TransactionOutputError),
     #[error("failed to build proven transaction")]
     ProvenTransactionBuildFailed(#[source] ProvenTransactionError),
+    #[error("delegated prover failed to prove the transaction")]
+    DelegatedProvingFailed(#[source] Box<dyn Error + Send + Sync + 'static>),
+    #[error("failed to deserialize the proven transaction returned by the delegated prover")]
+    ProvenTransactionDeserializationFailed(#[source] DeserializationError),
+    #[error(
+        "delegated prover returned proven transaction {actual} but transaction {expected} was submitted"
+    )]
+    ProvenTransactionIdMismatch {
+        expected: TransactionId,
+        actual: TransactionId,
+    },
+    #[error("failed to verify the proven transaction returned by the delegated prover")]
+    ProvenTransactionVerificationFailed(#[source] TransactionVerifierError),
     #[error("the advice map contains conflicting map entries")]
     ConflictingAdviceMapEntry(#[source] TransactionAdviceMapMismatch),
     // Print the diagnostic directly instead of returning the source error. In the source error
@@ -224,6 +384,8 @@ pub enum TransactionKernelError {
     FailedToAddAssetToNote(#[source] NoteError),
     #[error("note input data has hash {actual} but expected hash {expected}")]
     InvalidNoteInputs { expected: Word, actual: Word },
+    #[error("custom event ID {0} is not a u32 value")]
+    InvalidCustomEventId(Felt),
     #[error(
         "storage slot index {actual} is invalid, must be smaller than the number of account storage slots {max}"
     )]
@@ -276,6 +438,10 @@ pub enum TransactionKernelError {
     AccountStorageSlotsNumMissing(u32),
     #[error("account nonce can only be incremented once")]
     NonceCanOnlyIncrementOnce,
+    #[error(
+        "note execution took at least {num_cycles} cycles which exceeds the note cycle limit of {max_cycles} cycles"
+    )]
+    NoteCycleLimitExceeded { num_cycles: u32, max_cycles: u32 },
     #[error("failed to convert fee asset into fungible asset")]
     FailedToConvertFeeAsset(#[source] AssetError),
     #[error(
@@ -306,13 +472,25 @@ pub enum TransactionKernelError {
         source: DataStoreError,
     },
     #[error(
-        "native asset amount {account_balance} in the account vault is not sufficient to cover the transaction fee of {tx_fee}"
+        "native asset amount {account_balance} in the vault of account {account_id} is not sufficient to cover the transaction fee of {} issued by faucet {}",
+        tx_fee.amount(),
+        tx_fee.faucet_id()
     )]
-    InsufficientFee { account_balance: u64, tx_fee: u64 },
+    InsufficientFee {
+        account_id: AccountId,
+        account_balance: u64,
+        tx_fee: FungibleAsset,
+    },
     /// This variant signals that a signature over the contained commitments is required, but
     /// missing.
-    #[error("transaction requires a signature")]
-    Unauthorized(Box<TransactionSummary>),
+    #[error(
+        "transaction against account {account_id} requires a signature over the transaction summary with commitment {}",
+        summary.to_commitment()
+    )]
+    Unauthorized {
+        account_id: AccountId,
+        summary: Box<TransactionSummary>,
+    },
     /// A generic error returned when the transaction kernel did not behave as expected.
     #[error("{message}")]
     Other {
@@ -446,3 +624,79 @@ mod error_assertions {
         _assert_error_is_send_sync_static(err);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use miden_objects::testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE;
+
+    use super::*;
+
+    #[test]
+    fn transaction_executor_error_classification() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let note_id = NoteId::new(Word::from([1u32; 4]), Word::from([2u32; 4]));
+
+        let stale_note = TransactionExecutorError::NoteBlockPastReferenceBlock(note_id, 5.into());
+        assert!(stale_note.is_retryable());
+        assert!(!stale_note.is_auth_error());
+
+        let missing_block = TransactionExecutorError::FetchTransactionInputsFailed(
+            DataStoreError::BlockNotFound(5.into()),
+        );
+        assert!(missing_block.is_retryable());
+
+        let missing_account = TransactionExecutorError::FetchTransactionInputsFailed(
+            DataStoreError::AccountNotFound(account_id),
+        );
+        assert!(!missing_account.is_retryable());
+
+        let insufficient_fee = TransactionExecutorError::InsufficientFee {
+            account_id,
+            account_balance: 1,
+            tx_fee: FungibleAsset::mock(2).unwrap_fungible(),
+        };
+        assert!(!insufficient_fee.is_retryable());
+        assert!(!insufficient_fee.is_auth_error());
+
+        let missing_authenticator = TransactionExecutorError::MissingAuthenticator;
+        assert!(!missing_authenticator.is_retryable());
+        assert!(missing_authenticator.is_auth_error());
+    }
+
+    #[test]
+    fn transaction_kernel_error_display_includes_context() {
+        let account_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let tx_fee = FungibleAsset::mock(10).unwrap_fungible();
+
+        let insufficient_fee =
+            TransactionKernelError::InsufficientFee { account_id, account_balance: 5, tx_fee };
+        assert_eq!(
+            insufficient_fee.to_string(),
+            format!(
+                "native asset amount 5 in the vault of account {account_id} is not sufficient to cover the transaction fee of 10 issued by faucet {}",
+                tx_fee.faucet_id()
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_execution_options_cycles_reports_violated_bound() {
+        let too_few = TransactionExecutorError::InvalidExecutionOptionsCycles {
+            min_cycles: 4096,
+            max_cycles: 8192,
+            actual: 4000,
+        };
+        assert!(too_few.to_string().ends_with("found 4000 which is 96 below the minimum"));
+
+        let too_many = TransactionExecutorError::InvalidExecutionOptionsCycles {
+            min_cycles: 4096,
+            max_cycles: 8192,
+            actual: 10000,
+        };
+        assert!(too_many.to_string().ends_with("found 10000 which is 1808 above the maximum"));
+    }
+}
@@ -0,0 +1,222 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::sync::Arc;
+
+use miden_objects::Word;
+use miden_objects::account::{AccountId, PartialAccount, StorageMapWitness};
+use miden_objects::assembly::mast::MastForest;
+use miden_objects::asset::{AssetVaultKey, AssetWitness};
+use miden_objects::block::{BlockHeader, BlockNumber};
+use miden_objects::note::NoteScript;
+use miden_objects::transaction::{AccountInputs, PartialBlockchain};
+use miden_objects::utils::sync::RwLock;
+use miden_processor::{FutureMaybeSend, MastForestStore};
+
+use crate::{DataStore, DataStoreError};
+
+// CACHING DATA STORE
+// ================================================================================================
+
+/// A [`DataStore`] decorator which caches the data fetched from an inner data store.
+///
+/// This is useful for data stores backed by a remote source, since executing several transactions
+/// typically requires fetching the same data repeatedly.
+///
+/// Data which cannot change is cached indefinitely. This includes:
+/// - the reference block headers and partial blockchains, keyed by the requested block numbers.
+/// - asset and storage map witnesses, which are keyed by the vault and map roots they open.
+/// - note scripts, which are keyed by their roots.
+///
+/// The native and foreign account states are cached per account ID and are invalidated:
+/// - explicitly, through [`CachingDataStore::invalidate_account`].
+/// - automatically, when a transaction against the account was executed successfully and changed
+///   the account's commitment, see [`DataStore::on_transaction_executed`].
+///
+/// Failed requests are not cached, since the data may become available later, e.g. once an
+/// account has been created. In particular, requests for accounts which are unknown to the inner
+/// data store are forwarded to it every time.
+///
+/// MAST forests are not cached since the inner store is expected to serve them locally.
+pub struct CachingDataStore<D> {
+    inner: D,
+    /// account_id |-> partial account (state at the time of the last fetch)
+    accounts: RwLock<BTreeMap<AccountId, PartialAccount>>,
+    /// ref_blocks |-> (reference block header, partial blockchain)
+    blocks: RwLock<BTreeMap<BTreeSet<BlockNumber>, (BlockHeader, PartialBlockchain)>>,
+    /// (foreign_account_id, ref_block) |-> foreign account inputs
+    foreign_accounts: RwLock<BTreeMap<(AccountId, BlockNumber), AccountInputs>>,
+    /// (account_id, vault_root, vault_key) |-> asset witness
+    asset_witnesses: RwLock<BTreeMap<(AccountId, Word, AssetVaultKey), AssetWitness>>,
+    /// (account_id, map_root, map_key) |-> storage map witness
+    storage_map_witnesses: RwLock<BTreeMap<(AccountId, Word, Word), StorageMapWitness>>,
+    /// script_root |-> note script
+    note_scripts: RwLock<BTreeMap<Word, NoteScript>>,
+}
+
+impl<D: DataStore> CachingDataStore<D> {
+    /// Returns a new [`CachingDataStore`] which caches the data fetched from the provided data
+    /// store.
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            accounts: RwLock::new(BTreeMap::new()),
+            blocks: RwLock::new(BTreeMap::new()),
+            foreign_accounts: RwLock::new(BTreeMap::new()),
+            asset_witnesses: RwLock::new(BTreeMap::new()),
+            storage_map_witnesses: RwLock::new(BTreeMap::new()),
+            note_scripts: RwLock::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns a reference to the inner data store.
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    /// Consumes the caching data store and returns the inner data store.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    /// Removes all cached state of the account with the provided ID, so that it is fetched from
+    /// the inner data store the next time it is requested.
+    ///
+    /// This affects both the cached native account state and the cached foreign account inputs of
+    /// the account.
+    pub fn invalidate_account(&self, account_id: AccountId) {
+        self.accounts.write().remove(&account_id);
+        self.foreign_accounts
+            .write()
+            .retain(|(foreign_account_id, _), _| *foreign_account_id != account_id);
+    }
+}
+
+// DATA STORE IMPLEMENTATION
+// ================================================================================================
+
+impl<D: DataStore + Sync> DataStore for CachingDataStore<D> {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        async move {
+            let account = self.accounts.read().get(&account_id).cloned();
+            let block_data = self.blocks.read().get(&ref_blocks).cloned();
+
+            if let (Some(account), Some((block_header, partial_blockchain))) = (account, block_data)
+            {
+                return Ok((account, block_header, partial_blockchain));
+            }
+
+            let (account, block_header, partial_blockchain) =
+                self.inner.get_transaction_inputs(account_id, ref_blocks.clone()).await?;
+
+            self.accounts.write().insert(account_id, account.clone());
+            self.blocks
+                .write()
+                .insert(ref_blocks, (block_header.clone(), partial_blockchain.clone()));
+
+            Ok((account, block_header, partial_blockchain))
+        }
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        async move {
+            let key = (foreign_account_id, ref_block);
+            if let Some(account_inputs) = self.foreign_accounts.read().get(&key).cloned() {
+                return Ok(account_inputs);
+            }
+
+            let account_inputs =
+                self.inner.get_foreign_account_inputs(foreign_account_id, ref_block).await?;
+            self.foreign_accounts.write().insert(key, account_inputs.clone());
+
+            Ok(account_inputs)
+        }
+    }
+
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_key: AssetVaultKey,
+    ) -> impl FutureMaybeSend<Result<AssetWitness, DataStoreError>> {
+        async move {
+            let key = (account_id, vault_root, vault_key);
+            if let Some(witness) = self.asset_witnesses.read().get(&key).cloned() {
+                return Ok(witness);
+            }
+
+            let witness =
+                self.inner.get_vault_asset_witness(account_id, vault_root, vault_key).await?;
+            self.asset_witnesses.write().insert(key, witness.clone());
+
+            Ok(witness)
+        }
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        async move {
+            let key = (account_id, map_root, map_key);
+            if let Some(witness) = self.storage_map_witnesses.read().get(&key).cloned() {
+                return Ok(witness);
+            }
+
+            let witness = self.inner.get_storage_map_witness(account_id, map_root, map_key).await?;
+            self.storage_map_witnesses.write().insert(key, witness.clone());
+
+            Ok(witness)
+        }
+    }
+
+    fn get_note_script(
+        &self,
+        script_root: Word,
+    ) -> impl FutureMaybeSend<Result<NoteScript, DataStoreError>> {
+        async move {
+            if let Some(note_script) = self.note_scripts.read().get(&script_root).cloned() {
+                return Ok(note_script);
+            }
+
+            let note_script = self.inner.get_note_script(script_root).await?;
+            self.note_scripts.write().insert(script_root, note_script.clone());
+
+            Ok(note_script)
+        }
+    }
+
+    /// Invalidates the cached state of the account if its commitment differs from the provided
+    /// final account commitment and forwards the notification to the inner data store.
+    fn on_transaction_executed(&self, account_id: AccountId, final_account_commitment: Word) {
+        let is_stale = self
+            .accounts
+            .read()
+            .get(&account_id)
+            .is_some_and(|account| account.commitment() != final_account_commitment);
+
+        if is_stale {
+            self.invalidate_account(account_id);
+        }
+
+        self.inner.on_transaction_executed(account_id, final_account_commitment);
+    }
+}
+
+// MAST FOREST STORE IMPLEMENTATION
+// ================================================================================================
+
+impl<D: DataStore> MastForestStore for CachingDataStore<D> {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        self.inner.get(procedure_hash)
+    }
+}
@@ -82,4 +82,15 @@ pub trait DataStore: MastForestStore {
         &self,
         script_root: Word,
     ) -> impl FutureMaybeSend<Result<NoteScript, DataStoreError>>;
+
+    /// Notifies the data store that a transaction against the account with the specified ID was
+    /// executed successfully and resulted in the provided final account commitment.
+    ///
+    /// This is called by the [`TransactionExecutor`](crate::TransactionExecutor) after a
+    /// transaction was executed and allows data stores that cache account state to invalidate it.
+    ///
+    /// The default implementation does nothing.
+    fn on_transaction_executed(&self, account_id: AccountId, final_account_commitment: Word) {
+        let _ = (account_id, final_account_commitment);
+    }
 }
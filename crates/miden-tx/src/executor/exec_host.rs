@@ -1,8 +1,12 @@
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use miden_lib::transaction::{EventId, TransactionAdviceInputs};
+use miden_lib::transaction::{
+    ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE,
+    EventId,
+    TransactionAdviceInputs,
+};
 use miden_objects::account::{
     AccountCode,
     AccountDelta,
@@ -16,14 +20,23 @@ use miden_objects::asset::{Asset, AssetVaultKey, AssetWitness, FungibleAsset};
 use miden_objects::block::BlockNumber;
 use miden_objects::crypto::merkle::SmtProof;
 use miden_objects::note::{NoteInputs, NoteMetadata, NoteRecipient};
-use miden_objects::transaction::{InputNote, InputNotes, OutputNote};
+use miden_objects::transaction::{
+    CustomEvent,
+    InputNote,
+    InputNotes,
+    OutputNote,
+    StorageAccessLog,
+};
 use miden_objects::vm::AdviceMap;
 use miden_objects::{Felt, Hasher, Word};
 use miden_processor::{
     AdviceMutation,
     AsyncHost,
     BaseHost,
+    DebugHandler,
+    DefaultDebugHandler,
     EventError,
+    ExecutionError,
     FutureMaybeSend,
     MastForest,
     ProcessState,
@@ -78,9 +91,25 @@ where
     /// authenticator that produced it.
     generated_signatures: BTreeMap<Word, Vec<Felt>>,
 
+    /// The advice map keys and Merkle store nodes that were provided to the transaction kernel in
+    /// response to its requests during execution.
+    ///
+    /// Unlike the rest of the advice inputs, this data cannot be rebuilt from the transaction
+    /// inputs, so it is the only advice data that must be retained for re-executing the
+    /// transaction.
+    accessed_advice_keys: BTreeSet<Word>,
+
+    /// The custom events emitted via `miden::tx::emit_event` during transaction execution, in the
+    /// order in which they were emitted.
+    custom_events: Vec<CustomEvent>,
+
     /// The source manager to track source code file span information, improving any MASM related
     /// error messages.
     source_manager: Arc<dyn SourceManagerSync>,
+
+    /// The maximum number of cycles the execution of a single note may take, or `None` if the
+    /// execution of notes is not limited.
+    note_cycle_limit: Option<u32>,
 }
 
 impl<'store, 'auth, STORE, AUTH> TransactionExecutorHost<'store, 'auth, STORE, AUTH>
@@ -116,7 +145,10 @@ where
             ref_block,
             accessed_foreign_account_code: Vec::new(),
             generated_signatures: BTreeMap::new(),
+            accessed_advice_keys: BTreeSet::new(),
+            custom_events: Vec::new(),
             source_manager,
+            note_cycle_limit: None,
         }
     }
 
@@ -128,9 +160,50 @@ where
         self.base_host.tx_progress()
     }
 
+    // MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Enables recording the storage slots and storage map items accessed during transaction
+    /// execution.
+    pub fn enable_storage_access_tracking(&mut self) {
+        self.base_host.enable_storage_access_tracking();
+    }
+
+    /// Limits the number of cycles the execution of a single note may take to `max_cycles`.
+    ///
+    /// The fast processor does not support cycle limits, so the limit is checked whenever the
+    /// executing note emits an event, which includes the event emitted at the end of its
+    /// execution. The execution is aborted with
+    /// [`TransactionKernelError::NoteCycleLimitExceeded`] at the first event at which the note has
+    /// exceeded the limit.
+    pub fn set_note_cycle_limit(&mut self, max_cycles: u32) {
+        self.note_cycle_limit = Some(max_cycles);
+    }
+
     // EVENT HANDLERS
     // --------------------------------------------------------------------------------------------
 
+    /// Checks that the note currently being executed, if any, has not exceeded the note cycle
+    /// limit.
+    fn check_note_cycle_limit(&self, process: &ProcessState) -> Result<(), TransactionKernelError> {
+        let Some(max_cycles) = self.note_cycle_limit else {
+            return Ok(());
+        };
+        let Some((_, interval)) = self.tx_progress().note_execution().last() else {
+            return Ok(());
+        };
+        let Some(start) = interval.start().filter(|_| interval.end().is_none()) else {
+            return Ok(());
+        };
+
+        let num_cycles = process.clk().as_u32().saturating_sub(start.as_u32());
+        if num_cycles > max_cycles {
+            return Err(TransactionKernelError::NoteCycleLimitExceeded { num_cycles, max_cycles });
+        }
+
+        Ok(())
+    }
+
     /// Handles a request for a foreign account by querying the data store for its account inputs.
     async fn on_foreign_account_requested(
         &mut self,
@@ -270,8 +343,9 @@ where
         // Return an error if the balance in the account does not cover the fee.
         if current_fee_asset.amount() < fee_asset.amount() {
             return Err(TransactionKernelError::InsufficientFee {
+                account_id: self.base_host.initial_account_header().id(),
                 account_balance: current_fee_asset.amount(),
-                tx_fee: fee_asset.amount(),
+                tx_fee: fee_asset,
             });
         }
 
@@ -413,8 +487,26 @@ where
         Ok(mutations)
     }
 
-    /// Consumes `self` and returns the account delta, output notes, generated signatures and
-    /// transaction progress.
+    /// Records the advice map keys and Merkle store nodes inserted by the provided mutations as
+    /// accessed.
+    fn track_advice_mutations(&mut self, mutations: &[AdviceMutation]) {
+        for mutation in mutations {
+            match mutation {
+                AdviceMutation::ExtendMap { other } => {
+                    self.accessed_advice_keys.extend(other.iter().map(|(key, _)| *key));
+                },
+                AdviceMutation::ExtendMerkleStore { infos } => {
+                    self.accessed_advice_keys.extend(infos.iter().map(|info| info.value));
+                },
+                // Advice stack values are consumed by the kernel right away, so there is nothing
+                // to retain.
+                AdviceMutation::ExtendStack { .. } => (),
+            }
+        }
+    }
+
+    /// Consumes `self` and returns the account delta, output notes, generated signatures, accessed
+    /// advice keys, transaction progress, storage access log and custom events.
     #[allow(clippy::type_complexity)]
     pub fn into_parts(
         self,
@@ -424,9 +516,13 @@ where
         Vec<OutputNote>,
         Vec<AccountCode>,
         BTreeMap<Word, Vec<Felt>>,
+        BTreeSet<Word>,
         TransactionProgress,
+        Option<StorageAccessLog>,
+        Vec<CustomEvent>,
     ) {
-        let (account_delta, input_notes, output_notes, tx_progress) = self.base_host.into_parts();
+        let (account_delta, input_notes, output_notes, tx_progress, storage_access_log) =
+            self.base_host.into_parts();
 
         (
             account_delta,
@@ -434,7 +530,10 @@ where
             output_notes,
             self.accessed_foreign_account_code,
             self.generated_signatures,
+            self.accessed_advice_keys,
             tx_progress,
+            storage_access_log,
+            self.custom_events,
         )
     }
 }
@@ -456,6 +555,22 @@ where
         let span = source_manager.location_to_span(location.clone()).unwrap_or_default();
         (span, maybe_file)
     }
+
+    fn on_trace(
+        &mut self,
+        process: &mut ProcessState,
+        trace_id: u32,
+    ) -> Result<(), ExecutionError> {
+        if trace_id == ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE {
+            return self.base_host.on_account_storage_after_get_item().map_err(|_| {
+                ExecutionError::FailedToExecuteProgram(
+                    "failed to record the read of an account storage item",
+                )
+            });
+        }
+
+        DefaultDebugHandler.on_trace(process, trace_id)
+    }
 }
 
 impl<STORE, AUTH> AsyncHost for TransactionExecutorHost<'_, '_, STORE, AUTH>
@@ -476,7 +591,10 @@ where
 
         // TODO: Eventually, refactor this to let TransactionEvent contain the data directly, which
         // should be cleaner.
-        let event_handling_result = self.base_host.handle_event(process, event_id);
+        let event_handling_result = self
+            .check_note_cycle_limit(process)
+            .map_err(EventError::from)
+            .and_then(|_| self.base_host.handle_event(process, event_id));
 
         async move {
             let event_handling = event_handling_result?;
@@ -487,7 +605,7 @@ where
                 },
             };
 
-            match event_data {
+            let mutations = match event_data {
                 TransactionEventData::AuthRequest { pub_key_hash, signing_inputs } => self
                     .on_auth_requested(pub_key_hash, signing_inputs)
                     .await
@@ -537,7 +655,15 @@ where
                     )
                     .await
                     .map_err(EventError::from),
-            }
+                TransactionEventData::CustomEvent { event } => {
+                    self.custom_events.push(event);
+                    Ok(Vec::new())
+                },
+            }?;
+
+            self.track_advice_mutations(&mutations);
+
+            Ok(mutations)
         }
     }
 }
@@ -0,0 +1,188 @@
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use miden_objects::Word;
+use miden_objects::account::{AccountId, PartialAccount, StorageMapWitness};
+use miden_objects::assembly::mast::MastForest;
+use miden_objects::asset::{AssetVaultKey, AssetWitness};
+use miden_objects::block::{BlockHeader, BlockNumber};
+use miden_objects::crypto::merkle::{NodeIndex, SmtLeaf, SmtProof, SparseMerklePath};
+use miden_objects::note::NoteScript;
+use miden_objects::transaction::{AccountInputs, PartialBlockchain, TransactionInputs};
+use miden_processor::{AdviceInputs, FutureMaybeSend, MastForestStore};
+
+use crate::{DataStore, DataStoreError, TransactionMastStore};
+
+// INPUTS DATA STORE
+// ================================================================================================
+
+/// A [`DataStore`] which serves the data requested during the execution of a transaction from its
+/// [`TransactionInputs`] instead of an external source.
+///
+/// Asset and storage map witnesses are opened from the partial state of the native account. Asset
+/// witnesses which are not tracked by the partial account are reconstructed from the advice inputs,
+/// which is necessary to re-execute the inputs of an executed transaction, since the fee asset
+/// witness is always requested. MAST forests are served for the native and foreign account code
+/// of the inputs. Foreign account inputs cannot be provided, since the transaction inputs do not
+/// contain them.
+pub(super) struct InputsDataStore {
+    account: PartialAccount,
+    advice_inputs: AdviceInputs,
+    mast_store: TransactionMastStore,
+}
+
+impl InputsDataStore {
+    /// Returns a new [`InputsDataStore`] serving the data of the provided transaction inputs.
+    pub fn new(tx_inputs: &TransactionInputs) -> Self {
+        let mast_store = TransactionMastStore::new();
+        mast_store.load_account_code(tx_inputs.account().code());
+        for account_code in tx_inputs.foreign_account_code() {
+            mast_store.load_account_code(account_code);
+        }
+
+        let mut advice_inputs = tx_inputs.advice_inputs().clone();
+        advice_inputs.extend(tx_inputs.tx_args().advice_inputs().clone());
+
+        Self {
+            account: tx_inputs.account().clone(),
+            advice_inputs,
+            mast_store,
+        }
+    }
+
+    /// Returns an error if the provided account ID is not the ID of the native account.
+    fn check_native_account(&self, account_id: AccountId) -> Result<(), DataStoreError> {
+        if account_id != self.account.id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+
+        Ok(())
+    }
+
+    /// Reconstructs the witness for the provided vault key against the provided vault root from
+    /// the Merkle store and advice map of the advice inputs, or returns `None` if they do not
+    /// contain the witness.
+    fn asset_witness_from_advice(
+        &self,
+        vault_root: Word,
+        vault_key: AssetVaultKey,
+    ) -> Option<AssetWitness> {
+        let leaf_index = vault_key.to_leaf_index();
+        let proof = self
+            .advice_inputs
+            .store
+            .get_path(vault_root, NodeIndex::from(leaf_index))
+            .ok()?;
+
+        let empty_leaf = SmtLeaf::new_empty(leaf_index);
+        let leaf = if proof.value == empty_leaf.hash() {
+            empty_leaf
+        } else {
+            let entries: Vec<(Word, Word)> = self
+                .advice_inputs
+                .map
+                .get(&proof.value)?
+                .chunks_exact(8)
+                .map(|entry| {
+                    (
+                        Word::new([entry[0], entry[1], entry[2], entry[3]]),
+                        Word::new([entry[4], entry[5], entry[6], entry[7]]),
+                    )
+                })
+                .collect();
+            SmtLeaf::new(entries, leaf_index).ok()?
+        };
+
+        let path = SparseMerklePath::try_from(proof.path).ok()?;
+        AssetWitness::new(SmtProof::new(path, leaf).ok()?).ok()
+    }
+}
+
+impl DataStore for InputsDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        _ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        async move {
+            Err(DataStoreError::other(format!(
+                "transaction inputs for account {account_id} are not provided when executing with pre-loaded inputs"
+            )))
+        }
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        _ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        async move { Err(DataStoreError::AccountNotFound(foreign_account_id)) }
+    }
+
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        vault_root: Word,
+        vault_key: AssetVaultKey,
+    ) -> impl FutureMaybeSend<Result<AssetWitness, DataStoreError>> {
+        async move {
+            self.check_native_account(account_id)?;
+            if vault_root != self.account.vault().root() {
+                return Err(DataStoreError::other(format!(
+                    "vault root {vault_root} does not match the vault root of account {account_id}"
+                )));
+            }
+
+            self.account.vault().open(vault_key).or_else(|err| {
+                self.asset_witness_from_advice(vault_root, vault_key).ok_or_else(|| {
+                    DataStoreError::other_with_source(
+                        format!("failed to open asset witness for vault key {vault_key}"),
+                        err,
+                    )
+                })
+            })
+        }
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        map_root: Word,
+        map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        async move {
+            self.check_native_account(account_id)?;
+            let storage_map =
+                self.account.storage().maps().find(|map| map.root() == map_root).ok_or_else(
+                    || {
+                        DataStoreError::other(format!(
+                            "storage map with root {map_root} not found in account {account_id}"
+                        ))
+                    },
+                )?;
+
+            storage_map.open(&map_key).map_err(|err| {
+                DataStoreError::other_with_source(
+                    format!("failed to open storage map witness for map key {map_key}"),
+                    err,
+                )
+            })
+        }
+    }
+
+    fn get_note_script(
+        &self,
+        script_root: Word,
+    ) -> impl FutureMaybeSend<Result<NoteScript, DataStoreError>> {
+        async move { Err(DataStoreError::NoteScriptNotFound(script_root)) }
+    }
+}
+
+impl MastForestStore for InputsDataStore {
+    fn get(&self, procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        self.mast_store.get(procedure_hash)
+    }
+}
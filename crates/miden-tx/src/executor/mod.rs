@@ -1,7 +1,8 @@
 use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 
-use miden_lib::transaction::TransactionKernel;
+use miden_lib::transaction::{TransactionAdviceInputs, TransactionKernel};
 use miden_objects::account::AccountId;
 use miden_objects::assembly::DefaultSourceManager;
 use miden_objects::assembly::debuginfo::SourceManagerSync;
@@ -13,10 +14,11 @@ use miden_objects::transaction::{
     InputNotes,
     TransactionArgs,
     TransactionInputs,
+    TransactionMeasurements,
     TransactionScript,
 };
 use miden_objects::vm::StackOutputs;
-use miden_objects::{Felt, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES};
+use miden_objects::{Felt, Hasher, MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES};
 use miden_processor::fast::FastProcessor;
 use miden_processor::{AdviceInputs, ExecutionError, StackInputs};
 pub use miden_processor::{ExecutionOptions, MastForestStore};
@@ -24,7 +26,7 @@ pub use miden_processor::{ExecutionOptions, MastForestStore};
 use super::TransactionExecutorError;
 use crate::auth::TransactionAuthenticator;
 use crate::errors::TransactionKernelError;
-use crate::host::{AccountProcedureIndexMap, ScriptMastForestStore};
+use crate::host::{AccountProcedureIndexMap, MastForestResolver, ScriptMastForestStore};
 
 mod exec_host;
 pub use exec_host::TransactionExecutorHost;
@@ -32,10 +34,23 @@ pub use exec_host::TransactionExecutorHost;
 mod data_store;
 pub use data_store::DataStore;
 
+mod profile;
+pub use profile::ExecutionProfile;
+
+mod caching_data_store;
+pub use caching_data_store::CachingDataStore;
+
+mod noop_data_store;
+pub use noop_data_store::NoopDataStore;
+
+mod inputs_data_store;
+use inputs_data_store::InputsDataStore;
+
 mod notes_checker;
 pub use notes_checker::{
     FailedNote,
     MAX_NUM_CHECKER_NOTES,
+    NoteCheckerConfig,
     NoteConsumptionChecker,
     NoteConsumptionInfo,
 };
@@ -56,7 +71,9 @@ pub struct TransactionExecutor<'store, 'auth, STORE: 'store, AUTH: 'auth> {
     data_store: &'store STORE,
     authenticator: Option<&'auth AUTH>,
     source_manager: Arc<dyn SourceManagerSync>,
+    mast_forest_resolver: Option<Arc<dyn MastForestResolver>>,
     exec_options: ExecutionOptions,
+    storage_access_tracking: bool,
 }
 
 impl<'store, 'auth, STORE, AUTH> TransactionExecutor<'store, 'auth, STORE, AUTH>
@@ -77,13 +94,9 @@ where
             data_store,
             authenticator: None,
             source_manager: Arc::new(DefaultSourceManager::default()),
-            exec_options: ExecutionOptions::new(
-                Some(MAX_TX_EXECUTION_CYCLES),
-                MIN_TX_EXECUTION_CYCLES,
-                false,
-                false,
-            )
-            .expect("Must not fail while max cycles is more than min trace length"),
+            mast_forest_resolver: None,
+            exec_options: ExecutionProfile::max().to_execution_options(false, false),
+            storage_access_tracking: false,
         }
     }
 
@@ -111,6 +124,20 @@ where
         self
     }
 
+    /// Adds the specified [MastForestResolver] to the executor and returns the resulting executor.
+    ///
+    /// The resolver is consulted for procedures whose MAST forest is neither part of the
+    /// transaction or note scripts nor provided by the [DataStore]. If the resolver cannot provide
+    /// the forest either, execution fails with an error containing the root of the missing
+    /// procedure.
+    ///
+    /// This will overwrite any previously set resolver.
+    #[must_use]
+    pub fn with_mast_forest_resolver(mut self, resolver: Arc<dyn MastForestResolver>) -> Self {
+        self.mast_forest_resolver = Some(resolver);
+        self
+    }
+
     /// Sets the [ExecutionOptions] for the executor to the provided options and returns the
     /// resulting executor.
     ///
@@ -129,6 +156,19 @@ where
         Ok(self)
     }
 
+    /// Sets the cycle limits of the executor to the ones of the provided [ExecutionProfile] and
+    /// returns the resulting executor.
+    ///
+    /// Tracing and debug mode settings of the executor are preserved.
+    #[must_use]
+    pub fn with_profile(mut self, profile: ExecutionProfile) -> Self {
+        self.exec_options = profile.to_execution_options(
+            self.exec_options.enable_tracing(),
+            self.exec_options.enable_debugging(),
+        );
+        self
+    }
+
     /// Puts the [TransactionExecutor] into debug mode and returns the resulting executor.
     ///
     /// When transaction executor is in debug mode, all transaction-related code (note scripts,
@@ -152,6 +192,19 @@ where
         self
     }
 
+    /// Enables storage access tracking for the created instance of [TransactionExecutor] and
+    /// returns the resulting executor.
+    ///
+    /// When storage access tracking is enabled, the executor records which storage slots and
+    /// storage map items of the native and foreign accounts are read or written during execution.
+    /// The log is available via [`ExecutedTransaction::storage_access_log`]. When disabled, no log
+    /// is allocated.
+    #[must_use]
+    pub fn with_storage_access_tracking(mut self) -> Self {
+        self.storage_access_tracking = true;
+        self
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -165,6 +218,10 @@ where
     /// provided `notes` were created. Then, it executes the transaction program and creates an
     /// [`ExecutedTransaction`].
     ///
+    /// Note arguments attached to the provided `notes` (see [`InputNote::with_args`]) are added to
+    /// the note arguments of `tx_args`, unless `tx_args` already specifies arguments for the
+    /// respective note.
+    ///
     /// # Errors:
     ///
     /// Returns an error if:
@@ -172,6 +229,8 @@ where
     /// - If the transaction arguments contain foreign account data not anchored in the reference
     ///   block.
     /// - If any input notes were created in block numbers higher than the reference block.
+    /// - If the transaction takes more than the maximum number of cycles of the executor's
+    ///   [`ExecutionOptions`].
     pub async fn execute_transaction(
         &self,
         account_id: AccountId,
@@ -181,25 +240,125 @@ where
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
         let tx_inputs = self.prepare_tx_inputs(account_id, block_ref, notes, tx_args).await?;
 
+        let executed_transaction = self.execute_prepared(self.data_store, tx_inputs).await?;
+
+        self.data_store
+            .on_transaction_executed(account_id, executed_transaction.final_account().commitment());
+
+        Ok(executed_transaction)
+    }
+
+    /// Executes a transaction from the provided, pre-loaded [`TransactionInputs`] and returns an
+    /// [`ExecutedTransaction`].
+    ///
+    /// Unlike [`TransactionExecutor::execute_transaction`], this method never fetches data from the
+    /// executor's [`DataStore`], so the executor can be constructed with a [`NoopDataStore`].
+    /// Instead, asset and storage map witnesses are opened from the partial account of the inputs
+    /// and the code of the native and foreign accounts is taken from the inputs. Data already
+    /// present in the advice inputs, e.g. witnesses loaded or signatures generated during a
+    /// previous execution, is used as-is. Hence, re-executing the inputs of an
+    /// [`ExecutedTransaction`] results in the same transaction.
+    ///
+    /// Note arguments attached to the input notes are added to the transaction arguments of the
+    /// inputs in the same way as in [`TransactionExecutor::execute_transaction`].
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If any input notes were created in block numbers higher than the reference block of the
+    ///   inputs.
+    /// - If the transaction invokes procedures of a foreign account, since foreign account inputs
+    ///   can only be provided by a [`DataStore`].
+    /// - If the transaction accesses an asset or storage map item which is neither tracked by the
+    ///   partial account nor present in the advice inputs.
+    /// - If the execution of the transaction program fails.
+    /// - If the transaction takes more than the maximum number of cycles of the executor's
+    ///   [`ExecutionOptions`].
+    pub async fn execute_with_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        validate_input_notes(tx_inputs.input_notes(), tx_inputs.block_header().block_num())?;
+
+        let mut tx_args = tx_inputs.tx_args().clone();
+        extend_attached_note_args(tx_inputs.input_notes(), &mut tx_args);
+        let tx_inputs = tx_inputs.with_tx_args(tx_args);
+
+        let data_store = InputsDataStore::new(&tx_inputs);
+        self.execute_prepared(&data_store, tx_inputs).await
+    }
+
+    /// Prepares and executes a transaction in the same way as
+    /// [`TransactionExecutor::execute_transaction`], but aborts the execution if it does not
+    /// complete within the provided `timeout`.
+    ///
+    /// The timeout is only checked when the execution yields, i.e. while waiting on the
+    /// [`DataStore`] or the authenticator, so a transaction that is busy executing the
+    /// transaction program can exceed the timeout. The method must be called from within a tokio
+    /// runtime with the time driver enabled.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If the transaction does not complete within `timeout`.
+    /// - If [`TransactionExecutor::execute_transaction`] returns an error.
+    #[cfg(feature = "std")]
+    pub async fn execute_transaction_with_timeout(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+        timeout: core::time::Duration,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        tokio::time::timeout(
+            timeout,
+            self.execute_transaction(account_id, block_ref, notes, tx_args),
+        )
+        .await
+        .map_err(|_| TransactionExecutorError::ExecutionTimeout(timeout))?
+    }
+
+    /// Prepares and executes a transaction specified by the provided arguments and returns the
+    /// number of cycles its execution took.
+    ///
+    /// The transaction is prepared and executed in the same way as in
+    /// [`TransactionExecutor::execute_transaction`], but the [`ExecutedTransaction`] is not built.
+    /// This makes the method suitable for estimating the cost of a transaction, e.g. for fee
+    /// estimation.
+    ///
+    /// The returned cycle count is the sum of the cycles spent in the prologue, the note
+    /// processing, the transaction script processing and the epilogue, i.e. it matches
+    /// [`TransactionMeasurements::total_cycles`](miden_objects::transaction::TransactionMeasurements::total_cycles)
+    /// of the executed transaction.
+    ///
+    /// # Errors:
+    ///
+    /// Returns an error if:
+    /// - If required data can not be fetched from the [`DataStore`].
+    /// - If any input notes were created in block numbers higher than the reference block.
+    /// - If the execution of the transaction program fails.
+    /// - If the transaction takes more than the maximum number of cycles of the executor's
+    ///   [`ExecutionOptions`].
+    pub async fn estimate_cycles(
+        &self,
+        account_id: AccountId,
+        block_ref: BlockNumber,
+        notes: InputNotes<InputNote>,
+        tx_args: TransactionArgs,
+    ) -> Result<u32, TransactionExecutorError> {
+        let tx_inputs = self.prepare_tx_inputs(account_id, block_ref, notes, tx_args).await?;
+
         let (mut host, stack_inputs, advice_inputs) = self.prepare_transaction(&tx_inputs).await?;
 
-        let processor = FastProcessor::new_debug(stack_inputs.as_slice(), advice_inputs);
-        let output = processor
+        let processor =
+            FastProcessor::new_with_advice_inputs(stack_inputs.as_slice(), advice_inputs);
+        processor
             .execute(&TransactionKernel::main(), &mut host)
             .await
             .map_err(map_execution_error)?;
-        let stack_outputs = output.stack;
-        let advice_provider = output.advice;
 
-        // The stack is not necessary since it is being reconstructed when re-executing.
-        let (_stack, advice_map, merkle_store) = advice_provider.into_parts();
-        let advice_inputs = AdviceInputs {
-            map: advice_map,
-            store: merkle_store,
-            ..Default::default()
-        };
-
-        build_executed_transaction(advice_inputs, tx_inputs, stack_outputs, host)
+        self.check_cycle_limit(&host)
     }
 
     // SCRIPT EXECUTION
@@ -252,11 +411,13 @@ where
         account_id: AccountId,
         block_ref: BlockNumber,
         input_notes: InputNotes<InputNote>,
-        tx_args: TransactionArgs,
+        mut tx_args: TransactionArgs,
     ) -> Result<TransactionInputs, TransactionExecutorError> {
         let mut ref_blocks = validate_input_notes(&input_notes, block_ref)?;
         ref_blocks.insert(block_ref);
 
+        extend_attached_note_args(&input_notes, &mut tx_args);
+
         let (account, block_header, blockchain) = self
             .data_store
             .get_transaction_inputs(account_id, ref_blocks)
@@ -281,22 +442,39 @@ where
         (TransactionExecutorHost<'store, 'auth, STORE, AUTH>, StackInputs, AdviceInputs),
         TransactionExecutorError,
     > {
-        let (stack_inputs, tx_advice_inputs) = TransactionKernel::prepare_inputs(tx_inputs)
+        self.prepare_transaction_with_store(self.data_store, tx_inputs)
+    }
+
+    /// Prepares the data needed for transaction execution in the same way as
+    /// [`Self::prepare_transaction`], but instantiates the transaction host with the provided data
+    /// store instead of the executor's one.
+    fn prepare_transaction_with_store<'a, S: DataStore + Sync>(
+        &self,
+        data_store: &'a S,
+        tx_inputs: &TransactionInputs,
+    ) -> Result<
+        (TransactionExecutorHost<'a, 'auth, S, AUTH>, StackInputs, AdviceInputs),
+        TransactionExecutorError,
+    > {
+        let (stack_inputs, tx_advice_inputs) = TransactionKernel::build_inputs(tx_inputs)
             .map_err(TransactionExecutorError::ConflictingAdviceMapEntry)?;
 
         // This reverses the stack inputs (even though it doesn't look like it does) because the
         // fast processor expects the reverse order.
         //
         // Once we use the FastProcessor for execution and proving, we can change the way these
-        // inputs are constructed in TransactionKernel::prepare_inputs.
+        // inputs are constructed in TransactionKernel::build_inputs.
         let stack_inputs = StackInputs::new(stack_inputs.iter().copied().collect()).unwrap();
 
         let input_notes = tx_inputs.input_notes();
 
-        let script_mast_store = ScriptMastForestStore::new(
+        let mut script_mast_store = ScriptMastForestStore::new(
             tx_inputs.tx_script(),
             input_notes.iter().map(|n| n.note().script()),
         );
+        if let Some(resolver) = &self.mast_forest_resolver {
+            script_mast_store = script_mast_store.with_resolver(resolver.clone());
+        }
 
         // To start executing the transaction, the procedure index map only needs to contain the
         // native account's procedures. Foreign accounts are inserted into the map on first access.
@@ -304,21 +482,73 @@ where
             AccountProcedureIndexMap::new([tx_inputs.account().code()])
                 .map_err(TransactionExecutorError::TransactionHostCreationFailed)?;
 
-        let host = TransactionExecutorHost::new(
+        let mut host = TransactionExecutorHost::new(
             tx_inputs.account(),
             input_notes.clone(),
-            self.data_store,
+            data_store,
             script_mast_store,
             account_procedure_index_map,
             self.authenticator,
             tx_inputs.block_header().block_num(),
             self.source_manager.clone(),
         );
+        if self.storage_access_tracking {
+            host.enable_storage_access_tracking();
+        }
 
         let advice_inputs = tx_advice_inputs.into_advice_inputs();
 
         Ok((host, stack_inputs, advice_inputs))
     }
+
+    /// Executes the transaction specified by the provided inputs against a host instantiated with
+    /// the provided data store and builds the resulting [`ExecutedTransaction`].
+    async fn execute_prepared<S: DataStore + Sync>(
+        &self,
+        data_store: &S,
+        tx_inputs: TransactionInputs,
+    ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        let (mut host, stack_inputs, advice_inputs) =
+            self.prepare_transaction_with_store(data_store, &tx_inputs)?;
+
+        let processor = FastProcessor::new_debug(stack_inputs.as_slice(), advice_inputs);
+        let output = processor
+            .execute(&TransactionKernel::main(), &mut host)
+            .await
+            .map_err(map_execution_error)?;
+        self.check_cycle_limit(&host)?;
+        let stack_outputs = output.stack;
+        let advice_provider = output.advice;
+
+        // The stack is not necessary since it is being reconstructed when re-executing.
+        let (_stack, advice_map, merkle_store) = advice_provider.into_parts();
+        let advice_inputs = AdviceInputs {
+            map: advice_map,
+            store: merkle_store,
+            ..Default::default()
+        };
+
+        build_executed_transaction(advice_inputs, tx_inputs, stack_outputs, host)
+    }
+
+    /// Returns the number of cycles the transaction executed by the provided host took.
+    ///
+    /// The fast processor does not enforce a cycle limit, so the maximum number of cycles of the
+    /// executor's [`ExecutionOptions`] is checked against the measured cycle count instead.
+    fn check_cycle_limit<S: DataStore + Sync>(
+        &self,
+        host: &TransactionExecutorHost<'_, 'auth, S, AUTH>,
+    ) -> Result<u32, TransactionExecutorError> {
+        let max_cycles = self.exec_options.max_cycles();
+        let measurements = TransactionMeasurements::from(host.tx_progress().clone());
+
+        u32::try_from(measurements.total_cycles())
+            .ok()
+            .filter(|num_cycles| *num_cycles <= max_cycles)
+            .ok_or(TransactionExecutorError::TransactionProgramExecutionFailed(
+                ExecutionError::CycleLimitExceeded(max_cycles),
+            ))
+    }
 }
 
 // HELPER FUNCTIONS
@@ -339,13 +569,25 @@ fn build_executed_transaction<STORE: DataStore + Sync, AUTH: TransactionAuthenti
         output_notes,
         accessed_foreign_account_code,
         generated_signatures,
+        mut accessed_advice_keys,
         tx_progress,
+        storage_access_log,
+        custom_events,
     ) = host.into_parts();
 
     let tx_outputs =
         TransactionKernel::from_transaction_parts(&stack_outputs, &advice_inputs, output_notes)
             .map_err(TransactionExecutorError::TransactionOutputConstructionFailed)?;
 
+    if let Some(max_fee) = tx_inputs.tx_args().max_fee()
+        && tx_outputs.fee.amount() > max_fee
+    {
+        return Err(TransactionExecutorError::FeeExceedsLimit {
+            fee: tx_outputs.fee.amount(),
+            limit: max_fee,
+        });
+    }
+
     let pre_fee_delta_commitment = pre_fee_account_delta.to_commitment();
     if tx_outputs.account_delta_commitment != pre_fee_delta_commitment {
         return Err(TransactionExecutorError::InconsistentAccountDeltaCommitment {
@@ -380,21 +622,50 @@ fn build_executed_transaction<STORE: DataStore + Sync, AUTH: TransactionAuthenti
         });
     }
 
+    // Advice provided with the transaction inputs prior to execution (e.g. by the note
+    // consumption checker) is not tracked by the host, so it is retained as a whole.
+    accessed_advice_keys.extend(tx_inputs.advice_inputs().map.iter().map(|(key, _)| *key));
+    accessed_advice_keys
+        .extend(tx_inputs.advice_inputs().store.inner_nodes().map(|node| node.value));
+
     // Introduce generated signatures into the witness inputs.
+    accessed_advice_keys.extend(generated_signatures.keys().copied());
     advice_inputs.map.extend(generated_signatures);
 
-    // Overwrite advice inputs from after the execution on the transaction inputs. This is
-    // guaranteed to be a superset of the original advice inputs.
-    let tx_inputs = tx_inputs
+    // The transaction outputs are read from the advice inputs after re-execution, so the entries
+    // holding the account update commitment and the final account header must be retained.
+    let final_account_commitment = tx_outputs.account.commitment();
+    accessed_advice_keys.insert(final_account_commitment);
+    accessed_advice_keys
+        .insert(Hasher::merge(&[final_account_commitment, tx_outputs.account_delta_commitment]));
+
+    // The kernel requests every foreign account it loads from the host, which tracks the provided
+    // data. Foreign account data passed with the transaction arguments up front is therefore only
+    // needed to the extent it was accessed, and the rest of it is stripped from the arguments.
+    let mut tx_args = tx_inputs.tx_args().clone();
+    let unused_foreign_account_keys =
+        TransactionAdviceInputs::foreign_account_keys(tx_args.advice_inputs())
+            .into_iter()
+            .filter(|key| !accessed_advice_keys.contains(key))
+            .collect();
+    tx_args.remove_advice(&unused_foreign_account_keys);
+
+    // Overwrite advice inputs from after the execution on the transaction inputs and strip all
+    // advice data that is rebuilt from the transaction inputs and arguments on re-execution.
+    let mut tx_inputs = tx_inputs
+        .with_tx_args(tx_args)
         .with_foreign_account_code(accessed_foreign_account_code)
         .with_advice_inputs(advice_inputs);
+    tx_inputs.strip_unused_advice(&accessed_advice_keys);
+
+    let executed_tx =
+        ExecutedTransaction::new(tx_inputs, tx_outputs, post_fee_account_delta, tx_progress.into())
+            .with_custom_events(custom_events);
 
-    Ok(ExecutedTransaction::new(
-        tx_inputs,
-        tx_outputs,
-        post_fee_account_delta,
-        tx_progress.into(),
-    ))
+    Ok(match storage_access_log {
+        Some(storage_access_log) => executed_tx.with_storage_access_log(storage_access_log),
+        None => executed_tx,
+    })
 }
 
 /// Validates that input notes were not created after the reference block.
@@ -422,6 +693,17 @@ fn validate_input_notes(
     Ok(ref_blocks)
 }
 
+/// Adds the arguments attached to the provided input notes to the transaction arguments, unless
+/// the transaction arguments already specify arguments for the respective note.
+fn extend_attached_note_args(notes: &InputNotes<InputNote>, tx_args: &mut TransactionArgs) {
+    let attached_note_args: Vec<_> = notes
+        .iter()
+        .filter_map(|note| note.args().map(|args| (note.id(), args)))
+        .filter(|(note_id, _)| tx_args.get_note_args(*note_id).is_none())
+        .collect();
+    tx_args.extend_note_args(attached_note_args);
+}
+
 /// Validates that the number of cycles specified is within the allowed range.
 fn validate_num_cycles(num_cycles: u32) -> Result<(), TransactionExecutorError> {
     if !(MIN_TX_EXECUTION_CYCLES..=MAX_TX_EXECUTION_CYCLES).contains(&num_cycles) {
@@ -445,18 +727,30 @@ fn map_execution_error(exec_err: ExecutionError) -> TransactionExecutorError {
     match exec_err {
         ExecutionError::EventError { ref error, .. } => {
             match error.downcast_ref::<TransactionKernelError>() {
-                Some(TransactionKernelError::Unauthorized(summary)) => {
-                    TransactionExecutorError::Unauthorized(summary.clone())
-                },
-                Some(TransactionKernelError::InsufficientFee { account_balance, tx_fee }) => {
-                    TransactionExecutorError::InsufficientFee {
-                        account_balance: *account_balance,
-                        tx_fee: *tx_fee,
+                Some(TransactionKernelError::Unauthorized { account_id, summary }) => {
+                    TransactionExecutorError::Unauthorized {
+                        account_id: *account_id,
+                        summary: summary.clone(),
                     }
                 },
+                Some(TransactionKernelError::InsufficientFee {
+                    account_id,
+                    account_balance,
+                    tx_fee,
+                }) => TransactionExecutorError::InsufficientFee {
+                    account_id: *account_id,
+                    account_balance: *account_balance,
+                    tx_fee: *tx_fee,
+                },
                 Some(TransactionKernelError::MissingAuthenticator) => {
                     TransactionExecutorError::MissingAuthenticator
                 },
+                Some(TransactionKernelError::NoteCycleLimitExceeded { num_cycles, max_cycles }) => {
+                    TransactionExecutorError::NoteCycleBudgetExceeded {
+                        num_cycles: *num_cycles,
+                        max_cycles: *max_cycles,
+                    }
+                },
                 _ => TransactionExecutorError::TransactionProgramExecutionFailed(exec_err),
             }
         },
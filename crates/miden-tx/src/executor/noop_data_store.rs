@@ -0,0 +1,75 @@
+use alloc::collections::BTreeSet;
+use alloc::sync::Arc;
+
+use miden_objects::Word;
+use miden_objects::account::{AccountId, PartialAccount, StorageMapWitness};
+use miden_objects::assembly::mast::MastForest;
+use miden_objects::asset::{AssetVaultKey, AssetWitness};
+use miden_objects::block::{BlockHeader, BlockNumber};
+use miden_objects::note::NoteScript;
+use miden_objects::transaction::{AccountInputs, PartialBlockchain};
+use miden_processor::{FutureMaybeSend, MastForestStore};
+
+use crate::{DataStore, DataStoreError};
+
+// NOOP DATA STORE
+// ================================================================================================
+
+/// A [`DataStore`] which does not provide any data.
+///
+/// This store is meant for constructing a [`TransactionExecutor`](super::TransactionExecutor)
+/// which only executes pre-loaded transaction inputs, see
+/// [`TransactionExecutor::execute_with_inputs`](super::TransactionExecutor::execute_with_inputs).
+/// All requests fail with an error and no MAST forests are served.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopDataStore;
+
+impl DataStore for NoopDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        _ref_blocks: BTreeSet<BlockNumber>,
+    ) -> impl FutureMaybeSend<Result<(PartialAccount, BlockHeader, PartialBlockchain), DataStoreError>>
+    {
+        async move { Err(DataStoreError::AccountNotFound(account_id)) }
+    }
+
+    fn get_foreign_account_inputs(
+        &self,
+        foreign_account_id: AccountId,
+        _ref_block: BlockNumber,
+    ) -> impl FutureMaybeSend<Result<AccountInputs, DataStoreError>> {
+        async move { Err(DataStoreError::AccountNotFound(foreign_account_id)) }
+    }
+
+    fn get_vault_asset_witness(
+        &self,
+        account_id: AccountId,
+        _vault_root: Word,
+        _vault_key: AssetVaultKey,
+    ) -> impl FutureMaybeSend<Result<AssetWitness, DataStoreError>> {
+        async move { Err(DataStoreError::AccountNotFound(account_id)) }
+    }
+
+    fn get_storage_map_witness(
+        &self,
+        account_id: AccountId,
+        _map_root: Word,
+        _map_key: Word,
+    ) -> impl FutureMaybeSend<Result<StorageMapWitness, DataStoreError>> {
+        async move { Err(DataStoreError::AccountNotFound(account_id)) }
+    }
+
+    fn get_note_script(
+        &self,
+        script_root: Word,
+    ) -> impl FutureMaybeSend<Result<NoteScript, DataStoreError>> {
+        async move { Err(DataStoreError::NoteScriptNotFound(script_root)) }
+    }
+}
+
+impl MastForestStore for NoopDataStore {
+    fn get(&self, _procedure_hash: &Word) -> Option<Arc<MastForest>> {
+        None
+    }
+}
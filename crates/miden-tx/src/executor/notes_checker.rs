@@ -1,12 +1,14 @@
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec::Vec;
 
-use miden_lib::note::{NoteConsumptionStatus, WellKnownNote};
+use miden_lib::note::{NoteConsumptionStatus, WellKnownNote, p2ide_cancel_note_id, p2ide_target};
 use miden_lib::transaction::TransactionKernel;
+use miden_objects::MAX_TX_EXECUTION_CYCLES;
 use miden_objects::account::AccountId;
 use miden_objects::block::BlockNumber;
-use miden_objects::note::Note;
+use miden_objects::note::{Note, NoteId};
 use miden_objects::transaction::{InputNote, InputNotes, TransactionArgs, TransactionInputs};
+use miden_processor::ExecutionError;
 use miden_processor::fast::FastProcessor;
 use miden_prover::AdviceInputs;
 
@@ -24,6 +26,52 @@ use crate::{DataStore, NoteCheckerError, TransactionExecutorError};
 /// Fixed at an amount that should keep each run of note consumption checking to a maximum of ~50ms.
 pub const MAX_NUM_CHECKER_NOTES: usize = 20;
 
+// NOTE CHECKER CONFIG
+// ================================================================================================
+
+/// Configures how the [`NoteConsumptionChecker`] checks the consumability of notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteCheckerConfig {
+    /// The maximum number of cycles the execution of a single note may take.
+    ///
+    /// A note whose execution takes more cycles is considered unconsumable. This prevents a
+    /// single expensive note from consuming the cycle budget of the entire transaction and masking
+    /// the results of the other notes.
+    ///
+    /// The budget is enforced during execution, see
+    /// [`TransactionExecutorHost::set_note_cycle_limit`](crate::TransactionExecutorHost::set_note_cycle_limit).
+    /// Since the fast processor used for checking does not support cycle limits, the budget is
+    /// checked whenever the note emits an event, so a note is aborted at the latest when its
+    /// execution ends.
+    pub per_note_max_cycles: u32,
+    /// Whether to stop checking as soon as the first note fails.
+    ///
+    /// The notes which have not been determined to be consumable or unconsumable by then are
+    /// returned as [`NoteConsumptionInfo::unchecked`].
+    pub stop_on_first_failure: bool,
+    /// The maximum amount of time [`NoteConsumptionChecker::check_notes_consumability`] may
+    /// spend on checking notes.
+    ///
+    /// The budget is checked before each execution attempt, so a single attempt may exceed it.
+    /// The notes which have not been checked when the budget is exhausted are returned as
+    /// [`NoteConsumptionInfo::unchecked`].
+    #[cfg(feature = "std")]
+    pub time_budget: Option<std::time::Duration>,
+}
+
+impl Default for NoteCheckerConfig {
+    /// Returns a config which allows each note to use the full transaction cycle budget, checks all
+    /// notes and has no time budget.
+    fn default() -> Self {
+        Self {
+            per_note_max_cycles: MAX_TX_EXECUTION_CYCLES,
+            stop_on_first_failure: false,
+            #[cfg(feature = "std")]
+            time_budget: None,
+        }
+    }
+}
+
 // NOTE CONSUMPTION INFO
 // ================================================================================================
 
@@ -39,13 +87,46 @@ impl FailedNote {
     pub fn new(note: Note, error: TransactionExecutorError) -> Self {
         Self { note, error }
     }
+
+    /// Returns the [`ExecutionError`] with which the VM aborted the execution of the note, or
+    /// `None` if the consumption failed for a reason other than a failed execution, e.g. because
+    /// the transaction could not be prepared or the note was not authorized by the account.
+    pub fn cause(&self) -> Option<&ExecutionError> {
+        match &self.error {
+            TransactionExecutorError::TransactionProgramExecutionFailed(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the consumption failed because the executed code itself is invalid, e.g.
+    /// because an assertion in the note script failed.
+    ///
+    /// Returns `false` if the execution failed due to the host, e.g. because an event handler
+    /// failed or the host could not provide the requested advice or MAST, as well as for failures
+    /// other than a failed execution, e.g. because the note exceeded its cycle budget.
+    pub fn is_script_error(&self) -> bool {
+        self.cause().is_some_and(|cause| {
+            !matches!(
+                cause,
+                ExecutionError::AdviceError { .. }
+                    | ExecutionError::CycleLimitExceeded(_)
+                    | ExecutionError::EventError { .. }
+                    | ExecutionError::MalformedMastForestInHost { .. }
+                    | ExecutionError::NoMastForestWithProcedure { .. }
+            )
+        })
+    }
 }
 
 /// Contains information about the successful and failed consumption of notes.
+///
+/// Notes for which checking was stopped early, as configured by [`NoteCheckerConfig`], are neither
+/// successful nor failed and are contained in `unchecked`.
 #[derive(Default, Debug)]
 pub struct NoteConsumptionInfo {
     pub successful: Vec<Note>,
     pub failed: Vec<FailedNote>,
+    pub unchecked: Vec<Note>,
 }
 
 impl NoteConsumptionInfo {
@@ -56,7 +137,13 @@ impl NoteConsumptionInfo {
 
     /// Creates a new [`NoteConsumptionInfo`] instance with the given successful and failed notes.
     pub fn new(successful: Vec<Note>, failed: Vec<FailedNote>) -> Self {
-        Self { successful, failed }
+        Self { successful, failed, ..Default::default() }
+    }
+
+    /// Creates a new [`NoteConsumptionInfo`] instance for a check which was stopped early, with
+    /// the given failed and unchecked notes.
+    fn new_stopped(failed: Vec<FailedNote>, unchecked: Vec<Note>) -> Self {
+        Self { failed, unchecked, ..Default::default() }
     }
 }
 
@@ -103,14 +190,23 @@ where
     /// otherwise-successful notes are retried in various combinations in an attempt to find a
     /// combination that passes the epilogue phase successfully.
     ///
-    /// Returns a list of successfully consumed notes and a list of failed notes.
+    /// Notes whose execution takes more than [`NoteCheckerConfig::per_note_max_cycles`] are
+    /// treated as failed notes with a [`TransactionExecutorError::NoteCycleBudgetExceeded`]
+    /// error. Checking can be stopped early through [`NoteCheckerConfig::stop_on_first_failure`]
+    /// and [`NoteCheckerConfig::time_budget`].
+    ///
+    /// Returns a list of successfully consumed notes, a list of failed notes and a list of notes
+    /// which were not checked because checking was stopped early.
     pub async fn check_notes_consumability(
         &self,
         target_account_id: AccountId,
         block_ref: BlockNumber,
         mut notes: Vec<Note>,
         tx_args: TransactionArgs,
+        config: NoteCheckerConfig,
     ) -> Result<NoteConsumptionInfo, NoteCheckerError> {
+        let deadline = CheckDeadline::new(&config);
+
         let num_notes = notes.len();
         if num_notes == 0 || num_notes > MAX_NUM_CHECKER_NOTES {
             return Err(NoteCheckerError::InputNoteCountOutOfRange(num_notes));
@@ -126,7 +222,125 @@ where
             .map_err(NoteCheckerError::TransactionPreparation)?;
 
         // Attempt to find an executable set of notes.
-        self.find_executable_notes_by_elimination(tx_inputs).await
+        self.find_executable_notes_by_elimination(tx_inputs, &config, &deadline).await
+    }
+
+    /// Checks whether some set of the provided input notes could be consumed by the provided
+    /// account, checking chunks of the notes on up to `parallelism` threads concurrently.
+    ///
+    /// The notes are partitioned into `parallelism` chunks of roughly equal size, each of which is
+    /// checked as in [`Self::check_notes_consumability`] against the same initial account state.
+    /// Each chunk is executed with its own host, so the result of checking one chunk does not
+    /// affect the others. Since notes from different chunks are never executed together during
+    /// that phase, the combinability of the notes across chunks is validated by a final
+    /// sequential check of the union of the successful notes of all chunks, which determines the
+    /// final set of successful notes. The final check is skipped if at most one chunk contains
+    /// successful notes.
+    ///
+    /// Besides using multiple threads, checking smaller chunks also reduces the number of notes
+    /// which are re-executed after each failed note is eliminated.
+    ///
+    /// Up to [`MAX_NUM_CHECKER_NOTES`] notes are allowed per chunk, i.e. up to
+    /// `MAX_NUM_CHECKER_NOTES * parallelism` notes in total. A `parallelism` of `0` is treated as
+    /// `1`. The options of the config apply to each chunk separately, e.g. with
+    /// [`NoteCheckerConfig::stop_on_first_failure`] each chunk stops on its own first failure, and
+    /// the time budget is shared by all chunks and the final check.
+    ///
+    /// Each chunk is checked on a scoped thread which drives the checking future to completion on
+    /// its own, so the futures returned by the data store are polled on these threads rather than
+    /// by the executor of the caller. Like the checking itself, this function blocks the calling
+    /// thread until all chunks have been checked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a thread to check a chunk of notes could not be spawned.
+    #[cfg(feature = "std")]
+    pub async fn check_notes_parallel(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        mut notes: Vec<Note>,
+        tx_args: TransactionArgs,
+        config: NoteCheckerConfig,
+        parallelism: usize,
+    ) -> Result<NoteConsumptionInfo, NoteCheckerError> {
+        let deadline = CheckDeadline::new(&config);
+        let parallelism = parallelism.max(1);
+
+        let num_notes = notes.len();
+        if num_notes == 0 || num_notes > MAX_NUM_CHECKER_NOTES * parallelism {
+            return Err(NoteCheckerError::InputNoteCountOutOfRange(num_notes));
+        }
+        // Ensure well-known notes are ordered first.
+        notes.sort_unstable_by_key(|note| WellKnownNote::from_note(note).is_none());
+
+        let mut tx_inputs = self
+            .0
+            .prepare_tx_inputs(
+                target_account_id,
+                block_ref,
+                InputNotes::from(notes.clone()),
+                tx_args,
+            )
+            .await
+            .map_err(NoteCheckerError::TransactionPreparation)?;
+
+        // Check each chunk of notes on its own thread.
+        let chunk_results = std::thread::scope(|scope| {
+            let handles = notes
+                .chunks(num_notes.div_ceil(parallelism))
+                .map(|chunk| {
+                    let mut chunk_inputs = tx_inputs.clone();
+                    chunk_inputs.set_input_notes(chunk.to_vec());
+                    let (config, deadline) = (&config, &deadline);
+
+                    scope.spawn(move || {
+                        futures::executor::block_on(self.find_executable_notes_by_elimination(
+                            chunk_inputs,
+                            config,
+                            deadline,
+                        ))
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let mut successful = Vec::new();
+        let mut failed = Vec::new();
+        let mut unchecked = Vec::new();
+        let mut num_successful_chunks = 0;
+        for chunk_result in chunk_results {
+            let chunk_info = chunk_result?;
+            num_successful_chunks += usize::from(!chunk_info.successful.is_empty());
+            successful.extend(chunk_info.successful);
+            failed.extend(chunk_info.failed);
+            unchecked.extend(chunk_info.unchecked);
+        }
+
+        // The successful notes of a single chunk were already executed together.
+        if num_successful_chunks <= 1 {
+            return Ok(NoteConsumptionInfo { successful, failed, unchecked });
+        }
+
+        // Validate that the notes which succeeded in their chunks can be consumed together.
+        tx_inputs.set_input_notes(successful);
+        let final_info =
+            self.find_executable_notes_by_elimination(tx_inputs, &config, &deadline).await?;
+        failed.extend(final_info.failed);
+        unchecked.extend(final_info.unchecked);
+
+        Ok(NoteConsumptionInfo {
+            successful: final_info.successful,
+            failed,
+            unchecked,
+        })
     }
 
     /// Checks whether the provided input note could be consumed by the provided account by
@@ -135,17 +349,25 @@ where
     /// This function takes into account the possibility that the signatures may not be loaded into
     /// the transaction context and returns the [`NoteConsumptionStatus`] result accordingly.
     ///
-    /// This function first applies the static analysis of the provided note, and if it doesn't
-    /// reveal any errors next it tries to execute the transaction. Based on the execution result,
-    /// it either returns a [`NoteCheckerError`] or the [`NoteConsumptionStatus`]: depending on
-    /// whether the execution succeeded, failed in the prologue, during the note execution process
-    /// or in the epilogue.
+    /// This function first applies the static analysis of the provided note and checks whether its
+    /// execution hint indicates that it is not meant to be consumed at `block_ref`, in which case
+    /// [`NoteConsumptionStatus::NotYetConsumable`] is returned. Execution hints are advisory and
+    /// not enforced by the transaction kernel, so such a note may still execute successfully. If
+    /// neither is conclusive, it tries to execute the transaction. Based on the execution
+    /// result, it either returns a [`NoteCheckerError`] or the [`NoteConsumptionStatus`]:
+    /// depending on whether the execution succeeded, failed in the prologue, during the note
+    /// execution process or in the epilogue.
+    ///
+    /// If the execution of the note takes more than [`NoteCheckerConfig::per_note_max_cycles`],
+    /// [`NoteConsumptionStatus::ExceededCycleBudget`] is returned. The other options of the config
+    /// do not apply to checking a single note.
     pub async fn can_consume(
         &self,
         target_account_id: AccountId,
         block_ref: BlockNumber,
         note: InputNote,
         tx_args: TransactionArgs,
+        config: NoteCheckerConfig,
     ) -> Result<NoteConsumptionStatus, NoteCheckerError> {
         // return the consumption status if we manage to determine it from the well-known note
         if let Some(well_known_note) = WellKnownNote::from_note(note.note())
@@ -155,6 +377,12 @@ where
             return Ok(consumption_status);
         }
 
+        // skip the execution if the note's execution hint indicates that it is not meant to be
+        // consumed at the block
+        if note.note().metadata().execution_hint().can_be_consumed(block_ref) == Some(false) {
+            return Ok(NoteConsumptionStatus::NotYetConsumable);
+        }
+
         // Prepare transaction inputs.
         let mut tx_inputs = self
             .0
@@ -168,7 +396,7 @@ where
             .map_err(NoteCheckerError::TransactionPreparation)?;
 
         // try to consume the provided note
-        match self.try_execute_notes(&mut tx_inputs).await {
+        match self.try_execute_notes(&mut tx_inputs, &config).await {
             // execution succeeded
             Ok(()) => Ok(NoteConsumptionStatus::Consumable),
             Err(tx_checker_error) => {
@@ -185,6 +413,12 @@ where
                     TransactionCheckerError::NoteExecution { .. } => {
                         Ok(NoteConsumptionStatus::UnconsumableConditions)
                     },
+                    // execution of the note took too many cycles
+                    TransactionCheckerError::NoteCycleBudgetExceeded {
+                        num_cycles,
+                        max_cycles,
+                        ..
+                    } => Ok(NoteConsumptionStatus::ExceededCycleBudget { num_cycles, max_cycles }),
                     // execution failed during the epilogue
                     TransactionCheckerError::EpilogueExecution(epilogue_error) => {
                         Ok(handle_epilogue_error(epilogue_error))
@@ -194,6 +428,37 @@ where
         }
     }
 
+    /// Checks whether the provided input note could be consumed by the provided account like
+    /// [`Self::can_consume`], taking into account the provided IDs of the P2IDE cancel notes seen
+    /// by the account.
+    ///
+    /// If the provided note is a P2IDE note targeting the provided account and its cancel note
+    /// (see [`p2ide_cancel_note_id`]) is contained in `seen_cancel_note_ids`, the note is treated
+    /// as [`NoteConsumptionStatus::NeverConsumable`], since its sender cancelled it. The caller is
+    /// responsible for only including the IDs of cancel notes sent by the sender of the note. The
+    /// status of the note for its sender, who can still reclaim it, is not affected.
+    pub async fn can_consume_with_cancellations(
+        &self,
+        target_account_id: AccountId,
+        block_ref: BlockNumber,
+        note: InputNote,
+        tx_args: TransactionArgs,
+        config: NoteCheckerConfig,
+        seen_cancel_note_ids: &BTreeSet<NoteId>,
+    ) -> Result<NoteConsumptionStatus, NoteCheckerError> {
+        if !seen_cancel_note_ids.is_empty()
+            && p2ide_target(note.note()) == Some(target_account_id)
+            && let Ok(cancel_note_id) = p2ide_cancel_note_id(note.note())
+            && seen_cancel_note_ids.contains(&cancel_note_id)
+        {
+            return Ok(NoteConsumptionStatus::NeverConsumable(
+                "the P2IDE note was cancelled by its sender".into(),
+            ));
+        }
+
+        self.can_consume(target_account_id, block_ref, note, tx_args, config).await
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -204,6 +469,8 @@ where
     async fn find_executable_notes_by_elimination(
         &self,
         mut tx_inputs: TransactionInputs,
+        config: &NoteCheckerConfig,
+        deadline: &CheckDeadline,
     ) -> Result<NoteConsumptionInfo, NoteCheckerError> {
         let mut candidate_notes = tx_inputs
             .input_notes()
@@ -216,18 +483,39 @@ where
         // either a set of notes executes without failure or the set of notes cannot be
         // further reduced.
         loop {
+            if deadline.is_exceeded() {
+                return Ok(NoteConsumptionInfo::new_stopped(failed_notes, candidate_notes));
+            }
+
             // Execute the candidate notes.
             tx_inputs.set_input_notes(candidate_notes.clone());
-            match self.try_execute_notes(&mut tx_inputs).await {
+            match self.try_execute_notes(&mut tx_inputs, config).await {
                 Ok(()) => {
                     // A full set of successful notes has been found.
                     let successful = candidate_notes;
                     return Ok(NoteConsumptionInfo::new(successful, failed_notes));
                 },
-                Err(TransactionCheckerError::NoteExecution { failed_note_index, error }) => {
+                Err(
+                    error @ (TransactionCheckerError::NoteExecution { .. }
+                    | TransactionCheckerError::NoteCycleBudgetExceeded { .. }),
+                ) => {
+                    let failed_note_index = match error {
+                        TransactionCheckerError::NoteExecution { failed_note_index, .. } => {
+                            failed_note_index
+                        },
+                        TransactionCheckerError::NoteCycleBudgetExceeded { note_index, .. } => {
+                            note_index
+                        },
+                        _ => unreachable!("error should be a note error"),
+                    };
+
                     // SAFETY: Failed note index is in bounds of the candidate notes.
                     let failed_note = candidate_notes.remove(failed_note_index);
-                    failed_notes.push(FailedNote::new(failed_note, error));
+                    failed_notes.push(FailedNote::new(failed_note, error.into()));
+
+                    if config.stop_on_first_failure {
+                        return Ok(NoteConsumptionInfo::new_stopped(failed_notes, candidate_notes));
+                    }
 
                     // All possible candidate combinations have been attempted.
                     if candidate_notes.is_empty() {
@@ -241,6 +529,8 @@ where
                             candidate_notes,
                             failed_notes,
                             tx_inputs,
+                            config,
+                            deadline,
                         )
                         .await;
                     return Ok(consumption_info);
@@ -261,17 +551,24 @@ where
     /// This method incrementally tries combinations of increasing size (1 note, 2 notes, 3 notes,
     /// etc.) and builds upon previously successful combinations to find the maximum executable
     /// set.
+    ///
+    /// If checking is stopped early, the notes which are not part of the successful combination
+    /// and did not fail are returned as unchecked notes.
     async fn find_largest_executable_combination(
         &self,
         mut remaining_notes: Vec<Note>,
         mut failed_notes: Vec<FailedNote>,
         mut tx_inputs: TransactionInputs,
+        config: &NoteCheckerConfig,
+        deadline: &CheckDeadline,
     ) -> NoteConsumptionInfo {
         let mut successful_notes = Vec::new();
         let mut failed_note_index = BTreeMap::new();
 
+        let mut stopped = false;
+
         // Iterate by note count: try 1 note, then 2, then 3, etc.
-        for size in 1..=remaining_notes.len() {
+        'sizes: for size in 1..=remaining_notes.len() {
             // Can't build a combination of size N without at least N-1 successful notes.
             if successful_notes.len() < size - 1 {
                 break;
@@ -279,10 +576,15 @@ where
 
             // Try adding each remaining note to the current successful combination.
             for (idx, note) in remaining_notes.iter().enumerate() {
+                if deadline.is_exceeded() {
+                    stopped = true;
+                    break 'sizes;
+                }
+
                 successful_notes.push(note.clone());
 
                 tx_inputs.set_input_notes(successful_notes.clone());
-                match self.try_execute_notes(&mut tx_inputs).await {
+                match self.try_execute_notes(&mut tx_inputs, config).await {
                     Ok(()) => {
                         // The successfully added note might have failed earlier. Remove it from the
                         // failed list.
@@ -301,14 +603,33 @@ where
                         // note).
                         failed_note_index
                             .insert(failed_note.id(), FailedNote::new(failed_note, error.into()));
+
+                        if config.stop_on_first_failure {
+                            stopped = true;
+                            break 'sizes;
+                        }
                     },
                 }
             }
         }
 
+        // When stopping early, the remaining notes which did not fail have not been checked.
+        let unchecked = if stopped {
+            remaining_notes
+                .into_iter()
+                .filter(|note| !failed_note_index.contains_key(&note.id()))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
         // Append failed notes to the list of failed notes provided as input.
         failed_notes.extend(failed_note_index.into_values());
-        NoteConsumptionInfo::new(successful_notes, failed_notes)
+        NoteConsumptionInfo {
+            successful: successful_notes,
+            failed: failed_notes,
+            unchecked,
+        }
     }
 
     /// Attempts to execute a transaction with the provided input notes.
@@ -316,9 +637,13 @@ where
     /// This method executes the full transaction pipeline including prologue, note execution,
     /// and epilogue phases. It returns `Ok(())` if all notes are successfully consumed,
     /// or a specific [`NoteExecutionError`] indicating where and why the execution failed.
+    ///
+    /// If a note takes more than [`NoteCheckerConfig::per_note_max_cycles`], its execution is
+    /// aborted and the note is reported as [`TransactionCheckerError::NoteCycleBudgetExceeded`].
     async fn try_execute_notes(
         &self,
         tx_inputs: &mut TransactionInputs,
+        config: &NoteCheckerConfig,
     ) -> Result<(), TransactionCheckerError> {
         if tx_inputs.input_notes().is_empty() {
             return Ok(());
@@ -329,6 +654,7 @@ where
                 .prepare_transaction(tx_inputs)
                 .await
                 .map_err(TransactionCheckerError::TransactionPreparation)?;
+        host.set_note_cycle_limit(config.per_note_max_cycles);
 
         let processor =
             FastProcessor::new_with_advice_inputs(stack_inputs.as_slice(), advice_inputs);
@@ -366,11 +692,23 @@ where
                 // If the interval end of the last note is specified, then an error occurred after
                 // notes processing.
                 if last_note_interval.end().is_some() {
-                    Err(TransactionCheckerError::EpilogueExecution(error))
-                } else {
-                    // Return the index of the failed note.
-                    let failed_note_index = success_notes.len();
-                    Err(TransactionCheckerError::NoteExecution { failed_note_index, error })
+                    return Err(TransactionCheckerError::EpilogueExecution(error));
+                }
+
+                // Return the index of the failed note.
+                let failed_note_index = success_notes.len();
+                match error {
+                    TransactionExecutorError::NoteCycleBudgetExceeded {
+                        num_cycles,
+                        max_cycles,
+                    } => Err(TransactionCheckerError::NoteCycleBudgetExceeded {
+                        note_index: failed_note_index,
+                        num_cycles,
+                        max_cycles,
+                    }),
+                    error => {
+                        Err(TransactionCheckerError::NoteExecution { failed_note_index, error })
+                    },
                 }
             },
         }
@@ -380,6 +718,36 @@ where
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Tracks the time budget of a notes consumability check.
+///
+/// Without the `std` feature there is no clock, so the deadline is never exceeded.
+struct CheckDeadline {
+    #[cfg(feature = "std")]
+    deadline: Option<std::time::Instant>,
+}
+
+impl CheckDeadline {
+    /// Returns a new deadline which expires after the time budget of the provided config, starting
+    /// now.
+    #[cfg_attr(not(feature = "std"), allow(unused_variables))]
+    fn new(config: &NoteCheckerConfig) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            deadline: config.time_budget.map(|budget| std::time::Instant::now() + budget),
+        }
+    }
+
+    /// Returns `true` if the time budget is exhausted.
+    fn is_exceeded(&self) -> bool {
+        #[cfg(feature = "std")]
+        if let Some(deadline) = self.deadline {
+            return std::time::Instant::now() >= deadline;
+        }
+
+        false
+    }
+}
+
 /// Handle the epilogue error during the note consumption check in the `can_consume` method.
 ///
 /// The goal of this helper function is to handle the cases where the account couldn't consume the
@@ -388,7 +756,7 @@ fn handle_epilogue_error(epilogue_error: TransactionExecutorError) -> NoteConsum
     match epilogue_error {
         // `Unauthorized` is returned for the multisig accounts if the transaction doesn't have
         // enough signatures.
-        TransactionExecutorError::Unauthorized(_)
+        TransactionExecutorError::Unauthorized { .. }
         // `MissingAuthenticator` is returned for the account with the basic auth if the
         // authenticator was not provided to the executor (UnreachableAuth).
         | TransactionExecutorError::MissingAuthenticator => {
@@ -0,0 +1,99 @@
+use miden_objects::{MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES};
+use miden_processor::ExecutionOptions;
+
+// EXECUTION PROFILE
+// ================================================================================================
+
+/// A preset of cycle limits for the [`TransactionExecutor`](super::TransactionExecutor) tuned for
+/// a common kind of transaction.
+///
+/// A profile defines the maximum number of cycles a transaction may take before its execution is
+/// aborted and the number of cycles it is expected to take, which is used to pre-allocate the
+/// execution trace. All presets are within [`MIN_TX_EXECUTION_CYCLES`] and
+/// [`MAX_TX_EXECUTION_CYCLES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionProfile {
+    max_cycles: u32,
+    expected_cycles: u32,
+}
+
+impl ExecutionProfile {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the profile for wallet transactions, e.g. consuming or creating a few notes with
+    /// signature-based authentication.
+    ///
+    /// A simple wallet transaction takes somewhat more than `2^16` cycles, and the maximum leaves
+    /// room for consuming several notes.
+    pub const fn wallet() -> Self {
+        Self::new(1 << 18, 1 << 17)
+    }
+
+    /// Returns the profile for faucet transactions, e.g. minting assets into a few notes.
+    ///
+    /// Since such transactions do not consume notes, the maximum is lower than for
+    /// [`Self::wallet`] and aborts runaway faucet transactions earlier.
+    pub const fn faucet() -> Self {
+        Self::new(1 << 17, 1 << 17)
+    }
+
+    /// Returns the profile for checking the consumability of batches of notes, e.g. with the
+    /// [`NoteConsumptionChecker`](super::NoteConsumptionChecker), where a single transaction may
+    /// consume many notes.
+    pub const fn batch_checker() -> Self {
+        Self::new(1 << 21, 1 << 18)
+    }
+
+    /// Returns the profile which allows transactions to take up to [`MAX_TX_EXECUTION_CYCLES`].
+    ///
+    /// These are the limits used by a [`TransactionExecutor`](super::TransactionExecutor) unless
+    /// configured otherwise.
+    pub const fn max() -> Self {
+        Self::new(MAX_TX_EXECUTION_CYCLES, MIN_TX_EXECUTION_CYCLES)
+    }
+
+    /// Returns a new profile with the provided cycle limits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the limits are not within [`MIN_TX_EXECUTION_CYCLES`] and
+    /// [`MAX_TX_EXECUTION_CYCLES`] or if `expected_cycles` exceeds `max_cycles`, which makes
+    /// invalid presets fail at compile time.
+    const fn new(max_cycles: u32, expected_cycles: u32) -> Self {
+        assert!(MIN_TX_EXECUTION_CYCLES <= expected_cycles);
+        assert!(expected_cycles <= max_cycles);
+        assert!(max_cycles <= MAX_TX_EXECUTION_CYCLES);
+
+        Self { max_cycles, expected_cycles }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the maximum number of cycles a transaction may take under this profile.
+    pub const fn max_cycles(&self) -> u32 {
+        self.max_cycles
+    }
+
+    /// Returns the number of cycles a transaction is expected to take under this profile.
+    pub const fn expected_cycles(&self) -> u32 {
+        self.expected_cycles
+    }
+
+    /// Returns the [`ExecutionOptions`] with the cycle limits of this profile and the provided
+    /// tracing and debugging settings.
+    pub fn to_execution_options(
+        &self,
+        enable_tracing: bool,
+        enable_debugging: bool,
+    ) -> ExecutionOptions {
+        ExecutionOptions::new(
+            Some(self.max_cycles),
+            self.expected_cycles,
+            enable_tracing,
+            enable_debugging,
+        )
+        .expect("profile limits should be valid execution options")
+    }
+}
@@ -7,11 +7,11 @@ use crate::errors::{TransactionHostError, TransactionKernelError};
 // ACCOUNT PROCEDURE INDEX MAP
 // ================================================================================================
 
-/// A map of maps { acct_code_commitment |-> { proc_root |-> proc_index } } for all known
-/// procedures of account interfaces for all accounts expected to be invoked during transaction
-/// execution.
+/// A map of maps { acct_code_commitment |-> { proc_root |-> (proc_index, storage_offset) } } for
+/// all known procedures of account interfaces for all accounts expected to be invoked during
+/// transaction execution.
 #[derive(Debug, Clone, Default)]
-pub struct AccountProcedureIndexMap(BTreeMap<Word, BTreeMap<Word, u8>>);
+pub struct AccountProcedureIndexMap(BTreeMap<Word, BTreeMap<Word, (u8, u8)>>);
 
 impl AccountProcedureIndexMap {
     /// Returns a new [`AccountProcedureIndexMap`] instantiated with account procedures from the
@@ -35,8 +35,8 @@ impl AccountProcedureIndexMap {
     /// [`AccountCode::commitment`] as the key.
     ///
     /// The resulting instance will map the account code commitment to a mapping of
-    /// `proc_root |-> proc_index` for any account that is expected to be involved in the
-    /// transaction, enabling fast procedure index lookups at runtime.
+    /// `proc_root |-> (proc_index, storage_offset)` for any account that is expected to be involved
+    /// in the transaction, enabling fast procedure index lookups at runtime.
     pub fn insert_code(&mut self, code: &AccountCode) -> Result<(), TransactionHostError> {
         let mut procedure_map = BTreeMap::new();
         for (proc_idx, proc_info) in code.procedures().iter().enumerate() {
@@ -46,7 +46,7 @@ impl AccountProcedureIndexMap {
                 )
             })?;
 
-            procedure_map.insert(*proc_info.mast_root(), proc_idx);
+            procedure_map.insert(*proc_info.mast_root(), (proc_idx, proc_info.storage_offset()));
         }
 
         self.0.insert(code.commitment(), procedure_map);
@@ -61,6 +61,25 @@ impl AccountProcedureIndexMap {
     /// Returns an error if the procedure at the top of the operand stack is not present in this
     /// map.
     pub fn get_proc_index(&self, process: &ProcessState) -> Result<u8, TransactionKernelError> {
+        self.get_proc_entry(process).map(|(proc_idx, _)| proc_idx)
+    }
+
+    /// Returns the storage offset of the procedure whose root is currently at the top of the
+    /// operand stack in the provided process.
+    ///
+    /// # Errors
+    /// Returns an error if the procedure at the top of the operand stack is not present in this
+    /// map.
+    pub fn get_proc_storage_offset(
+        &self,
+        process: &ProcessState,
+    ) -> Result<u8, TransactionKernelError> {
+        self.get_proc_entry(process).map(|(_, storage_offset)| storage_offset)
+    }
+
+    /// Returns the index and storage offset of the procedure whose root is currently at the top of
+    /// the operand stack in the provided process.
+    fn get_proc_entry(&self, process: &ProcessState) -> Result<(u8, u8), TransactionKernelError> {
         // get active account code commitment
         let code_commitment = {
             let account_stack_top_ptr = process
@@ -17,7 +17,7 @@ mod kernel_process;
 use kernel_process::TransactionKernelProcess;
 
 mod script_mast_forest_store;
-pub use script_mast_forest_store::ScriptMastForestStore;
+pub use script_mast_forest_store::{MastForestResolver, ScriptMastForestStore};
 
 mod tx_progress;
 
@@ -40,10 +40,14 @@ use miden_objects::account::{
 use miden_objects::asset::{Asset, AssetVault, AssetVaultKey, FungibleAsset};
 use miden_objects::note::{NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript};
 use miden_objects::transaction::{
+    CustomEvent,
+    CustomEventId,
     InputNote,
     InputNotes,
     OutputNote,
     OutputNotes,
+    StorageAccessKind,
+    StorageAccessLog,
     TransactionMeasurements,
     TransactionSummary,
 };
@@ -89,8 +93,9 @@ pub struct TransactionBaseHost<'store, STORE> {
     /// The delta is updated by event handlers.
     account_delta: AccountDeltaTracker,
 
-    /// A map of the procedure MAST roots to the corresponding procedure indices for all the
-    /// account codes involved in the transaction (for native and foreign accounts alike).
+    /// A map of the procedure MAST roots to the corresponding procedure indices and storage
+    /// offsets for all the account codes involved in the transaction (for native and foreign
+    /// accounts alike).
     acct_procedure_index_map: AccountProcedureIndexMap,
 
     /// Input notes consumed by the transaction.
@@ -105,6 +110,19 @@ pub struct TransactionBaseHost<'store, STORE> {
     /// The progress is updated event handlers.
     tx_progress: TransactionProgress,
 
+    /// The log of the storage slots and storage map items accessed during transaction execution,
+    /// or `None` if storage access tracking is disabled.
+    ///
+    /// The log is updated by event handlers.
+    storage_access_log: Option<StorageAccessLog>,
+
+    /// The account ID and absolute slot index of the storage item the active account procedure
+    /// may be about to read, captured when the procedure is authenticated.
+    ///
+    /// This is only tracked if storage access tracking is enabled and is consumed by
+    /// [`Self::on_account_storage_after_get_item`].
+    pending_storage_read: Option<(AccountId, Felt)>,
+
     /// Handle the VM default events _before_ passing it to user defined ones.
     stdlib_handlers: EventHandlerRegistry,
 }
@@ -145,6 +163,8 @@ where
             output_notes: BTreeMap::default(),
             input_notes,
             tx_progress: TransactionProgress::default(),
+            storage_access_log: None,
+            pending_storage_read: None,
             stdlib_handlers,
         }
     }
@@ -153,12 +173,15 @@ where
     // --------------------------------------------------------------------------------------------
 
     /// Returns the [`MastForest`] that contains the procedure with the given `procedure_root`.
+    ///
+    /// The script MAST forests are searched first, followed by the user-provided store. The
+    /// [`MastForestResolver`] of the script store, if any, is only consulted if neither contains
+    /// the procedure.
     pub fn get_mast_forest(&self, procedure_root: &Word) -> Option<Arc<MastForest>> {
-        // Search in the note MAST forest store, otherwise fall back to the user-provided store
-        match self.scripts_mast_store.get(procedure_root) {
-            Some(forest) => Some(forest),
-            None => self.mast_store.get(procedure_root),
-        }
+        self.scripts_mast_store
+            .get_local(procedure_root)
+            .or_else(|| self.mast_store.get(procedure_root))
+            .or_else(|| self.scripts_mast_store.resolve(procedure_root))
     }
 
     /// Returns a reference to the `tx_progress` field of this transaction host.
@@ -199,10 +222,17 @@ where
         self.output_notes.values().cloned().map(|builder| builder.build()).collect()
     }
 
-    /// Consumes `self` and returns the account delta, output notes and transaction progress.
+    /// Consumes `self` and returns the account delta, output notes, transaction progress and
+    /// storage access log.
     pub fn into_parts(
         self,
-    ) -> (AccountDelta, InputNotes<InputNote>, Vec<OutputNote>, TransactionProgress) {
+    ) -> (
+        AccountDelta,
+        InputNotes<InputNote>,
+        Vec<OutputNote>,
+        TransactionProgress,
+        Option<StorageAccessLog>,
+    ) {
         let output_notes = self.output_notes.into_values().map(|builder| builder.build()).collect();
 
         (
@@ -210,12 +240,19 @@ where
             self.input_notes,
             output_notes,
             self.tx_progress,
+            self.storage_access_log,
         )
     }
 
     // MUTATORS
     // --------------------------------------------------------------------------------------------
 
+    /// Enables recording the storage slots and storage map items accessed by the native and
+    /// foreign accounts during transaction execution.
+    pub fn enable_storage_access_tracking(&mut self) {
+        self.storage_access_log.get_or_insert_default();
+    }
+
     /// Inserts an output note builder at the specified index.
     ///
     /// # Errors
@@ -396,6 +433,7 @@ where
               // Note: This always returns an error to abort the transaction.
               Err(self.on_unauthorized(process))
             }
+            TransactionEvent::CustomEventEmitted => self.on_custom_event_emitted(process),
         }
         .map_err(EventError::from)?;
 
@@ -495,7 +533,30 @@ where
             );
         }
 
-        TransactionKernelError::Unauthorized(Box::new(tx_summary))
+        TransactionKernelError::Unauthorized {
+            account_id: self.initial_account_header().id(),
+            summary: Box::new(tx_summary),
+        }
+    }
+
+    /// Extracts the ID and payload of the custom event emitted via `miden::tx::emit_event`.
+    ///
+    /// Expected stack state:
+    ///
+    /// ```text
+    /// `[event, custom_event_id, PAYLOAD_0, PAYLOAD_1]`
+    /// ```
+    fn on_custom_event_emitted(
+        &self,
+        process: &ProcessState,
+    ) -> Result<TransactionEventHandling, TransactionKernelError> {
+        let custom_event_id = CustomEventId::try_from(process.get_stack_item(1))
+            .map_err(TransactionKernelError::InvalidCustomEventId)?;
+        let payload = [process.get_stack_word(2), process.get_stack_word(6)];
+
+        Ok(TransactionEventHandling::Unhandled(TransactionEventData::CustomEvent {
+            event: CustomEvent::new(custom_event_id, payload),
+        }))
     }
 
     /// Extracts all necessary data to handle
@@ -608,12 +669,23 @@ where
 
     /// Loads the index of the procedure root onto the advice stack.
     ///
-    /// Expected stack state: `[event, PROC_ROOT, ...]`
+    /// If storage access tracking is enabled, this also captures the absolute index of the storage
+    /// slot the procedure would read, so that the read can be recorded by
+    /// [`Self::on_account_storage_after_get_item`].
+    ///
+    /// Expected stack state: `[event, PROC_ROOT, slot_index, ...]`
     fn on_account_push_procedure_index(
         &mut self,
         process: &ProcessState,
     ) -> Result<Vec<AdviceMutation>, TransactionKernelError> {
         let proc_idx = self.acct_procedure_index_map.get_proc_index(process)?;
+
+        if self.storage_access_log.is_some() {
+            let storage_offset = self.acct_procedure_index_map.get_proc_storage_offset(process)?;
+            let slot_index = process.get_stack_item(5) + Felt::from(storage_offset);
+            self.pending_storage_read = Some((process.get_active_account_id()?, slot_index));
+        }
+
         Ok(vec![AdviceMutation::extend_stack([Felt::from(proc_idx)])])
     }
 
@@ -663,21 +735,68 @@ where
             new_slot_value,
         );
 
+        if let Some(storage_access_log) = self.storage_access_log.as_mut() {
+            storage_access_log.record_slot_access(
+                self.initial_account_header.id(),
+                slot_index.as_int() as u8,
+                StorageAccessKind::Write,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Records the read of a storage slot if storage access tracking is enabled.
+    ///
+    /// This is invoked on the
+    /// [`ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE`](miden_lib::transaction::ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE)
+    /// trace rather than on an event, so that reading storage items does not incur any overhead in
+    /// the kernel. Since the slot index is no longer on the stack at this point, the index captured
+    /// when the reading procedure was authenticated is used instead.
+    pub fn on_account_storage_after_get_item(&mut self) -> Result<(), TransactionKernelError> {
+        let Some(storage_access_log) = self.storage_access_log.as_mut() else {
+            return Ok(());
+        };
+
+        let (account_id, slot_index) = self
+            .pending_storage_read
+            .take()
+            .expect("storage read should be captured when the procedure is authenticated");
+        let slot_index = u8::try_from(slot_index.as_int()).map_err(|_| {
+            TransactionKernelError::InvalidStorageSlotIndex {
+                max: u8::MAX as u64,
+                actual: slot_index.as_int(),
+            }
+        })?;
+
+        storage_access_log.record_slot_access(account_id, slot_index, StorageAccessKind::Read);
+
         Ok(())
     }
 
     /// Checks if the necessary witness for accessing the map item is already in the merkle store,
     /// and if not, extracts all necessary data for requesting it.
     ///
+    /// If storage access tracking is enabled, the read of the map item is recorded as well.
+    ///
     /// Expected stack state: `[event, KEY, ROOT, index]`
     pub fn on_account_storage_before_get_map_item(
-        &self,
+        &mut self,
         process: &ProcessState,
     ) -> Result<TransactionEventHandling, TransactionKernelError> {
         let map_key = process.get_stack_word(1);
         let current_map_root = process.get_stack_word(5);
         let slot_index = process.get_stack_item(9);
 
+        if let Some(storage_access_log) = self.storage_access_log.as_mut() {
+            storage_access_log.record_map_item_access(
+                process.get_active_account_id()?,
+                slot_index.as_int() as u8,
+                map_key,
+                StorageAccessKind::Read,
+            );
+        }
+
         self.on_account_storage_before_get_or_set_map_item(
             slot_index,
             current_map_root,
@@ -801,6 +920,15 @@ where
             new_map_value,
         );
 
+        if let Some(storage_access_log) = self.storage_access_log.as_mut() {
+            storage_access_log.record_map_item_access(
+                self.initial_account_header.id(),
+                slot_index.as_int() as u8,
+                key,
+                StorageAccessKind::Write,
+            );
+        }
+
         Ok(())
     }
 
@@ -1041,7 +1169,18 @@ where
             ));
         }
 
-        Ok(TransactionSummary::new(account_delta, input_notes, output_notes, salt))
+        let tx_summary = TransactionSummary::new(account_delta, input_notes, output_notes, salt);
+        let actual_summary_commitment = tx_summary.to_commitment();
+        if actual_summary_commitment != msg {
+            return Err(TransactionKernelError::TransactionSummaryCommitmentMismatch(
+                format!(
+                    "expected transaction summary commitment to be {actual_summary_commitment} but was {msg}"
+                )
+                .into(),
+            ));
+        }
+
+        Ok(tx_summary)
     }
 }
 
@@ -1118,6 +1257,11 @@ pub(super) enum TransactionEventData {
         /// The serial number extracted from the advice provider.
         serial_num: Word,
     },
+    /// The data of a custom event emitted by an account component or script.
+    CustomEvent {
+        /// The custom event extracted from the stack.
+        event: CustomEvent,
+    },
 }
 
 // HELPER FUNCTIONS
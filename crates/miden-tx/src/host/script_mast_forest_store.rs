@@ -1,5 +1,6 @@
 use alloc::collections::BTreeMap;
 use alloc::sync::Arc;
+use core::fmt;
 
 use miden_objects::Word;
 use miden_objects::assembly::mast::MastForest;
@@ -8,14 +9,45 @@ use miden_objects::transaction::TransactionScript;
 use miden_objects::vm::AdviceMap;
 use miden_processor::MastForestStore;
 
+// MAST FOREST RESOLVER
+// ================================================================================================
+
+/// A fallback source of MAST forests which is consulted when a procedure is not found in any of the
+/// MAST forests loaded up front.
+///
+/// This allows integrators to serve procedure MAST forests from their own storage (e.g. a
+/// content-addressed cache) without implementing a full [`DataStore`](crate::DataStore).
+///
+/// The trait is implemented for all closures of the form
+/// `Fn(&Word) -> Option<Arc<MastForest>>`.
+pub trait MastForestResolver: Send + Sync {
+    /// Returns the [`MastForest`] containing the procedure with the provided MAST root, or `None`
+    /// if the resolver cannot provide it.
+    fn resolve(&self, procedure_root: &Word) -> Option<Arc<MastForest>>;
+}
+
+impl<F> MastForestResolver for F
+where
+    F: Fn(&Word) -> Option<Arc<MastForest>> + Send + Sync,
+{
+    fn resolve(&self, procedure_root: &Word) -> Option<Arc<MastForest>> {
+        self(procedure_root)
+    }
+}
+
+// SCRIPT MAST FOREST STORE
+// ================================================================================================
+
 /// Stores the MAST forests for a set of scripts (both note scripts and transaction scripts).
 ///
 /// A [ScriptMastForestStore] is meant to exclusively store MAST forests related to both
-/// transaction and input note scripts.
-#[derive(Debug, Clone, Default)]
+/// transaction and input note scripts. Optionally, a [`MastForestResolver`] can be attached to the
+/// store, which is consulted for procedures not contained in any of the stored forests.
+#[derive(Clone, Default)]
 pub struct ScriptMastForestStore {
     mast_forests: BTreeMap<Word, Arc<MastForest>>,
     advice_map: AdviceMap,
+    resolver: Option<Arc<dyn MastForestResolver>>,
 }
 
 impl ScriptMastForestStore {
@@ -27,6 +59,7 @@ impl ScriptMastForestStore {
         let mut mast_store = ScriptMastForestStore {
             mast_forests: BTreeMap::new(),
             advice_map: AdviceMap::default(),
+            resolver: None,
         };
 
         for note_script in note_scripts {
@@ -39,6 +72,16 @@ impl ScriptMastForestStore {
         mast_store
     }
 
+    /// Attaches the provided [`MastForestResolver`] to this store and returns the resulting store.
+    ///
+    /// The resolver is consulted whenever a procedure is not found in any of the stored forests.
+    /// This will overwrite any previously set resolver.
+    #[must_use]
+    pub fn with_resolver(mut self, resolver: Arc<dyn MastForestResolver>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
     /// Registers all procedures of the provided [MastForest] with this store.
     fn insert(&mut self, mast_forest: Arc<MastForest>) {
         // only register procedures that are local to this forest
@@ -56,6 +99,28 @@ impl ScriptMastForestStore {
     pub fn advice_map(&self) -> &AdviceMap {
         &self.advice_map
     }
+
+    /// Returns the [`MastForest`] containing the procedure with the provided MAST root if it is one
+    /// of the stored forests, without consulting the resolver.
+    pub fn get_local(&self, procedure_root: &Word) -> Option<Arc<MastForest>> {
+        self.mast_forests.get(procedure_root).cloned()
+    }
+
+    /// Returns the [`MastForest`] containing the procedure with the provided MAST root from the
+    /// resolver, or `None` if no resolver is set or the resolver cannot provide it.
+    pub fn resolve(&self, procedure_root: &Word) -> Option<Arc<MastForest>> {
+        self.resolver.as_ref().and_then(|resolver| resolver.resolve(procedure_root))
+    }
+}
+
+impl fmt::Debug for ScriptMastForestStore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScriptMastForestStore")
+            .field("mast_forests", &self.mast_forests)
+            .field("advice_map", &self.advice_map)
+            .field("has_resolver", &self.resolver.is_some())
+            .finish()
+    }
 }
 
 // MAST FOREST STORE IMPLEMENTATION
@@ -63,6 +128,6 @@ impl ScriptMastForestStore {
 
 impl MastForestStore for ScriptMastForestStore {
     fn get(&self, procedure_root: &Word) -> Option<Arc<MastForest>> {
-        self.mast_forests.get(procedure_root).cloned()
+        self.get_local(procedure_root).or_else(|| self.resolve(procedure_root))
     }
 }
@@ -128,7 +128,8 @@ impl StorageDeltaTracker {
     ///   initial value at the beginning of transaction execution.
     fn normalize(self) -> AccountStorageDelta {
         let Self { storage_header, init_maps, delta } = self;
-        let (mut value_slots, mut map_slots) = delta.into_parts();
+        // Slot type changes cannot be made during transaction execution, so none are tracked.
+        let (mut value_slots, mut map_slots, _) = delta.into_parts();
 
         // Keep only the values whose new value is different from the initial value.
         value_slots.retain(|slot_idx, new_value| {
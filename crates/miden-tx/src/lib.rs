@@ -8,11 +8,15 @@ extern crate std;
 
 mod executor;
 pub use executor::{
+    CachingDataStore,
     DataStore,
     ExecutionOptions,
+    ExecutionProfile,
     FailedNote,
     MAX_NUM_CHECKER_NOTES,
     MastForestStore,
+    NoopDataStore,
+    NoteCheckerConfig,
     NoteConsumptionChecker,
     NoteConsumptionInfo,
     TransactionExecutor,
@@ -20,14 +24,23 @@ pub use executor::{
 };
 
 mod host;
-pub use host::{AccountProcedureIndexMap, LinkMap, MemoryViewer, ScriptMastForestStore};
+pub use host::{
+    AccountProcedureIndexMap,
+    LinkMap,
+    MastForestResolver,
+    MemoryViewer,
+    ScriptMastForestStore,
+};
 
 mod prover;
 pub use prover::{
+    DelegatedProver,
     LocalTransactionProver,
     ProvingOptions,
     TransactionMastStore,
+    TransactionProver,
     TransactionProverHost,
+    validate_executed_transaction,
 };
 
 mod verifier;
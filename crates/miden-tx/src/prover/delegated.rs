@@ -0,0 +1,94 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::error::Error;
+
+use miden_objects::MIN_PROOF_SECURITY_LEVEL;
+use miden_objects::transaction::{ExecutedTransaction, ProvenTransaction};
+use miden_objects::utils::{Deserializable, Serializable};
+use miden_processor::FutureMaybeSend;
+
+use super::{TransactionProver, validate_executed_transaction};
+use crate::{TransactionProverError, TransactionVerifier};
+
+// DELEGATED TRANSACTION PROVER
+// ================================================================================================
+
+/// A transaction prover which delegates proving to an external service.
+///
+/// The executed transaction is serialized and handed to the user-provided transport, which is
+/// expected to return the serialized [`ProvenTransaction`], e.g. by sending the bytes to a remote
+/// proving service. The transport is a closure of the form
+/// `Fn(Vec<u8>) -> Future<Output = Result<Vec<u8>, E>>`.
+///
+/// The returned proven transaction is only accepted if its ID matches the ID of the submitted
+/// executed transaction and its proof is verified by a [`TransactionVerifier`].
+pub struct DelegatedProver<T> {
+    transport: T,
+    verifier: TransactionVerifier,
+}
+
+impl<T> DelegatedProver<T> {
+    /// Creates a new [`DelegatedProver`] which sends executed transactions through the provided
+    /// transport and requires the returned proofs to have at least [`MIN_PROOF_SECURITY_LEVEL`].
+    pub fn new(transport: T) -> Self {
+        Self::with_proof_security_level(transport, MIN_PROOF_SECURITY_LEVEL)
+    }
+
+    /// Creates a new [`DelegatedProver`] which sends executed transactions through the provided
+    /// transport and requires the returned proofs to have at least the specified security level.
+    pub fn with_proof_security_level(transport: T, proof_security_level: u32) -> Self {
+        Self {
+            transport,
+            verifier: TransactionVerifier::new(proof_security_level),
+        }
+    }
+}
+
+impl<T, F, E> TransactionProver for DelegatedProver<T>
+where
+    T: Fn(Vec<u8>) -> F + Send + Sync,
+    F: Future<Output = Result<Vec<u8>, E>> + FutureMaybeSend<Result<Vec<u8>, E>>,
+    E: Error + Send + Sync + 'static,
+{
+    /// Proves the provided [`ExecutedTransaction`] through the transport of this prover.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The executed transaction is inconsistent, see [`validate_executed_transaction`].
+    /// - The transport fails.
+    /// - The bytes returned by the transport are not a valid [`ProvenTransaction`].
+    /// - The ID of the returned proven transaction does not match the ID of the executed
+    ///   transaction.
+    /// - The proof of the returned proven transaction cannot be verified.
+    #[allow(clippy::manual_async_fn)]
+    fn prove(
+        &self,
+        executed_transaction: ExecutedTransaction,
+    ) -> impl FutureMaybeSend<Result<ProvenTransaction, TransactionProverError>> {
+        async move {
+            validate_executed_transaction(&executed_transaction)?;
+
+            let expected_id = executed_transaction.id();
+            let response = (self.transport)(executed_transaction.to_bytes())
+                .await
+                .map_err(|err| TransactionProverError::DelegatedProvingFailed(Box::new(err)))?;
+
+            let proven_transaction = ProvenTransaction::read_from_bytes(&response)
+                .map_err(TransactionProverError::ProvenTransactionDeserializationFailed)?;
+
+            if proven_transaction.id() != expected_id {
+                return Err(TransactionProverError::ProvenTransactionIdMismatch {
+                    expected: expected_id,
+                    actual: proven_transaction.id(),
+                });
+            }
+
+            self.verifier
+                .verify(&proven_transaction)
+                .map_err(TransactionProverError::ProvenTransactionVerificationFailed)?;
+
+            Ok(proven_transaction)
+        }
+    }
+}
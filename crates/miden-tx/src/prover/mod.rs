@@ -1,12 +1,17 @@
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
 use miden_lib::transaction::TransactionKernel;
+use miden_objects::ACCOUNT_UPDATE_MAX_SIZE;
 use miden_objects::account::delta::AccountUpdateDetails;
 use miden_objects::account::{AccountDelta, PartialAccount};
 use miden_objects::asset::Asset;
 use miden_objects::block::BlockNumber;
+use miden_objects::note::NoteId;
 use miden_objects::transaction::{
+    ExecutedTransaction,
     InputNote,
     InputNotes,
     OutputNote,
@@ -15,6 +20,7 @@ use miden_objects::transaction::{
     TransactionInputs,
     TransactionOutputs,
 };
+use miden_processor::FutureMaybeSend;
 pub use miden_prover::ProvingOptions;
 use miden_prover::{ExecutionProof, Word, prove};
 
@@ -27,6 +33,25 @@ pub use prover_host::TransactionProverHost;
 mod mast_store;
 pub use mast_store::TransactionMastStore;
 
+mod delegated;
+pub use delegated::DelegatedProver;
+
+// TRANSACTION PROVER
+// ------------------------------------------------------------------------------------------------
+
+/// Describes a backend which turns [`ExecutedTransaction`]s into [`ProvenTransaction`]s.
+///
+/// This allows code to be generic over where transactions are proven, e.g. locally using
+/// [`LocalTransactionProver`] or by a remote service using [`DelegatedProver`].
+pub trait TransactionProver {
+    /// Proves the provided [`ExecutedTransaction`] and returns the resulting
+    /// [`ProvenTransaction`].
+    fn prove(
+        &self,
+        executed_transaction: ExecutedTransaction,
+    ) -> impl FutureMaybeSend<Result<ProvenTransaction, TransactionProverError>>;
+}
+
 // LOCAL TRANSACTION PROVER
 // ------------------------------------------------------------------------------------------------
 
@@ -34,6 +59,7 @@ pub use mast_store::TransactionMastStore;
 pub struct LocalTransactionProver {
     mast_store: Arc<TransactionMastStore>,
     proof_options: ProvingOptions,
+    account_update_max_size: usize,
 }
 
 impl LocalTransactionProver {
@@ -42,9 +68,29 @@ impl LocalTransactionProver {
         Self {
             mast_store: Arc::new(TransactionMastStore::new()),
             proof_options,
+            account_update_max_size: ACCOUNT_UPDATE_MAX_SIZE as usize,
         }
     }
 
+    /// Checks that the estimated size of the account update of the provided transaction does not
+    /// exceed the maximum account update size, see
+    /// [`ExecutedTransaction::account_update_size_estimate`].
+    fn check_account_update_size(
+        &self,
+        executed_transaction: &ExecutedTransaction,
+    ) -> Result<(), TransactionProverError> {
+        let estimated_size = executed_transaction.account_update_size_estimate();
+        if estimated_size > self.account_update_max_size {
+            return Err(TransactionProverError::AccountUpdateSizeLimitExceeded {
+                account_id: executed_transaction.account_id(),
+                estimated_size,
+                max_size: self.account_update_max_size,
+            });
+        }
+
+        Ok(())
+    }
+
     fn build_proven_transaction(
         &self,
         input_notes: &InputNotes<InputNote>,
@@ -85,7 +131,8 @@ impl LocalTransactionProver {
 
         let builder = match account.has_public_state() {
             true => {
-                let account_update_details = AccountUpdateDetails::Delta(post_fee_account_delta);
+                let account_update_details =
+                    AccountUpdateDetails::Delta(Box::new(post_fee_account_delta));
                 builder.account_update_details(account_update_details)
             },
             false => builder,
@@ -94,12 +141,40 @@ impl LocalTransactionProver {
         builder.build().map_err(TransactionProverError::ProvenTransactionBuildFailed)
     }
 
+    /// Proves the provided [`ExecutedTransaction`] and returns the resulting
+    /// [`ProvenTransaction`].
+    ///
+    /// Before proving, the executed transaction is checked for consistency using
+    /// [`validate_executed_transaction`], so that executed transactions received from untrusted
+    /// sources are rejected early with a specific error. Similarly, transactions whose account
+    /// update would exceed [`ACCOUNT_UPDATE_MAX_SIZE`] are rejected before proving.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The executed transaction is inconsistent, see [`validate_executed_transaction`].
+    /// - The estimated size of the account update exceeds [`ACCOUNT_UPDATE_MAX_SIZE`], see
+    ///   [`ExecutedTransaction::account_update_size_estimate`].
+    /// - Proving the transaction fails.
     pub fn prove(
         &self,
-        tx_inputs: impl Into<TransactionInputs>,
+        executed_transaction: ExecutedTransaction,
     ) -> Result<ProvenTransaction, TransactionProverError> {
-        let tx_inputs = tx_inputs.into();
-        let (stack_inputs, advice_inputs) = TransactionKernel::prepare_inputs(&tx_inputs)
+        validate_executed_transaction(&executed_transaction)?;
+        self.check_account_update_size(&executed_transaction)?;
+        self.prove_inputs(executed_transaction.into())
+    }
+
+    /// Proves the transaction described by the provided [`TransactionInputs`] and returns the
+    /// resulting [`ProvenTransaction`].
+    ///
+    /// Unlike [`Self::prove`], the inputs are not checked for consistency with the outputs of a
+    /// previous execution.
+    pub fn prove_inputs(
+        &self,
+        tx_inputs: TransactionInputs,
+    ) -> Result<ProvenTransaction, TransactionProverError> {
+        let (stack_inputs, advice_inputs) = TransactionKernel::build_inputs(&tx_inputs)
             .map_err(TransactionProverError::ConflictingAdviceMapEntry)?;
 
         self.mast_store.load_account_code(tx_inputs.account().code());
@@ -157,21 +232,157 @@ impl LocalTransactionProver {
     }
 }
 
+impl TransactionProver for LocalTransactionProver {
+    #[allow(clippy::manual_async_fn)]
+    fn prove(
+        &self,
+        executed_transaction: ExecutedTransaction,
+    ) -> impl FutureMaybeSend<Result<ProvenTransaction, TransactionProverError>> {
+        async move { LocalTransactionProver::prove(self, executed_transaction) }
+    }
+}
+
 impl Default for LocalTransactionProver {
     fn default() -> Self {
-        Self {
-            mast_store: Arc::new(TransactionMastStore::new()),
-            proof_options: Default::default(),
+        Self::new(Default::default())
+    }
+}
+
+// VALIDATION
+// ================================================================================================
+
+/// The maximum number of blocks by which the expiration block number of a transaction may exceed
+/// its reference block number, as enforced by the transaction kernel.
+const MAX_EXPIRATION_DELTA: u32 = u16::MAX as u32;
+
+/// Checks that the provided [`ExecutedTransaction`] is internally consistent, without proving it.
+///
+/// This allows rejecting executed transactions received from untrusted sources before spending
+/// resources on proving them. The following is checked:
+/// - The transaction inputs are valid, see [`TransactionInputs::validate`].
+/// - The account delta is for the account against which the transaction was executed.
+/// - The account delta commitment in the transaction outputs matches the account delta.
+/// - The fee is paid in the native asset of the reference block, does not exceed the maximum fee of
+///   the transaction arguments and can be removed from the account delta.
+/// - The nonce delta of the account delta matches the nonces of the initial and final account
+///   states.
+/// - No output note is also consumed as an input note of the transaction, which would make the note
+///   commitments of the transaction contradict each other.
+/// - All output notes were created by the account against which the transaction was executed.
+/// - The expiration block number is either unset (i.e. [`u32::MAX`]) or lies after the reference
+///   block within the maximum expiration delta.
+///
+/// # Errors
+///
+/// Returns an error if any of the above checks fails.
+pub fn validate_executed_transaction(
+    executed_transaction: &ExecutedTransaction,
+) -> Result<(), TransactionProverError> {
+    executed_transaction
+        .tx_inputs()
+        .validate()
+        .map_err(TransactionProverError::InvalidTransactionInputs)?;
+
+    let account_id = executed_transaction.account_id();
+    let account_delta = executed_transaction.account_delta();
+    if account_delta.id() != account_id {
+        return Err(TransactionProverError::InconsistentAccountDeltaId {
+            expected: account_id,
+            actual: account_delta.id(),
+        });
+    }
+
+    let fee = executed_transaction.fee();
+    let native_asset_id = executed_transaction.block_header().fee_parameters().native_asset_id();
+    if fee.faucet_id() != native_asset_id {
+        return Err(TransactionProverError::FeeAssetNotNativeAsset {
+            expected: native_asset_id,
+            actual: fee.faucet_id(),
+        });
+    }
+    if let Some(max_fee) = executed_transaction.tx_args().max_fee()
+        && fee.amount() > max_fee
+    {
+        return Err(TransactionProverError::FeeExceedsLimit { fee: fee.amount(), limit: max_fee });
+    }
+
+    // The account delta of the executed transaction is the post-fee delta, while the kernel
+    // commits to the pre-fee delta, so the fee is added back before comparing the commitments.
+    let mut pre_fee_account_delta = account_delta.clone();
+    pre_fee_account_delta
+        .vault_mut()
+        .add_asset(Asset::from(fee))
+        .map_err(TransactionProverError::AddFeeAssetToDelta)?;
+
+    let output_commitment = executed_transaction.tx_outputs().account_delta_commitment;
+    let delta_commitment = pre_fee_account_delta.to_commitment();
+    if output_commitment != delta_commitment {
+        return Err(TransactionProverError::InconsistentAccountDeltaCommitment {
+            output_commitment,
+            delta_commitment,
+        });
+    }
+
+    let nonce_delta = executed_transaction.final_account().nonce()
+        - executed_transaction.initial_account().nonce();
+    if nonce_delta != account_delta.nonce_delta() {
+        return Err(TransactionProverError::InconsistentAccountNonceDelta {
+            expected: nonce_delta,
+            actual: account_delta.nonce_delta(),
+        });
+    }
+
+    let input_note_ids: BTreeSet<NoteId> =
+        executed_transaction.input_notes().iter().map(|note| note.id()).collect();
+    for output_note in executed_transaction.output_notes().iter() {
+        if input_note_ids.contains(&output_note.id()) {
+            return Err(TransactionProverError::OutputNoteConsumedAsInputNote(output_note.id()));
+        }
+
+        let sender = output_note.metadata().sender();
+        if sender != account_id {
+            return Err(TransactionProverError::OutputNoteSenderMismatch {
+                note_id: output_note.id(),
+                sender,
+                account_id,
+            });
         }
     }
+
+    let ref_block_num = executed_transaction.block_header().block_num();
+    let expiration_block_num = executed_transaction.expiration_block_num();
+    let is_valid_expiration = expiration_block_num.as_u32() == u32::MAX
+        || expiration_block_num
+            .as_u32()
+            .checked_sub(ref_block_num.as_u32())
+            .is_some_and(|delta| (1..=MAX_EXPIRATION_DELTA).contains(&delta));
+    if !is_valid_expiration {
+        return Err(TransactionProverError::InvalidExpirationBlockNum {
+            ref_block_num,
+            expiration_block_num,
+        });
+    }
+
+    Ok(())
 }
 
 #[cfg(any(feature = "testing", test))]
 impl LocalTransactionProver {
+    /// Overrides the maximum size of account updates, which defaults to
+    /// [`ACCOUNT_UPDATE_MAX_SIZE`].
+    ///
+    /// This allows testing the account update size check without creating large transactions.
+    pub fn with_account_update_max_size(mut self, account_update_max_size: usize) -> Self {
+        self.account_update_max_size = account_update_max_size;
+        self
+    }
+
     pub fn prove_dummy(
         &self,
         executed_transaction: miden_objects::transaction::ExecutedTransaction,
     ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.check_account_update_size(&executed_transaction)?;
+
         let (tx_inputs, tx_outputs, account_delta, _) = executed_transaction.into_parts();
 
         let (partial_account, ref_block, _, input_notes, _) = tx_inputs.into_parts();
@@ -1,7 +1,7 @@
 use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use miden_lib::transaction::EventId;
+use miden_lib::transaction::{ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE, EventId};
 use miden_objects::Word;
 use miden_objects::account::{AccountDelta, PartialAccount};
 use miden_objects::assembly::debuginfo::Location;
@@ -10,7 +10,10 @@ use miden_objects::transaction::{InputNote, InputNotes, OutputNote};
 use miden_processor::{
     AdviceMutation,
     BaseHost,
+    DebugHandler,
+    DefaultDebugHandler,
     EventError,
+    ExecutionError,
     MastForest,
     MastForestStore,
     ProcessState,
@@ -74,7 +77,9 @@ where
     pub fn into_parts(
         self,
     ) -> (AccountDelta, InputNotes<InputNote>, Vec<OutputNote>, TransactionProgress) {
-        self.base_host.into_parts()
+        let (account_delta, input_notes, output_notes, tx_progress, _) =
+            self.base_host.into_parts();
+        (account_delta, input_notes, output_notes, tx_progress)
     }
 }
 
@@ -94,6 +99,19 @@ where
         // is only used to improve error message quality which we shouldn't run into here.
         (SourceSpan::UNKNOWN, None)
     }
+
+    fn on_trace(
+        &mut self,
+        process: &mut ProcessState,
+        trace_id: u32,
+    ) -> Result<(), ExecutionError> {
+        // Storage accesses are only recorded during execution.
+        if trace_id == ACCOUNT_STORAGE_AFTER_GET_ITEM_TRACE {
+            return Ok(());
+        }
+
+        DefaultDebugHandler.on_trace(process, trace_id)
+    }
 }
 
 impl<STORE> SyncHost for TransactionProverHost<'_, STORE>
@@ -130,6 +148,9 @@ where
                     // improves error messages for users and the error should not be relevant during
                     // proving, we ignore it.
                     TransactionEventData::TransactionFeeComputed { .. } => Ok(Vec::new()),
+                    // Custom events are only recorded by the executor and have no effect on the
+                    // proof, so there is nothing to do.
+                    TransactionEventData::CustomEvent { .. } => Ok(Vec::new()),
                 }
             },
             TransactionEventHandling::Handled(mutations) => Ok(mutations),